@@ -0,0 +1,197 @@
+//! Procedural macros for [`generic-upper-bound`](https://docs.rs/generic-upper-bound).
+//!
+//! Use them through the `macros` feature of that crate instead of depending on this crate directly.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse::Parser, parse_macro_input, spanned::Spanned, Error, Expr, GenericParam,
+    Ident, ItemFn, Path, ReturnType, Token,
+};
+
+/// Turns a generic `const fn` into an upper bound acceptor.
+///
+/// The last generic parameter of the function must be a `const` parameter of type `usize`. It
+/// receives the upper bound, and the body of the function becomes the
+/// [`EVAL`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/macro.impl_accept_upper_bound.html)
+/// expression. The function itself is replaced with a zero-sized struct of the same name that is
+/// generic over the remaining parameters and implements `AcceptUpperBound`.
+///
+/// The attribute takes the following arguments:
+/// - `desired = EXPR` (required): The value of `DESIRED_GENERIC`.
+/// - `ladder = EXPR`: The value of `LADDER`.
+/// - `crate = PATH`: The path of the `generic_upper_bound` crate, if it was renamed.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// pub trait MyTrait {
+///     const SOME_STR: &'static str;
+/// }
+/// /// Concatenates `A::SOME_STR` and `B::SOME_STR`.
+/// #[gub::acceptor(desired = A::SOME_STR.len() + B::SOME_STR.len(), crate = gub)]
+/// pub const fn Concat<A: MyTrait, B: MyTrait, const UPPER: usize>() -> &'static [u8] {
+///     &gub::build::BytesWriter::<UPPER>::new()
+///         .write_str(A::SOME_STR)
+///         .write_str(B::SOME_STR)
+///         .finish()
+///         .0
+/// }
+/// impl MyTrait for () {
+///     const SOME_STR: &'static str = "ABC";
+/// }
+/// impl MyTrait for i32 {
+///     const SOME_STR: &'static str = "123";
+/// }
+/// assert_eq!(gub::eval_str::<Concat<(), i32>>(), "ABC123");
+/// ```
+///
+/// The function must not take any arguments:
+/// ```compile_fail
+/// #[generic_upper_bound::acceptor(desired = 1)]
+/// const fn Bad<const UPPER: usize>(x: usize) -> usize {
+///     x
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn acceptor(
+    args: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(item as ItemFn);
+    Args::parse
+        .parse(args)
+        .and_then(|args| expand(args, item))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct Args {
+    desired: Option<Expr>,
+    ladder: Option<Expr>,
+    krate: Option<Path>,
+}
+
+impl Args {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = Self {
+            desired: None,
+            ladder: None,
+            krate: None,
+        };
+        while !input.is_empty() {
+            let name = Ident::parse_any(input)?;
+            input.parse::<Token![=]>()?;
+            let duplicate = match name.to_string().as_str() {
+                "desired" => args.desired.replace(input.parse()?).is_some(),
+                "ladder" => args.ladder.replace(input.parse()?).is_some(),
+                "crate" => args.krate.replace(input.parse()?).is_some(),
+                _ => return Err(Error::new(name.span(), "unknown argument")),
+            };
+            if duplicate {
+                return Err(Error::new(name.span(), "duplicate argument"));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+fn expand(args: Args, item: ItemFn) -> syn::Result<TokenStream> {
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+    let Some(desired) = args.desired else {
+        return Err(Error::new(Span::call_site(), "missing argument `desired`"));
+    };
+    let krate = args
+        .krate
+        .map_or_else(|| quote!(::generic_upper_bound), |krate| quote!(#krate));
+    let ladder = args
+        .ladder
+        .map(|ladder| quote!(const LADDER: #krate::Ladder = #ladder;));
+
+    if sig.constness.is_none() {
+        return Err(Error::new(
+            sig.fn_token.span,
+            "acceptors must be `const fn`",
+        ));
+    }
+    if let Some(token) = sig
+        .asyncness
+        .as_ref()
+        .map(Spanned::span)
+        .or(sig.unsafety.map(|u| u.span))
+    {
+        return Err(Error::new(token, "acceptors cannot be `async` or `unsafe`"));
+    }
+    if !sig.inputs.is_empty() || sig.variadic.is_some() {
+        return Err(Error::new(
+            sig.inputs.span(),
+            "acceptors cannot take arguments",
+        ));
+    }
+    let ReturnType::Type(_, output) = sig.output else {
+        return Err(Error::new(
+            sig.ident.span(),
+            "acceptors must have a return type",
+        ));
+    };
+
+    let mut generics = sig.generics;
+    let upper = match generics.params.pop().map(|pair| pair.into_value()) {
+        Some(GenericParam::Const(upper)) => upper,
+        _ => return Err(Error::new(
+            generics.span(),
+            "the last generic parameter must be the `const` parameter receiving the upper bound",
+        )),
+    };
+    let (upper, upper_ty) = (upper.ident, upper.ty);
+
+    let name = sig.ident;
+    let where_clause = generics.where_clause.take();
+    let mut impl_params = generics.params.clone();
+    let mut type_args = Vec::new();
+    let mut phantom = Vec::new();
+    for param in &mut impl_params {
+        match param {
+            GenericParam::Lifetime(lt) => {
+                let lt = &lt.lifetime;
+                type_args.push(quote!(#lt));
+                phantom.push(quote!(&#lt ()));
+            }
+            GenericParam::Type(ty) => {
+                ty.eq_token = None;
+                ty.default = None;
+                let ident = &ty.ident;
+                type_args.push(quote!(#ident));
+                phantom.push(quote!(::core::marker::PhantomData<#ident>));
+            }
+            GenericParam::Const(cn) => {
+                cn.eq_token = None;
+                cn.default = None;
+                let ident = &cn.ident;
+                type_args.push(quote!(#ident));
+            }
+        }
+    }
+    let struct_params = generics.params;
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis struct #name<#struct_params>(
+            ::core::marker::PhantomData<fn() -> (#(#phantom,)*)>
+        ) #where_clause;
+        #krate::impl_accept_upper_bound! {
+            impl{#impl_params} #name<#(#type_args),*> #where_clause;
+
+            const DESIRED_GENERIC: usize = #desired;
+            #ladder
+            const EVAL<const #upper: #upper_ty>: #output = #block;
+        }
+    })
+}
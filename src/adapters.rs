@@ -0,0 +1,3014 @@
+//! Ready-made acceptors for common string, byte and slice computations.
+//!
+//! The parameters of the adapters are types that implement [`Const`]. The examples below pass
+//! plain values using [`const_str!`](crate::const_str), [`const_bytes!`](crate::const_bytes) and
+//! the [`consts`](crate::consts) module. To use an adapter on a value that depends on generic
+//! parameters, such as an associated const of a trait, implement [`Const`] for a type that forwards
+//! the value:
+//!
+//! ```
+//! use core::marker::PhantomData;
+//! use generic_upper_bound as gub;
+//! gub::const_str! {
+//!     struct Comma = ", ";
+//! }
+//! trait Fields {
+//!     const FIELDS: &'static [&'static str];
+//!     const FIELD_LIST: &'static str = gub::adapters::join::<Comma, FieldsOf<Self>>();
+//! }
+//! struct FieldsOf<T: ?Sized>(PhantomData<T>);
+//! impl<T: Fields + ?Sized> gub::Const for FieldsOf<T> {
+//!     type Type = &'static [&'static str];
+//!     const VALUE: Self::Type = T::FIELDS;
+//! }
+//! struct Point;
+//! impl Fields for Point {
+//!     const FIELDS: &'static [&'static str] = &["x", "y", "z"];
+//! }
+//! assert_eq!(Point::FIELD_LIST, "x, y, z");
+//! ```
+
+use core::{cmp::Ordering, ffi::CStr};
+
+use crate::{
+    build::{BytesWriter, Span},
+    eval_cstr, eval_str, eval_trimmed, AcceptUpperBound, Const, Ladder,
+};
+
+/// Concatenates the strings in `L::VALUE`.
+///
+/// The output is padded with zeros. Use [`concat_strs`] to get the trimmed string.
+pub struct ConcatStrs<L>(L);
+crate::impl_accept_upper_bound! {
+    impl{L: Const<Type = &'static [&'static str]>} ConcatStrs<L>;
+
+    const DESIRED_GENERIC: usize = crate::str::total_len(crate::const_value::<L>());
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let strs = crate::const_value::<L>();
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < strs.len() {
+            w = w.extend_from_slice(strs[i].as_bytes());
+            i += 1;
+        }
+        w.finish().0
+    };
+}
+
+/// Concatenates the strings in `L::VALUE` at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// trait Named {
+///     const NAME: &'static str;
+/// }
+/// struct Path<A, B>(A, B);
+/// impl<A: Named, B: Named> gub::Const for Path<A, B> {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &[A::NAME, "::", B::NAME];
+/// }
+/// impl<A: Named, B: Named> Named for (A, B) {
+///     const NAME: &'static str = gub::adapters::concat_strs::<Path<A, B>>();
+/// }
+/// impl Named for u8 {
+///     const NAME: &'static str = "u8";
+/// }
+/// impl Named for () {
+///     const NAME: &'static str = "unit";
+/// }
+/// assert_eq!(<(u8, ((), u8))>::NAME, "u8::unit::u8");
+/// ```
+pub const fn concat_strs<L: Const<Type = &'static [&'static str]>>() -> &'static str {
+    eval_str::<ConcatStrs<L>>()
+}
+
+/// Joins the strings in `L::VALUE`, separated by `Sep::VALUE`.
+///
+/// The output is padded with zeros. Use [`join`] to get the trimmed string.
+pub struct Join<Sep, L>(Sep, L);
+crate::impl_accept_upper_bound! {
+    impl{Sep: Const<Type = &'static str>, L: Const<Type = &'static [&'static str]>} Join<Sep, L>;
+
+    const DESIRED_GENERIC: usize = {
+        let strs = crate::const_value::<L>();
+        let seps = strs.len().saturating_sub(1);
+        crate::str::total_len(strs) + seps * crate::const_value::<Sep>().len()
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let strs = crate::const_value::<L>();
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < strs.len() {
+            if i != 0 {
+                w = w.extend_from_slice(crate::const_value::<Sep>().as_bytes());
+            }
+            w = w.extend_from_slice(strs[i].as_bytes());
+            i += 1;
+        }
+        w.finish().0
+    };
+}
+
+/// Joins the strings in `L::VALUE`, separated by `Sep::VALUE`, at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// gub::const_str! {
+///     struct Comma = ", ";
+/// }
+/// struct Fields;
+/// impl gub::Const for Fields {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["x", "y", "z"];
+/// }
+/// struct NoFields;
+/// impl gub::Const for NoFields {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &[];
+/// }
+/// assert_eq!(gub::adapters::join::<Comma, Fields>(), "x, y, z");
+/// assert_eq!(gub::adapters::join::<Comma, NoFields>(), "");
+/// ```
+pub const fn join<Sep: Const<Type = &'static str>, L: Const<Type = &'static [&'static str]>>(
+) -> &'static str {
+    eval_str::<Join<Sep, L>>()
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack` at or after `start`.
+const fn find_bytes(haystack: &[u8], needle: &[u8], start: usize) -> Option<usize> {
+    if start > haystack.len() {
+        return None;
+    }
+    match crate::slice::find(haystack.split_at(start).1, needle) {
+        Some(i) => Some(start + i),
+        None => None,
+    }
+}
+
+/// Returns `s[start..end]`, which must lie on character boundaries.
+const fn substr(s: &'static str, start: usize, end: usize) -> &'static str {
+    // `konst` only checks the boundaries instead of validating the whole piece
+    #[cfg(feature = "konst")]
+    let piece = konst::string::get_range(s, start, end);
+    #[cfg(not(feature = "konst"))]
+    let piece = match core::str::from_utf8(s.as_bytes().split_at(end).0.split_at(start).1) {
+        Ok(piece) => Some(piece),
+        Err(_) => None,
+    };
+    match piece {
+        Some(piece) => piece,
+        None => panic!("the split is not on a character boundary"),
+    }
+}
+
+/// Splits the string `S::VALUE` by the separator `Sep::VALUE`, like [`str::split`].
+///
+/// Evaluation panics if the separator is empty. The desired value is the number of pieces,
+/// which is one more than the number of non-overlapping occurrences of the separator. The
+/// output is padded with empty strings. Use [`split`] to get the trimmed output.
+pub struct Split<S, Sep>(S, Sep);
+crate::impl_accept_upper_bound! {
+    impl{S: Const<Type = &'static str>, Sep: Const<Type = &'static str>} Split<S, Sep>;
+
+    const DESIRED_GENERIC: usize = {
+        let (s, sep) = (crate::const_value::<S>().as_bytes(), crate::const_value::<Sep>().as_bytes());
+        if sep.is_empty() {
+            panic!("the separator is empty");
+        }
+        let mut count = 1;
+        let mut start = 0;
+        while let Some(i) = find_bytes(s, sep, start) {
+            count += 1;
+            start = i + sep.len();
+        }
+        count
+    };
+
+    const EVAL<const UPPER: usize>: &'static [&'static str] = &{
+        let (s, sep) = (crate::const_value::<S>(), crate::const_value::<Sep>().as_bytes());
+        let mut out = [""; UPPER];
+        let mut count = 0;
+        let mut start = 0;
+        while let Some(i) = find_bytes(s.as_bytes(), sep, start) {
+            out[count] = substr(s, start, i);
+            count += 1;
+            start = i + sep.len();
+        }
+        out[count] = substr(s, start, s.len());
+        out
+    };
+}
+
+/// Splits the string `S::VALUE` by the separator `Sep::VALUE` at compile time.
+///
+/// # Panics
+/// If the separator is empty. This check happens during const evaluation, so it results in a
+/// compile error even if this function is called at runtime.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// gub::const_str! {
+///     struct Features = "std,alloc,,serde";
+///     struct NoFeatures = "";
+///     struct Comma = ",";
+/// }
+/// assert_eq!(gub::adapters::split::<Features, Comma>(), ["std", "alloc", "", "serde"]);
+/// assert_eq!(gub::adapters::split::<NoFeatures, Comma>(), [""]);
+/// ```
+pub const fn split<S: Const<Type = &'static str>, Sep: Const<Type = &'static str>>(
+) -> &'static [&'static str] {
+    eval_trimmed::<Split<S, Sep>, &'static str>()
+}
+
+/// Replaces all non-overlapping occurrences of `From::VALUE` in the string `S::VALUE` with
+/// `To::VALUE`, like [`str::replace`].
+///
+/// Evaluation panics if `From::VALUE` is empty. The desired value is the exact length of the
+/// output. The output is padded with zeros. Use [`replace`] to get the trimmed string.
+///
+/// [`str::replace`]: https://doc.rust-lang.org/std/primitive.str.html#method.replace
+pub struct Replace<S, From, To>(S, From, To);
+crate::impl_accept_upper_bound! {
+    impl{
+        S: Const<Type = &'static str>,
+        From: Const<Type = &'static str>,
+        To: Const<Type = &'static str>,
+    } Replace<S, From, To>;
+
+    const DESIRED_GENERIC: usize = {
+        let (s, from) = (crate::const_value::<S>().as_bytes(), crate::const_value::<From>().as_bytes());
+        if from.is_empty() {
+            panic!("the pattern is empty");
+        }
+        let mut count = 0;
+        let mut start = 0;
+        while let Some(i) = find_bytes(s, from, start) {
+            count += 1;
+            start = i + from.len();
+        }
+        s.len() - count * from.len() + count * crate::const_value::<To>().len()
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let (s, from) = (crate::const_value::<S>().as_bytes(), crate::const_value::<From>().as_bytes());
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut start = 0;
+        while let Some(i) = find_bytes(s, from, start) {
+            w = w.extend_from_slice(s.split_at(i).0.split_at(start).1);
+            w = w.write_str(crate::const_value::<To>());
+            start = i + from.len();
+        }
+        w.extend_from_slice(s.split_at(start).1).finish().0
+    };
+}
+
+/// Replaces all occurrences of `From::VALUE` in the string `S::VALUE` with `To::VALUE` at
+/// compile time.
+///
+/// # Panics
+/// If `From::VALUE` is empty. This check happens during const evaluation, so it results in a
+/// compile error even if this function is called at runtime.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// gub::const_str! {
+///     struct Template = "{}: enable {} before use";
+///     struct Placeholder = "{}";
+///     struct Name = "uart0";
+/// }
+/// assert_eq!(
+///     gub::adapters::replace::<Template, Placeholder, Name>(),
+///     "uart0: enable uart0 before use",
+/// );
+/// ```
+pub const fn replace<
+    S: Const<Type = &'static str>,
+    From: Const<Type = &'static str>,
+    To: Const<Type = &'static str>,
+>() -> &'static str {
+    eval_str::<Replace<S, From, To>>()
+}
+
+/// A [`Const`] with the bytes of the string `S::VALUE`.
+pub struct AsBytes<S>(S);
+impl<S: Const<Type = &'static str>> Const for AsBytes<S> {
+    type Type = &'static [u8];
+    const VALUE: Self::Type = crate::const_value::<S>().as_bytes();
+}
+
+/// Repeats the bytes `S::VALUE` `N::VALUE` times.
+///
+/// The output is padded with zeros. Use [`repeat_bytes`] or [`repeat_str`] to get the trimmed
+/// output.
+pub struct Repeat<S, N>(S, N);
+crate::impl_accept_upper_bound! {
+    impl{S: Const<Type = &'static [u8]>, N: Const<Type = usize>} Repeat<S, N>;
+
+    const DESIRED_GENERIC: usize = crate::const_value::<S>().len() * crate::const_value::<N>();
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < crate::const_value::<N>() {
+            w = w.extend_from_slice(crate::const_value::<S>());
+            i += 1;
+        }
+        w.finish().0
+    };
+}
+
+/// Repeats the bytes `S::VALUE` `N::VALUE` times at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::consts::ConstUsize;
+/// gub::const_bytes! {
+///     struct Pattern = &[0xde, 0xad];
+/// }
+/// assert_eq!(
+///     gub::adapters::repeat_bytes::<Pattern, ConstUsize<3>>(),
+///     [0xde, 0xad, 0xde, 0xad, 0xde, 0xad],
+/// );
+/// ```
+pub const fn repeat_bytes<S: Const<Type = &'static [u8]>, N: Const<Type = usize>>() -> &'static [u8]
+{
+    eval_trimmed::<Repeat<S, N>, u8>()
+}
+
+/// Repeats the string `S::VALUE` `N::VALUE` times at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::consts::ConstUsize;
+/// gub::const_str! {
+///     struct Dash = "-";
+/// }
+/// assert_eq!(gub::adapters::repeat_str::<Dash, ConstUsize<5>>(), "-----");
+/// ```
+pub const fn repeat_str<S: Const<Type = &'static str>, N: Const<Type = usize>>() -> &'static str {
+    eval_str::<Repeat<AsBytes<S>, N>>()
+}
+
+/// Concatenates the byte strings in `L::VALUE` and appends a NUL terminator.
+///
+/// The output is padded with zeros. Use [`concat_cstr`] to get the trimmed C string.
+///
+/// # Panics
+/// Evaluation panics if any of the byte strings contains a NUL byte.
+pub struct ConcatCStr<L>(L);
+crate::impl_accept_upper_bound! {
+    impl{L: Const<Type = &'static [&'static [u8]]>} ConcatCStr<L>;
+
+    const DESIRED_GENERIC: usize = {
+        let parts = crate::const_value::<L>();
+        let mut len = 1;
+        let mut i = 0;
+        while i < parts.len() {
+            len += parts[i].len();
+            i += 1;
+        }
+        len
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let parts = crate::const_value::<L>();
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < parts.len() {
+            let mut j = 0;
+            while j < parts[i].len() {
+                assert!(parts[i][j] != 0, "C string parts cannot contain NUL bytes");
+                j += 1;
+            }
+            w = w.extend_from_slice(parts[i]);
+            i += 1;
+        }
+        // the NUL terminator is already there, since the buffer is zero-initialized
+        w.finish().0
+    };
+}
+
+/// Concatenates the byte strings in `L::VALUE` into a C string at compile time.
+///
+/// # Panics
+/// If any of the byte strings contains a NUL byte. This check happens during const evaluation,
+/// so it results in a compile error even if this function is called at runtime.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Symbol;
+/// impl gub::Const for Symbol {
+///     type Type = &'static [&'static [u8]];
+///     const VALUE: Self::Type = &[b"mylib_", b"init"];
+/// }
+/// let name = gub::adapters::concat_cstr::<Symbol>();
+/// assert_eq!(name.to_bytes_with_nul(), b"mylib_init\0");
+/// ```
+/// ```compile_fail
+/// # use generic_upper_bound as gub;
+/// struct Parts;
+/// impl gub::Const for Parts {
+///     type Type = &'static [&'static [u8]];
+///     const VALUE: Self::Type = &[b"a\0b"];
+/// }
+/// let _ = gub::adapters::concat_cstr::<Parts>();
+/// ```
+pub const fn concat_cstr<L: Const<Type = &'static [&'static [u8]]>>() -> &'static CStr {
+    eval_cstr::<ConcatCStr<L>>()
+}
+
+/// Encodes the string `S::VALUE` as UTF-16 and appends a NUL terminator.
+///
+/// The desired value is the exact number of UTF-16 code units, including the terminator, which
+/// is at most `S::VALUE.len() + 1`. The output is padded with zeros. Use [`utf16`] to get the
+/// trimmed output.
+pub struct Utf16<S>(S);
+crate::impl_accept_upper_bound! {
+    impl{S: Const<Type = &'static str>} Utf16<S>;
+
+    const DESIRED_GENERIC: usize = {
+        let bytes = crate::const_value::<S>().as_bytes();
+        let mut len = 1;
+        let mut i = 0;
+        while i < bytes.len() {
+            // one code unit per char, two for chars outside of the BMP
+            if bytes[i] & 0xc0 != 0x80 {
+                len += 1;
+            }
+            if bytes[i] >= 0xf0 {
+                len += 1;
+            }
+            i += 1;
+        }
+        len
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u16] = &{
+        let bytes = crate::const_value::<S>().as_bytes();
+        let mut out = [0; UPPER];
+        let mut off = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            // decode the char starting at `i`, which is valid UTF-8
+            let (c, len) = match bytes[i] {
+                b @ 0x00..=0x7f => (b as u32, 1),
+                b @ 0xc0..=0xdf => ((b as u32 & 0x1f) << 6 | (bytes[i + 1] as u32 & 0x3f), 2),
+                b @ 0xe0..=0xef => (
+                    (b as u32 & 0x0f) << 12
+                        | (bytes[i + 1] as u32 & 0x3f) << 6
+                        | (bytes[i + 2] as u32 & 0x3f),
+                    3,
+                ),
+                b => (
+                    (b as u32 & 0x07) << 18
+                        | (bytes[i + 1] as u32 & 0x3f) << 12
+                        | (bytes[i + 2] as u32 & 0x3f) << 6
+                        | (bytes[i + 3] as u32 & 0x3f),
+                    4,
+                ),
+            };
+            if c < 0x10000 {
+                out[off] = c as u16;
+                off += 1;
+            } else {
+                let c = c - 0x10000;
+                out[off] = 0xd800 | (c >> 10) as u16;
+                out[off + 1] = 0xdc00 | (c & 0x3ff) as u16;
+                off += 2;
+            }
+            i += len;
+        }
+        out
+    };
+}
+
+/// Encodes the string `S::VALUE` as a NUL-terminated UTF-16 string at compile time.
+///
+/// The string is not checked for interior NUL characters.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Title;
+/// impl gub::Const for Title {
+///     type Type = &'static str;
+///     const VALUE: Self::Type = "Grüße 🦀";
+/// }
+/// let expected: Vec<u16> = "Grüße 🦀\0".encode_utf16().collect();
+/// assert_eq!(gub::adapters::utf16::<Title>(), expected);
+/// ```
+pub const fn utf16<S: Const<Type = &'static str>>() -> &'static [u16] {
+    eval_trimmed::<Utf16<S>, u16>()
+}
+
+/// Returns the length of the valid prefix of `bytes` and the number of invalid bytes after it,
+/// like [`Utf8Error::valid_up_to`](core::str::Utf8Error::valid_up_to) and
+/// [`Utf8Error::error_len`](core::str::Utf8Error::error_len).
+const fn next_utf8_error(bytes: &[u8]) -> (usize, usize) {
+    match core::str::from_utf8(bytes) {
+        Ok(_) => (bytes.len(), 0),
+        Err(err) => match err.error_len() {
+            Some(len) => (err.valid_up_to(), len),
+            None => (err.valid_up_to(), bytes.len() - err.valid_up_to()),
+        },
+    }
+}
+
+/// Converts the bytes `B::VALUE` to a string, replacing invalid UTF-8 sequences with
+/// [`char::REPLACEMENT_CHARACTER`], like [`String::from_utf8_lossy`].
+///
+/// The desired value is the exact length of the output, which is at most three times the
+/// length of the input. The output is padded with zeros. Use [`utf8_lossy`] to get the trimmed
+/// string.
+///
+/// [`String::from_utf8_lossy`]: https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy
+pub struct Utf8Lossy<B>(B);
+crate::impl_accept_upper_bound! {
+    impl{B: Const<Type = &'static [u8]>} Utf8Lossy<B>;
+
+    const DESIRED_GENERIC: usize = {
+        let mut rest = crate::const_value::<B>();
+        let mut len = 0;
+        while !rest.is_empty() {
+            let (valid, invalid) = next_utf8_error(rest);
+            len += valid;
+            if invalid != 0 {
+                len += char::REPLACEMENT_CHARACTER.len_utf8();
+            }
+            rest = rest.split_at(valid + invalid).1;
+        }
+        len
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let mut rest = crate::const_value::<B>();
+        let mut w = BytesWriter::<UPPER>::new();
+        while !rest.is_empty() {
+            let (valid, invalid) = next_utf8_error(rest);
+            w = w.extend_from_slice(rest.split_at(valid).0);
+            if invalid != 0 {
+                w = w.write_str("\u{fffd}");
+            }
+            rest = rest.split_at(valid + invalid).1;
+        }
+        w.finish().0
+    };
+}
+
+/// Converts the bytes `B::VALUE` to a string at compile time, replacing invalid UTF-8
+/// sequences with [`char::REPLACEMENT_CHARACTER`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// gub::const_bytes! {
+///     struct RawName = b"temp\xff\xfe sensor \xe2\x82";
+/// }
+/// let name = gub::adapters::utf8_lossy::<RawName>();
+/// assert_eq!(name, "temp\u{fffd}\u{fffd} sensor \u{fffd}");
+/// assert_eq!(name, String::from_utf8_lossy(gub::const_value::<RawName>()));
+/// ```
+pub const fn utf8_lossy<B: Const<Type = &'static [u8]>>() -> &'static str {
+    eval_str::<Utf8Lossy<B>>()
+}
+
+/// Encodes the bytes `B::VALUE` as hex digits, in uppercase if `UPPERCASE` is `true`.
+///
+/// The output is padded with zeros. Use [`hex_encode`] or [`hex_encode_upper`] to get the
+/// trimmed string.
+pub struct HexEncode<B, const UPPERCASE: bool = false>(B);
+crate::impl_accept_upper_bound! {
+    impl{B: Const<Type = &'static [u8]>, const UPPERCASE: bool} HexEncode<B, UPPERCASE>;
+
+    const DESIRED_GENERIC: usize = 2 * crate::const_value::<B>().len();
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let digits = if UPPERCASE {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+        let bytes = crate::const_value::<B>();
+        let mut out = [0; UPPER];
+        let mut i = 0;
+        while i < bytes.len() {
+            out[2 * i] = digits[(bytes[i] >> 4) as usize];
+            out[2 * i + 1] = digits[(bytes[i] & 0xf) as usize];
+            i += 1;
+        }
+        out
+    };
+}
+
+/// Encodes the bytes `B::VALUE` as lowercase hex digits at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Id;
+/// impl gub::Const for Id {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = &[0xde, 0xad, 0xbe, 0xef];
+/// }
+/// assert_eq!(gub::adapters::hex_encode::<Id>(), "deadbeef");
+/// assert_eq!(gub::adapters::hex_encode_upper::<Id>(), "DEADBEEF");
+/// ```
+pub const fn hex_encode<B: Const<Type = &'static [u8]>>() -> &'static str {
+    eval_str::<HexEncode<B>>()
+}
+
+/// Encodes the bytes `B::VALUE` as uppercase hex digits at compile time.
+///
+/// See [`hex_encode`] for an example.
+pub const fn hex_encode_upper<B: Const<Type = &'static [u8]>>() -> &'static str {
+    eval_str::<HexEncode<B, true>>()
+}
+
+/// Converts the string `S::VALUE` to ASCII uppercase if `UPPERCASE` is `true` and to ASCII
+/// lowercase otherwise. Non-ASCII characters are unchanged.
+///
+/// The output is padded with zeros. Use [`to_ascii_uppercase`] or [`to_ascii_lowercase`] to get
+/// the trimmed string.
+pub struct ToAsciiCase<S, const UPPERCASE: bool>(S);
+crate::impl_accept_upper_bound! {
+    impl{S: Const<Type = &'static str>, const UPPERCASE: bool} ToAsciiCase<S, UPPERCASE>;
+
+    const DESIRED_GENERIC: usize = crate::const_value::<S>().len();
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let bytes = crate::const_value::<S>().as_bytes();
+        let mut out = [0; UPPER];
+        let mut i = 0;
+        while i < bytes.len() {
+            out[i] = if UPPERCASE {
+                bytes[i].to_ascii_uppercase()
+            } else {
+                bytes[i].to_ascii_lowercase()
+            };
+            i += 1;
+        }
+        out
+    };
+}
+
+/// Converts the string `S::VALUE` to ASCII uppercase.
+pub type ToAsciiUppercase<S> = ToAsciiCase<S, true>;
+
+/// Converts the string `S::VALUE` to ASCII lowercase.
+pub type ToAsciiLowercase<S> = ToAsciiCase<S, false>;
+
+/// Converts the string `S::VALUE` to ASCII uppercase at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// gub::const_str! {
+///     struct Setting = "log_level";
+/// }
+/// assert_eq!(gub::adapters::to_ascii_uppercase::<Setting>(), "LOG_LEVEL");
+/// ```
+pub const fn to_ascii_uppercase<S: Const<Type = &'static str>>() -> &'static str {
+    eval_str::<ToAsciiUppercase<S>>()
+}
+
+/// Converts the string `S::VALUE` to ASCII lowercase at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Header;
+/// impl gub::Const for Header {
+///     type Type = &'static str;
+///     const VALUE: Self::Type = "Content-Type";
+/// }
+/// assert_eq!(gub::adapters::to_ascii_lowercase::<Header>(), "content-type");
+/// ```
+pub const fn to_ascii_lowercase<S: Const<Type = &'static str>>() -> &'static str {
+    eval_str::<ToAsciiLowercase<S>>()
+}
+
+/// Reverses the bytes `B::VALUE`.
+///
+/// If `CHARS` is `true`, the input is treated as UTF-8 and the order of the characters is
+/// reversed instead, keeping the bytes of each character in order. The output is padded with
+/// zeros. Use [`reverse_bytes`] or [`reverse_str`] to get the trimmed output.
+pub struct Reverse<B, const CHARS: bool = false>(B);
+crate::impl_accept_upper_bound! {
+    impl{B: Const<Type = &'static [u8]>, const CHARS: bool} Reverse<B, CHARS>;
+
+    const DESIRED_GENERIC: usize = crate::const_value::<B>().len();
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let bytes = crate::const_value::<B>();
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut end = bytes.len();
+        while end > 0 {
+            let mut start = end - 1;
+            // move to the first byte of the character, skipping continuation bytes
+            while CHARS && start > 0 && bytes[start] & 0xc0 == 0x80 {
+                start -= 1;
+            }
+            let mut i = start;
+            while i < end {
+                w = w.push(bytes[i]);
+                i += 1;
+            }
+            end = start;
+        }
+        w.finish().0
+    };
+}
+
+/// Reverses the bytes `B::VALUE` at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Magic;
+/// impl gub::Const for Magic {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = &[0x7f, b'E', b'L', b'F'];
+/// }
+/// assert_eq!(gub::adapters::reverse_bytes::<Magic>(), b"FLE\x7f");
+/// ```
+pub const fn reverse_bytes<B: Const<Type = &'static [u8]>>() -> &'static [u8] {
+    eval_trimmed::<Reverse<B>, u8>()
+}
+
+/// Reverses the characters of the string `S::VALUE` at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// gub::const_str! {
+///     struct Domain = "café.example";
+/// }
+/// assert_eq!(gub::adapters::reverse_str::<Domain>(), "elpmaxe.éfac");
+/// ```
+pub const fn reverse_str<S: Const<Type = &'static str>>() -> &'static str {
+    eval_str::<Reverse<AsBytes<S>, true>>()
+}
+
+/// Where [`Pad`] places the string within the padded output.
+///
+/// This trait is sealed and implemented for [`AlignLeft`], [`AlignRight`] and [`AlignCenter`].
+pub trait PadAlign: crate::sealed::Sealed {
+    #[doc(hidden)]
+    const __KIND: pad_kind::PadKind;
+}
+
+mod pad_kind {
+    #[derive(Clone, Copy)]
+    pub enum PadKind {
+        Left,
+        Right,
+        Center,
+    }
+}
+use pad_kind::PadKind;
+
+/// Places the string at the start of the output, with the padding after it.
+pub struct AlignLeft;
+/// Places the string at the end of the output, with the padding before it.
+pub struct AlignRight;
+/// Places the string in the middle of the output. If the padding cannot be split evenly, the
+/// extra fill character goes after the string, like `format!("{:^5}", "ab")`.
+pub struct AlignCenter;
+
+macro_rules! impl_pad_align {
+    ($($Align:ident $kind:ident)*) => {$(
+        impl crate::sealed::Sealed for $Align {}
+        impl PadAlign for $Align {
+            #[doc(hidden)]
+            const __KIND: PadKind = PadKind::$kind;
+        }
+    )*};
+}
+impl_pad_align! {
+    AlignLeft Left
+    AlignRight Right
+    AlignCenter Center
+}
+
+/// Returns the number of fill characters before and after `s` when padding it to `width` chars.
+const fn pad_counts(s: &str, width: usize, kind: PadKind) -> (usize, usize) {
+    let len = crate::str::utf8_char_count(s);
+    let total = width.saturating_sub(len);
+    match kind {
+        PadKind::Left => (0, total),
+        PadKind::Right => (total, 0),
+        PadKind::Center => (total / 2, total - total / 2),
+    }
+}
+
+/// Pads the string `S::VALUE` with the character `Fill::VALUE` to a width of `Width::VALUE`
+/// chars, placing it according to `Align`.
+///
+/// Strings that are at least `Width::VALUE` chars long are unchanged. The desired value is the
+/// exact length of the output in bytes. The output is padded with zeros. Use [`pad_left`],
+/// [`pad_right`] or [`pad_center`] to get the trimmed string.
+pub struct Pad<S, Width, Fill, Align>(S, Width, Fill, Align);
+crate::impl_accept_upper_bound! {
+    impl{
+        S: Const<Type = &'static str>,
+        Width: Const<Type = usize>,
+        Fill: Const<Type = char>,
+        Align: PadAlign,
+    } Pad<S, Width, Fill, Align>;
+
+    const DESIRED_GENERIC: usize = {
+        let s = crate::const_value::<S>();
+        let (before, after) = pad_counts(s, crate::const_value::<Width>(), Align::__KIND);
+        s.len() + (before + after) * crate::const_value::<Fill>().len_utf8()
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let s = crate::const_value::<S>();
+        let (before, after) = pad_counts(s, crate::const_value::<Width>(), Align::__KIND);
+        let (fill, fill_len) = crate::str::encode_utf8(crate::const_value::<Fill>());
+        let fill = fill.split_at(fill_len).0;
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < before {
+            w = w.extend_from_slice(fill);
+            i += 1;
+        }
+        w = w.write_str(s);
+        let mut i = 0;
+        while i < after {
+            w = w.extend_from_slice(fill);
+            i += 1;
+        }
+        w.finish().0
+    };
+}
+
+/// Pads the string `S::VALUE` on the left with `Fill::VALUE` to `Width::VALUE` chars at
+/// compile time, aligning it to the right.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::consts::{ConstChar, ConstUsize};
+/// gub::const_str! {
+///     struct Status = "STAT";
+///     struct Interrupt = "INTMASK";
+/// }
+/// assert_eq!(gub::adapters::pad_left::<Status, ConstUsize<6>, ConstChar<'·'>>(), "··STAT");
+/// assert_eq!(gub::adapters::pad_left::<Interrupt, ConstUsize<6>, ConstChar<'·'>>(), "INTMASK");
+/// ```
+pub const fn pad_left<
+    S: Const<Type = &'static str>,
+    Width: Const<Type = usize>,
+    Fill: Const<Type = char>,
+>() -> &'static str {
+    eval_str::<Pad<S, Width, Fill, AlignRight>>()
+}
+
+/// Pads the string `S::VALUE` on the right with `Fill::VALUE` to `Width::VALUE` chars at
+/// compile time, aligning it to the left.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Id;
+/// impl gub::Const for Id {
+///     type Type = &'static str;
+///     const VALUE: Self::Type = "id";
+/// }
+/// struct Four;
+/// impl gub::Const for Four {
+///     type Type = usize;
+///     const VALUE: Self::Type = 4;
+/// }
+/// struct Space;
+/// impl gub::Const for Space {
+///     type Type = char;
+///     const VALUE: Self::Type = ' ';
+/// }
+/// assert_eq!(gub::adapters::pad_right::<Id, Four, Space>(), "id  ");
+/// ```
+pub const fn pad_right<
+    S: Const<Type = &'static str>,
+    Width: Const<Type = usize>,
+    Fill: Const<Type = char>,
+>() -> &'static str {
+    eval_str::<Pad<S, Width, Fill, AlignLeft>>()
+}
+
+/// Pads the string `S::VALUE` on both sides with `Fill::VALUE` to `Width::VALUE` chars at
+/// compile time, centering it. See [`AlignCenter`] for how uneven padding is split.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Title;
+/// impl gub::Const for Title {
+///     type Type = &'static str;
+///     const VALUE: Self::Type = "ab";
+/// }
+/// struct Five;
+/// impl gub::Const for Five {
+///     type Type = usize;
+///     const VALUE: Self::Type = 5;
+/// }
+/// struct Star;
+/// impl gub::Const for Star {
+///     type Type = char;
+///     const VALUE: Self::Type = '*';
+/// }
+/// assert_eq!(gub::adapters::pad_center::<Title, Five, Star>(), format!("{:*^5}", "ab"));
+/// ```
+pub const fn pad_center<
+    S: Const<Type = &'static str>,
+    Width: Const<Type = usize>,
+    Fill: Const<Type = char>,
+>() -> &'static str {
+    eval_str::<Pad<S, Width, Fill, AlignCenter>>()
+}
+
+/// A way of escaping strings for [`Escape`].
+///
+/// This trait is sealed and implemented for [`EscapeC`], [`EscapeRust`] and [`EscapeJson`].
+pub trait EscapeStyle: crate::sealed::Sealed {
+    /// The maximum number of bytes that a single byte is escaped to.
+    const MAX_EXPANSION: usize;
+    #[doc(hidden)]
+    const __KIND: escape_kind::EscapeKind;
+}
+
+mod escape_kind {
+    #[derive(Clone, Copy)]
+    pub enum EscapeKind {
+        C,
+        Rust,
+        Json,
+    }
+}
+use escape_kind::EscapeKind;
+
+/// Escapes strings for C string literals.
+///
+/// `\`, `"`, `\n`, `\r` and `\t` are escaped with a backslash, and other ASCII control
+/// characters as three octal digits, e.g. `\033`. Non-ASCII characters are unchanged.
+pub struct EscapeC;
+/// Escapes strings for Rust string literals.
+///
+/// `\`, `"`, `\n`, `\r`, `\t` and `\0` are escaped with a backslash, and other ASCII control
+/// characters as `\u{..}`, e.g. `\u{1b}`. Non-ASCII characters are unchanged.
+pub struct EscapeRust;
+/// Escapes strings for JSON strings.
+///
+/// `\`, `"`, `\n`, `\r`, `\t`, backspace and form feed are escaped with a backslash, and other
+/// ASCII control characters as `\u00..`, e.g. `\u001b`. Non-ASCII characters are unchanged.
+pub struct EscapeJson;
+
+macro_rules! impl_escape_style {
+    ($($Style:ident $kind:ident $max:literal)*) => {$(
+        impl crate::sealed::Sealed for $Style {}
+        impl EscapeStyle for $Style {
+            const MAX_EXPANSION: usize = $max;
+            #[doc(hidden)]
+            const __KIND: EscapeKind = EscapeKind::$kind;
+        }
+    )*};
+}
+impl_escape_style! {
+    EscapeC C 4
+    EscapeRust Rust 6
+    EscapeJson Json 6
+}
+
+/// Returns the escape sequence of `b` in the first `len` bytes of the array.
+const fn escape_byte(kind: EscapeKind, b: u8) -> ([u8; 6], usize) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let simple = match (b, kind) {
+        (b'\\' | b'"', _) => b,
+        (b'\n', _) => b'n',
+        (b'\r', _) => b'r',
+        (b'\t', _) => b't',
+        (0, EscapeKind::Rust) => b'0',
+        (0x08, EscapeKind::Json) => b'b',
+        (0x0c, EscapeKind::Json) => b'f',
+        (0x20.., EscapeKind::Json) | (0x20..=0x7e | 0x80.., _) => return ([b, 0, 0, 0, 0, 0], 1),
+        _ => 0,
+    };
+    if simple != 0 {
+        return ([b'\\', simple, 0, 0, 0, 0], 2);
+    }
+    let (hi, lo) = (HEX[(b >> 4) as usize], HEX[(b & 0xf) as usize]);
+    match kind {
+        EscapeKind::C => (
+            [
+                b'\\',
+                b'0' + (b >> 6),
+                b'0' + (b >> 3 & 7),
+                b'0' + (b & 7),
+                0,
+                0,
+            ],
+            4,
+        ),
+        EscapeKind::Rust if b < 0x10 => ([b'\\', b'u', b'{', lo, b'}', 0], 5),
+        EscapeKind::Rust => ([b'\\', b'u', b'{', hi, lo, b'}'], 6),
+        EscapeKind::Json => ([b'\\', b'u', b'0', b'0', hi, lo], 6),
+    }
+}
+
+/// Escapes the string `S::VALUE` according to `Style`.
+///
+/// The desired value is the exact length of the output, which is at most
+/// [`Style::MAX_EXPANSION`](EscapeStyle::MAX_EXPANSION) times the length of the input. The
+/// output is padded with zeros. Use [`escape`] to get the trimmed string.
+pub struct Escape<S, Style>(S, Style);
+crate::impl_accept_upper_bound! {
+    impl{S: Const<Type = &'static str>, Style: EscapeStyle} Escape<S, Style>;
+
+    const DESIRED_GENERIC: usize = {
+        let bytes = crate::const_value::<S>().as_bytes();
+        let mut len = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            len += escape_byte(Style::__KIND, bytes[i]).1;
+            i += 1;
+        }
+        len
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let bytes = crate::const_value::<S>().as_bytes();
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let (escaped, len) = escape_byte(Style::__KIND, bytes[i]);
+            w = w.extend_from_slice(escaped.split_at(len).0);
+            i += 1;
+        }
+        w.finish().0
+    };
+}
+
+/// Escapes the string `S::VALUE` according to `Style` at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{escape, EscapeC, EscapeJson, EscapeRust};
+/// struct Message;
+/// impl gub::Const for Message {
+///     type Type = &'static str;
+///     const VALUE: Self::Type = "say \"hi\"\n\x1b[0m\0ü";
+/// }
+/// assert_eq!(escape::<Message, EscapeC>(), r#"say \"hi\"\n\033[0m\000ü"#);
+/// assert_eq!(escape::<Message, EscapeRust>(), r#"say \"hi\"\n\u{1b}[0m\0ü"#);
+/// assert_eq!(escape::<Message, EscapeJson>(), r#"say \"hi\"\n\u001b[0m\u0000ü"#);
+/// assert_eq!(
+///     escape::<Message, EscapeRust>(),
+///     format!("{:?}", gub::const_value::<Message>()).trim_matches('"'),
+/// );
+/// ```
+pub const fn escape<S: Const<Type = &'static str>, Style: EscapeStyle>() -> &'static str {
+    eval_str::<Escape<S, Style>>()
+}
+
+/// Encodes the bytes `B::VALUE` as base64.
+///
+/// If `URL_SAFE` is `true`, the URL-safe alphabet is used instead of the standard one. If `PAD`
+/// is `true`, the output is padded with `=` to a multiple of 4 characters. The output is
+/// padded with zeros. Use [`base64_encode`], [`base64_encode_url`] or [`eval_str`] to get the
+/// trimmed string.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::Base64Encode;
+/// struct Data;
+/// impl gub::Const for Data {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = &[0xfb, 0xff];
+/// }
+/// assert_eq!(gub::eval_str::<Base64Encode<Data, false, false>>(), "+/8");
+/// assert_eq!(gub::eval_str::<Base64Encode<Data, true, true>>(), "-_8=");
+/// ```
+pub struct Base64Encode<B, const URL_SAFE: bool = false, const PAD: bool = true>(B);
+crate::impl_accept_upper_bound! {
+    impl{B: Const<Type = &'static [u8]>, const URL_SAFE: bool, const PAD: bool}
+        Base64Encode<B, URL_SAFE, PAD>;
+
+    const DESIRED_GENERIC: usize = {
+        let len = crate::const_value::<B>().len();
+        if PAD {
+            4 * len.div_ceil(3)
+        } else {
+            (4 * len).div_ceil(3)
+        }
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let alphabet = if URL_SAFE {
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+        } else {
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+        };
+        let bytes = crate::const_value::<B>();
+        let mut out = [0; UPPER];
+        let mut off = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            let chunk = match bytes.len() - i {
+                1 => [bytes[i], 0, 0],
+                2 => [bytes[i], bytes[i + 1], 0],
+                _ => [bytes[i], bytes[i + 1], bytes[i + 2]],
+            };
+            let n = (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32;
+            // each input byte yields one output character, plus one for the whole chunk
+            let chars = if bytes.len() - i < 3 { bytes.len() - i + 1 } else { 4 };
+            let mut j = 0;
+            while j < 4 {
+                if j < chars {
+                    out[off] = alphabet[(n >> (18 - 6 * j) & 0x3f) as usize];
+                    off += 1;
+                } else if PAD {
+                    out[off] = b'=';
+                    off += 1;
+                }
+                j += 1;
+            }
+            i += 3;
+        }
+        out
+    };
+}
+
+/// Encodes the bytes `B::VALUE` as padded base64 with the standard alphabet at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Data;
+/// impl gub::Const for Data {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = b"hello";
+/// }
+/// assert_eq!(gub::adapters::base64_encode::<Data>(), "aGVsbG8=");
+/// assert_eq!(gub::adapters::base64_encode_url::<Data>(), "aGVsbG8");
+/// ```
+pub const fn base64_encode<B: Const<Type = &'static [u8]>>() -> &'static str {
+    eval_str::<Base64Encode<B>>()
+}
+
+/// Encodes the bytes `B::VALUE` as unpadded base64 with the URL-safe alphabet at compile time.
+///
+/// See [`base64_encode`] for an example.
+pub const fn base64_encode_url<B: Const<Type = &'static [u8]>>() -> &'static str {
+    eval_str::<Base64Encode<B, true, false>>()
+}
+
+/// The unreserved characters of RFC 3986 as a table for [`PercentEncode`]: ASCII letters and
+/// digits, `-`, `.`, `_` and `~`.
+pub struct Unreserved;
+impl Const for Unreserved {
+    type Type = [bool; 256];
+    const VALUE: Self::Type =
+        byte_set(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~");
+}
+
+/// Percent-encodes the bytes of the string `S::VALUE`, keeping the bytes `b` for which
+/// `Keep::VALUE[b]` is `true`.
+///
+/// Every other byte is encoded as `%` followed by two uppercase hex digits. The table
+/// `Keep::VALUE` can be created using [`byte_set`], e.g. to additionally keep `/` in paths. The
+/// desired value is the exact length of the output, which is at most three times the length of
+/// the input. The output is padded with zeros. Use [`percent_encode`] or
+/// [`percent_encode_keeping`] to get the trimmed string.
+pub struct PercentEncode<S, Keep = Unreserved>(S, Keep);
+crate::impl_accept_upper_bound! {
+    impl{S: Const<Type = &'static str>, Keep: Const<Type = [bool; 256]>} PercentEncode<S, Keep>;
+
+    const DESIRED_GENERIC: usize = {
+        let (bytes, keep) = (crate::const_value::<S>().as_bytes(), crate::const_value::<Keep>());
+        let mut len = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            len += if keep[bytes[i] as usize] { 1 } else { 3 };
+            i += 1;
+        }
+        len
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        let (bytes, keep) = (crate::const_value::<S>().as_bytes(), crate::const_value::<Keep>());
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            w = if keep[b as usize] {
+                w.push(b)
+            } else {
+                w.push(b'%')
+                    .push(HEX[(b >> 4) as usize])
+                    .push(HEX[(b & 0xf) as usize])
+            };
+            i += 1;
+        }
+        w.finish().0
+    };
+}
+
+/// Percent-encodes the string `S::VALUE` at compile time, keeping only the [`Unreserved`]
+/// characters.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Query;
+/// impl gub::Const for Query {
+///     type Type = &'static str;
+///     const VALUE: Self::Type = "a b&c=ü";
+/// }
+/// assert_eq!(gub::adapters::percent_encode::<Query>(), "a%20b%26c%3D%C3%BC");
+/// ```
+pub const fn percent_encode<S: Const<Type = &'static str>>() -> &'static str {
+    eval_str::<PercentEncode<S>>()
+}
+
+/// Percent-encodes the string `S::VALUE` at compile time, keeping the bytes `b` for which
+/// `Keep::VALUE[b]` is `true`.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{percent_encode_keeping, Unreserved};
+/// /// The unreserved characters and `/`.
+/// struct PathChars;
+/// impl gub::Const for PathChars {
+///     type Type = [bool; 256];
+///     const VALUE: Self::Type = {
+///         let mut set = Unreserved::VALUE;
+///         set[b'/' as usize] = true;
+///         set
+///     };
+/// }
+/// gub::const_str! {
+///     struct Report = "reports/Q1 2024";
+/// }
+/// assert_eq!(percent_encode_keeping::<Report, PathChars>(), "reports/Q1%202024");
+/// ```
+pub const fn percent_encode_keeping<
+    S: Const<Type = &'static str>,
+    Keep: Const<Type = [bool; 256]>,
+>() -> &'static str {
+    eval_str::<PercentEncode<S, Keep>>()
+}
+
+/// Formats the integer `N::VALUE` in decimal.
+///
+/// The output is padded with zeros. Use [`usize_to_str`] to get the trimmed string.
+pub struct UsizeToStr<N>(N);
+crate::impl_accept_upper_bound! {
+    impl{N: Const<Type = usize>} UsizeToStr<N>;
+
+    const DESIRED_GENERIC: usize = match crate::const_value::<N>().checked_ilog10() {
+        Some(log) => log as usize + 1,
+        None => 1,
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let mut out = [0; UPPER];
+        let mut n = crate::const_value::<N>();
+        let mut i = crate::desired_generic::<UsizeToStr<N>>();
+        while i > 0 {
+            i -= 1;
+            out[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+        }
+        out
+    };
+}
+
+/// Formats the integer `N::VALUE` in decimal at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::consts::ConstUsize;
+/// struct Description;
+/// impl gub::Const for Description {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &[
+///         "buffer of ",
+///         gub::adapters::usize_to_str::<ConstUsize<4096>>(),
+///         " bytes",
+///     ];
+/// }
+/// assert_eq!(gub::adapters::concat_strs::<Description>(), "buffer of 4096 bytes");
+/// ```
+pub const fn usize_to_str<N: Const<Type = usize>>() -> &'static str {
+    eval_str::<UsizeToStr<N>>()
+}
+
+/// Sorts the bytes `B::VALUE` in ascending order.
+///
+/// The output is padded with zeros. Use [`sort_bytes`] to get the trimmed output.
+pub struct SortBytes<B>(B);
+crate::impl_accept_upper_bound! {
+    impl{B: Const<Type = &'static [u8]>} SortBytes<B>;
+
+    const DESIRED_GENERIC: usize = crate::const_value::<B>().len();
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let bytes = crate::const_value::<B>();
+        let mut out = [0; UPPER];
+        // insertion sort, inserting `bytes[i]` into the sorted prefix `out[..i]`
+        let mut i = 0;
+        while i < bytes.len() {
+            let mut j = i;
+            while j > 0 && out[j - 1] > bytes[i] {
+                out[j] = out[j - 1];
+                j -= 1;
+            }
+            out[j] = bytes[i];
+            i += 1;
+        }
+        out
+    };
+}
+
+/// Sorts the bytes `B::VALUE` in ascending order at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// gub::const_bytes! {
+///     struct Letters = b"qwerty";
+/// }
+/// assert_eq!(gub::adapters::sort_bytes::<Letters>(), b"eqrtwy");
+/// ```
+pub const fn sort_bytes<B: Const<Type = &'static [u8]>>() -> &'static [u8] {
+    eval_trimmed::<SortBytes<B>, u8>()
+}
+
+/// An ordering of values, used by [`SortBy`].
+///
+/// Implement this trait using [`impl_comparator!`](crate::impl_comparator).
+pub trait Comparator {
+    /// The type of the values.
+    type Item: Copy + 'static;
+    /// A [`Const`] with the values of `L::VALUE` in the order of this comparator, using an array
+    /// of length `UPPER` as storage.
+    type Sorted<L: Const<Type = &'static [Self::Item]>, const UPPER: usize>: Const<
+        Type = &'static [Self::Item],
+    >;
+}
+
+/// Sorts the values `L::VALUE` using the comparator `C`.
+///
+/// The sort is stable, so values that compare as equal keep their relative order. The desired
+/// value is the length of `L::VALUE`. The output is already trimmed. Use [`sort_by`] to evaluate
+/// it.
+pub struct SortBy<L, C>(L, C);
+impl<L: Const<Type = &'static [C::Item]>, C: Comparator> AcceptUpperBound for SortBy<L, C> {
+    type Output = &'static [C::Item];
+    const DESIRED_GENERIC: usize = crate::const_value::<L>().len();
+    type Eval<const UPPER: usize> = C::Sorted<L, UPPER>;
+}
+
+/// Sorts the values `L::VALUE` using the comparator `C` at compile time.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use generic_upper_bound as gub;
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Irq {
+///     name: &'static str,
+///     priority: u8,
+/// }
+/// struct ByPriority;
+/// gub::impl_comparator! {
+///     impl{} ByPriority;
+///     fn(a: &Irq, b: &Irq) -> Ordering = if a.priority < b.priority {
+///         Ordering::Less
+///     } else if a.priority > b.priority {
+///         Ordering::Greater
+///     } else {
+///         Ordering::Equal
+///     };
+/// }
+/// struct Irqs;
+/// impl gub::Const for Irqs {
+///     type Type = &'static [Irq];
+///     const VALUE: Self::Type = &[
+///         Irq { name: "uart", priority: 3 },
+///         Irq { name: "timer", priority: 1 },
+///         Irq { name: "spi", priority: 3 },
+///         Irq { name: "dma", priority: 2 },
+///     ];
+/// }
+/// const BY_PRIORITY: &[Irq] = gub::adapters::sort_by::<Irqs, ByPriority>();
+/// let names: Vec<_> = BY_PRIORITY.iter().map(|irq| irq.name).collect();
+/// assert_eq!(names, ["timer", "dma", "uart", "spi"]);
+/// ```
+pub const fn sort_by<L: Const<Type = &'static [C::Item]>, C: Comparator>() -> &'static [C::Item] {
+    crate::eval_with_upper_bound::<SortBy<L, C>>()
+}
+
+/// An element type of the slices that [`DedupSorted`], [`ConcatSlices`], [`Chunked`] and
+/// [`PadWith`](crate::combinators::PadWith) can be applied to.
+///
+/// This trait is sealed and implemented for `u8`, `u16`, `u32`, `u64`, `u128` and `usize`.
+pub trait SliceElem: crate::sealed::Sealed + Copy + 'static {
+    #[doc(hidden)]
+    type __DedupSorted<B: Const<Type = &'static [Self]>>: AcceptUpperBound<Output = &'static [Self]>;
+    #[doc(hidden)]
+    type __PadWith<A: AcceptUpperBound<Output = &'static [Self]>, F: Const<Type = Self>>: AcceptUpperBound<
+        Output = &'static [Self],
+    >;
+    #[doc(hidden)]
+    type __ConcatSlices<L: Const<Type = &'static [&'static [Self]]>, F: Const<Type = Self>>: AcceptUpperBound<
+        Output = &'static [Self],
+    >;
+    #[doc(hidden)]
+    const __ZERO: Self;
+}
+
+/// Removes consecutive duplicates from the slice `B::VALUE`.
+///
+/// If the slice is sorted, the output contains every element exactly once. The desired value is
+/// the exact length of the output. The output is padded with zeros. Use [`dedup_sorted`] to get
+/// the trimmed output.
+pub struct DedupSorted<B>(B);
+impl<T: SliceElem, B: Const<Type = &'static [T]>> AcceptUpperBound for DedupSorted<B> {
+    type Output = &'static [T];
+    const DESIRED_GENERIC: usize = crate::desired_generic::<T::__DedupSorted<B>>();
+    const LADDER: Ladder = <T::__DedupSorted<B> as AcceptUpperBound>::LADDER;
+    type Eval<const UPPER: usize> = <T::__DedupSorted<B> as AcceptUpperBound>::Eval<UPPER>;
+}
+
+/// The implementations of the [`SliceElem`] adapters for each element type, since generic
+/// references to arrays of generic types cannot be `'static`.
+mod slice_elem {
+    use super::SliceElem;
+    use crate::{AcceptUpperBound, Const, Ladder};
+
+    macro_rules! impl_slice_elem {
+        ($($T:ident $Dedup:ident $PadWith:ident $Concat:ident)*) => {$(
+            pub struct $Dedup<B>(B);
+            crate::impl_accept_upper_bound! {
+                impl{B: Const<Type = &'static [$T]>} $Dedup<B>;
+
+                const DESIRED_GENERIC: usize = {
+                    let items = crate::const_value::<B>();
+                    let mut len = 0;
+                    let mut i = 0;
+                    while i < items.len() {
+                        if i == 0 || items[i] != items[i - 1] {
+                            len += 1;
+                        }
+                        i += 1;
+                    }
+                    len
+                };
+
+                const EVAL<const UPPER: usize>: &'static [$T] = &{
+                    let items = crate::const_value::<B>();
+                    let mut out = [0; UPPER];
+                    let mut len = 0;
+                    let mut i = 0;
+                    while i < items.len() {
+                        if i == 0 || items[i] != items[i - 1] {
+                            out[len] = items[i];
+                            len += 1;
+                        }
+                        i += 1;
+                    }
+                    out
+                };
+            }
+
+            pub struct $PadWith<A, F>(A, F);
+            crate::impl_accept_upper_bound! {
+                impl{A: AcceptUpperBound<Output = &'static [$T]>, F: Const<Type = $T>} $PadWith<A, F>;
+
+                const DESIRED_GENERIC: usize = crate::desired_generic::<A>();
+                const LADDER: Ladder = A::LADDER;
+                const EVAL<const UPPER: usize>: &'static [$T] = &{
+                    let items = crate::const_value::<A::Eval<UPPER>>();
+                    let desired = crate::desired_generic::<A>();
+                    if items.len() < desired {
+                        panic_with!(
+                            "the output is shorter than the desired value",
+                            "len" = items.len(),
+                            "desired" = desired,
+                        );
+                    }
+                    let mut out = [crate::const_value::<F>(); UPPER];
+                    #[cfg(feature = "rust-1_87")]
+                    #[allow(clippy::incompatible_msrv)]
+                    out.split_at_mut(desired).0.copy_from_slice(items.split_at(desired).0);
+                    #[cfg(not(feature = "rust-1_87"))]
+                    {
+                        let mut i = 0;
+                        while i < desired {
+                            out[i] = items[i];
+                            i += 1;
+                        }
+                    }
+                    out
+                };
+            }
+
+            pub struct $Concat<L, F>(L, F);
+            crate::impl_accept_upper_bound! {
+                impl{L: Const<Type = &'static [&'static [$T]]>, F: Const<Type = $T>} $Concat<L, F>;
+
+                const DESIRED_GENERIC: usize = {
+                    let slices = crate::const_value::<L>();
+                    let mut len = 0;
+                    let mut i = 0;
+                    while i < slices.len() {
+                        len += slices[i].len();
+                        i += 1;
+                    }
+                    len
+                };
+                const EVAL<const UPPER: usize>: &'static [$T] = &{
+                    let slices = crate::const_value::<L>();
+                    let mut out = [crate::const_value::<F>(); UPPER];
+                    let mut len = 0;
+                    let mut i = 0;
+                    while i < slices.len() {
+                        let mut j = 0;
+                        while j < slices[i].len() {
+                            out[len] = slices[i][j];
+                            len += 1;
+                            j += 1;
+                        }
+                        i += 1;
+                    }
+                    out
+                };
+            }
+
+            impl SliceElem for $T {
+                type __DedupSorted<B: Const<Type = &'static [$T]>> = $Dedup<B>;
+                type __PadWith<A: AcceptUpperBound<Output = &'static [$T]>, F: Const<Type = $T>> =
+                    $PadWith<A, F>;
+                type __ConcatSlices<L: Const<Type = &'static [&'static [$T]]>, F: Const<Type = $T>> =
+                    $Concat<L, F>;
+                const __ZERO: $T = 0;
+            }
+        )*};
+    }
+    impl_slice_elem! {
+        u8 DedupU8 PadWithU8 ConcatU8
+        u16 DedupU16 PadWithU16 ConcatU16
+        u32 DedupU32 PadWithU32 ConcatU32
+        u64 DedupU64 PadWithU64 ConcatU64
+        u128 DedupU128 PadWithU128 ConcatU128
+        usize DedupUsize PadWithUsize ConcatUsize
+    }
+}
+
+/// Removes consecutive duplicates from the slice `B::VALUE` at compile time.
+///
+/// Together with [`sort_bytes`], this can be used to build sets at compile time. The length of
+/// the output is available as a constant through [`desired_generic`](crate::desired_generic).
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{dedup_sorted, sort_bytes, DedupSorted};
+/// gub::const_bytes! {
+///     struct Chars = b"hello world";
+///     struct Sorted = sort_bytes::<Chars>();
+/// }
+/// assert_eq!(dedup_sorted::<Sorted, u8>(), b" dehlorw");
+/// assert_eq!(gub::desired_generic::<DedupSorted<Sorted>>(), 8);
+///
+/// struct Ids;
+/// impl gub::Const for Ids {
+///     type Type = &'static [usize];
+///     const VALUE: Self::Type = &[1, 1, 2, 3, 3, 3, 10];
+/// }
+/// assert_eq!(dedup_sorted::<Ids, usize>(), [1, 2, 3, 10]);
+/// ```
+pub const fn dedup_sorted<B: Const<Type = &'static [T]>, T: SliceElem>() -> &'static [T] {
+    eval_trimmed::<DedupSorted<B>, T>()
+}
+
+/// Concatenates the slices in `L::VALUE`.
+///
+/// The desired value is the total length of the slices. The output is padded with `F::VALUE`.
+/// Use [`concat_slices`] to get the trimmed output.
+///
+/// Unlike [`ConcatStrs`], this is generic over the element type. It is limited to the types that
+/// implement [`SliceElem`], since a `'static` reference to an array whose element type is generic
+/// cannot be created in a constant.
+pub struct ConcatSlices<L, F>(L, F);
+impl<T: SliceElem, L: Const<Type = &'static [&'static [T]]>, F: Const<Type = T>> AcceptUpperBound
+    for ConcatSlices<L, F>
+{
+    type Output = &'static [T];
+    const DESIRED_GENERIC: usize = crate::desired_generic::<T::__ConcatSlices<L, F>>();
+    const LADDER: Ladder = <T::__ConcatSlices<L, F> as AcceptUpperBound>::LADDER;
+    type Eval<const UPPER: usize> = <T::__ConcatSlices<L, F> as AcceptUpperBound>::Eval<UPPER>;
+}
+
+/// Concatenates the slices in `L::VALUE` at compile time.
+///
+/// `F::VALUE` is only used as the padding of [`ConcatSlices`] and does not appear in the output.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// trait Peripheral {
+///     const REGISTERS: &'static [u32];
+/// }
+/// struct Registers<A, B>(A, B);
+/// impl<A: Peripheral, B: Peripheral> gub::Const for Registers<A, B> {
+///     type Type = &'static [&'static [u32]];
+///     const VALUE: Self::Type = &[A::REGISTERS, &[0xdead_beef], B::REGISTERS];
+/// }
+/// struct Zero;
+/// impl gub::Const for Zero {
+///     type Type = u32;
+///     const VALUE: Self::Type = 0;
+/// }
+/// struct Uart;
+/// impl Peripheral for Uart {
+///     const REGISTERS: &'static [u32] = &[0x4000_0000, 0x4000_0004];
+/// }
+/// struct Timer;
+/// impl Peripheral for Timer {
+///     const REGISTERS: &'static [u32] = &[0x4001_0000];
+/// }
+/// assert_eq!(
+///     gub::adapters::concat_slices::<Registers<Uart, Timer>, Zero, u32>(),
+///     [0x4000_0000, 0x4000_0004, 0xdead_beef, 0x4001_0000],
+/// );
+/// ```
+pub const fn concat_slices<
+    L: Const<Type = &'static [&'static [T]]>,
+    F: Const<Type = T>,
+    T: SliceElem,
+>() -> &'static [T] {
+    eval_trimmed::<ConcatSlices<L, F>, T>()
+}
+
+/// A slice that is computed in chunks by [`Chunked`].
+///
+/// Each chunk is a separate constant, so a chunk can be computed with an array whose length is a
+/// small generic parameter, instead of an array of the full length of the output. This bounds the
+/// memory and number of steps needed to compute each chunk, which matters for outputs that are
+/// too expensive to compute in one `EVAL` within the limits of the const interpreter. The output
+/// itself is still a single array, see [`Chunked`].
+pub trait ChunkSource {
+    /// The element type of the output.
+    type Item: SliceElem;
+
+    /// The number of chunks, which can be at most [`Chunked::MAX_CHUNKS`].
+    const CHUNKS: usize;
+
+    /// The chunk with index `INDEX`, for each `INDEX < CHUNKS`.
+    type Chunk<const INDEX: usize>: Const<Type = &'static [Self::Item]>;
+}
+
+/// Concatenates the chunks of a [`ChunkSource`].
+///
+/// The desired value is the total length of the chunks. Only the chunks with an index below
+/// [`ChunkSource::CHUNKS`] are evaluated, each by a separate constant. The output is padded with
+/// zeros. Use [`chunked`] to get the trimmed output.
+///
+/// # Memory
+/// Chunking bounds the memory and steps needed to compute the elements, but not the size of the
+/// output: Stitching the chunks together copies them into a single array of `UPPER` elements,
+/// which is the output itself. The largest array during evaluation is therefore the output, or
+/// one chunk if that is larger. Outputs that are too large for the const interpreter even without
+/// computing their elements cannot be created this way.
+///
+/// # Panics
+/// If there are more than [`Chunked::MAX_CHUNKS`] chunks.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{chunked, ChunkSource};
+/// /// The squares of the numbers below `N`, computed `CHUNK` at a time.
+/// struct Squares<const N: usize, const CHUNK: usize>;
+/// impl<const N: usize, const CHUNK: usize> ChunkSource for Squares<N, CHUNK> {
+///     type Item = u64;
+///     const CHUNKS: usize = N.div_ceil(CHUNK);
+///     type Chunk<const INDEX: usize> = SquaresChunk<N, CHUNK, INDEX>;
+/// }
+/// struct SquaresChunk<const N: usize, const CHUNK: usize, const INDEX: usize>;
+/// impl<const N: usize, const CHUNK: usize, const INDEX: usize> SquaresChunk<N, CHUNK, INDEX> {
+///     const START: usize = INDEX * CHUNK;
+///     const FULL: &'static [u64; CHUNK] = &{
+///         let mut out = [0; CHUNK];
+///         let mut i = 0;
+///         while i < CHUNK {
+///             out[i] = ((Self::START + i) * (Self::START + i)) as u64;
+///             i += 1;
+///         }
+///         out
+///     };
+/// }
+/// impl<const N: usize, const CHUNK: usize, const INDEX: usize> gub::Const
+///     for SquaresChunk<N, CHUNK, INDEX>
+/// {
+///     type Type = &'static [u64];
+///     // the last chunk can be shorter
+///     const VALUE: Self::Type = if N - Self::START < CHUNK {
+///         Self::FULL.split_at(N - Self::START).0
+///     } else {
+///         Self::FULL
+///     };
+/// }
+/// let squares = chunked::<Squares<1000, 64>, u64>();
+/// assert_eq!(squares.len(), 1000);
+/// assert_eq!(squares[999], 999 * 999);
+/// assert!(squares.iter().enumerate().all(|(i, &sq)| sq == (i * i) as u64));
+///
+/// // more chunks than fit into one group of 32
+/// let squares = chunked::<Squares<5000, 16>, u64>();
+/// assert_eq!(squares[4999], 4999 * 4999);
+/// ```
+pub struct Chunked<S>(S);
+impl<S: ChunkSource> Chunked<S> {
+    /// The maximum number of chunks.
+    pub const MAX_CHUNKS: usize = GROUP_LEN * GROUPS;
+}
+impl<T: SliceElem, S: ChunkSource<Item = T>> AcceptUpperBound for Chunked<S> {
+    type Output = &'static [T];
+    const DESIRED_GENERIC: usize =
+        crate::desired_generic::<T::__ConcatSlices<ChunkList<S>, ZeroOf<T>>>();
+    const LADDER: Ladder = <T::__ConcatSlices<ChunkList<S>, ZeroOf<T>> as AcceptUpperBound>::LADDER;
+    type Eval<const UPPER: usize> =
+        <T::__ConcatSlices<ChunkList<S>, ZeroOf<T>> as AcceptUpperBound>::Eval<UPPER>;
+}
+
+/// The number of chunks in each [`ChunkGroup`], which are the indices generated by
+/// `with_indices!` from `[0] 1 2 4 8 16`.
+const GROUP_LEN: usize = 32;
+/// The number of [`ChunkGroup`]s. Their impls are generated from a list of literals, since
+/// `generic_const_exprs` rejects impls for const arguments that are expressions.
+const GROUPS: usize = 32;
+
+/// Invokes `$mac! { $($args)* { I0 } { I1 } ... }` with the indices below the sum of the powers
+/// of two that follow the list, in ascending order. Each index is a const expression.
+macro_rules! with_indices {
+    ($mac:ident { $($args:tt)* } [$($i:expr),*] $bit:literal $($bits:literal)*) => {
+        with_indices! { $mac { $($args)* } [$($i,)* $($i + $bit),*] $($bits)* }
+    };
+    ($mac:ident { $($args:tt)* } [$($i:expr),*]) => {
+        $mac! { $($args)* $({ $i })* }
+    };
+}
+
+/// The chunks of `S`, each of which is only evaluated if its index is below `S::CHUNKS`.
+#[doc(hidden)]
+pub struct ChunkList<S>(S);
+impl<S: ChunkSource> Const for ChunkList<S> {
+    type Type = &'static [&'static [S::Item]];
+    const VALUE: Self::Type = {
+        if S::CHUNKS > Chunked::<S>::MAX_CHUNKS {
+            panic_with!(
+                "too many chunks",
+                "CHUNKS" = S::CHUNKS,
+                "MAX_CHUNKS" = Chunked::<S>::MAX_CHUNKS,
+            );
+        }
+        Self::ALL.split_at(S::CHUNKS).0
+    };
+}
+macro_rules! impl_chunk_list {
+    ($($group:block)*) => {
+        impl<S: ChunkSource> ChunkList<S> {
+            // not an array reference, since `generic_const_exprs` would make its length generic
+            const ALL: &'static [&'static [S::Item]] = &{
+                let groups = [$(group_or_empty::<S, ChunkGroup<S, $group>, $group>(),)*];
+                let mut all: [&'static [S::Item]; GROUPS * GROUP_LEN] = [&[]; GROUPS * GROUP_LEN];
+                let mut i = 0;
+                while i < S::CHUNKS && i < all.len() {
+                    all[i] = groups[i / GROUP_LEN][i % GROUP_LEN];
+                    i += 1;
+                }
+                all
+            };
+        }
+    };
+}
+with_indices! { impl_chunk_list {} [0] 1 2 4 8 16 }
+
+/// The chunks of `S` with an index from `GROUP * GROUP_LEN` to `(GROUP + 1) * GROUP_LEN`.
+struct ChunkGroup<S, const GROUP: usize>(S);
+macro_rules! impl_chunk_group {
+    ($group:literal $($index:block)*) => {
+        impl<S: ChunkSource> Const for ChunkGroup<S, $group> {
+            type Type = [&'static [S::Item]; GROUP_LEN];
+            const VALUE: Self::Type =
+                [$(chunk_or_empty::<S, { ($group) * GROUP_LEN + $index }>(),)*];
+        }
+    };
+}
+macro_rules! impl_chunk_groups {
+    ($($group:literal)*) => {$(
+        with_indices! { impl_chunk_group { $group } [0] 1 2 4 8 16 }
+    )*};
+}
+impl_chunk_groups! { 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 }
+
+/// Returns `G`, the group `GROUP` of the chunks of `S`, or empty chunks if the group starts after
+/// the last chunk, in which case none of its chunks are evaluated.
+const fn group_or_empty<S: ChunkSource, G, const GROUP: usize>() -> [&'static [S::Item]; GROUP_LEN]
+where
+    G: Const<Type = [&'static [S::Item]; GROUP_LEN]>,
+{
+    if GROUP * GROUP_LEN < S::CHUNKS {
+        crate::const_value::<G>()
+    } else {
+        [&[]; GROUP_LEN]
+    }
+}
+
+/// Returns chunk `INDEX` of `S`, or an empty slice if there is no such chunk. Since the chunk is
+/// only referenced through a function call, it is not evaluated in the latter case.
+const fn chunk_or_empty<S: ChunkSource, const INDEX: usize>() -> &'static [S::Item] {
+    if INDEX < S::CHUNKS {
+        crate::const_value::<S::Chunk<INDEX>>()
+    } else {
+        &[]
+    }
+}
+
+#[doc(hidden)]
+pub struct ZeroOf<T>(T);
+impl<T: SliceElem> Const for ZeroOf<T> {
+    type Type = T;
+    const VALUE: Self::Type = T::__ZERO;
+}
+
+/// Concatenates the chunks of `S` at compile time. See [`Chunked`].
+pub const fn chunked<S: ChunkSource<Item = T>, T: SliceElem>() -> &'static [T] {
+    eval_trimmed::<Chunked<S>, T>()
+}
+
+/// Keeps the bytes `b` of `B::VALUE` for which `P::VALUE[b]` is `true`.
+///
+/// The table `P::VALUE` can be created using [`byte_set`]. The desired value is the exact length
+/// of the output. The output is padded with zeros. Use [`filter_bytes`] to get the trimmed output.
+pub struct Filter<B, P>(B, P);
+crate::impl_accept_upper_bound! {
+    impl{B: Const<Type = &'static [u8]>, P: Const<Type = [bool; 256]>} Filter<B, P>;
+
+    const DESIRED_GENERIC: usize = {
+        let (bytes, keep) = (crate::const_value::<B>(), crate::const_value::<P>());
+        let mut len = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if keep[bytes[i] as usize] {
+                len += 1;
+            }
+            i += 1;
+        }
+        len
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let (bytes, keep) = (crate::const_value::<B>(), crate::const_value::<P>());
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            if keep[bytes[i] as usize] {
+                w = w.push(bytes[i]);
+            }
+            i += 1;
+        }
+        w.finish().0
+    };
+}
+
+/// Keeps the bytes `b` of `B::VALUE` for which `P::VALUE[b]` is `true` at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{byte_set, filter_bytes};
+/// struct Digits;
+/// impl gub::Const for Digits {
+///     type Type = [bool; 256];
+///     const VALUE: Self::Type = byte_set(b"0123456789");
+/// }
+/// gub::const_bytes! {
+///     struct Version = b"v1.20.3-rc1";
+/// }
+/// assert_eq!(filter_bytes::<Version, Digits>(), b"12031");
+/// ```
+pub const fn filter_bytes<B: Const<Type = &'static [u8]>, P: Const<Type = [bool; 256]>>(
+) -> &'static [u8] {
+    eval_trimmed::<Filter<B, P>, u8>()
+}
+
+/// Returns a table for [`Filter`] that contains `true` exactly for the bytes in `bytes`.
+pub const fn byte_set(bytes: &[u8]) -> [bool; 256] {
+    let mut set = [false; 256];
+    let mut i = 0;
+    while i < bytes.len() {
+        set[bytes[i] as usize] = true;
+        i += 1;
+    }
+    set
+}
+
+/// Returns a table for [`Filter`] that contains `true` exactly for the bytes not in `bytes`.
+///
+/// This is used to strip the bytes in `bytes`.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Spaced;
+/// impl gub::Const for Spaced {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = b"a b\tc";
+/// }
+/// struct NoWhitespace;
+/// impl gub::Const for NoWhitespace {
+///     type Type = [bool; 256];
+///     const VALUE: Self::Type = gub::adapters::byte_set_except(b" \t\n\r");
+/// }
+/// assert_eq!(gub::adapters::filter_bytes::<Spaced, NoWhitespace>(), b"abc");
+/// ```
+pub const fn byte_set_except(bytes: &[u8]) -> [bool; 256] {
+    let mut set = [true; 256];
+    let mut i = 0;
+    while i < bytes.len() {
+        set[bytes[i] as usize] = false;
+        i += 1;
+    }
+    set
+}
+
+/// Sorts the key-value pairs `L::VALUE` by key.
+///
+/// The output consists of the indices of the pairs in the order of their keys, padded with zeros.
+/// Use [`sort_table`] to get a [`SortedTable`], which allows looking up values by key.
+///
+/// Only the indices are sorted, since a reference to a generic value computed during const
+/// evaluation cannot be `'static`.
+///
+/// # Panics
+/// Evaluation panics if a key occurs more than once.
+pub struct SortTable<L>(L);
+crate::impl_accept_upper_bound! {
+    impl{V: 'static, L: Const<Type = &'static [(&'static str, V)]>} SortTable<L>;
+
+    const DESIRED_GENERIC: usize = crate::const_value::<L>().len();
+
+    const EVAL<const UPPER: usize>: &'static [usize] = &{
+        let items = crate::const_value::<L>();
+        // insertion sort, inserting `i` into the sorted prefix `order[..i]`
+        let mut order = [0; UPPER];
+        let mut i = 0;
+        while i < items.len() {
+            let mut j = i;
+            while j > 0 {
+                match cmp_str(items[order[j - 1]].0, items[i].0) {
+                    Ordering::Less => break,
+                    Ordering::Equal => panic_with!("duplicate key in the table", "key" = items[i].0),
+                    Ordering::Greater => {
+                        order[j] = order[j - 1];
+                        j -= 1;
+                    }
+                }
+            }
+            order[j] = i;
+            i += 1;
+        }
+        order
+    };
+}
+
+/// Key-value pairs that were sorted by key at compile time. Returned by [`sort_table`].
+pub struct SortedTable<V: 'static> {
+    entries: &'static [(&'static str, V)],
+    order: &'static [usize],
+}
+
+impl<V> SortedTable<V> {
+    /// Returns the number of entries.
+    pub const fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns whether the table is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns the entry at index `i` in the order of the keys.
+    ///
+    /// # Panics
+    /// If `i` is out of bounds.
+    pub const fn get_index(&self, i: usize) -> &'static (&'static str, V) {
+        &self.entries[self.order[i]]
+    }
+
+    /// Returns the value for `key` using binary search.
+    pub const fn lookup(&self, key: &str) -> Option<&'static V> {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let entry = self.get_index(mid);
+            match cmp_str(entry.0, key) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Equal => return Some(&entry.1),
+                Ordering::Greater => hi = mid,
+            }
+        }
+        None
+    }
+
+    /// Returns an iterator over the entries in the order of the keys.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'static (&'static str, V)> {
+        let entries = self.entries;
+        self.order.iter().map(move |&i| &entries[i])
+    }
+}
+
+impl<V> Clone for SortedTable<V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<V> Copy for SortedTable<V> {}
+
+impl<V: core::fmt::Debug> core::fmt::Debug for SortedTable<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+/// Sorts the key-value pairs `L::VALUE` by key at compile time.
+///
+/// # Panics
+/// If a key occurs more than once. This check happens during const evaluation, so it results in a
+/// compile error even if this function is called at runtime.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{sort_table, SortedTable};
+/// struct Commands;
+/// impl gub::Const for Commands {
+///     type Type = &'static [(&'static str, u32)];
+///     const VALUE: Self::Type = &[("ls", 1), ("cd", 2), ("echo", 3)];
+/// }
+/// const TABLE: SortedTable<u32> = sort_table::<Commands, _>();
+/// const ECHO: Option<&u32> = TABLE.lookup("echo");
+/// assert_eq!(ECHO, Some(&3));
+/// assert_eq!(TABLE.lookup("rm"), None);
+/// let keys: Vec<_> = TABLE.iter().map(|&(k, _)| k).collect();
+/// assert_eq!(keys, ["cd", "echo", "ls"]);
+/// ```
+/// ```compile_fail
+/// # use generic_upper_bound as gub;
+/// struct Pairs;
+/// impl gub::Const for Pairs {
+///     type Type = &'static [(&'static str, u32)];
+///     const VALUE: Self::Type = &[("a", 1), ("a", 2)];
+/// }
+/// let _ = gub::adapters::sort_table::<Pairs, _>();
+/// ```
+pub const fn sort_table<L: Const<Type = &'static [(&'static str, V)]>, V: 'static>(
+) -> SortedTable<V> {
+    SortedTable {
+        entries: crate::const_value::<L>(),
+        order: eval_trimmed::<SortTable<L>, usize>(),
+    }
+}
+
+/// Compares two strings lexicographically by their bytes, like `Ord for str`.
+const fn cmp_str(a: &str, b: &str) -> Ordering {
+    #[cfg(feature = "konst")]
+    {
+        konst::string::cmp_str(a, b)
+    }
+    #[cfg(not(feature = "konst"))]
+    {
+        crate::slice::compare(a.as_bytes(), b.as_bytes())
+    }
+}
+
+/// A node of a prefix trie built by [`Trie`].
+///
+/// The children of a node form a linked list through [`next_sibling`](Self::next_sibling). Node
+/// `0` is the root, so `0` is used to indicate that there is no child or sibling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TrieNode {
+    /// The byte on the edge from the parent to this node. Unused for the root.
+    pub byte: u8,
+    /// The index of the first child, or `0` if there is none.
+    pub first_child: usize,
+    /// The index of the next child of the parent, or `0` if there is none.
+    pub next_sibling: usize,
+    /// The index of the keyword that ends at this node, if any.
+    pub keyword: Option<usize>,
+}
+
+impl TrieNode {
+    const EMPTY: Self = Self {
+        byte: 0,
+        first_child: 0,
+        next_sibling: 0,
+        keyword: None,
+    };
+}
+
+/// Builds a prefix trie of the keywords `L::VALUE`.
+///
+/// The desired value is the exact number of nodes, which is one more than the number of distinct
+/// non-empty prefixes of the keywords and at most one more than their total length. The output is
+/// padded with empty nodes. Use [`trie`] to get a [`TrieTable`], which allows matching input
+/// against the keywords.
+///
+/// # Panics
+/// Evaluation panics if a keyword occurs more than once.
+pub struct Trie<L>(L);
+crate::impl_accept_upper_bound! {
+    impl{L: Const<Type = &'static [&'static str]>} Trie<L>;
+
+    const DESIRED_GENERIC: usize = {
+        let keywords = crate::const_value::<L>();
+        let mut count = 1;
+        let mut i = 0;
+        while i < keywords.len() {
+            // the prefixes of `keywords[i]` that are not prefixes of an earlier keyword
+            let mut shared = 0;
+            let mut j = 0;
+            while j < i {
+                let common = common_prefix_len(keywords[i].as_bytes(), keywords[j].as_bytes());
+                if common > shared {
+                    shared = common;
+                }
+                j += 1;
+            }
+            count += keywords[i].len() - shared;
+            i += 1;
+        }
+        count
+    };
+
+    const EVAL<const UPPER: usize>: &'static [TrieNode] = &{
+        let keywords = crate::const_value::<L>();
+        let mut nodes = [TrieNode::EMPTY; UPPER];
+        let mut len = 1;
+        let mut i = 0;
+        while i < keywords.len() {
+            let bytes = keywords[i].as_bytes();
+            let mut node = 0;
+            let mut k = 0;
+            while k < bytes.len() {
+                let mut child = nodes[node].first_child;
+                while child != 0 && nodes[child].byte != bytes[k] {
+                    child = nodes[child].next_sibling;
+                }
+                if child == 0 {
+                    child = len;
+                    nodes[child] = TrieNode {
+                        byte: bytes[k],
+                        next_sibling: nodes[node].first_child,
+                        ..TrieNode::EMPTY
+                    };
+                    nodes[node].first_child = child;
+                    len += 1;
+                }
+                node = child;
+                k += 1;
+            }
+            if nodes[node].keyword.is_some() {
+                panic_with!("duplicate keyword", "keyword" = keywords[i]);
+            }
+            nodes[node].keyword = Some(i);
+            i += 1;
+        }
+        nodes
+    };
+}
+
+const fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    let mut i = 0;
+    while i < a.len() && i < b.len() && a[i] == b[i] {
+        i += 1;
+    }
+    i
+}
+
+/// A prefix trie of keywords that was built at compile time. Returned by [`trie`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrieTable {
+    nodes: &'static [TrieNode],
+}
+
+impl TrieTable {
+    /// Returns the nodes of the trie, starting with the root.
+    pub const fn nodes(&self) -> &'static [TrieNode] {
+        self.nodes
+    }
+
+    /// Returns the index of the child of `node` whose edge is labeled `byte`.
+    ///
+    /// # Panics
+    /// If `node` is out of bounds.
+    pub const fn child(&self, node: usize, byte: u8) -> Option<usize> {
+        let mut child = self.nodes[node].first_child;
+        while child != 0 {
+            if self.nodes[child].byte == byte {
+                return Some(child);
+            }
+            child = self.nodes[child].next_sibling;
+        }
+        None
+    }
+
+    /// Returns the index of the keyword that is equal to `input`.
+    pub const fn matches(&self, input: &str) -> Option<usize> {
+        let bytes = input.as_bytes();
+        let mut node = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            node = match self.child(node, bytes[i]) {
+                Some(child) => child,
+                None => return None,
+            };
+            i += 1;
+        }
+        self.nodes[node].keyword
+    }
+
+    /// Returns the index and length of the longest keyword that `input` starts with.
+    pub const fn longest_prefix(&self, input: &str) -> Option<(usize, usize)> {
+        let bytes = input.as_bytes();
+        let mut found = match self.nodes[0].keyword {
+            Some(keyword) => Some((keyword, 0)),
+            None => None,
+        };
+        let mut node = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            node = match self.child(node, bytes[i]) {
+                Some(child) => child,
+                None => break,
+            };
+            i += 1;
+            if let Some(keyword) = self.nodes[node].keyword {
+                found = Some((keyword, i));
+            }
+        }
+        found
+    }
+}
+
+/// Builds a prefix trie of the keywords `L::VALUE` at compile time.
+///
+/// # Panics
+/// If a keyword occurs more than once. This check happens during const evaluation, so it results
+/// in a compile error even if this function is called at runtime.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{trie, TrieTable};
+/// struct Routes;
+/// impl gub::Const for Routes {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["/", "/users", "/users/", "/user"];
+/// }
+/// const TRIE: TrieTable = trie::<Routes>();
+/// const USERS: Option<usize> = TRIE.matches("/users");
+/// assert_eq!(USERS, Some(1));
+/// assert_eq!(TRIE.matches("/use"), None);
+/// assert_eq!(TRIE.longest_prefix("/users/42"), Some((2, 7)));
+/// assert_eq!(TRIE.longest_prefix("/posts"), Some((0, 1)));
+/// // the root and one node per distinct prefix
+/// assert_eq!(TRIE.nodes().len(), 8);
+/// ```
+/// ```compile_fail
+/// # use generic_upper_bound as gub;
+/// struct Keywords;
+/// impl gub::Const for Keywords {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["if", "else", "if"];
+/// }
+/// let _ = gub::adapters::trie::<Keywords>();
+/// ```
+pub const fn trie<L: Const<Type = &'static [&'static str]>>() -> TrieTable {
+    TrieTable {
+        nodes: eval_trimmed::<Trie<L>, TrieNode>(),
+    }
+}
+
+/// Returns the index of the first string before `strs[i]` that contains it, and the position of
+/// `strs[i]` in that string.
+///
+/// A string that is contained in an earlier one is not stored in the pool of [`InternPool`]. The
+/// first string that contains it is always stored, since a string that contains it and is
+/// contained in an even earlier one would make that one the first.
+const fn find_container(strs: &[&str], i: usize) -> Option<(usize, usize)> {
+    let mut j = 0;
+    while j < i {
+        if let Some(k) = crate::slice::find(strs[j].as_bytes(), strs[i].as_bytes()) {
+            return Some((j, k));
+        }
+        j += 1;
+    }
+    None
+}
+
+/// Concatenates the strings in `L::VALUE`, skipping strings that are contained in an earlier
+/// string.
+///
+/// This is the pool of [`intern_strs`]. The desired value is the exact length of the pool. The
+/// output is padded with zeros.
+pub struct InternPool<L>(L);
+crate::impl_accept_upper_bound! {
+    impl{L: Const<Type = &'static [&'static str]>} InternPool<L>;
+
+    const DESIRED_GENERIC: usize = {
+        let strs = crate::const_value::<L>();
+        let mut len = 0;
+        let mut i = 0;
+        while i < strs.len() {
+            if find_container(strs, i).is_none() {
+                len += strs[i].len();
+            }
+            i += 1;
+        }
+        len
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let strs = crate::const_value::<L>();
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < strs.len() {
+            if find_container(strs, i).is_none() {
+                w = w.write_str(strs[i]);
+            }
+            i += 1;
+        }
+        w.finish().0
+    };
+}
+
+/// The position of each string of `L::VALUE` in the pool of [`InternPool`].
+///
+/// The desired value is the number of strings. The output is padded with [`Span::EMPTY`].
+pub struct InternSpans<L>(L);
+crate::impl_accept_upper_bound! {
+    impl{L: Const<Type = &'static [&'static str]>} InternSpans<L>;
+
+    const DESIRED_GENERIC: usize = crate::const_value::<L>().len();
+
+    const EVAL<const UPPER: usize>: &'static [Span] = &{
+        let strs = crate::const_value::<L>();
+        let mut spans = [Span::EMPTY; UPPER];
+        let mut offset = 0;
+        let mut i = 0;
+        while i < strs.len() {
+            let len = strs[i].len();
+            spans[i] = match find_container(strs, i) {
+                Some((j, k)) => Span { start: spans[j].start + k, len },
+                None => {
+                    offset += len;
+                    Span { start: offset - len, len }
+                }
+            };
+            i += 1;
+        }
+        spans
+    };
+}
+
+/// Strings that share a single pooled string. Returned by [`intern_strs`].
+#[derive(Debug, Clone, Copy)]
+pub struct StrPool {
+    pool: &'static str,
+    spans: &'static [Span],
+}
+
+impl StrPool {
+    /// Returns the number of strings.
+    pub const fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Returns whether there are no strings.
+    pub const fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns the string at index `i`.
+    ///
+    /// # Panics
+    /// If `i` is out of bounds.
+    pub const fn get(&self, i: usize) -> &'static str {
+        let span = self.spans[i];
+        let bytes = self
+            .pool
+            .as_bytes()
+            .split_at(span.end())
+            .0
+            .split_at(span.start)
+            .1;
+        // SAFETY: Every span refers to a copy of one of the strings in the pool.
+        unsafe { core::str::from_utf8_unchecked(bytes) }
+    }
+
+    /// Returns the pooled string that all strings refer to.
+    pub const fn pool(&self) -> &'static str {
+        self.pool
+    }
+
+    /// Returns the position of each string in [`pool`](Self::pool).
+    pub const fn spans(&self) -> &'static [Span] {
+        self.spans
+    }
+
+    /// Returns an iterator over the strings.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'static str> {
+        let pool = *self;
+        (0..pool.len()).map(move |i| pool.get(i))
+    }
+}
+
+/// Stores the strings in `L::VALUE` in a single pooled string at compile time.
+///
+/// Strings that are contained in an earlier string, including duplicates, are not stored again
+/// but refer to the earlier copy. This is useful if many strings that were built from the same
+/// parts, e.g. using [`concat_strs`], would otherwise be stored separately.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{intern_strs, StrPool};
+/// struct Messages;
+/// impl gub::Const for Messages {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type =
+///         &["uart: overrun", "uart: framing error", "overrun", "uart: overrun", "uart"];
+/// }
+/// const POOL: StrPool = intern_strs::<Messages>();
+/// const OVERRUN: &str = POOL.get(2);
+/// assert_eq!(OVERRUN, "overrun");
+/// assert_eq!(POOL.pool(), "uart: overrunuart: framing error");
+/// assert!(POOL.iter().eq(gub::const_value::<Messages>().iter().copied()));
+/// assert_eq!(POOL.spans()[3], POOL.spans()[0]);
+/// ```
+pub const fn intern_strs<L: Const<Type = &'static [&'static str]>>() -> StrPool {
+    StrPool {
+        pool: eval_str::<InternPool<L>>(),
+        spans: eval_trimmed::<InternSpans<L>, Span>(),
+    }
+}
+
+/// A hash algorithm for [`HashOf`].
+///
+/// This trait is sealed and implemented for [`Crc32`], [`Fnv1a32`] and [`Fnv1a64`].
+pub trait HashAlgo: crate::sealed::Sealed {
+    /// The type of the hash.
+    type Hash: Copy + 'static;
+    #[doc(hidden)]
+    type __Hash<A: AcceptUpperBound<Output = &'static [u8]>>: Const<Type = Self::Hash>;
+}
+
+/// The CRC-32 checksum used by zlib and PNG. See [`crc32`].
+pub struct Crc32;
+/// The 32-bit FNV-1a hash. See [`fnv1a_32`].
+pub struct Fnv1a32;
+/// The 64-bit FNV-1a hash. See [`fnv1a_64`].
+pub struct Fnv1a64;
+
+mod hash_impl {
+    pub struct Crc32<A>(A);
+    pub struct Fnv1a32<A>(A);
+    pub struct Fnv1a64<A>(A);
+}
+
+macro_rules! impl_hash_algo {
+    ($($Algo:ident $hash:ident -> $T:ty;)*) => {$(
+        impl crate::sealed::Sealed for $Algo {}
+        impl HashAlgo for $Algo {
+            type Hash = $T;
+            #[doc(hidden)]
+            type __Hash<A: AcceptUpperBound<Output = &'static [u8]>> = hash_impl::$Algo<A>;
+        }
+        impl<A: AcceptUpperBound<Output = &'static [u8]>> Const for hash_impl::$Algo<A> {
+            type Type = $T;
+            const VALUE: Self::Type = $hash(eval_trimmed::<A, u8>());
+        }
+    )*};
+}
+impl_hash_algo! {
+    Crc32 crc32 -> u32;
+    Fnv1a32 fnv1a_32 -> u32;
+    Fnv1a64 fnv1a_64 -> u64;
+}
+
+/// A [`Const`] with the hash of the trimmed output of `A`, computed using `Algo`.
+///
+/// The hash is computed from the first [`desired_generic`](crate::desired_generic) bytes of
+/// the output, so it does not depend on the upper bound that was used. This makes it suitable
+/// for stable IDs and cache keys. See [`hash_of`].
+pub struct HashOf<A, Algo>(A, Algo);
+impl<A: AcceptUpperBound<Output = &'static [u8]>, Algo: HashAlgo> Const for HashOf<A, Algo> {
+    type Type = Algo::Hash;
+    const VALUE: Self::Type = crate::const_value::<Algo::__Hash<A>>();
+}
+
+/// Returns the hash of the trimmed output of `A`, computed using `Algo` at compile time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{hash_of, Crc32, Fnv1a64, Join};
+/// gub::const_str! {
+///     struct Comma = ",";
+/// }
+/// struct Fields;
+/// impl gub::Const for Fields {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["1234", "5678", "9"];
+/// }
+/// const ID: u32 = hash_of::<Join<Comma, Fields>, Crc32>();
+/// assert_eq!(ID, gub::adapters::crc32(b"1234,5678,9"));
+/// assert_eq!(
+///     hash_of::<Join<Comma, Fields>, Fnv1a64>(),
+///     gub::adapters::fnv1a_64(b"1234,5678,9"),
+/// );
+/// ```
+pub const fn hash_of<A: AcceptUpperBound<Output = &'static [u8]>, Algo: HashAlgo>() -> Algo::Hash {
+    crate::const_value::<HashOf<A, Algo>>()
+}
+
+/// Computes the CRC-32 checksum of `bytes`, as used by zlib and PNG.
+///
+/// ```
+/// use generic_upper_bound::adapters::crc32;
+/// assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+/// ```
+pub const fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        crc ^= bytes[i] as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+            bit += 1;
+        }
+        i += 1;
+    }
+    !crc
+}
+
+/// Computes the 32-bit FNV-1a hash of `bytes`.
+///
+/// ```
+/// use generic_upper_bound::adapters::fnv1a_32;
+/// assert_eq!(fnv1a_32(b""), 0x811c_9dc5);
+/// assert_eq!(fnv1a_32(b"a"), 0xe40c_292c);
+/// ```
+pub const fn fnv1a_32(bytes: &[u8]) -> u32 {
+    let mut hash = 0x811c_9dc5u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u32).wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes the 64-bit FNV-1a hash of `bytes`.
+///
+/// ```
+/// use generic_upper_bound::adapters::fnv1a_64;
+/// assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+/// ```
+pub const fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u64).wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+/// Prepends the length of the trimmed output of `A` to it, as a `WIDTH`-byte unsigned integer in
+/// big-endian byte order if `BIG_ENDIAN` is `true` and in little-endian byte order otherwise.
+///
+/// The length is [`desired_generic::<A>()`](crate::desired_generic), so the header does not
+/// depend on the upper bound that was used. The desired value is `WIDTH` more than that of `A`.
+/// The output is padded with zeros. Use [`length_prefixed`] to get the trimmed output and
+/// [`split_length_prefixed`] to read it back.
+///
+/// # Panics
+/// Evaluation panics if `WIDTH` is not between `1` and `8` or if the length does not fit into
+/// `WIDTH` bytes.
+pub struct LengthPrefixed<A, const WIDTH: usize = 4, const BIG_ENDIAN: bool = false>(A);
+crate::impl_accept_upper_bound! {
+    impl{A: AcceptUpperBound<Output = &'static [u8]>, const WIDTH: usize, const BIG_ENDIAN: bool}
+        LengthPrefixed<A, WIDTH, BIG_ENDIAN>;
+
+    const DESIRED_GENERIC: usize = crate::math::checked_add(WIDTH, crate::desired_generic::<A>());
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        if WIDTH == 0 || WIDTH > 8 {
+            panic_with!("the width of the length header is not between 1 and 8", "WIDTH" = WIDTH);
+        }
+        let len = crate::desired_generic::<A>();
+        if WIDTH < 8 && (len as u64) >> (8 * WIDTH) != 0 {
+            panic_with!(
+                "the length does not fit into the length header",
+                "len" = len,
+                "WIDTH" = WIDTH,
+            );
+        }
+        let le = (len as u64).to_le_bytes();
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < WIDTH {
+            w = w.push(if BIG_ENDIAN { le[WIDTH - 1 - i] } else { le[i] });
+            i += 1;
+        }
+        w.extend_from_slice(eval_trimmed::<A, u8>()).finish().0
+    };
+}
+
+/// Prepends the length of the trimmed output of `A` to it at compile time. See
+/// [`LengthPrefixed`].
+///
+/// The blob is self-describing, so it can be stored e.g. in flash memory and parsed at runtime
+/// using [`split_length_prefixed`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{length_prefixed, split_length_prefixed, Join};
+/// gub::const_str! {
+///     struct Comma = ",";
+/// }
+/// struct Names;
+/// impl gub::Const for Names {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["temp", "rh"];
+/// }
+/// const BLOB: &[u8] = length_prefixed::<Join<Comma, Names>, 2, true>();
+/// assert_eq!(BLOB, b"\x00\x07temp,rh");
+/// assert_eq!(split_length_prefixed::<2, true>(BLOB), Some((&b"temp,rh"[..], &[][..])));
+/// ```
+/// ```compile_fail
+/// # use generic_upper_bound as gub;
+/// struct Long;
+/// gub::impl_accept_upper_bound! {
+///     impl{} Long;
+///     const DESIRED_GENERIC: usize = 256;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[b'x'; UPPER];
+/// }
+/// // the length does not fit into one byte
+/// let _ = gub::adapters::length_prefixed::<Long, 1, false>();
+/// ```
+pub const fn length_prefixed<
+    A: AcceptUpperBound<Output = &'static [u8]>,
+    const WIDTH: usize,
+    const BIG_ENDIAN: bool,
+>() -> &'static [u8] {
+    eval_trimmed::<LengthPrefixed<A, WIDTH, BIG_ENDIAN>, u8>()
+}
+
+/// Splits a blob created by [`length_prefixed`] with the same `WIDTH` and `BIG_ENDIAN` into its
+/// payload and the bytes after it.
+///
+/// Returns `None` if `bytes` is shorter than the header or the length in the header.
+///
+/// # Panics
+/// If `WIDTH` is not between `1` and `8`.
+///
+/// ```
+/// use generic_upper_bound::adapters::split_length_prefixed;
+/// let blobs = b"\x02\x00hi\x03\x00abc";
+/// let (first, rest) = split_length_prefixed::<2, false>(blobs).unwrap();
+/// let (second, rest) = split_length_prefixed::<2, false>(rest).unwrap();
+/// assert_eq!((first, second, rest), (&b"hi"[..], &b"abc"[..], &[][..]));
+/// assert_eq!(split_length_prefixed::<2, false>(b"\x05\x00abc"), None);
+/// ```
+pub const fn split_length_prefixed<const WIDTH: usize, const BIG_ENDIAN: bool>(
+    bytes: &[u8],
+) -> Option<(&[u8], &[u8])> {
+    if WIDTH == 0 || WIDTH > 8 {
+        panic_with!(
+            "the width of the length header is not between 1 and 8",
+            "WIDTH" = WIDTH
+        );
+    }
+    if bytes.len() < WIDTH {
+        return None;
+    }
+    let (header, rest) = bytes.split_at(WIDTH);
+    let mut len: u64 = 0;
+    let mut i = 0;
+    while i < WIDTH {
+        let byte = if BIG_ENDIAN {
+            header[i]
+        } else {
+            header[WIDTH - 1 - i]
+        };
+        len = (len << 8) | byte as u64;
+        i += 1;
+    }
+    if len > rest.len() as u64 {
+        return None;
+    }
+    Some(rest.split_at(len as usize))
+}
+
+/// Returns the length of the run of equal bytes in `bytes` starting at `start`, up to 255.
+const fn run_len(bytes: &[u8], start: usize) -> usize {
+    let mut end = start + 1;
+    while end < bytes.len() && end - start < 255 && bytes[end] == bytes[start] {
+        end += 1;
+    }
+    end - start
+}
+
+/// Compresses the bytes `B::VALUE` using run-length encoding.
+///
+/// Each run of up to 255 equal bytes is encoded as two bytes: the length of the run, followed by
+/// the repeated byte. The desired value is the exact length of the output, which is at most
+/// twice the length of the input. The output is padded with zeros. Use [`rle_encode`] to get the
+/// trimmed output and [`rle_decode_into`] to decompress it.
+pub struct RleEncode<B>(B);
+crate::impl_accept_upper_bound! {
+    impl{B: Const<Type = &'static [u8]>} RleEncode<B>;
+
+    const DESIRED_GENERIC: usize = {
+        let bytes = crate::const_value::<B>();
+        let mut len = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            i += run_len(bytes, i);
+            len += 2;
+        }
+        len
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let bytes = crate::const_value::<B>();
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let run = run_len(bytes, i);
+            w = w.push(run as u8).push(bytes[i]);
+            i += run;
+        }
+        w.finish().0
+    };
+}
+
+/// Compresses the bytes `B::VALUE` at compile time. See [`RleEncode`] for the format.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::{adapters, build::BytesWriter};
+/// gub::const_bytes! {
+///     struct Bitmap = &[0, 0, 0, 0, 0xff, 0xff, 0, 0, 0, 0];
+/// }
+/// const PACKED: &[u8] = adapters::rle_encode::<Bitmap>();
+/// assert_eq!(PACKED, [4, 0, 2, 0xff, 4, 0]);
+///
+/// const LEN: usize = adapters::rle_decoded_len(PACKED);
+/// const UNPACKED: [u8; LEN] = adapters::rle_decode_into(PACKED, BytesWriter::new()).finish().0;
+/// assert_eq!(UNPACKED, gub::const_value::<Bitmap>());
+/// ```
+pub const fn rle_encode<B: Const<Type = &'static [u8]>>() -> &'static [u8] {
+    eval_trimmed::<RleEncode<B>, u8>()
+}
+
+/// Returns the length of the bytes that were compressed by [`RleEncode`] into `encoded`.
+///
+/// # Panics
+/// If `encoded` has an odd length.
+#[track_caller]
+pub const fn rle_decoded_len(encoded: &[u8]) -> usize {
+    if encoded.len() % 2 != 0 {
+        panic!("the run-length encoded input has an odd length");
+    }
+    let mut len = 0;
+    let mut i = 0;
+    while i < encoded.len() {
+        len += encoded[i] as usize;
+        i += 2;
+    }
+    len
+}
+
+/// Decompresses bytes that were compressed by [`RleEncode`] and appends them to `w`.
+///
+/// Use [`rle_decoded_len`] to determine the required capacity. See [`rle_encode`] for an
+/// example.
+///
+/// # Panics
+/// If `encoded` has an odd length or if the decompressed bytes do not fit into the remaining
+/// capacity.
+#[track_caller]
+pub const fn rle_decode_into<const CAP: usize>(
+    encoded: &[u8],
+    mut w: BytesWriter<CAP>,
+) -> BytesWriter<CAP> {
+    if encoded.len() % 2 != 0 {
+        panic!("the run-length encoded input has an odd length");
+    }
+    let mut i = 0;
+    while i < encoded.len() {
+        let mut n = 0;
+        while n < encoded[i] {
+            w = w.push(encoded[i + 1]);
+            n += 1;
+        }
+        i += 2;
+    }
+    w
+}
+
+/// XORs the bytes `A::VALUE` and `B::VALUE` element-wise.
+///
+/// Evaluation panics if the inputs have different lengths. The desired value is the length of
+/// the inputs. The output is padded with zeros. Use [`xor_bytes`] to get the trimmed output.
+pub struct Xor<A, B>(A, B);
+crate::impl_accept_upper_bound! {
+    impl{A: Const<Type = &'static [u8]>, B: Const<Type = &'static [u8]>} Xor<A, B>;
+
+    const DESIRED_GENERIC: usize = {
+        let (a, b) = (crate::const_value::<A>(), crate::const_value::<B>());
+        if a.len() != b.len() {
+            panic_with!("the inputs have different lengths", "left" = a.len(), "right" = b.len());
+        }
+        a.len()
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let (a, b) = (crate::const_value::<A>(), crate::const_value::<B>());
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < a.len() && i < b.len() {
+            w = w.push(a[i] ^ b[i]);
+            i += 1;
+        }
+        w.finish().0
+    };
+}
+
+/// XORs the bytes `A::VALUE` and `B::VALUE` element-wise at compile time.
+///
+/// # Panics
+/// If the inputs have different lengths. This check happens during const evaluation, so it
+/// results in a compile error even if this function is called at runtime.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// gub::const_bytes! {
+///     struct Key = &[0x12, 0x34, 0x56];
+///     struct Mask = &[0xff, 0x0f, 0x00];
+/// }
+/// assert_eq!(gub::adapters::xor_bytes::<Key, Mask>(), [0xed, 0x3b, 0x56]);
+/// ```
+/// ```compile_fail
+/// # use generic_upper_bound as gub;
+/// struct Short;
+/// impl gub::Const for Short {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = &[1];
+/// }
+/// struct Long;
+/// impl gub::Const for Long {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = &[1, 2];
+/// }
+/// let _ = gub::adapters::xor_bytes::<Short, Long>();
+/// ```
+pub const fn xor_bytes<A: Const<Type = &'static [u8]>, B: Const<Type = &'static [u8]>>(
+) -> &'static [u8] {
+    eval_trimmed::<Xor<A, B>, u8>()
+}
+
+/// Interleaves the bytes `A::VALUE` and `B::VALUE`, starting with the first byte of `A::VALUE`.
+///
+/// If `TRUNCATE` is `true`, the output stops after the shorter input ends, like
+/// [`Iterator::zip`]. Otherwise, the rest of the longer input is appended. The desired value is
+/// the exact length of the output. The output is padded with zeros. Use [`zip_bytes`] or
+/// [`interleave_bytes`] to get the trimmed output.
+pub struct Interleave<A, B, const TRUNCATE: bool = false>(A, B);
+crate::impl_accept_upper_bound! {
+    impl{A: Const<Type = &'static [u8]>, B: Const<Type = &'static [u8]>, const TRUNCATE: bool}
+        Interleave<A, B, TRUNCATE>;
+
+    const DESIRED_GENERIC: usize = {
+        let (a, b) = (crate::const_value::<A>(), crate::const_value::<B>());
+        if !TRUNCATE {
+            a.len() + b.len()
+        } else if a.len() < b.len() {
+            2 * a.len()
+        } else {
+            2 * b.len()
+        }
+    };
+
+    const EVAL<const UPPER: usize>: &'static [u8] = &{
+        let (a, b) = (crate::const_value::<A>(), crate::const_value::<B>());
+        let mut w = BytesWriter::<UPPER>::new();
+        let mut i = 0;
+        while i < a.len() || i < b.len() {
+            if TRUNCATE && (i >= a.len() || i >= b.len()) {
+                break;
+            }
+            if i < a.len() {
+                w = w.push(a[i]);
+            }
+            if i < b.len() {
+                w = w.push(b[i]);
+            }
+            i += 1;
+        }
+        w.finish().0
+    };
+}
+
+/// Interleaves the bytes `A::VALUE` and `B::VALUE` at compile time, stopping after the shorter
+/// input ends.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Keys;
+/// impl gub::Const for Keys {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = b"abc";
+/// }
+/// struct Values;
+/// impl gub::Const for Values {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = b"12";
+/// }
+/// assert_eq!(gub::adapters::zip_bytes::<Keys, Values>(), b"a1b2");
+/// ```
+pub const fn zip_bytes<A: Const<Type = &'static [u8]>, B: Const<Type = &'static [u8]>>(
+) -> &'static [u8] {
+    eval_trimmed::<Interleave<A, B, true>, u8>()
+}
+
+/// Interleaves the bytes `A::VALUE` and `B::VALUE` at compile time, appending the rest of the
+/// longer input.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Even;
+/// impl gub::Const for Even {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = b"0246";
+/// }
+/// struct Odd;
+/// impl gub::Const for Odd {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = b"13";
+/// }
+/// assert_eq!(gub::adapters::interleave_bytes::<Even, Odd>(), b"012346");
+/// ```
+pub const fn interleave_bytes<A: Const<Type = &'static [u8]>, B: Const<Type = &'static [u8]>>(
+) -> &'static [u8] {
+    eval_trimmed::<Interleave<A, B>, u8>()
+}
+
+/// A mapping from indices to values, used by [`FromFn`].
+///
+/// Implement this trait using [`impl_index_mapper!`](crate::impl_index_mapper).
+pub trait IndexMapper {
+    /// The type of the values.
+    type Item: 'static;
+    /// A [`Const`] with the values for the indices below `N::VALUE`, using an array of length
+    /// `UPPER` as storage.
+    type Table<N: Const<Type = usize>, const UPPER: usize>: Const<Type = &'static [Self::Item]>;
+}
+
+/// The values of the mapping `M` for the indices below `N::VALUE`.
+///
+/// The desired value is `N::VALUE`. The output is already trimmed. Use [`from_fn`] to evaluate
+/// it.
+pub struct FromFn<N, M>(N, M);
+impl<N: Const<Type = usize>, M: IndexMapper> AcceptUpperBound for FromFn<N, M> {
+    type Output = &'static [M::Item];
+    const DESIRED_GENERIC: usize = crate::const_value::<N>();
+    type Eval<const UPPER: usize> = M::Table<N, UPPER>;
+}
+
+/// Returns the values of the mapping `M` for the indices below `N::VALUE`, computed at compile
+/// time.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::consts::ConstUsize;
+/// const STEPS: usize = 8;
+/// struct Triangle;
+/// gub::impl_index_mapper! {
+///     impl{} Triangle;
+///     fn(i: usize) -> u16 = {
+///         let dist = if i < STEPS / 2 { i } else { STEPS - i };
+///         (100 * dist / (STEPS / 2)) as u16
+///     };
+/// }
+/// assert_eq!(
+///     gub::adapters::from_fn::<ConstUsize<STEPS>, Triangle>(),
+///     [0, 25, 50, 75, 100, 75, 50, 25],
+/// );
+/// ```
+pub const fn from_fn<N: Const<Type = usize>, M: IndexMapper>() -> &'static [M::Item] {
+    eval_trimmed::<FromFn<N, M>, M::Item>()
+}
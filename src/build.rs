@@ -0,0 +1,791 @@
+//! Const builders for use inside of [`AcceptUpperBound::Eval`](crate::AcceptUpperBound::Eval)
+//! implementations.
+//!
+//! Since `&mut` references cannot be used in const fns on the MSRV, the builders are passed and
+//! returned by value.
+
+use core::{
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit},
+    slice,
+};
+
+use crate::{const_value, Const};
+
+/// A const builder for a byte array of length `CAP`.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::build::BytesWriter;
+/// struct Greet<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Greet<N>;
+///     const DESIRED_GENERIC: usize = "Hello, ".len() + N + 1;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &{
+///         let mut w = BytesWriter::<UPPER>::new().write_str("Hello, ");
+///         let mut i = 0;
+///         while i < N {
+///             w = w.push(b'o');
+///             i += 1;
+///         }
+///         w.push(b'!').finish().0
+///     };
+/// }
+/// assert_eq!(gub::eval_str::<Greet<3>>(), "Hello, ooo!");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BytesWriter<const CAP: usize> {
+    buf: [u8; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> BytesWriter<CAP> {
+    /// Creates an empty writer. The unwritten part of the buffer is filled with zeros.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; CAP],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of bytes that were written.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no bytes were written.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a byte.
+    ///
+    /// # Panics
+    /// If `CAP` bytes were already written.
+    #[track_caller]
+    pub const fn push(mut self, byte: u8) -> Self {
+        if self.len >= CAP {
+            panic_with!("the writer is full", "CAP" = CAP);
+        }
+        self.buf[self.len] = byte;
+        self.len += 1;
+        self
+    }
+
+    /// Appends all bytes of `bytes`.
+    ///
+    /// # Panics
+    /// If the bytes do not fit into the remaining capacity.
+    #[track_caller]
+    pub const fn extend_from_slice(mut self, bytes: &[u8]) -> Self {
+        if bytes.len() > CAP - self.len {
+            panic_with!(
+                "the writer is full",
+                "CAP" = CAP,
+                "len" = self.len,
+                "additional" = bytes.len(),
+            );
+        }
+        #[cfg(feature = "rust-1_87")]
+        #[allow(clippy::incompatible_msrv)]
+        {
+            let rest = self.buf.split_at_mut(self.len).1;
+            rest.split_at_mut(bytes.len()).0.copy_from_slice(bytes);
+            self.len += bytes.len();
+        }
+        #[cfg(not(feature = "rust-1_87"))]
+        {
+            let mut i = 0;
+            while i < bytes.len() {
+                self.buf[self.len] = bytes[i];
+                self.len += 1;
+                i += 1;
+            }
+        }
+        self
+    }
+
+    /// Appends the bytes of `s`.
+    ///
+    /// # Panics
+    /// If the bytes do not fit into the remaining capacity.
+    #[track_caller]
+    pub const fn write_str(self, s: &str) -> Self {
+        self.extend_from_slice(s.as_bytes())
+    }
+
+    /// Returns the buffer and the number of bytes that were written.
+    pub const fn finish(self) -> ([u8; CAP], usize) {
+        (self.buf, self.len)
+    }
+}
+
+impl<const CAP: usize> Default for BytesWriter<CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A const builder for a bitset with a runtime number of bits, stored in a byte array of length
+/// `CAP`.
+///
+/// Bit `i` is stored in byte `i / 8` as the bit with value `1 << (i % 8)`. An acceptor that needs
+/// `n` bits should use [`bitset_bytes(n)`](bitset_bytes) as its desired value. The bits of the
+/// trimmed output can be read using [`test_bit`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::build::{bitset_bytes, test_bit, BitsetBuilder};
+/// /// The primes below `N`.
+/// struct Primes<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Primes<N>;
+///     const DESIRED_GENERIC: usize = bitset_bytes(N);
+///     const EVAL<const UPPER: usize>: &'static [u8] = &{
+///         let mut composite = BitsetBuilder::<UPPER>::new(N);
+///         let mut primes = BitsetBuilder::<UPPER>::new(N);
+///         let mut i = 2;
+///         while i < N {
+///             if !composite.test(i) {
+///                 primes = primes.set(i);
+///                 let mut j = i * i;
+///                 while j < N {
+///                     composite = composite.set(j);
+///                     j += i;
+///                 }
+///             }
+///             i += 1;
+///         }
+///         primes.finish().0
+///     };
+/// }
+/// const PRIMES: &[u8] = gub::eval_trimmed::<Primes<100>, u8>();
+/// assert_eq!(PRIMES.len(), 13);
+/// assert!(test_bit(PRIMES, 97));
+/// assert!(!test_bit(PRIMES, 91));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BitsetBuilder<const CAP: usize> {
+    buf: [u8; CAP],
+    bits: usize,
+}
+
+impl<const CAP: usize> BitsetBuilder<CAP> {
+    /// Creates a bitset with `bits` bits that are all unset.
+    ///
+    /// # Panics
+    /// If [`bitset_bytes(bits)`](bitset_bytes) is larger than `CAP`.
+    #[track_caller]
+    pub const fn new(bits: usize) -> Self {
+        if bitset_bytes(bits) > CAP {
+            panic_with!(
+                "the bitset does not fit into the buffer",
+                "CAP" = CAP,
+                "bits" = bits
+            );
+        }
+        Self {
+            buf: [0; CAP],
+            bits,
+        }
+    }
+
+    /// Returns the number of bits.
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns whether bit `i` is set.
+    ///
+    /// # Panics
+    /// If `i` is not less than the number of bits.
+    #[track_caller]
+    pub const fn test(&self, i: usize) -> bool {
+        self.check(i);
+        self.buf[i / 8] & (1 << (i % 8)) != 0
+    }
+
+    /// Sets bit `i`.
+    ///
+    /// # Panics
+    /// If `i` is not less than the number of bits.
+    #[track_caller]
+    pub const fn set(mut self, i: usize) -> Self {
+        self.check(i);
+        self.buf[i / 8] |= 1 << (i % 8);
+        self
+    }
+
+    /// Unsets bit `i`.
+    ///
+    /// # Panics
+    /// If `i` is not less than the number of bits.
+    #[track_caller]
+    pub const fn clear(mut self, i: usize) -> Self {
+        self.check(i);
+        self.buf[i / 8] &= !(1 << (i % 8));
+        self
+    }
+
+    #[track_caller]
+    const fn check(&self, i: usize) {
+        if i >= self.bits {
+            panic_with!("bit index out of bounds", "index" = i, "bits" = self.bits);
+        }
+    }
+
+    /// Returns the buffer and the number of bytes that are used, i.e.
+    /// [`bitset_bytes(self.bits())`](bitset_bytes).
+    pub const fn finish(self) -> ([u8; CAP], usize) {
+        (self.buf, bitset_bytes(self.bits))
+    }
+}
+
+/// Returns the number of bytes needed to store `bits` bits, i.e. `bits` divided by `8`, rounded
+/// up.
+pub const fn bitset_bytes(bits: usize) -> usize {
+    bits.div_ceil(8)
+}
+
+/// Returns whether bit `i` is set in a bitset built by [`BitsetBuilder`].
+///
+/// # Panics
+/// If `i / 8` is out of bounds for `bytes`.
+pub const fn test_bit(bytes: &[u8], i: usize) -> bool {
+    bytes[i / 8] & (1 << (i % 8)) != 0
+}
+
+/// Returns `bytes` without the trailing bytes that are equal to `SENTINEL`.
+///
+/// This trims outputs that were padded with `SENTINEL`, e.g. using
+/// [`PadWith`](crate::combinators::PadWith).
+///
+/// ```
+/// use generic_upper_bound::build::trim_trailing_u8;
+/// assert_eq!(trim_trailing_u8::<0xff>(&[1, 0, 0xff, 0xff]), [1, 0]);
+/// ```
+pub const fn trim_trailing_u8<const SENTINEL: u8>(bytes: &[u8]) -> &[u8] {
+    let mut len = bytes.len();
+    while len > 0 && bytes[len - 1] == SENTINEL {
+        len -= 1;
+    }
+    bytes.split_at(len).0
+}
+
+/// Returns `items` without the trailing elements that are equal to `SENTINEL`.
+///
+/// See [`trim_trailing_u8`].
+pub const fn trim_trailing_usize<const SENTINEL: usize>(items: &[usize]) -> &[usize] {
+    let mut len = items.len();
+    while len > 0 && items[len - 1] == SENTINEL {
+        len -= 1;
+    }
+    items.split_at(len).0
+}
+
+/// A const builder for an array of length `CAP` with elements of type `T`.
+///
+/// The unwritten part of the buffer is filled with a value from a [`Const`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::build::ArrayBuilder;
+/// struct Zero;
+/// impl gub::Const for Zero {
+///     type Type = (u32, char);
+///     const VALUE: Self::Type = (0, '\0');
+/// }
+/// struct Table<const N: u32>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: u32} Table<N>;
+///     const DESIRED_GENERIC: usize = N as usize;
+///     const EVAL<const UPPER: usize>: &'static [(u32, char)] = &{
+///         let mut b = ArrayBuilder::<_, UPPER>::new::<Zero>();
+///         while b.len() < N as usize {
+///             let i = b.len() as u32;
+///             b = b.push((i, match char::from_digit(i % 10, 10) {
+///                 Some(c) => c,
+///                 None => unreachable!(),
+///             }));
+///         }
+///         b.finish().0
+///     };
+/// }
+/// assert_eq!(gub::eval_trimmed::<Table<3>, _>(), [(0, '0'), (1, '1'), (2, '2')]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayBuilder<T, const CAP: usize> {
+    buf: [T; CAP],
+    len: usize,
+}
+
+impl<T: Copy, const CAP: usize> ArrayBuilder<T, CAP> {
+    /// Creates an empty builder whose buffer is filled with `F::VALUE`.
+    pub const fn new<F: Const<Type = T>>() -> Self {
+        Self {
+            buf: [const_value::<F>(); CAP],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements that were pushed.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no elements were pushed.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends an element.
+    ///
+    /// # Panics
+    /// If `CAP` elements were already pushed.
+    #[track_caller]
+    pub const fn push(mut self, item: T) -> Self {
+        if self.len >= CAP {
+            panic_with!("the builder is full", "CAP" = CAP);
+        }
+        self.buf[self.len] = item;
+        self.len += 1;
+        self
+    }
+
+    /// Appends all elements of `items`.
+    ///
+    /// # Panics
+    /// If the elements do not fit into the remaining capacity.
+    #[track_caller]
+    pub const fn extend(mut self, items: &[T]) -> Self {
+        if items.len() > CAP - self.len {
+            panic_with!(
+                "the builder is full",
+                "CAP" = CAP,
+                "len" = self.len,
+                "additional" = items.len(),
+            );
+        }
+        #[cfg(feature = "rust-1_87")]
+        #[allow(clippy::incompatible_msrv)]
+        {
+            let rest = self.buf.split_at_mut(self.len).1;
+            rest.split_at_mut(items.len()).0.copy_from_slice(items);
+            self.len += items.len();
+        }
+        #[cfg(not(feature = "rust-1_87"))]
+        {
+            let mut i = 0;
+            while i < items.len() {
+                self.buf[self.len] = items[i];
+                self.len += 1;
+                i += 1;
+            }
+        }
+        self
+    }
+
+    /// Returns the buffer and the number of elements that were pushed.
+    pub const fn finish(self) -> ([T; CAP], usize) {
+        (self.buf, self.len)
+    }
+}
+
+/// An array of `MaybeUninit<T>` with an initialized prefix.
+///
+/// This is usually used through [`UninitArrayBuilder`], which is the array version of this type.
+/// A reference to it can be coerced to a reference to the slice version, whose type does not
+/// depend on the capacity. This allows returning it from an
+/// [`AcceptUpperBound::Eval`](crate::AcceptUpperBound::Eval) implementation.
+///
+/// Unlike with [`ArrayBuilder`], `T` does not need to be [`Copy`] and no fill value is needed.
+/// The pushed elements are never dropped.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::build::{UninitArrayBuilder, UninitPrefix};
+/// #[derive(Debug, PartialEq)]
+/// struct Id(u32);
+/// struct Ids<const N: u32>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: u32} Ids<N>;
+///     const DESIRED_GENERIC: usize = N as usize;
+///     const EVAL<const UPPER: usize>: &'static UninitPrefix<Id> = &{
+///         let mut b = UninitArrayBuilder::<Id, UPPER>::new();
+///         while b.len() < N as usize {
+///             let id = Id(b.len() as u32);
+///             b = b.push(id);
+///         }
+///         b
+///     };
+/// }
+/// const IDS: &[Id] = gub::eval_with_upper_bound::<Ids<3>>().finish_prefix();
+/// assert_eq!(IDS, [Id(0), Id(1), Id(2)]);
+/// ```
+pub struct UninitPrefix<T, B: ?Sized = [MaybeUninit<T>]> {
+    len: usize,
+    _elem: PhantomData<T>,
+    buf: B,
+}
+
+/// A const builder for an array of `MaybeUninit<T>` with capacity `CAP`.
+///
+/// See [`UninitPrefix`] for details.
+pub type UninitArrayBuilder<T, const CAP: usize> = UninitPrefix<T, [MaybeUninit<T>; CAP]>;
+
+impl<T, const CAP: usize> UninitPrefix<T, [MaybeUninit<T>; CAP]> {
+    /// Creates an empty builder.
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            _elem: PhantomData,
+            // SAFETY: An array of `MaybeUninit` does not need to be initialized.
+            buf: unsafe { MaybeUninit::uninit().assume_init() },
+        }
+    }
+
+    /// Appends an element.
+    ///
+    /// # Panics
+    /// If `CAP` elements were already pushed.
+    #[track_caller]
+    pub const fn push(mut self, item: T) -> Self {
+        if self.len >= CAP {
+            panic_with!("the builder is full", "CAP" = CAP);
+        }
+        self.buf[self.len] = MaybeUninit::new(item);
+        self.len += 1;
+        self
+    }
+
+    /// Returns the initialized prefix.
+    pub const fn finish_prefix(&self) -> &[T] {
+        // SAFETY: The first `len` elements were initialized by `push`.
+        unsafe { slice::from_raw_parts(self.buf.as_ptr().cast(), self.len) }
+    }
+
+    /// Returns the elements as an array.
+    ///
+    /// # Panics
+    /// If fewer than `CAP` elements were pushed.
+    #[track_caller]
+    pub const fn into_array(self) -> [T; CAP] {
+        if self.len != CAP {
+            panic_with!("the builder is not full", "CAP" = CAP, "len" = self.len);
+        }
+        // A union is used instead of borrowing `self.buf`, which the MSRV does not allow if `T`
+        // may have interior mutability.
+        union Transmute<T, const CAP: usize> {
+            uninit: ManuallyDrop<[MaybeUninit<T>; CAP]>,
+            init: ManuallyDrop<[T; CAP]>,
+        }
+        let buf = Transmute {
+            uninit: ManuallyDrop::new(self.buf),
+        };
+        // SAFETY: All `CAP` elements were initialized by `push` and `[MaybeUninit<T>; CAP]` has
+        // the same layout as `[T; CAP]`.
+        ManuallyDrop::into_inner(unsafe { buf.init })
+    }
+}
+
+impl<T, B: ?Sized> UninitPrefix<T, B> {
+    /// Returns the number of elements that were pushed.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no elements were pushed.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T> UninitPrefix<T> {
+    /// Returns the initialized prefix.
+    pub const fn finish_prefix(&self) -> &[T] {
+        // SAFETY: The first `len` elements were initialized by `push`, since this can only be
+        // created by coercing a reference to an `UninitArrayBuilder`.
+        unsafe { slice::from_raw_parts(self.buf.as_ptr().cast(), self.len) }
+    }
+}
+
+impl<T, const CAP: usize> Default for UninitPrefix<T, [MaybeUninit<T>; CAP]> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns an array of length `N` whose elements are each a fresh evaluation of `F::VALUE`.
+///
+/// Unlike `[const_value::<F>(); N]`, this works for element types that are not [`Copy`] (e.g. ones
+/// that contain a `Cell`). See [`array_from_const!`](crate::array_from_const) for a version whose
+/// elements can depend on the index.
+///
+/// ```
+/// use core::cell::Cell;
+/// use generic_upper_bound as gub;
+/// struct Counter;
+/// impl gub::Const for Counter {
+///     type Type = Cell<u32>;
+///     const VALUE: Self::Type = Cell::new(0);
+/// }
+/// let counters = gub::build::from_fn_const::<Counter, 3>();
+/// assert_eq!(gub::array_from_const!(Counter; 2).map(Cell::into_inner), [0, 0]);
+/// counters[1].set(7);
+/// assert_eq!(counters.map(Cell::into_inner), [0, 7, 0]);
+/// ```
+pub const fn from_fn_const<F: Const, const N: usize>() -> [F::Type; N] {
+    let mut b = UninitArrayBuilder::<F::Type, N>::new();
+    let mut i = 0;
+    while i < N {
+        b = b.push(const_value::<F>());
+        i += 1;
+    }
+    b.into_array()
+}
+
+/// A range of elements that were allocated in a [`ConstArena`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The offset of the first element.
+    pub start: usize,
+    /// The number of elements.
+    pub len: usize,
+}
+
+impl Span {
+    /// An empty span.
+    pub const EMPTY: Self = Self { start: 0, len: 0 };
+
+    /// Returns the offset after the last element.
+    pub const fn end(&self) -> usize {
+        self.start + self.len
+    }
+}
+
+/// A const bump allocator with capacity `CAP` for building structured data, such as lists of
+/// lists or trees, before serializing it.
+///
+/// Values and slices are allocated by appending them, and are referred to by their offset or
+/// [`Span`], which can themselves be stored in the arena if `T` is `usize`. Allocated elements
+/// can be read and overwritten, but not freed. The arena is usually sized by `UPPER` and turned
+/// into the output using [`Self::finish`] or [`Self::flatten`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::{build::{ConstArena, Span}, consts::ConstUsize};
+/// /// The first `N` rows of Pascal's triangle, last row first.
+/// struct Pascal<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Pascal<N>;
+///     const DESIRED_GENERIC: usize = N * (N + 1) / 2;
+///     const EVAL<const UPPER: usize>: &'static [usize] = &{
+///         let mut arena = ConstArena::<usize, UPPER>::new();
+///         let mut rows = [Span::EMPTY; N];
+///         let mut prev = Span::EMPTY;
+///         let mut i = 0;
+///         while i < N {
+///             let row;
+///             (arena, row) = arena.reserve(i + 1, 1);
+///             let mut j = 1;
+///             while j < i {
+///                 let sum = arena.get(prev.start + j - 1) + arena.get(prev.start + j);
+///                 arena = arena.set(row.start + j, sum);
+///                 j += 1;
+///             }
+///             rows[N - 1 - i] = row;
+///             prev = row;
+///             i += 1;
+///         }
+///         arena.flatten::<ConstUsize<0>, UPPER>(&rows).finish().0
+///     };
+/// }
+/// assert_eq!(
+///     gub::eval_trimmed::<Pascal<4>, _>(),
+///     [1, 3, 3, 1, 1, 2, 1, 1, 1, 1],
+/// );
+/// ```
+pub struct ConstArena<T, const CAP: usize> {
+    buf: [MaybeUninit<T>; CAP],
+    len: usize,
+}
+
+impl<T: Copy, const CAP: usize> ConstArena<T, CAP> {
+    /// Creates an empty arena.
+    pub const fn new() -> Self {
+        Self {
+            // SAFETY: An array of `MaybeUninit` does not need to be initialized.
+            buf: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements that were allocated.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no elements were allocated.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements that can still be allocated.
+    pub const fn remaining(&self) -> usize {
+        CAP - self.len
+    }
+
+    /// Allocates `value` and returns its offset.
+    ///
+    /// # Panics
+    /// If the arena is full.
+    #[track_caller]
+    pub const fn alloc_value(self, value: T) -> (Self, usize) {
+        let (arena, span) = self.reserve(1, value);
+        (arena, span.start)
+    }
+
+    /// Allocates a copy of `items` and returns its span.
+    ///
+    /// # Panics
+    /// If the elements do not fit into the remaining capacity.
+    #[track_caller]
+    pub const fn alloc_slice(mut self, items: &[T]) -> (Self, Span) {
+        self = self.check_capacity(items.len());
+        let span = Span {
+            start: self.len,
+            len: items.len(),
+        };
+        let mut i = 0;
+        while i < items.len() {
+            self.buf[self.len] = MaybeUninit::new(items[i]);
+            self.len += 1;
+            i += 1;
+        }
+        (self, span)
+    }
+
+    /// Allocates `len` copies of `fill` and returns their span, e.g. to fill them in later using
+    /// [`Self::set`].
+    ///
+    /// # Panics
+    /// If the elements do not fit into the remaining capacity.
+    #[track_caller]
+    pub const fn reserve(mut self, len: usize, fill: T) -> (Self, Span) {
+        self = self.check_capacity(len);
+        let span = Span {
+            start: self.len,
+            len,
+        };
+        while self.len < span.end() {
+            self.buf[self.len] = MaybeUninit::new(fill);
+            self.len += 1;
+        }
+        (self, span)
+    }
+
+    #[track_caller]
+    const fn check_capacity(self, additional: usize) -> Self {
+        if additional > CAP - self.len {
+            panic_with!(
+                "the arena is full",
+                "CAP" = CAP,
+                "len" = self.len,
+                "additional" = additional,
+            );
+        }
+        self
+    }
+
+    /// Returns the element at `offset`.
+    ///
+    /// # Panics
+    /// If `offset` was not allocated.
+    #[track_caller]
+    pub const fn get(&self, offset: usize) -> T {
+        self.allocated()[offset]
+    }
+
+    /// Overwrites the element at `offset`.
+    ///
+    /// # Panics
+    /// If `offset` was not allocated.
+    #[track_caller]
+    pub const fn set(mut self, offset: usize, value: T) -> Self {
+        if offset >= self.len {
+            panic_with!(
+                "the offset was not allocated",
+                "offset" = offset,
+                "len" = self.len,
+            );
+        }
+        self.buf[offset] = MaybeUninit::new(value);
+        self
+    }
+
+    /// Returns the elements of `span`.
+    ///
+    /// # Panics
+    /// If `span` was not allocated.
+    #[track_caller]
+    pub const fn slice(&self, span: Span) -> &[T] {
+        if span.start > self.len || span.len > self.len - span.start {
+            panic_with!(
+                "the span was not allocated",
+                "start" = span.start,
+                "span_len" = span.len,
+                "len" = self.len,
+            );
+        }
+        // SAFETY: The first `len` elements were initialized when they were allocated.
+        unsafe { slice::from_raw_parts(self.buf.as_ptr().cast::<T>().add(span.start), span.len) }
+    }
+
+    /// Returns all allocated elements, in the order they were allocated.
+    pub const fn allocated(&self) -> &[T] {
+        self.slice(Span {
+            start: 0,
+            len: self.len,
+        })
+    }
+
+    /// Returns the allocated elements followed by copies of `F::VALUE`, and the number of
+    /// allocated elements.
+    pub const fn finish<F: Const<Type = T>>(&self) -> ([T; CAP], usize) {
+        ArrayBuilder::<T, CAP>::new::<F>()
+            .extend(self.allocated())
+            .finish()
+    }
+
+    /// Concatenates the elements of `spans` into an [`ArrayBuilder`] whose unused elements are
+    /// `F::VALUE`.
+    ///
+    /// # Panics
+    /// If a span was not allocated or the elements do not fit into `N` elements.
+    #[track_caller]
+    pub const fn flatten<F: Const<Type = T>, const N: usize>(
+        &self,
+        spans: &[Span],
+    ) -> ArrayBuilder<T, N> {
+        let mut b = ArrayBuilder::<T, N>::new::<F>();
+        let mut i = 0;
+        while i < spans.len() {
+            b = b.extend(self.slice(spans[i]));
+            i += 1;
+        }
+        b
+    }
+}
+
+impl<T: Copy, const CAP: usize> Clone for ConstArena<T, CAP> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: Copy, const CAP: usize> Copy for ConstArena<T, CAP> {}
+
+impl<T: Copy, const CAP: usize> Default for ConstArena<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
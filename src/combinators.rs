@@ -0,0 +1,703 @@
+//! Wrappers that modify the behavior of other acceptors.
+
+use crate::{adapters::SliceElem, const_value, math, AcceptUpperBound, Const, Ladder};
+
+/// Wraps an [`AcceptUpperBound`] such that its upper bound is a multiple of `ALIGN`.
+///
+/// The upper bound is the smallest candidate of `A`'s [ladder](AcceptUpperBound::LADDER) that is
+/// a multiple of `ALIGN` (see [`Ladder::multiple_of`]). This is useful for aligned buffers
+/// and SIMD-friendly tables.
+///
+/// # Panics
+/// Evaluation panics if there is no such candidate, which can only happen if `ALIGN` is not a
+/// power of two or if the desired value is very large.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::combinators::AlignTo;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = UPPER % 64;
+/// }
+/// assert_eq!(gub::get_upper_bound::<AlignTo<Len<37>, 64>>(), 64);
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::get_upper_bound::<AlignTo<Len<100>, 64>>(), 128);
+/// assert_eq!(gub::eval_with_upper_bound::<AlignTo<Len<100>, 64>>(), 0);
+/// ```
+pub struct AlignTo<A, const ALIGN: usize>(A);
+impl<A: AcceptUpperBound, const ALIGN: usize> AcceptUpperBound for AlignTo<A, ALIGN> {
+    type Output = A::Output;
+    const DESIRED_GENERIC: usize = crate::desired_generic::<A>();
+    const LADDER: Ladder = A::LADDER.multiple_of(ALIGN);
+    type Eval<const UPPER: usize> = AlignToEval<A, ALIGN, UPPER>;
+}
+
+#[doc(hidden)]
+pub struct AlignToEval<A, const ALIGN: usize, const UPPER: usize>(A);
+impl<A: AcceptUpperBound, const ALIGN: usize, const UPPER: usize> Const
+    for AlignToEval<A, ALIGN, UPPER>
+{
+    type Type = A::Output;
+    const VALUE: Self::Type = {
+        if UPPER % ALIGN != 0 {
+            panic_with!(
+                "no candidate that is a multiple of ALIGN is large enough",
+                "ALIGN" = ALIGN,
+                "UPPER" = UPPER,
+            );
+        }
+        const_value::<A::Eval<UPPER>>()
+    };
+}
+
+/// Wraps an [`AcceptUpperBound`] that outputs a slice such that all elements after the first
+/// [`desired_generic`](crate::desired_generic) are `F::VALUE`.
+///
+/// This is useful if `0`, which most acceptors pad their output with, is a meaningful element.
+/// The output can then be trimmed using the padding as a sentinel, e.g. with
+/// [`build::trim_trailing_u8`](crate::build::trim_trailing_u8).
+///
+/// # Panics
+/// Evaluation panics if the output of `A` is shorter than its desired value.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::{build::trim_trailing_u8, combinators::{AlignTo, PadWith}};
+/// /// The names in `L::VALUE`, each followed by a NUL byte.
+/// struct NulSeparated<L>(L);
+/// gub::impl_accept_upper_bound! {
+///     impl{L: gub::Const<Type = &'static [&'static str]>} NulSeparated<L>;
+///     const DESIRED_GENERIC: usize =
+///         gub::str::total_len(gub::const_value::<L>()) + gub::const_value::<L>().len();
+///     const EVAL<const UPPER: usize>: &'static [u8] = &{
+///         let names = gub::const_value::<L>();
+///         let mut w = gub::build::BytesWriter::<UPPER>::new();
+///         let mut i = 0;
+///         while i < names.len() {
+///             w = w.write_str(names[i]).push(0);
+///             i += 1;
+///         }
+///         w.finish().0
+///     };
+/// }
+/// struct Names;
+/// impl gub::Const for Names {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["a", "bc", "def"];
+/// }
+/// struct Ff;
+/// impl gub::Const for Ff {
+///     type Type = u8;
+///     const VALUE: Self::Type = 0xff;
+/// }
+/// type Table = PadWith<AlignTo<NulSeparated<Names>, 16>, Ff>;
+/// let padded = gub::eval_with_upper_bound::<Table>();
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(padded, b"a\0bc\0def\0\xff\xff\xff\xff\xff\xff\xff");
+/// assert_eq!(trim_trailing_u8::<0xff>(padded), b"a\0bc\0def\0");
+/// ```
+pub struct PadWith<A, F>(A, F);
+impl<T, A, F> AcceptUpperBound for PadWith<A, F>
+where
+    T: SliceElem,
+    A: AcceptUpperBound<Output = &'static [T]>,
+    F: Const<Type = T>,
+{
+    type Output = &'static [T];
+    const DESIRED_GENERIC: usize = crate::desired_generic::<T::__PadWith<A, F>>();
+    const LADDER: Ladder = <T::__PadWith<A, F> as AcceptUpperBound>::LADDER;
+    type Eval<const UPPER: usize> = <T::__PadWith<A, F> as AcceptUpperBound>::Eval<UPPER>;
+}
+
+macro_rules! impl_tuple {
+    ($(#[$meta:meta])* $($A:ident)*) => {
+        $(#[$meta])*
+        impl<$($A: AcceptUpperBound),*> AcceptUpperBound for ($($A,)*) {
+            type Output = ($($A::Output,)*);
+            const DESIRED_GENERIC: usize = {
+                let mut max = 0;
+                $(
+                    if crate::desired_generic::<$A>() > max {
+                        max = crate::desired_generic::<$A>();
+                    }
+                )*
+                max
+            };
+            const LADDER: Ladder = {
+                let ladder = Ladder::DEFAULT;
+                $(let ladder = ladder.and($A::LADDER);)*
+                ladder
+            };
+            type Eval<const UPPER: usize> = TupleEval<Self, UPPER>;
+        }
+        impl<$($A: AcceptUpperBound),*, const UPPER: usize> Const for TupleEval<($($A,)*), UPPER> {
+            type Type = ($($A::Output,)*);
+            const VALUE: Self::Type = ($(const_value::<$A::Eval<UPPER>>(),)*);
+        }
+    };
+}
+impl_tuple! {
+    /// Evaluates every acceptor in a tuple with the same upper bound.
+    ///
+    /// This is implemented for tuples of up to 8 acceptors.
+    ///
+    /// `DESIRED_GENERIC` is the largest desired value of the components and the
+    /// [ladder](AcceptUpperBound::LADDER) only contains candidates of all components' ladders.
+    /// Since all components share one `UPPER`, this instantiates fewer consts than evaluating
+    /// each component separately.
+    ///
+    /// ```
+    /// use generic_upper_bound as gub;
+    /// struct Len<const N: usize>;
+    /// gub::impl_accept_upper_bound! {
+    ///     impl{const N: usize} Len<N>;
+    ///     const DESIRED_GENERIC: usize = N;
+    ///     const EVAL<const UPPER: usize>: usize = UPPER;
+    /// }
+    /// let (a, b) = gub::eval_with_upper_bound::<(Len<3>, Len<100>)>();
+    /// assert_eq!(a, b);
+    /// assert_eq!(a, gub::get_upper_bound::<Len<100>>());
+    /// let (a, .., e) = gub::eval_with_upper_bound::<(Len<1>, Len<2>, Len<3>, Len<4>, Len<5>)>();
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert_eq!((a, e), (5, 5));
+    /// ```
+    A B
+}
+impl_tuple!(A B C);
+impl_tuple!(A B C D);
+impl_tuple!(A B C D E);
+impl_tuple!(A B C D E F);
+impl_tuple!(A B C D E F G);
+impl_tuple!(A B C D E F G H);
+
+#[doc(hidden)]
+pub struct TupleEval<T, const UPPER: usize>(T);
+
+/// A post-processing step for the output of an [`AcceptUpperBound`], used by [`Map`].
+///
+/// This is most easily implemented using [`impl_map!`](crate::impl_map).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot map outputs of type `{T}`",
+    label = "`{Self}` does not implement `MapOutput<{T}>`",
+    note = "implement it using `generic_upper_bound::impl_map!`"
+)]
+pub trait MapOutput<T> {
+    /// The type of the processed output.
+    type Output;
+    /// A [`Const`] that processes the output of `A` for the upper bound `UPPER`.
+    type Apply<A: AcceptUpperBound<Output = T>, const UPPER: usize>: Const<Type = Self::Output>;
+}
+
+/// Wraps an [`AcceptUpperBound`] such that its output is processed by `F`.
+///
+/// The desired value and [ladder](AcceptUpperBound::LADDER) are those of `A`.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::combinators::Map;
+/// struct Repeat<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Repeat<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[b'a'; UPPER];
+/// }
+/// struct Trim;
+/// gub::impl_map! {
+///     impl{} Trim;
+///     fn(bytes: &'static [u8], desired: usize) -> &'static str = match core::str::from_utf8(
+///         bytes.split_at(desired).0,
+///     ) {
+///         Ok(s) => s,
+///         Err(_) => panic!("invalid UTF-8"),
+///     };
+/// }
+/// assert_eq!(gub::eval_with_upper_bound::<Map<Repeat<3>, Trim>>(), "aaa");
+/// assert_eq!(gub::eval_with_upper_bound::<Map<Repeat<1000>, Trim>>().len(), 1000);
+/// ```
+pub struct Map<A, F>(A, F);
+impl<A: AcceptUpperBound, F: MapOutput<A::Output>> AcceptUpperBound for Map<A, F> {
+    type Output = F::Output;
+    const DESIRED_GENERIC: usize = crate::desired_generic::<A>();
+    const LADDER: Ladder = A::LADDER;
+    type Eval<const UPPER: usize> = F::Apply<A, UPPER>;
+}
+
+/// The second stage of [`Then`], which is an acceptor that depends on the output of the first.
+pub trait ThenAccept<T> {
+    /// The output of the second stage.
+    type Output;
+    /// The acceptor of the second stage, given the output of the first stage as a [`Const`].
+    type Stage<V: Const<Type = T>>: AcceptUpperBound<Output = Self::Output>;
+}
+
+/// Chains two acceptors, such that the second one can depend on the output of the first.
+///
+/// `A` is evaluated using [`eval_with_upper_bound`](crate::eval_with_upper_bound), then the
+/// acceptor `B::Stage<V>` is evaluated with its own upper bound, where `V` is a [`Const`] with the
+/// output of `A`.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::{combinators::Then, Const};
+/// // First stage: the digits of N
+/// struct Digits<const N: u64>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: u64} Digits<N>;
+///     const DESIRED_GENERIC: usize = N.ilog10() as usize + 1;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &{
+///         let mut out = [0; UPPER];
+///         let (mut n, mut i) = (N, 0);
+///         while n > 0 {
+///             out[i] = b'0' + (n % 10) as u8;
+///             (n, i) = (n / 10, i + 1);
+///         }
+///         out
+///     };
+/// }
+/// // Second stage: a buffer whose length depends on the sum of the digits
+/// struct DigitSum;
+/// impl gub::combinators::ThenAccept<&'static [u8]> for DigitSum {
+///     type Output = usize;
+///     type Stage<V: Const<Type = &'static [u8]>> = DigitSumStage<V>;
+/// }
+/// struct DigitSumStage<V>(V);
+/// gub::impl_accept_upper_bound! {
+///     impl{V: Const<Type = &'static [u8]>} DigitSumStage<V>;
+///     const DESIRED_GENERIC: usize = {
+///         let (digits, mut sum, mut i) = (gub::const_value::<V>(), 0, 0);
+///         while i < digits.len() {
+///             sum += digits[i].saturating_sub(b'0') as usize;
+///             i += 1;
+///         }
+///         sum
+///     };
+///     const EVAL<const UPPER: usize>: usize = [(); UPPER].len();
+/// }
+/// let bound = gub::eval_with_upper_bound::<Then<Digits<99999>, DigitSum>>();
+/// assert!(bound >= 45);
+/// assert_eq!(gub::desired_generic::<Then<Digits<99999>, DigitSum>>(), 45);
+/// ```
+pub struct Then<A, B>(A, B);
+impl<A: AcceptUpperBound, B: ThenAccept<A::Output>> AcceptUpperBound for Then<A, B> {
+    type Output = B::Output;
+    const DESIRED_GENERIC: usize = crate::desired_generic::<B::Stage<Nested<A>>>();
+    const LADDER: Ladder = <B::Stage<Nested<A>>>::LADDER;
+    type Eval<const UPPER: usize> = <B::Stage<Nested<A>> as AcceptUpperBound>::Eval<UPPER>;
+}
+
+/// Selects between two acceptors with the same output, using `A` if `C::VALUE` is `true` and `B`
+/// otherwise.
+///
+/// The desired value, ladder and evaluation are those of the selected acceptor. The other
+/// acceptor is not evaluated, so it may panic or fail to have an upper bound.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::combinators::Cond;
+/// struct Fill<const N: usize, const BYTE: u8>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize, const BYTE: u8} Fill<N, BYTE>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[BYTE; UPPER];
+/// }
+/// trait Layout {
+///     const COMPACT: bool;
+/// }
+/// struct IsCompact<T>(T);
+/// impl<T: Layout> gub::Const for IsCompact<T> {
+///     type Type = bool;
+///     const VALUE: Self::Type = T::COMPACT;
+/// }
+/// struct Small;
+/// impl Layout for Small {
+///     const COMPACT: bool = true;
+/// }
+/// struct Large;
+/// impl Layout for Large {
+///     const COMPACT: bool = false;
+/// }
+/// type Buffer<T> = Cond<IsCompact<T>, Fill<4, b'c'>, Fill<300, b'w'>>;
+/// assert_eq!(gub::eval_str::<Buffer<Small>>(), "cccc");
+/// assert_eq!(gub::eval_str::<Buffer<Large>>(), "w".repeat(300));
+/// ```
+pub struct Cond<C, A, B>(C, A, B);
+impl<C, A, B> AcceptUpperBound for Cond<C, A, B>
+where
+    C: Const<Type = bool>,
+    A: AcceptUpperBound,
+    B: AcceptUpperBound<Output = A::Output>,
+{
+    type Output = A::Output;
+    const DESIRED_GENERIC: usize = if const_value::<C>() {
+        crate::desired_generic::<A>()
+    } else {
+        crate::desired_generic::<B>()
+    };
+    const LADDER: Ladder = if const_value::<C>() {
+        A::LADDER
+    } else {
+        B::LADDER
+    };
+    type Eval<const UPPER: usize> = CondEval<C, A, B, UPPER>;
+}
+
+#[doc(hidden)]
+pub struct CondEval<C, A, B, const UPPER: usize>(C, A, B);
+impl<C, A, B, const UPPER: usize> Const for CondEval<C, A, B, UPPER>
+where
+    C: Const<Type = bool>,
+    A: AcceptUpperBound,
+    B: AcceptUpperBound<Output = A::Output>,
+{
+    type Type = A::Output;
+    // The `Eval`s are only referenced through function calls, so that the one that is not
+    // selected is not evaluated.
+    const VALUE: Self::Type = if const_value::<C>() {
+        const_value::<A::Eval<UPPER>>()
+    } else {
+        const_value::<B::Eval<UPPER>>()
+    };
+}
+
+/// Wraps an [`AcceptUpperBound`] whose output is an array, such that the output is a reference
+/// to a single evaluation of it.
+///
+/// The compiler already evaluates every associated constant once per instantiation and caches
+/// the result. Since the output of an acceptor is such a constant, evaluating `A` in several
+/// places, e.g. through [`Nested`], only runs its `EVAL` once. However, the cached output is
+/// copied into every place that uses it, and values that are computed by `const fn`s, such as
+/// helpers called from both `DESIRED_GENERIC` and `EVAL`, are recomputed on every call. This
+/// wrapper avoids the former: its output is a `&'static` reference to the output of `A`, so
+/// large arrays are evaluated once and then shared instead of copied. For the latter, move the
+/// shared computation into a [`Const`].
+///
+/// The output of `A` must implement [`MemoizeOutput`], since a reference to a constant of a
+/// generic type cannot be `'static`.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::combinators::{Memoize, Nested};
+/// /// The squares of the numbers below `N`, which are all below `UPPER`.
+/// struct Squares<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Squares<N>;
+///     const DESIRED_GENERIC: usize = N * N;
+///     const EVAL<const UPPER: usize>: [usize; 64] = {
+///         let mut out = [0; 64];
+///         let mut i = 0;
+///         while i < N {
+///             out[i] = i * i;
+///             i += 1;
+///         }
+///         out
+///     };
+/// }
+/// const TABLE: &[usize; 64] = gub::const_value::<Nested<Memoize<Squares<8>>>>();
+/// assert_eq!(TABLE[..8], [0, 1, 4, 9, 16, 25, 36, 49]);
+/// assert_eq!(gub::eval_with_upper_bound::<Memoize<Squares<8>>>()[7], 49);
+/// ```
+pub struct Memoize<A>(A);
+impl<A: AcceptUpperBound> AcceptUpperBound for Memoize<A>
+where
+    A::Output: MemoizeOutput,
+{
+    type Output = &'static A::Output;
+    const DESIRED_GENERIC: usize =
+        crate::desired_generic::<<A::Output as MemoizeOutput>::__Memoize<A>>();
+    const LADDER: Ladder = <<A::Output as MemoizeOutput>::__Memoize<A> as AcceptUpperBound>::LADDER;
+    type Eval<const UPPER: usize> =
+        <<A::Output as MemoizeOutput>::__Memoize<A> as AcceptUpperBound>::Eval<UPPER>;
+}
+
+/// The output of an acceptor that can be wrapped in [`Memoize`].
+///
+/// This trait is sealed and implemented for arrays of the primitive integer types, `bool` and
+/// `char`.
+pub trait MemoizeOutput: crate::sealed::Sealed + 'static {
+    #[doc(hidden)]
+    type __Memoize<A: AcceptUpperBound<Output = Self>>: AcceptUpperBound<Output = &'static Self>;
+}
+
+/// The implementations of [`Memoize`] for each element type, since generic references to
+/// constants of generic types cannot be `'static`.
+mod memoize {
+    use super::MemoizeOutput;
+    use crate::{const_value, AcceptUpperBound, Const, Ladder};
+
+    macro_rules! impl_memoize_output {
+        ($($T:ident $Memoize:ident $Eval:ident)*) => {$(
+            pub struct $Memoize<A, const N: usize>(A);
+            impl<A: AcceptUpperBound<Output = [$T; N]>, const N: usize> AcceptUpperBound
+                for $Memoize<A, N>
+            {
+                type Output = &'static [$T; N];
+                const DESIRED_GENERIC: usize = crate::desired_generic::<A>();
+                const LADDER: Ladder = A::LADDER;
+                type Eval<const UPPER: usize> = $Eval<A, N, UPPER>;
+            }
+
+            pub struct $Eval<A, const N: usize, const UPPER: usize>(A);
+            impl<A: AcceptUpperBound<Output = [$T; N]>, const N: usize, const UPPER: usize> Const
+                for $Eval<A, N, UPPER>
+            {
+                type Type = &'static [$T; N];
+                const VALUE: Self::Type = &const_value::<A::Eval<UPPER>>();
+            }
+
+            impl<const N: usize> crate::sealed::Sealed for [$T; N] {}
+            impl<const N: usize> MemoizeOutput for [$T; N] {
+                type __Memoize<A: AcceptUpperBound<Output = Self>> = $Memoize<A, N>;
+            }
+        )*};
+    }
+    impl_memoize_output! {
+        u8 MemoizeU8 MemoizeEvalU8
+        u16 MemoizeU16 MemoizeEvalU16
+        u32 MemoizeU32 MemoizeEvalU32
+        u64 MemoizeU64 MemoizeEvalU64
+        u128 MemoizeU128 MemoizeEvalU128
+        usize MemoizeUsize MemoizeEvalUsize
+        i8 MemoizeI8 MemoizeEvalI8
+        i16 MemoizeI16 MemoizeEvalI16
+        i32 MemoizeI32 MemoizeEvalI32
+        i64 MemoizeI64 MemoizeEvalI64
+        i128 MemoizeI128 MemoizeEvalI128
+        isize MemoizeIsize MemoizeEvalIsize
+        bool MemoizeBool MemoizeEvalBool
+        char MemoizeChar MemoizeEvalChar
+    }
+}
+
+/// Wraps an [`AcceptUpperBound`] such that its output is paired with its
+/// [`desired_generic`](crate::desired_generic).
+///
+/// Padded outputs are usually only meaningful together with the desired value, so this makes
+/// them self-describing. See also [`eval_with_len`](crate::eval_with_len).
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::combinators::WithLen;
+/// struct Zeros<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Zeros<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[0; UPPER];
+/// }
+/// let (zeros, len) = gub::eval_with_upper_bound::<WithLen<Zeros<5>>>();
+/// assert_eq!(len, 5);
+/// assert_eq!(zeros[..len], [0; 5]);
+/// ```
+pub struct WithLen<A>(A);
+impl<A: AcceptUpperBound> AcceptUpperBound for WithLen<A> {
+    type Output = (A::Output, usize);
+    const DESIRED_GENERIC: usize = crate::desired_generic::<A>();
+    const LADDER: Ladder = A::LADDER;
+    type Eval<const UPPER: usize> = WithLenEval<A, UPPER>;
+}
+
+#[doc(hidden)]
+pub struct WithLenEval<A, const UPPER: usize>(A);
+impl<A: AcceptUpperBound, const UPPER: usize> Const for WithLenEval<A, UPPER> {
+    type Type = (A::Output, usize);
+    const VALUE: Self::Type = (
+        const_value::<A::Eval<UPPER>>(),
+        crate::desired_generic::<A>(),
+    );
+}
+
+/// Wraps an [`AcceptUpperBound`] such that its desired value is multiplied by `NUM / DEN`,
+/// rounded up.
+///
+/// This reuses the desired value of `A` to size a related buffer, e.g. one with twice the
+/// capacity or a quarter of it. The [ladder](AcceptUpperBound::LADDER) is that of `A`. If
+/// `NUM >= DEN`, the upper bound is large enough for `A`, so the evaluation is forwarded to `A`.
+///
+/// # Panics
+/// Evaluation panics if the scaled desired value is smaller than that of `A`, in which case the
+/// wrapper can only be used for its bound. Computing the desired value panics if `DEN` is zero or
+/// the result overflows.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::combinators::Scale;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// assert_eq!(gub::desired_generic::<Scale<Len<10>, 3, 2>>(), 15);
+/// assert_eq!(gub::desired_generic::<Scale<Len<10>, 1, 4>>(), 3);
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::eval_with_upper_bound::<Scale<Len<20>, 2, 1>>(), 40);
+/// ```
+pub struct Scale<A, const NUM: usize, const DEN: usize>(A);
+impl<A: AcceptUpperBound, const NUM: usize, const DEN: usize> AcceptUpperBound
+    for Scale<A, NUM, DEN>
+{
+    type Output = A::Output;
+    const DESIRED_GENERIC: usize =
+        math::div_ceil(math::checked_mul(crate::desired_generic::<A>(), NUM), DEN);
+    const LADDER: Ladder = A::LADDER;
+    type Eval<const UPPER: usize> = ScaleEval<A, NUM, DEN, UPPER>;
+}
+
+#[doc(hidden)]
+pub struct ScaleEval<A, const NUM: usize, const DEN: usize, const UPPER: usize>(A);
+impl<A: AcceptUpperBound, const NUM: usize, const DEN: usize, const UPPER: usize> Const
+    for ScaleEval<A, NUM, DEN, UPPER>
+{
+    type Type = A::Output;
+    const VALUE: Self::Type = {
+        if UPPER < crate::desired_generic::<A>() {
+            panic_with!(
+                "the scaled upper bound is smaller than the desired value of the wrapped acceptor",
+                "UPPER" = UPPER,
+                "desired" = crate::desired_generic::<A>(),
+            );
+        }
+        const_value::<A::Eval<UPPER>>()
+    };
+}
+
+/// Evaluates two acceptors with the same upper bound, which is at least the sum of their desired
+/// values.
+///
+/// This is useful for a buffer that holds the outputs of both acceptors. The output consists of
+/// the outputs of `A` and `B`, like for the tuple `(A, B)`, and the
+/// [ladder](AcceptUpperBound::LADDER) only contains candidates of both ladders.
+///
+/// # Panics
+/// Computing the desired value panics if the sum overflows.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::combinators::{MaxOf, SumOf};
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// assert_eq!(gub::desired_generic::<SumOf<Len<20>, Len<30>>>(), 50);
+/// assert_eq!(gub::desired_generic::<MaxOf<Len<20>, Len<30>>>(), 30);
+/// let (a, b) = gub::eval_with_upper_bound::<SumOf<Len<20>, Len<30>>>();
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!((a, b), (50, 50));
+/// ```
+pub struct SumOf<A, B>(A, B);
+impl<A: AcceptUpperBound, B: AcceptUpperBound> AcceptUpperBound for SumOf<A, B> {
+    type Output = (A::Output, B::Output);
+    const DESIRED_GENERIC: usize =
+        math::checked_add(crate::desired_generic::<A>(), crate::desired_generic::<B>());
+    const LADDER: Ladder = <(A, B) as AcceptUpperBound>::LADDER;
+    type Eval<const UPPER: usize> = TupleEval<(A, B), UPPER>;
+}
+
+/// Evaluates two acceptors with the same upper bound, which is at least the maximum of their
+/// desired values.
+///
+/// This is the same as the tuple `(A, B)`, named for symmetry with [`SumOf`].
+pub struct MaxOf<A, B>(A, B);
+impl<A: AcceptUpperBound, B: AcceptUpperBound> AcceptUpperBound for MaxOf<A, B> {
+    type Output = (A::Output, B::Output);
+    const DESIRED_GENERIC: usize = crate::desired_generic::<(A, B)>();
+    const LADDER: Ladder = <(A, B) as AcceptUpperBound>::LADDER;
+    type Eval<const UPPER: usize> = TupleEval<(A, B), UPPER>;
+}
+
+/// The output of an [`AcceptUpperBound`] as a [`Const`].
+///
+/// This allows evaluating an acceptor inside the `DESIRED_GENERIC` or `EVAL` of another one, or
+/// passing its output to the [adapters](crate::adapters). See [`NestedTrimmed`] for acceptors
+/// whose output is padded.
+///
+/// Evaluation can be nested arbitrarily deeply, as long as no acceptor ends up evaluating itself.
+/// In that case, the compiler reports a cycle when const-evaluating the acceptor's `Eval`.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::combinators::NestedTrimmed;
+/// struct Ab;
+/// gub::impl_accept_upper_bound! {
+///     impl{} Ab;
+///     const DESIRED_GENERIC: usize = 2;
+///     const EVAL<const UPPER: usize>: &'static [u8] = b"ab";
+/// }
+/// // Repeats the output of `A` twice, by evaluating `A` once
+/// struct Twice<A>(A);
+/// gub::impl_accept_upper_bound! {
+///     impl{A: gub::AcceptUpperBound<Output = &'static [u8]>} Twice<A>;
+///     const DESIRED_GENERIC: usize = 2 * gub::desired_generic::<A>();
+///     const EVAL<const UPPER: usize>: &'static [u8] = {
+///         let inner = gub::const_value::<NestedTrimmed<A, u8>>();
+///         &gub::build::BytesWriter::<UPPER>::new()
+///             .extend_from_slice(inner)
+///             .extend_from_slice(inner)
+///             .finish()
+///             .0
+///     };
+/// }
+/// let s = gub::eval_str::<Twice<Twice<Twice<Twice<Twice<Ab>>>>>>();
+/// assert_eq!(s, "ab".repeat(32));
+/// ```
+pub struct Nested<A>(A);
+impl<A: AcceptUpperBound> Const for Nested<A> {
+    type Type = A::Output;
+    const VALUE: Self::Type = crate::eval_with_upper_bound::<A>();
+}
+
+/// The output of an [`AcceptUpperBound`] as a [`Const`], trimmed to
+/// [`desired_generic`](crate::desired_generic) elements like [`eval_trimmed`](crate::eval_trimmed).
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::combinators::NestedTrimmed;
+/// struct Zeros<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Zeros<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[0; UPPER];
+/// }
+/// assert_eq!(gub::adapters::hex_encode::<NestedTrimmed<Zeros<100>, u8>>(), "00".repeat(100));
+/// ```
+pub struct NestedTrimmed<A, T>(A, T);
+impl<T: 'static, A: AcceptUpperBound<Output = &'static [T]>> Const for NestedTrimmed<A, T> {
+    type Type = &'static [T];
+    const VALUE: Self::Type = crate::eval_trimmed::<A, T>();
+}
+
+/// The output of an [`AcceptUpperBound`] as a [`Const`], trimmed and converted to a string like
+/// [`eval_str`](crate::eval_str).
+///
+/// This allows passing the output of an acceptor to an adapter that takes a string.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{ConcatStrs, ToAsciiCase};
+/// use gub::combinators::NestedStr;
+/// struct Parts;
+/// impl gub::Const for Parts {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["Hello", ", ", "World"];
+/// }
+/// let upper = gub::eval_str::<ToAsciiCase<NestedStr<ConcatStrs<Parts>>, true>>();
+/// assert_eq!(upper, "HELLO, WORLD");
+/// ```
+pub struct NestedStr<A>(A);
+impl<A: AcceptUpperBound<Output = &'static [u8]>> Const for NestedStr<A> {
+    type Type = &'static str;
+    const VALUE: Self::Type = crate::eval_str::<A>();
+}
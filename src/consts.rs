@@ -0,0 +1,60 @@
+//! Canonical [`Const`] implementors for feeding plain values into adapters and combinators.
+//!
+//! Many adapters take their parameters as types that implement [`Const`], e.g. the number of
+//! repetitions of [`Repeat`](crate::adapters::Repeat). The types in this module turn const generic
+//! arguments into such types, and [`const_str!`](crate::const_str) and
+//! [`const_bytes!`](crate::const_bytes) declare types for string and byte string values, so that
+//! no one-off [`Const`] implementations are needed.
+//!
+//! ```
+//! use generic_upper_bound as gub;
+//! use gub::adapters::{pad_left, Repeat};
+//! use gub::consts::{ConstChar, ConstUsize};
+//! gub::const_bytes! {
+//!     struct Dash = b"-";
+//! }
+//! gub::const_str! {
+//!     struct Name = "gub";
+//! }
+//! assert_eq!(gub::eval_trimmed::<Repeat<Dash, ConstUsize<3>>, u8>(), b"---");
+//! assert_eq!(pad_left::<Name, ConstUsize<5>, ConstChar<'.'>>(), "..gub");
+//! ```
+
+use crate::Const;
+
+/// A `usize` as a type.
+///
+/// ```
+/// use generic_upper_bound::{const_value, consts::ConstUsize};
+/// assert_eq!(const_value::<ConstUsize<37>>(), 37);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ConstUsize<const N: usize>;
+impl<const N: usize> Const for ConstUsize<N> {
+    type Type = usize;
+    const VALUE: Self::Type = N;
+}
+
+/// A `u8` as a type, e.g. for the fill byte of an adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ConstU8<const N: u8>;
+impl<const N: u8> Const for ConstU8<N> {
+    type Type = u8;
+    const VALUE: Self::Type = N;
+}
+
+/// A `bool` as a type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ConstBool<const B: bool>;
+impl<const B: bool> Const for ConstBool<B> {
+    type Type = bool;
+    const VALUE: Self::Type = B;
+}
+
+/// A `char` as a type, e.g. for the fill character of [`Pad`](crate::adapters::Pad).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ConstChar<const C: char>;
+impl<const C: char> Const for ConstChar<C> {
+    type Type = char;
+    const VALUE: Self::Type = C;
+}
@@ -0,0 +1,193 @@
+//! Compile-time formatting of strings whose pieces depend on generic parameters.
+//!
+//! [`impl_format!`](crate::impl_format) implements [`AcceptUpperBound`](crate::AcceptUpperBound)
+//! for a type such that its output is a template with each `{}` replaced by an argument, similar
+//! to [`format!`]. The arguments are constant expressions that can use the generic parameters of
+//! the type. Supported argument types are `&str`, `char`, `bool` and the primitive integer types,
+//! which are formatted like their [`Display`](core::fmt::Display) implementations. Use `{{` and
+//! `}}` for literal braces. Format specifiers such as `{:x}` are not supported.
+//!
+//! The desired value is the exact length of the output, so [`eval_str`](crate::eval_str) returns
+//! the formatted string.
+//!
+//! This module requires the `fmt` feature.
+//!
+//! [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+
+use crate::build::BytesWriter;
+
+/// The next part of a template, as returned by [`next_token`].
+enum Token {
+    Byte(u8),
+    Arg,
+    End,
+}
+
+/// Returns the part of the template at `pos` and the position after it.
+#[track_caller]
+const fn next_token(template: &[u8], pos: usize) -> (Token, usize) {
+    if pos >= template.len() {
+        return (Token::End, pos);
+    }
+    let next = if pos + 1 < template.len() {
+        template[pos + 1]
+    } else {
+        0
+    };
+    match (template[pos], next) {
+        (b'{', b'{') => (Token::Byte(b'{'), pos + 2),
+        (b'}', b'}') => (Token::Byte(b'}'), pos + 2),
+        (b'{', b'}') => (Token::Arg, pos + 2),
+        (b'{', _) => panic!("invalid format template: expected `{{}}` or `{{{{`"),
+        (b'}', _) => panic!("invalid format template: unmatched `}}`"),
+        (b, _) => (Token::Byte(b), pos + 1),
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __formatted_len(template: &str, arg_lens: &[usize]) -> usize {
+    let template = template.as_bytes();
+    let (mut len, mut args, mut pos) = (0, 0, 0);
+    loop {
+        let (token, next) = next_token(template, pos);
+        match token {
+            Token::Byte(_) => len += 1,
+            Token::Arg if args < arg_lens.len() => {
+                len += arg_lens[args];
+                args += 1;
+            }
+            Token::Arg => panic!("the format template has more `{{}}` than arguments"),
+            Token::End if args < arg_lens.len() => {
+                panic!("the format template has fewer `{{}}` than arguments")
+            }
+            Token::End => return len,
+        }
+        pos = next;
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __write_until_arg<const CAP: usize>(
+    template: &str,
+    mut pos: usize,
+    mut w: BytesWriter<CAP>,
+) -> (BytesWriter<CAP>, usize) {
+    loop {
+        let (token, next) = next_token(template.as_bytes(), pos);
+        match token {
+            Token::Byte(b) => w = w.push(b),
+            Token::Arg => return (w, next),
+            Token::End => panic!("the format template has fewer `{{}}` than arguments"),
+        }
+        pos = next;
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __write_rest<const CAP: usize>(
+    template: &str,
+    mut pos: usize,
+    mut w: BytesWriter<CAP>,
+) -> BytesWriter<CAP> {
+    loop {
+        let (token, next) = next_token(template.as_bytes(), pos);
+        match token {
+            Token::Byte(b) => w = w.push(b),
+            Token::Arg => panic!("the format template has more `{{}}` than arguments"),
+            Token::End => return w,
+        }
+        pos = next;
+    }
+}
+
+/// Returns the number of decimal digits of `n`.
+const fn decimal_len(mut n: u128) -> usize {
+    let mut len = 1;
+    while n >= 10 {
+        n /= 10;
+        len += 1;
+    }
+    len
+}
+
+/// Appends the decimal digits of `n` to `w`.
+const fn write_decimal<const CAP: usize>(w: BytesWriter<CAP>, n: u128) -> BytesWriter<CAP> {
+    let mut digits = [0; 39];
+    let len = decimal_len(n);
+    let (mut n, mut i) = (n, len);
+    while i > 0 {
+        i -= 1;
+        digits[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+    }
+    w.extend_from_slice(digits.split_at(len).0)
+}
+
+/// An argument of [`impl_format!`](crate::impl_format), which selects how it is formatted based
+/// on its type.
+#[doc(hidden)]
+pub struct __Arg<T>(pub T);
+
+macro_rules! impl_unsigned {
+    ($($T:ty)*) => {$(
+        impl __Arg<$T> {
+            pub const fn __len(&self) -> usize {
+                decimal_len(self.0 as u128)
+            }
+            pub const fn __write<const CAP: usize>(&self, w: BytesWriter<CAP>) -> BytesWriter<CAP> {
+                write_decimal(w, self.0 as u128)
+            }
+        }
+    )*};
+}
+impl_unsigned!(u8 u16 u32 u64 u128 usize);
+
+macro_rules! impl_signed {
+    ($($T:ty)*) => {$(
+        impl __Arg<$T> {
+            pub const fn __len(&self) -> usize {
+                (self.0 < 0) as usize + decimal_len(self.0.unsigned_abs() as u128)
+            }
+            pub const fn __write<const CAP: usize>(&self, w: BytesWriter<CAP>) -> BytesWriter<CAP> {
+                let w = if self.0 < 0 { w.push(b'-') } else { w };
+                write_decimal(w, self.0.unsigned_abs() as u128)
+            }
+        }
+    )*};
+}
+impl_signed!(i8 i16 i32 i64 i128 isize);
+
+impl __Arg<&str> {
+    pub const fn __len(&self) -> usize {
+        self.0.len()
+    }
+    pub const fn __write<const CAP: usize>(&self, w: BytesWriter<CAP>) -> BytesWriter<CAP> {
+        w.write_str(self.0)
+    }
+}
+
+impl __Arg<char> {
+    pub const fn __len(&self) -> usize {
+        self.0.len_utf8()
+    }
+    pub const fn __write<const CAP: usize>(&self, w: BytesWriter<CAP>) -> BytesWriter<CAP> {
+        let (bytes, len) = crate::str::encode_utf8(self.0);
+        w.extend_from_slice(bytes.split_at(len).0)
+    }
+}
+
+impl __Arg<bool> {
+    pub const fn __len(&self) -> usize {
+        if self.0 {
+            4
+        } else {
+            5
+        }
+    }
+    pub const fn __write<const CAP: usize>(&self, w: BytesWriter<CAP>) -> BytesWriter<CAP> {
+        w.write_str(if self.0 { "true" } else { "false" })
+    }
+}
@@ -1,5 +1,196 @@
-include!(concat!(env!("OUT_DIR"), "/for_each_size.rs"));
-use for_each_size;
+#[macro_use]
+mod for_each_size;
+
+#[cfg(target_pointer_width = "16")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_size {
+    ($($mac:tt)*) => { $crate::for_each_size_u16! { $($mac)* } };
+}
+#[cfg(target_pointer_width = "16")]
+macro_rules! size_tree {
+    ($($mac:tt)*) => { size_tree_u16! { $($mac)* } };
+}
+#[cfg(target_pointer_width = "32")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_size {
+    ($($mac:tt)*) => { $crate::for_each_size_u32! { $($mac)* } };
+}
+#[cfg(target_pointer_width = "32")]
+macro_rules! size_tree {
+    ($($mac:tt)*) => { size_tree_u32! { $($mac)* } };
+}
+#[cfg(target_pointer_width = "64")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_size {
+    ($($mac:tt)*) => { $crate::for_each_size_u64! { $($mac)* } };
+}
+#[cfg(target_pointer_width = "64")]
+macro_rules! size_tree {
+    ($($mac:tt)*) => { size_tree_u64! { $($mac)* } };
+}
+
+/// Invokes the passed macro with the candidates of [`Ladder::DEFAULT`] in ascending order, once
+/// for each range of the `for_each_default_size_*` macros, so it must expand to statements.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size {
+    ($($mac:tt)*) => {
+        $crate::for_each_default_size_dense! { $($mac)* }
+        $crate::for_each_default_size_16! { $($mac)* }
+        $crate::for_each_default_size_20! { $($mac)* }
+        $crate::for_each_default_size_31! { $($mac)* }
+        $crate::for_each_default_size_max! { $($mac)* }
+    };
+}
+
+/// Invokes the passed macro with the indices of [`SLOTS`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_slot {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+        }
+    };
+}
+
+/// A family of consts, one for each candidate, together with the selected candidate.
+pub trait Dispatch {
+    type Output;
+    const ACTUAL: usize;
+    type At<const N: usize>: Const<Type = Self::Output>;
+}
+
+/// Like [`Dispatch`], for bound types whose candidates are passed as `u128`.
+pub trait DispatchWide {
+    type Output;
+    const ACTUAL: u128;
+    type At<const N: u128>: Const<Type = Self::Output>;
+}
+
+/// An inner node of a tree from `size_tree`, for the bound type `W`.
+///
+/// Every node is a separate const that only refers to its children, so evaluating the root
+/// only evaluates the nodes on the path to `E::ACTUAL`. No other `E::At<N>` is referenced.
+pub struct Node<W, E, const MID: u128>(W, E);
+/// A leaf of a tree from `size_tree`, identified by its first candidate.
+pub struct Leaf<W, E, const FIRST: u128>(W, E);
+
+/// Expands to the type of the root of a tree from `size_tree`.
+macro_rules! tree_ty {
+    ($W:ident $E:ty { $l:tt $mid:tt $r:tt }) => {
+        Node<$W, $E, $mid>
+    };
+    ($W:ident $E:ty [$first:tt $($rest:tt)*]) => {
+        Leaf<$W, $E, $first>
+    };
+}
+
+/// Implements [`Const`] for all nodes of a tree from `size_tree`.
+macro_rules! impl_tree {
+    ($W:ident $Dispatch:ident { $l:tt $mid:tt $r:tt }) => {
+        impl<E: $Dispatch> Const for Node<$W, E, $mid> {
+            type Type = E::Output;
+            const VALUE: Self::Type = if E::ACTUAL < $mid {
+                const_value::<tree_ty!($W E $l)>()
+            } else {
+                const_value::<tree_ty!($W E $r)>()
+            };
+        }
+        impl_tree! { $W $Dispatch $l }
+        impl_tree! { $W $Dispatch $r }
+    };
+    ($W:ident $Dispatch:ident [$first:tt $($rest:tt)*]) => {
+        impl<E: $Dispatch> Const for Leaf<$W, E, $first> {
+            type Type = E::Output;
+            const VALUE: Self::Type = if E::ACTUAL == $first {
+                const_value::<E::At<$first>>()
+            } $(else if E::ACTUAL == $rest {
+                const_value::<E::At<$rest>>()
+            })* else {
+                unreachable()
+            };
+        }
+    };
+}
+size_tree! { impl_tree { usize Dispatch } }
+size_tree_u8! { impl_tree { u8 DispatchWide } }
+size_tree_u16! { impl_tree { u16 DispatchWide } }
+size_tree_u32! { impl_tree { u32 DispatchWide } }
+size_tree_u64! { impl_tree { u64 DispatchWide } }
+size_tree_u128! { impl_tree { u128 DispatchWide } }
+/// Marks the tree of `usize` candidates that are passed as `u128`.
+pub enum WideUsize {}
+impl WideUsize {
+    const BITS: u32 = usize::BITS;
+}
+size_tree! { impl_tree { WideUsize DispatchWide } }
+
+/// Dispatches to `E::At<E::ACTUAL>` using [`Slots`] if the candidates of the bound type `W` were
+/// replaced using `GUB_CANDIDATES`, and using the tree `T` from `size_tree` otherwise.
+pub struct Search<W, E, T>(W, E, T);
+/// Dispatches to `E::At<E::ACTUAL>`, where `E::ACTUAL` is one of the custom candidates.
+pub struct Slots<W, E>(W, E);
+
+/// Expands to the type of a [`Search`] through a tree from `size_tree`.
+macro_rules! search_ty {
+    ($W:ident $E:ty { $($tree:tt)* }) => {
+        Search<$W, $E, tree_ty!($W $E { $($tree)* })>
+    };
+    ($W:ident $E:ty [$($leaf:tt)*]) => {
+        Search<$W, $E, tree_ty!($W $E [$($leaf)*])>
+    };
+}
+
+macro_rules! impl_slots {
+    (@wide [$($i:tt)*] $W:ident) => {
+        impl<E: DispatchWide, T: Const<Type = E::Output>> Const for Search<$W, E, T> {
+            type Type = E::Output;
+            const VALUE: Self::Type = if IS_CUSTOM && $W::BITS == usize::BITS {
+                const_value::<Slots<$W, E>>()
+            } else {
+                const_value::<T>()
+            };
+        }
+        impl<E: DispatchWide> Const for Slots<$W, E> {
+            type Type = E::Output;
+            const VALUE: Self::Type = $(if E::ACTUAL == SLOTS[$i] as u128 {
+                const_value::<E::At<{ SLOTS[$i] as u128 }>>()
+            } else)* {
+                unreachable()
+            };
+        }
+    };
+    ($($i:tt)*) => {
+        impl<E: Dispatch, T: Const<Type = E::Output>> Const for Search<usize, E, T> {
+            type Type = E::Output;
+            const VALUE: Self::Type = if IS_CUSTOM {
+                const_value::<Slots<usize, E>>()
+            } else {
+                const_value::<T>()
+            };
+        }
+        impl<E: Dispatch> Const for Slots<usize, E> {
+            type Type = E::Output;
+            const VALUE: Self::Type = $(if E::ACTUAL == SLOTS[$i] {
+                const_value::<E::At<{ SLOTS[$i] }>>()
+            } else)* {
+                unreachable()
+            };
+        }
+        impl_slots! { @wide [$($i)*] u8 }
+        impl_slots! { @wide [$($i)*] u16 }
+        impl_slots! { @wide [$($i)*] u32 }
+        impl_slots! { @wide [$($i)*] u64 }
+        impl_slots! { @wide [$($i)*] u128 }
+        impl_slots! { @wide [$($i)*] WideUsize }
+    };
+}
+for_each_slot! { impl_slots }
 
 #[track_caller]
 #[cold]
@@ -7,38 +198,916 @@ const fn unreachable() -> ! {
     unreachable!()
 }
 
-use crate::{const_value, AcceptUpperBound, Impl};
+use core::{ffi::CStr, marker::PhantomData, mem::MaybeUninit};
+
+use crate::{
+    const_value, AcceptLowerBound, AcceptNonZeroUpperBound, AcceptUpperBound, AcceptUpperBound2,
+    AcceptUpperBoundOf, BoundVisitor, Const, ExactUpperBound, Impl, Impl2, ImplLower, Ladder,
+};
+
+/// The value of the `GUB_CANDIDATES` environment variable when this crate is compiled, see the
+/// [`ladder`](crate::ladder) module. Cargo rebuilds the crate when it changes.
+const CUSTOM_ENV: Option<&str> = option_env!("GUB_CANDIDATES");
+
+/// Whether the `usize` candidates were replaced using `GUB_CANDIDATES`.
+pub(crate) const IS_CUSTOM: bool = CUSTOM_ENV.is_some();
+
+/// The number of custom candidates that can be dispatched to, including the maximum value.
+const SLOT_COUNT: usize = {
+    macro_rules! count {
+        ($($i:tt)*) => { [$($i),*].len() };
+    }
+    for_each_slot! { count }
+};
+
+/// The custom candidates and their number, see [`SLOTS`].
+const PARSED: ([usize; SLOT_COUNT], usize) = match CUSTOM_ENV {
+    Some(list) => parse_candidates(list.as_bytes()),
+    None => ([usize::MAX; SLOT_COUNT], 0),
+};
+
+/// The custom candidates in ascending order, followed by copies of `usize::MAX` in the unused
+/// slots. Unlike the default candidates, they are only known after parsing `GUB_CANDIDATES`, so
+/// they are dispatched to as `{ SLOTS[i] }` for every index `i` from `for_each_slot!`.
+pub const SLOTS: [usize; SLOT_COUNT] = PARSED.0;
+/// The number of custom candidates, including the maximum value.
+pub const CUSTOM_LEN: usize = PARSED.1;
+
+const SLOTS_REF: &[usize] = &SLOTS;
+/// The custom candidates in ascending order.
+const CUSTOM_CANDIDATES: &[usize] = SLOTS_REF.split_at(CUSTOM_LEN).0;
+
+/// Parses a comma-separated list of candidates. The maximum value is always added.
+const fn parse_candidates(list: &[u8]) -> ([usize; SLOT_COUNT], usize) {
+    let mut slots = [usize::MAX; SLOT_COUNT];
+    // the number of used slots before the maximum value, which is already in place
+    let mut len = 0;
+    let mut start = 0;
+    while start <= list.len() {
+        let mut end = start;
+        while end < list.len() && list[end] != b',' {
+            end += 1;
+        }
+        let value = parse_candidate(list, start, end);
+        start = end + 1;
+        let n = match value {
+            Some(n) if n != usize::MAX => n,
+            _ => continue,
+        };
+        let mut i = 0;
+        while slots[i] < n {
+            i += 1;
+        }
+        if slots[i] == n {
+            continue;
+        }
+        if len + 1 == SLOT_COUNT {
+            panic!("`GUB_CANDIDATES` lists too many values (at most 31 besides `usize::MAX`)");
+        }
+        let mut j = len;
+        while j > i {
+            slots[j] = slots[j - 1];
+            j -= 1;
+        }
+        slots[i] = n;
+        len += 1;
+    }
+    (slots, len + 1)
+}
+
+/// Parses `list[start..end]` as a decimal or `0x`-prefixed hexadecimal integer, ignoring
+/// surrounding whitespace and underscores. Returns `None` if it is empty.
+const fn parse_candidate(list: &[u8], mut start: usize, mut end: usize) -> Option<usize> {
+    while start < end && list[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while start < end && list[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    if start == end {
+        return None;
+    }
+    let radix = if end - start > 2 && list[start] == b'0' && list[start + 1] == b'x' {
+        start += 2;
+        16
+    } else {
+        10
+    };
+    let mut n: usize = 0;
+    let mut digits = 0;
+    while start < end {
+        let c = list[start];
+        start += 1;
+        let digit = match c {
+            b'_' => continue,
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' if radix == 16 => c - b'a' + 10,
+            b'A'..=b'F' if radix == 16 => c - b'A' + 10,
+            _ => panic!("`GUB_CANDIDATES` contains a value that is not an unsigned integer"),
+        };
+        n = match n.checked_mul(radix) {
+            Some(n) => match n.checked_add(digit as usize) {
+                Some(n) => n,
+                None => panic!("`GUB_CANDIDATES` contains a value larger than `usize::MAX`"),
+            },
+            None => panic!("`GUB_CANDIDATES` contains a value larger than `usize::MAX`"),
+        };
+        digits += 1;
+    }
+    if digits == 0 {
+        panic!("`GUB_CANDIDATES` contains a value that is not an unsigned integer");
+    }
+    Some(n)
+}
+
+/// Returns the smallest candidate of `ladder` that is at least `desired`.
+pub(crate) const fn upper_bound_for(desired: usize, ladder: Ladder) -> usize {
+    match try_upper_bound_for(desired, ladder) {
+        Some(n) => n,
+        None => panic_with!(
+            "the desired value is larger than the largest candidate (see `ladder::MAX_BOUND`)",
+            "desired" = desired,
+            "MAX_BOUND" = crate::ladder::MAX_BOUND,
+        ),
+    }
+}
+
+/// Like [`upper_bound_for`], but returns `None` if there is no such candidate.
+pub(crate) const fn try_upper_bound_for(desired: usize, ladder: Ladder) -> Option<usize> {
+    if IS_CUSTOM {
+        scan_upper_bound_for(desired, ladder)
+    } else {
+        ladder.closed_form_upper_bound(desired)
+    }
+}
+
+/// Like [`try_upper_bound_for`], but scans [`SIZES`]. This is only needed for custom candidates,
+/// which are not determined by the parameters of the ladder.
+const fn scan_upper_bound_for(desired: usize, ladder: Ladder) -> Option<usize> {
+    let mut i = 0;
+    while i < SIZES.len() {
+        if SIZES[i] >= desired && ladder.contains(SIZES[i]) {
+            return Some(SIZES[i]);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The custom candidates if there are any, or all values yielded by `for_each_size!` otherwise,
+/// in ascending order.
+const SIZES: &[usize] = if IS_CUSTOM {
+    CUSTOM_CANDIDATES
+} else {
+    macro_rules! list {
+        ($($n:tt)*) => { &[$($n),*] };
+    }
+    for_each_size! { list }
+};
+
+/// Returns whether `n` is an element of [`SIZES`].
+pub(crate) const fn is_candidate(n: usize) -> bool {
+    let mut i = 0;
+    while i < SIZES.len() {
+        if SIZES[i] == n {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns the number of candidates of `ladder` that are smaller than `n`.
+pub(crate) const fn candidate_index(n: usize, ladder: Ladder) -> usize {
+    if ladder.is_refined() {
+        // the refinements are not in `SIZES`, so walk the ladder instead
+        let mut index = 0;
+        let mut next = try_upper_bound_for(0, ladder);
+        while let Some(candidate) = next {
+            if candidate >= n {
+                break;
+            }
+            index += 1;
+            next = try_upper_bound_for(candidate + 1, ladder);
+        }
+        return index;
+    }
+    let mut index = 0;
+    let mut i = 0;
+    while i < SIZES.len() && SIZES[i] < n {
+        if ladder.contains(SIZES[i]) {
+            index += 1;
+        }
+        i += 1;
+    }
+    index
+}
+
+/// The number of values yielded by `for_each_default_size!`.
+const DEFAULT_LEN: usize = {
+    let mut len = 0;
+    macro_rules! count {
+        ($($n:tt)*) => {$(
+            let _: usize = $n;
+            len += 1;
+        )*};
+    }
+    for_each_default_size! { count }
+    len
+};
+
+/// The values yielded by `for_each_default_size!`.
+const DEFAULT_SIZES: [usize; DEFAULT_LEN] = {
+    let mut sizes = [0; DEFAULT_LEN];
+    let mut len = 0;
+    macro_rules! fill {
+        ($($n:tt)*) => {$(
+            sizes[len] = $n;
+            len += 1;
+        )*};
+    }
+    for_each_default_size! { fill }
+    assert!(len == DEFAULT_LEN);
+    sizes
+};
+
+/// The number of custom candidates up to `MAX_BOUND`.
+const CUSTOM_DEFAULT_LEN: usize = {
+    let mut len = 0;
+    while len < CUSTOM_LEN && SLOTS[len] <= crate::ladder::MAX_BOUND {
+        len += 1;
+    }
+    len
+};
+
+/// The candidates of [`Ladder::DEFAULT`], in ascending order.
+pub(crate) const DEFAULT_CANDIDATES: &[usize] = if IS_CUSTOM {
+    CUSTOM_CANDIDATES.split_at(CUSTOM_DEFAULT_LEN).0
+} else {
+    &DEFAULT_SIZES
+};
+
+// The ranges of `for_each_default_size!` are selected using the enabled features. Check that
+// they yield exactly the candidates of `Ladder::DEFAULT`.
+const _: () = {
+    let ladder = Ladder::DEFAULT;
+    let mut i = 0;
+    while i < DEFAULT_CANDIDATES.len() {
+        let n = DEFAULT_CANDIDATES[i];
+        assert!(ladder.contains(n));
+        let prev = if i == 0 {
+            0
+        } else {
+            DEFAULT_CANDIDATES[i - 1] + 1
+        };
+        assert!(matches!(try_upper_bound_for(prev, ladder), Some(upper) if upper == n));
+        i += 1;
+    }
+    let last = DEFAULT_CANDIDATES[DEFAULT_CANDIDATES.len() - 1];
+    assert!(last == usize::MAX || try_upper_bound_for(last + 1, ladder).is_none());
+};
+
+// The closed form is only used for `usize`, but it is computed for any width. Check it against
+// the `u16` candidates, so that the search for 16-bit targets is covered by host builds. Custom
+// candidates replace those of `u16` on 16-bit targets.
+const _: () = if !IS_CUSTOM {
+    const MAX: u128 = u16::MAX as u128;
+    let ladder = Ladder::DEFAULT.coarse();
+    // the smallest desired value whose bound has not been checked yet
+    let mut next: u128 = 0;
+    macro_rules! check_u16 {
+        ($($n:tt)*) => {$(
+            if ladder.accepts($n, MAX) {
+                let n: u128 = $n;
+                assert!(matches!(ladder.coarse_upper_bound_in(next, MAX, MAX), Some(b) if b == n));
+                if next < n {
+                    let below = n - 1;
+                    assert!(matches!(ladder.coarse_upper_bound_in(below, MAX, MAX), Some(b) if b == n));
+                }
+                assert!(matches!(ladder.coarse_upper_bound_in(n, MAX, MAX), Some(b) if b == n));
+                next = n + 1;
+            }
+        )*};
+    }
+    for_each_size_u16! { check_u16 }
+    assert!(next == MAX + 1);
+    // limiting the candidates to `max_bound` excludes the maximum
+    assert!(ladder.coarse_upper_bound_in(MAX, MAX, 1 << 12).is_none());
+};
+
+/// Returns the smallest power of two that is at least `desired`.
+const fn pow2_upper_bound_for(desired: usize) -> usize {
+    match try_pow2_upper_bound_for(desired) {
+        Some(n) => n,
+        None => panic_with!(
+            "the desired value is larger than the largest power of two",
+            "desired" = desired,
+        ),
+    }
+}
+
+/// Like [`pow2_upper_bound_for`], but returns `None` if there is no such power of two.
+const fn try_pow2_upper_bound_for(desired: usize) -> Option<usize> {
+    if !IS_CUSTOM {
+        return match desired.checked_next_power_of_two() {
+            Some(n) if n <= crate::ladder::MAX_BOUND => Some(n),
+            _ => None,
+        };
+    }
+    // custom candidates do not necessarily include every power of two
+    let mut i = 0;
+    while i < SIZES.len() {
+        let n = SIZES[i];
+        if n >= desired && n.is_power_of_two() && n <= crate::ladder::MAX_BOUND {
+            return Some(n);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Returns the largest candidate of `ladder` that is at most `desired`.
+const fn lower_bound_for(desired: usize, ladder: Ladder) -> usize {
+    let mut lower = 0;
+    let mut i = 0;
+    while i < SIZES.len() && SIZES[i] <= desired {
+        if ladder.contains(SIZES[i]) {
+            lower = SIZES[i];
+        }
+        i += 1;
+    }
+    lower
+}
 
 impl<A: AcceptUpperBound> Impl<A> {
     // encourage the compiler to cache the result by promoting
     const DESIRED_REF: &'static usize = &A::DESIRED_GENERIC;
     pub const DESIRED: usize = *Self::DESIRED_REF;
 
-    pub const ACTUAL: usize = 'ret: {
-        let desired = A::DESIRED_GENERIC;
-        macro_rules! check_size {
-            ($($n:tt)*) => {$(
-                if $n >= desired {
-                    break 'ret $n;
+    pub const ACTUAL: usize = upper_bound_for(Self::DESIRED, A::LADDER);
+
+    pub const TRY_ACTUAL: Option<usize> = try_upper_bound_for(Self::DESIRED, A::LADDER);
+
+    /// The candidate of the coarse ladder that `ACTUAL` is a refinement of.
+    #[cfg(feature = "ladder-step-1_0625")]
+    const COARSE: usize = upper_bound_for(Self::ACTUAL, A::LADDER.coarse());
+
+    pub const TRY_SUPPORTED: Option<usize> =
+        if Self::DESIRED <= crate::ladder::MAX_SUPPORTED_DESIRED {
+            Self::TRY_ACTUAL
+        } else {
+            None
+        };
+
+    pub const SUPPORTED: usize = match Self::TRY_SUPPORTED {
+        Some(n) => n,
+        None => panic_with!(
+            "the desired value is larger than `ladder::MAX_SUPPORTED_DESIRED`",
+            "desired" = Self::DESIRED,
+            "MAX_SUPPORTED_DESIRED" = crate::ladder::MAX_SUPPORTED_DESIRED,
+        ),
+    };
+
+    pub const LADDER_INDEX: usize = candidate_index(Self::ACTUAL, A::LADDER);
+
+    #[cfg(not(feature = "ladder-step-1_0625"))]
+    pub const EVAL: A::Output = const_value::<size_tree! { search_ty { usize EvalUpper<A> } }>();
+
+    #[cfg(feature = "ladder-step-1_0625")]
+    pub const EVAL: A::Output = if A::LADDER.is_refined() {
+        const_value::<size_tree! { tree_ty { usize refine::Refined<A> } }>()
+    } else {
+        const_value::<size_tree! { search_ty { usize EvalUpper<A> } }>()
+    };
+
+    pub const ACTUAL_POW2: usize = pow2_upper_bound_for(Self::DESIRED);
+
+    pub const TRY_ACTUAL_POW2: Option<usize> = try_pow2_upper_bound_for(Self::DESIRED);
+
+    pub const EVAL_POW2: A::Output =
+        const_value::<size_tree! { search_ty { usize EvalPow2<A> } }>();
+}
+
+#[cfg(feature = "nightly")]
+impl<A: AcceptUpperBound> Impl<A>
+where
+    A::Output: core::marker::Freeze + 'static,
+{
+    // promoted like `DESIRED_REF`, so every use refers to the same allocation
+    pub const EVAL_REF: &'static A::Output = &Self::EVAL;
+}
+
+pub struct TryEval<A>(A);
+impl<A: AcceptUpperBound> Const for TryEval<A> {
+    type Type = Option<A::Output>;
+    // `Eval` is only referenced through a function call, so that it is not evaluated if there is
+    // no upper bound.
+    const VALUE: Self::Type = match Impl::<A>::TRY_ACTUAL {
+        Some(_) => Some(const_value::<Eval<A>>()),
+        None => None,
+    };
+}
+struct Eval<A>(A);
+impl<A: AcceptUpperBound> Const for Eval<A> {
+    type Type = A::Output;
+    const VALUE: Self::Type = Impl::<A>::EVAL;
+}
+
+pub struct ExactBound<A>(A);
+impl<A: ExactUpperBound> Const for ExactBound<A> {
+    type Type = usize;
+    const VALUE: Self::Type = {
+        if cfg!(debug_assertions) && Impl::<A>::ACTUAL != Impl::<A>::DESIRED {
+            panic_with!(
+                "the desired value of an `ExactUpperBound` is not a candidate",
+                "desired" = Impl::<A>::DESIRED,
+                "UPPER" = Impl::<A>::ACTUAL,
+            );
+        }
+        Impl::<A>::DESIRED
+    };
+}
+
+pub struct EvalExact<A>(A);
+impl<A: ExactUpperBound> Const for EvalExact<A> {
+    type Type = A::Output;
+    const VALUE: Self::Type = {
+        const_value::<ExactBound<A>>();
+        Impl::<A>::EVAL
+    };
+}
+
+pub struct EvalTrimmed<A, T>(A, T);
+impl<T: 'static, A: AcceptUpperBound<Output = &'static [T]>> Const for EvalTrimmed<A, T> {
+    type Type = &'static [T];
+    const VALUE: Self::Type = {
+        let output = Impl::<A>::EVAL;
+        if output.len() < Impl::<A>::DESIRED {
+            panic_with!(
+                "the output is shorter than the desired value",
+                "len" = output.len(),
+                "desired" = Impl::<A>::DESIRED,
+                "UPPER" = Impl::<A>::ACTUAL,
+            );
+        }
+        output.split_at(Impl::<A>::DESIRED).0
+    };
+}
+
+pub struct EvalIntoArray<A, T, F, const N: usize>(A, T, F);
+impl<
+        T: Copy + 'static,
+        F: Const<Type = T>,
+        A: AcceptUpperBound<Output = &'static [T]>,
+        const N: usize,
+    > Const for EvalIntoArray<A, T, F, N>
+{
+    type Type = [T; N];
+    const VALUE: Self::Type = {
+        if N < Impl::<A>::DESIRED {
+            panic_with!(
+                "the array is shorter than the desired value",
+                "N" = N,
+                "desired" = Impl::<A>::DESIRED,
+            );
+        }
+        crate::build::ArrayBuilder::<T, N>::new::<F>()
+            .extend(const_value::<EvalTrimmed<A, T>>())
+            .finish()
+            .0
+    };
+}
+
+pub struct EvalUnwrap<A, T>(A, T);
+impl<T: Copy, A: AcceptUpperBound<Output = Result<T, &'static str>>> Const for EvalUnwrap<A, T> {
+    type Type = T;
+    const VALUE: Self::Type = match Impl::<A>::EVAL {
+        Ok(value) => value,
+        Err(msg) => panic!("{}", msg),
+    };
+}
+
+pub struct EvalStr<A>(A);
+impl<A: AcceptUpperBound<Output = &'static [u8]>> Const for EvalStr<A> {
+    type Type = &'static str;
+    const VALUE: Self::Type = match core::str::from_utf8(const_value::<EvalTrimmed<A, u8>>()) {
+        Ok(s) => s,
+        Err(err) => panic_with!(
+            "the output is not valid UTF-8",
+            "valid_up_to" = err.valid_up_to(),
+        ),
+    };
+}
+
+pub struct EvalCStr<A>(A);
+impl<A: AcceptUpperBound<Output = &'static [u8]>> Const for EvalCStr<A> {
+    type Type = &'static CStr;
+    const VALUE: Self::Type = match CStr::from_bytes_with_nul(const_value::<EvalTrimmed<A, u8>>()) {
+        Ok(s) => s,
+        Err(_) => panic!("the output is not NUL-terminated or contains an interior NUL byte"),
+    };
+}
+
+/// An acceptor with the ladder and evaluation of `A` whose desired value is at least `FLOOR`.
+pub struct AtLeast<A, const FLOOR: usize>(A);
+impl<A: AcceptUpperBound, const FLOOR: usize> AcceptUpperBound for AtLeast<A, FLOOR> {
+    type Output = A::Output;
+    const DESIRED_GENERIC: usize = max(crate::desired_generic::<A>(), FLOOR);
+    const LADDER: Ladder = A::LADDER;
+    type Eval<const UPPER: usize> = A::Eval<UPPER>;
+}
+
+/// An acceptor with the desired value, ladder and evaluation of the non-zero acceptor `A`.
+pub struct NonZeroAcceptor<A>(A);
+impl<A: AcceptNonZeroUpperBound> AcceptUpperBound for NonZeroAcceptor<A> {
+    type Output = A::Output;
+    const DESIRED_GENERIC: usize = A::DESIRED_GENERIC.get();
+    const LADDER: Ladder = A::LADDER;
+    type Eval<const UPPER: usize> = A::Eval<UPPER>;
+}
+
+pub struct NonZeroConst<const N: usize>;
+impl<const N: usize> Const for NonZeroConst<N> {
+    type Type = core::num::NonZeroUsize;
+    const VALUE: Self::Type = match core::num::NonZeroUsize::new(N) {
+        Some(n) => n,
+        None => panic!("`N` is zero"),
+    };
+}
+
+pub(crate) const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// An acceptor with the desired value and ladder of `A` that evaluates to `V::Visit<UPPER>`.
+pub struct Visited<A, V>(A, V);
+impl<A: AcceptUpperBound, V: BoundVisitor> AcceptUpperBound for Visited<A, V> {
+    type Output = V::Output;
+    const DESIRED_GENERIC: usize = crate::desired_generic::<A>();
+    const LADDER: Ladder = A::LADDER;
+    type Eval<const UPPER: usize> = V::Visit<UPPER>;
+}
+
+pub struct EvalExplicit<A, const UPPER: usize>(A);
+impl<A: AcceptUpperBound, const UPPER: usize> Const for EvalExplicit<A, UPPER> {
+    type Type = A::Output;
+    const VALUE: Self::Type = {
+        if UPPER < Impl::<A>::DESIRED {
+            panic_with!(
+                "the explicit bound is smaller than the desired value",
+                "UPPER" = UPPER,
+                "desired" = Impl::<A>::DESIRED,
+            );
+        }
+        const_value::<A::Eval<UPPER>>()
+    };
+}
+
+struct EvalUpper<A>(A);
+impl<A: AcceptUpperBound> Dispatch for EvalUpper<A> {
+    type Output = A::Output;
+    const ACTUAL: usize = Impl::<A>::ACTUAL;
+    type At<const N: usize> = A::Eval<N>;
+}
+
+/// The second dispatch of refined ladders, see [`Ladder::FINE`].
+#[cfg(feature = "ladder-step-1_0625")]
+mod refine {
+    use super::{const_value, unreachable, Dispatch, EvalUpper, Impl};
+    use crate::{AcceptUpperBound, Const};
+
+    /// An inner node of the tree from `refine_tree`, which searches for the leaf of `C`.
+    pub struct Node<E, const C: usize, const MID: usize>(E);
+    /// A leaf of the tree from `refine_tree`, which dispatches to the refinements of `C`.
+    pub struct Leaf<E, const C: usize>(E);
+
+    /// Expands to the type of the root of a tree from `refine_tree`.
+    macro_rules! refine_ty {
+        ($E:ident $C:ident { $l:tt $mid:tt $r:tt }) => {
+            Node<$E, $C, $mid>
+        };
+        ($E:ident $C:ident [$c:tt $($n:tt)*]) => {
+            Leaf<$E, $c>
+        };
+    }
+
+    /// Implements [`Const`] for all nodes of the tree from `refine_tree`.
+    macro_rules! impl_refine_tree {
+        ({ $l:tt $mid:tt $r:tt }) => {
+            impl<E: Dispatch, const C: usize> Const for Node<E, C, $mid> {
+                type Type = E::Output;
+                const VALUE: Self::Type = if C < $mid {
+                    const_value::<refine_ty!(E C $l)>()
+                } else {
+                    const_value::<refine_ty!(E C $r)>()
+                };
+            }
+            impl_refine_tree! { $l }
+            impl_refine_tree! { $r }
+        };
+        ([$c:tt $($n:tt)*]) => {
+            impl<E: Dispatch> Const for Leaf<E, $c> {
+                type Type = E::Output;
+                const VALUE: Self::Type = $(if E::ACTUAL == $n {
+                    const_value::<E::At<$n>>()
+                } else)* {
+                    unreachable()
+                };
+            }
+        };
+    }
+    refine_tree! { impl_refine_tree {} }
+
+    /// Dispatches to the coarse candidate of the upper bound of `A`, whose `At<C>` dispatches
+    /// again to the upper bound among the refinements of `C`.
+    pub(super) struct Refined<A>(A);
+    impl<A: AcceptUpperBound> Dispatch for Refined<A> {
+        type Output = A::Output;
+        const ACTUAL: usize = Impl::<A>::COARSE;
+        type At<const C: usize> = Refine<EvalUpper<A>, C>;
+    }
+
+    /// Dispatches to `E::At<E::ACTUAL>`, where `E::ACTUAL` is `C` or one of its refinements.
+    pub struct Refine<E, const C: usize>(E);
+    impl<E: Dispatch, const C: usize> Const for Refine<E, C> {
+        type Type = E::Output;
+        const VALUE: Self::Type = if E::ACTUAL == C {
+            const_value::<E::At<C>>()
+        } else {
+            const_value::<refine_tree! { refine_ty { E C } }>()
+        };
+    }
+}
+
+struct EvalPow2<A>(A);
+impl<A: AcceptUpperBound> Dispatch for EvalPow2<A> {
+    type Output = A::Output;
+    const ACTUAL: usize = Impl::<A>::ACTUAL_POW2;
+    type At<const N: usize> = A::Eval<N>;
+}
+
+impl<A: AcceptLowerBound> ImplLower<A> {
+    // promoted like `Impl::DESIRED_REF`
+    const DESIRED_REF: &'static usize = &A::DESIRED_GENERIC;
+    pub const DESIRED: usize = *Self::DESIRED_REF;
+
+    // the refinements are not yielded by `for_each_size!`, so only the coarse ladder is used
+    pub const ACTUAL: usize = lower_bound_for(Self::DESIRED, Ladder::DEFAULT.coarse());
+
+    pub const EVAL: A::Output = const_value::<size_tree! { search_ty { usize EvalLower<A> } }>();
+}
+
+struct EvalLower<A>(A);
+impl<A: AcceptLowerBound> Dispatch for EvalLower<A> {
+    type Output = A::Output;
+    const ACTUAL: usize = ImplLower::<A>::ACTUAL;
+    type At<const N: usize> = A::Eval<N>;
+}
+
+impl<A: AcceptUpperBound2> Impl2<A> {
+    const DESIRED_REF: &'static (usize, usize) = &(A::DESIRED_GENERIC1, A::DESIRED_GENERIC2);
+    pub const DESIRED: (usize, usize) = *Self::DESIRED_REF;
+
+    // dispatching to refinements is only implemented for a single bound
+    pub const ACTUAL: (usize, usize) = (
+        upper_bound_for(Self::DESIRED.0, Ladder::DEFAULT.coarse()),
+        upper_bound_for(Self::DESIRED.1, Ladder::DEFAULT.coarse()),
+    );
+
+    pub const EVAL: A::Output = const_value::<size_tree! { search_ty { usize EvalFirst<A> } }>();
+}
+
+/// Dispatches the first bound of an [`AcceptUpperBound2`], then the second one.
+struct EvalFirst<A>(A);
+impl<A: AcceptUpperBound2> Dispatch for EvalFirst<A> {
+    type Output = A::Output;
+    const ACTUAL: usize = Impl2::<A>::ACTUAL.0;
+    type At<const N: usize> = size_tree! { search_ty { usize EvalSecond<A, N> } };
+}
+
+/// Dispatches the second bound of an [`AcceptUpperBound2`] after the first one was selected.
+struct EvalSecond<A, const UPPER1: usize>(A);
+impl<A: AcceptUpperBound2, const UPPER1: usize> Dispatch for EvalSecond<A, UPPER1> {
+    type Output = A::Output;
+    const ACTUAL: usize = Impl2::<A>::ACTUAL.1;
+    type At<const N: usize> = A::Eval<UPPER1, N>;
+}
+
+pub struct ImplOf<I, A>(I, A);
+
+pub trait ImplOfInt<I> {
+    type Output;
+    const DESIRED: I;
+    const ACTUAL: I;
+    const EVAL: Self::Output;
+}
+
+macro_rules! impl_ub_int {
+    ($d:tt $($I:ident $W:ident $for_each_size:ident $size_tree:ident)*) => {$(
+        impl crate::sealed::Sealed for $I {}
+        impl crate::UbInt for $I {
+            type __Impl<A: AcceptUpperBoundOf<Self>> = ImplOf<$I, A>;
+        }
+        impl<A: AcceptUpperBoundOf<$I>> ImplOf<$I, A> {
+            const DESIRED_REF: &'static $I = &A::DESIRED_GENERIC;
+            // the search is done on `u128`, which all candidates fit into
+            const ACTUAL_WIDE: u128 = 'ret: {
+                let desired = *Self::DESIRED_REF as u128;
+                if IS_CUSTOM && $W::BITS == usize::BITS {
+                    // like `Search`, which dispatches to the custom candidates in this case
+                    let mut i = 0;
+                    while SIZES[i] as u128 != $I::MAX as u128 {
+                        if SIZES[i] as u128 >= desired {
+                            break;
+                        }
+                        i += 1;
+                    }
+                    break 'ret SIZES[i] as u128;
                 }
-            )*};
+                macro_rules! check_size {
+                    ($d($d n:tt)*) => {$d(
+                        if $d n >= desired && Ladder::DEFAULT.accepts($d n, $I::MAX as u128) {
+                            break 'ret $d n;
+                        }
+                    )*};
+                }
+                $for_each_size! { check_size }
+                unreachable()
+            };
         }
-        for_each_size! { check_size }
-        unreachable()
+        impl<A: AcceptUpperBoundOf<$I>> ImplOfInt<$I> for ImplOf<$I, A> {
+            type Output = A::Output;
+
+            const DESIRED: $I = *Self::DESIRED_REF;
+
+            const ACTUAL: $I = Self::ACTUAL_WIDE as $I;
+
+            const EVAL: A::Output = const_value::<$size_tree! { search_ty { $W ImplOf<$I, A> } }>();
+        }
+        impl<A: AcceptUpperBoundOf<$I>> DispatchWide for ImplOf<$I, A> {
+            type Output = A::Output;
+            const ACTUAL: u128 = Self::ACTUAL_WIDE;
+            type At<const N: u128> = A::Eval<N>;
+        }
+    )*};
+}
+impl_ub_int! {
+    $
+    u8 u8 for_each_size_u8 size_tree_u8
+    u16 u16 for_each_size_u16 size_tree_u16
+    u32 u32 for_each_size_u32 size_tree_u32
+    u64 u64 for_each_size_u64 size_tree_u64
+    u128 u128 for_each_size_u128 size_tree_u128
+    usize WideUsize for_each_size size_tree
+}
+
+/// Calls `f` with a stack buffer of `N` elements, trimmed to `len`.
+#[inline(never)]
+fn run_with_buffer<T, R, F: FnOnce(&mut [MaybeUninit<T>]) -> R, const N: usize>(
+    len: usize,
+    f: F,
+) -> R {
+    // SAFETY: An array of `MaybeUninit` does not need to be initialized.
+    let mut buf: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+    f(&mut buf[..len])
+}
+
+/// Selects the function that `with_runtime_upper_bound` calls for the candidate `N`.
+///
+/// Candidates above `MAX` use a buffer of `MAX` elements instead. Since the function is selected
+/// by a const, `run_with_buffer` is never instantiated with a length above `MAX`, which could
+/// otherwise be too large for the target.
+struct RuntimeArm<T, R, F, const N: usize, const MAX: usize>(PhantomData<fn(T, F) -> R>);
+impl<T, R, F: FnOnce(&mut [MaybeUninit<T>]) -> R, const N: usize, const MAX: usize>
+    RuntimeArm<T, R, F, N, MAX>
+{
+    const RUN: fn(usize, F) -> R = if N <= MAX {
+        run_with_buffer::<T, R, F, N>
+    } else {
+        run_with_buffer::<T, R, F, MAX>
     };
+}
+
+pub(crate) fn with_runtime_upper_bound<T, R, F, const MAX: usize>(len: usize, f: F) -> R
+where
+    F: FnOnce(&mut [MaybeUninit<T>]) -> R,
+{
+    assert!(
+        len <= MAX,
+        "the length is larger than the maximum buffer length"
+    );
+    macro_rules! check_size {
+        ($($n:tt)*) => {$(
+            if !IS_CUSTOM && len <= $n {
+                return RuntimeArm::<T, R, F, $n, MAX>::RUN(len, f);
+            }
+        )*};
+    }
+    for_each_default_size! { check_size }
+    macro_rules! check_slot {
+        ($($i:tt)*) => {$(
+            if IS_CUSTOM && $i < CUSTOM_DEFAULT_LEN && len <= SLOTS[$i] {
+                return RuntimeArm::<T, R, F, { SLOTS[$i] }, MAX>::RUN(len, f);
+            }
+        )*};
+    }
+    for_each_slot! { check_slot }
+    panic!("the length is larger than the largest candidate (see `ladder::MAX_BOUND`)")
+}
+
+/// Selects the entry of an [`ErasedTable`] for the candidate `N`.
+///
+/// Like [`RuntimeArm`], candidates outside of `DESIRED..=M::VALUE` use a function that is never
+/// called, so the acceptor is only evaluated with the candidates in that range.
+struct ErasedEntry<A, M, const N: usize>(A, M);
+impl<A: AcceptUpperBound, M: Const<Type = usize>, const N: usize> ErasedEntry<A, M, N> {
+    const FN: fn() -> A::Output = if Impl::<A>::DESIRED <= N && N <= M::VALUE {
+        crate::eval_with_explicit_bound::<A, N>
+    } else {
+        unselected::<A::Output>
+    };
+}
 
-    pub const EVAL: A::Output = 'ret: {
-        let actual = Self::ACTUAL;
-        macro_rules! check_size {
+fn unselected<T>() -> T {
+    unreachable!("the candidate is outside of the range of the table")
+}
+
+/// The functions of an [`ErasedAcceptor`](crate::ErasedAcceptor), one for each element of
+/// `DEFAULT_CANDIDATES`.
+pub struct ErasedTable<A, M>(A, M);
+impl<A: AcceptUpperBound, M: Const<Type = usize>> ErasedTable<A, M>
+where
+    A::Output: 'static,
+{
+    pub const FNS: &'static [fn() -> A::Output] = if IS_CUSTOM {
+        Self::CUSTOM_FNS
+    } else {
+        Self::DEFAULT_FNS
+    };
+    const DEFAULT_FNS: &'static [fn() -> A::Output] = &{
+        let mut fns = [unselected::<A::Output> as fn() -> A::Output; DEFAULT_LEN];
+        let mut len = 0;
+        macro_rules! table {
             ($($n:tt)*) => {$(
-                if $n == actual {
-                    // SAFETY: This is only evaluated for the actual value of the const,
-                    // which returns init
-                    break 'ret const_value::<A::Eval<$n>>();
-                }
+                fns[len] = ErasedEntry::<A, M, $n>::FN;
+                len += 1;
             )*};
         }
-        for_each_size! { check_size }
-        unreachable()
+        for_each_default_size! { table }
+        assert!(len == DEFAULT_LEN);
+        fns
+    };
+    const CUSTOM_FNS: &'static [fn() -> A::Output] = {
+        macro_rules! table {
+            ($($i:tt)*) => {
+                &[$(if $i < CUSTOM_DEFAULT_LEN {
+                    ErasedEntry::<A, M, { SLOTS[$i] }>::FN
+                } else {
+                    unselected::<A::Output>
+                }),*]
+            };
+        }
+        for_each_slot! { table }
+    };
+    /// The index of the first candidate that is at least the desired value.
+    pub const START: usize = candidate_index(Impl::<A>::DESIRED, Ladder::DEFAULT);
+    /// The index after the last candidate that is at most `M::VALUE`.
+    pub const END: usize = {
+        let mut end = Self::START;
+        while end < DEFAULT_CANDIDATES.len() && DEFAULT_CANDIDATES[end] <= M::VALUE {
+            end += 1;
+        }
+        end
+    };
+}
+
+/// The upper bound of `A`.
+pub struct ActualOf<A>(A);
+impl<A: AcceptUpperBound> Const for ActualOf<A> {
+    type Type = usize;
+    const VALUE: Self::Type = Impl::<A>::ACTUAL;
+}
+
+#[cfg(feature = "typenum")]
+macro_rules! impl_to_uint {
+    ($([$n:tt $m:tt $e:tt])* $(; $max:tt $bits:tt)?) => {
+        $(
+            impl crate::typenum::ToUInt for crate::typenum::Candidate<$n> {
+                type Output = typenum::Shleft<typenum::U<$m>, typenum::U<$e>>;
+            }
+        )*
+        $(
+            impl crate::typenum::ToUInt for crate::typenum::Candidate<$max> {
+                type Output = typenum::Sub1<typenum::Shleft<typenum::U1, typenum::U<$bits>>>;
+            }
+        )?
     };
 }
+#[cfg(feature = "typenum")]
+size_shifts! { impl_to_uint }
+#[cfg(all(feature = "typenum", feature = "ladder-step-1_0625"))]
+refine_shifts! { impl_to_uint }
@@ -0,0 +1,4899 @@
+//! Candidates for each bound width, in ascending order.
+//!
+//! Each `for_each_size_uN` macro invokes the passed macro with:
+//! - every value up to `256`
+//! - every larger value with at most 4 significant bits, i.e. `m << e` with `8 <= m < 16`
+//! - `uN::MAX`
+//!
+//! These are the candidates of every supported ladder except for the refinements of
+//! `ladder-step-1_0625`. The actual ladders are filtered from them using `Ladder::accepts`. The
+//! macros are exported for use by `for_each_candidate!`.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_size_u8 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79
+            80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+            96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+            112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
+            128 129 130 131 132 133 134 135 136 137 138 139 140 141 142 143
+            144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159
+            160 161 162 163 164 165 166 167 168 169 170 171 172 173 174 175
+            176 177 178 179 180 181 182 183 184 185 186 187 188 189 190 191
+            192 193 194 195 196 197 198 199 200 201 202 203 204 205 206 207
+            208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223
+            224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239
+            240 241 242 243 244 245 246 247 248 249 250 251 252 253 254 255
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_size_u16 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79
+            80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+            96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+            112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
+            128 129 130 131 132 133 134 135 136 137 138 139 140 141 142 143
+            144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159
+            160 161 162 163 164 165 166 167 168 169 170 171 172 173 174 175
+            176 177 178 179 180 181 182 183 184 185 186 187 188 189 190 191
+            192 193 194 195 196 197 198 199 200 201 202 203 204 205 206 207
+            208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223
+            224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239
+            240 241 242 243 244 245 246 247 248 249 250 251 252 253 254 255
+            256 0x120 0x140 0x160 0x180 0x1a0 0x1c0 0x1e0 0x200 0x240 0x280 0x2c0 0x300 0x340 0x380 0x3c0
+            0x400 0x480 0x500 0x580 0x600 0x680 0x700 0x780 0x800 0x900 0xa00 0xb00 0xc00 0xd00 0xe00 0xf00
+            0x1000 0x1200 0x1400 0x1600 0x1800 0x1a00 0x1c00 0x1e00 0x2000 0x2400 0x2800 0x2c00 0x3000 0x3400 0x3800 0x3c00
+            0x4000 0x4800 0x5000 0x5800 0x6000 0x6800 0x7000 0x7800 0x8000 0x9000 0xa000 0xb000 0xc000 0xd000 0xe000 0xf000
+            0xffff
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_size_u32 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79
+            80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+            96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+            112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
+            128 129 130 131 132 133 134 135 136 137 138 139 140 141 142 143
+            144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159
+            160 161 162 163 164 165 166 167 168 169 170 171 172 173 174 175
+            176 177 178 179 180 181 182 183 184 185 186 187 188 189 190 191
+            192 193 194 195 196 197 198 199 200 201 202 203 204 205 206 207
+            208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223
+            224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239
+            240 241 242 243 244 245 246 247 248 249 250 251 252 253 254 255
+            256 0x120 0x140 0x160 0x180 0x1a0 0x1c0 0x1e0 0x200 0x240 0x280 0x2c0 0x300 0x340 0x380 0x3c0
+            0x400 0x480 0x500 0x580 0x600 0x680 0x700 0x780 0x800 0x900 0xa00 0xb00 0xc00 0xd00 0xe00 0xf00
+            0x1000 0x1200 0x1400 0x1600 0x1800 0x1a00 0x1c00 0x1e00 0x2000 0x2400 0x2800 0x2c00 0x3000 0x3400 0x3800 0x3c00
+            0x4000 0x4800 0x5000 0x5800 0x6000 0x6800 0x7000 0x7800 0x8000 0x9000 0xa000 0xb000 0xc000 0xd000 0xe000 0xf000
+            0x10000 0x12000 0x14000 0x16000 0x18000 0x1a000 0x1c000 0x1e000 0x20000 0x24000 0x28000 0x2c000 0x30000 0x34000 0x38000 0x3c000
+            0x40000 0x48000 0x50000 0x58000 0x60000 0x68000 0x70000 0x78000 0x80000 0x90000 0xa0000 0xb0000 0xc0000 0xd0000 0xe0000 0xf0000
+            0x100000 0x120000 0x140000 0x160000 0x180000 0x1a0000 0x1c0000 0x1e0000 0x200000 0x240000 0x280000 0x2c0000 0x300000 0x340000 0x380000 0x3c0000
+            0x400000 0x480000 0x500000 0x580000 0x600000 0x680000 0x700000 0x780000 0x800000 0x900000 0xa00000 0xb00000 0xc00000 0xd00000 0xe00000 0xf00000
+            0x1000000 0x1200000 0x1400000 0x1600000 0x1800000 0x1a00000 0x1c00000 0x1e00000 0x2000000 0x2400000 0x2800000 0x2c00000 0x3000000 0x3400000 0x3800000 0x3c00000
+            0x4000000 0x4800000 0x5000000 0x5800000 0x6000000 0x6800000 0x7000000 0x7800000 0x8000000 0x9000000 0xa000000 0xb000000 0xc000000 0xd000000 0xe000000 0xf000000
+            0x10000000 0x12000000 0x14000000 0x16000000 0x18000000 0x1a000000 0x1c000000 0x1e000000 0x20000000 0x24000000 0x28000000 0x2c000000 0x30000000 0x34000000 0x38000000 0x3c000000
+            0x40000000 0x48000000 0x50000000 0x58000000 0x60000000 0x68000000 0x70000000 0x78000000 0x80000000 0x90000000 0xa0000000 0xb0000000 0xc0000000 0xd0000000 0xe0000000 0xf0000000
+            0xffffffff
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_size_u64 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79
+            80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+            96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+            112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
+            128 129 130 131 132 133 134 135 136 137 138 139 140 141 142 143
+            144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159
+            160 161 162 163 164 165 166 167 168 169 170 171 172 173 174 175
+            176 177 178 179 180 181 182 183 184 185 186 187 188 189 190 191
+            192 193 194 195 196 197 198 199 200 201 202 203 204 205 206 207
+            208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223
+            224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239
+            240 241 242 243 244 245 246 247 248 249 250 251 252 253 254 255
+            256 0x120 0x140 0x160 0x180 0x1a0 0x1c0 0x1e0 0x200 0x240 0x280 0x2c0 0x300 0x340 0x380 0x3c0
+            0x400 0x480 0x500 0x580 0x600 0x680 0x700 0x780 0x800 0x900 0xa00 0xb00 0xc00 0xd00 0xe00 0xf00
+            0x1000 0x1200 0x1400 0x1600 0x1800 0x1a00 0x1c00 0x1e00 0x2000 0x2400 0x2800 0x2c00 0x3000 0x3400 0x3800 0x3c00
+            0x4000 0x4800 0x5000 0x5800 0x6000 0x6800 0x7000 0x7800 0x8000 0x9000 0xa000 0xb000 0xc000 0xd000 0xe000 0xf000
+            0x10000 0x12000 0x14000 0x16000 0x18000 0x1a000 0x1c000 0x1e000 0x20000 0x24000 0x28000 0x2c000 0x30000 0x34000 0x38000 0x3c000
+            0x40000 0x48000 0x50000 0x58000 0x60000 0x68000 0x70000 0x78000 0x80000 0x90000 0xa0000 0xb0000 0xc0000 0xd0000 0xe0000 0xf0000
+            0x100000 0x120000 0x140000 0x160000 0x180000 0x1a0000 0x1c0000 0x1e0000 0x200000 0x240000 0x280000 0x2c0000 0x300000 0x340000 0x380000 0x3c0000
+            0x400000 0x480000 0x500000 0x580000 0x600000 0x680000 0x700000 0x780000 0x800000 0x900000 0xa00000 0xb00000 0xc00000 0xd00000 0xe00000 0xf00000
+            0x1000000 0x1200000 0x1400000 0x1600000 0x1800000 0x1a00000 0x1c00000 0x1e00000 0x2000000 0x2400000 0x2800000 0x2c00000 0x3000000 0x3400000 0x3800000 0x3c00000
+            0x4000000 0x4800000 0x5000000 0x5800000 0x6000000 0x6800000 0x7000000 0x7800000 0x8000000 0x9000000 0xa000000 0xb000000 0xc000000 0xd000000 0xe000000 0xf000000
+            0x10000000 0x12000000 0x14000000 0x16000000 0x18000000 0x1a000000 0x1c000000 0x1e000000 0x20000000 0x24000000 0x28000000 0x2c000000 0x30000000 0x34000000 0x38000000 0x3c000000
+            0x40000000 0x48000000 0x50000000 0x58000000 0x60000000 0x68000000 0x70000000 0x78000000 0x80000000 0x90000000 0xa0000000 0xb0000000 0xc0000000 0xd0000000 0xe0000000 0xf0000000
+            0x100000000 0x120000000 0x140000000 0x160000000 0x180000000 0x1a0000000 0x1c0000000 0x1e0000000 0x200000000 0x240000000 0x280000000 0x2c0000000 0x300000000 0x340000000 0x380000000 0x3c0000000
+            0x400000000 0x480000000 0x500000000 0x580000000 0x600000000 0x680000000 0x700000000 0x780000000 0x800000000 0x900000000 0xa00000000 0xb00000000 0xc00000000 0xd00000000 0xe00000000 0xf00000000
+            0x1000000000 0x1200000000 0x1400000000 0x1600000000 0x1800000000 0x1a00000000 0x1c00000000 0x1e00000000 0x2000000000 0x2400000000 0x2800000000 0x2c00000000 0x3000000000 0x3400000000 0x3800000000 0x3c00000000
+            0x4000000000 0x4800000000 0x5000000000 0x5800000000 0x6000000000 0x6800000000 0x7000000000 0x7800000000 0x8000000000 0x9000000000 0xa000000000 0xb000000000 0xc000000000 0xd000000000 0xe000000000 0xf000000000
+            0x10000000000 0x12000000000 0x14000000000 0x16000000000 0x18000000000 0x1a000000000 0x1c000000000 0x1e000000000 0x20000000000 0x24000000000 0x28000000000 0x2c000000000 0x30000000000 0x34000000000 0x38000000000 0x3c000000000
+            0x40000000000 0x48000000000 0x50000000000 0x58000000000 0x60000000000 0x68000000000 0x70000000000 0x78000000000 0x80000000000 0x90000000000 0xa0000000000 0xb0000000000 0xc0000000000 0xd0000000000 0xe0000000000 0xf0000000000
+            0x100000000000 0x120000000000 0x140000000000 0x160000000000 0x180000000000 0x1a0000000000 0x1c0000000000 0x1e0000000000 0x200000000000 0x240000000000 0x280000000000 0x2c0000000000 0x300000000000 0x340000000000 0x380000000000 0x3c0000000000
+            0x400000000000 0x480000000000 0x500000000000 0x580000000000 0x600000000000 0x680000000000 0x700000000000 0x780000000000 0x800000000000 0x900000000000 0xa00000000000 0xb00000000000 0xc00000000000 0xd00000000000 0xe00000000000 0xf00000000000
+            0x1000000000000 0x1200000000000 0x1400000000000 0x1600000000000 0x1800000000000 0x1a00000000000 0x1c00000000000 0x1e00000000000 0x2000000000000 0x2400000000000 0x2800000000000 0x2c00000000000 0x3000000000000 0x3400000000000 0x3800000000000 0x3c00000000000
+            0x4000000000000 0x4800000000000 0x5000000000000 0x5800000000000 0x6000000000000 0x6800000000000 0x7000000000000 0x7800000000000 0x8000000000000 0x9000000000000 0xa000000000000 0xb000000000000 0xc000000000000 0xd000000000000 0xe000000000000 0xf000000000000
+            0x10000000000000 0x12000000000000 0x14000000000000 0x16000000000000 0x18000000000000 0x1a000000000000 0x1c000000000000 0x1e000000000000 0x20000000000000 0x24000000000000 0x28000000000000 0x2c000000000000 0x30000000000000 0x34000000000000 0x38000000000000 0x3c000000000000
+            0x40000000000000 0x48000000000000 0x50000000000000 0x58000000000000 0x60000000000000 0x68000000000000 0x70000000000000 0x78000000000000 0x80000000000000 0x90000000000000 0xa0000000000000 0xb0000000000000 0xc0000000000000 0xd0000000000000 0xe0000000000000 0xf0000000000000
+            0x100000000000000 0x120000000000000 0x140000000000000 0x160000000000000 0x180000000000000 0x1a0000000000000 0x1c0000000000000 0x1e0000000000000 0x200000000000000 0x240000000000000 0x280000000000000 0x2c0000000000000 0x300000000000000 0x340000000000000 0x380000000000000 0x3c0000000000000
+            0x400000000000000 0x480000000000000 0x500000000000000 0x580000000000000 0x600000000000000 0x680000000000000 0x700000000000000 0x780000000000000 0x800000000000000 0x900000000000000 0xa00000000000000 0xb00000000000000 0xc00000000000000 0xd00000000000000 0xe00000000000000 0xf00000000000000
+            0x1000000000000000 0x1200000000000000 0x1400000000000000 0x1600000000000000 0x1800000000000000 0x1a00000000000000 0x1c00000000000000 0x1e00000000000000 0x2000000000000000 0x2400000000000000 0x2800000000000000 0x2c00000000000000 0x3000000000000000 0x3400000000000000 0x3800000000000000 0x3c00000000000000
+            0x4000000000000000 0x4800000000000000 0x5000000000000000 0x5800000000000000 0x6000000000000000 0x6800000000000000 0x7000000000000000 0x7800000000000000 0x8000000000000000 0x9000000000000000 0xa000000000000000 0xb000000000000000 0xc000000000000000 0xd000000000000000 0xe000000000000000 0xf000000000000000
+            0xffffffffffffffff
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_size_u128 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79
+            80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+            96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+            112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
+            128 129 130 131 132 133 134 135 136 137 138 139 140 141 142 143
+            144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159
+            160 161 162 163 164 165 166 167 168 169 170 171 172 173 174 175
+            176 177 178 179 180 181 182 183 184 185 186 187 188 189 190 191
+            192 193 194 195 196 197 198 199 200 201 202 203 204 205 206 207
+            208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223
+            224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239
+            240 241 242 243 244 245 246 247 248 249 250 251 252 253 254 255
+            256 0x120 0x140 0x160 0x180 0x1a0 0x1c0 0x1e0 0x200 0x240 0x280 0x2c0 0x300 0x340 0x380 0x3c0
+            0x400 0x480 0x500 0x580 0x600 0x680 0x700 0x780 0x800 0x900 0xa00 0xb00 0xc00 0xd00 0xe00 0xf00
+            0x1000 0x1200 0x1400 0x1600 0x1800 0x1a00 0x1c00 0x1e00 0x2000 0x2400 0x2800 0x2c00 0x3000 0x3400 0x3800 0x3c00
+            0x4000 0x4800 0x5000 0x5800 0x6000 0x6800 0x7000 0x7800 0x8000 0x9000 0xa000 0xb000 0xc000 0xd000 0xe000 0xf000
+            0x10000 0x12000 0x14000 0x16000 0x18000 0x1a000 0x1c000 0x1e000 0x20000 0x24000 0x28000 0x2c000 0x30000 0x34000 0x38000 0x3c000
+            0x40000 0x48000 0x50000 0x58000 0x60000 0x68000 0x70000 0x78000 0x80000 0x90000 0xa0000 0xb0000 0xc0000 0xd0000 0xe0000 0xf0000
+            0x100000 0x120000 0x140000 0x160000 0x180000 0x1a0000 0x1c0000 0x1e0000 0x200000 0x240000 0x280000 0x2c0000 0x300000 0x340000 0x380000 0x3c0000
+            0x400000 0x480000 0x500000 0x580000 0x600000 0x680000 0x700000 0x780000 0x800000 0x900000 0xa00000 0xb00000 0xc00000 0xd00000 0xe00000 0xf00000
+            0x1000000 0x1200000 0x1400000 0x1600000 0x1800000 0x1a00000 0x1c00000 0x1e00000 0x2000000 0x2400000 0x2800000 0x2c00000 0x3000000 0x3400000 0x3800000 0x3c00000
+            0x4000000 0x4800000 0x5000000 0x5800000 0x6000000 0x6800000 0x7000000 0x7800000 0x8000000 0x9000000 0xa000000 0xb000000 0xc000000 0xd000000 0xe000000 0xf000000
+            0x10000000 0x12000000 0x14000000 0x16000000 0x18000000 0x1a000000 0x1c000000 0x1e000000 0x20000000 0x24000000 0x28000000 0x2c000000 0x30000000 0x34000000 0x38000000 0x3c000000
+            0x40000000 0x48000000 0x50000000 0x58000000 0x60000000 0x68000000 0x70000000 0x78000000 0x80000000 0x90000000 0xa0000000 0xb0000000 0xc0000000 0xd0000000 0xe0000000 0xf0000000
+            0x100000000 0x120000000 0x140000000 0x160000000 0x180000000 0x1a0000000 0x1c0000000 0x1e0000000 0x200000000 0x240000000 0x280000000 0x2c0000000 0x300000000 0x340000000 0x380000000 0x3c0000000
+            0x400000000 0x480000000 0x500000000 0x580000000 0x600000000 0x680000000 0x700000000 0x780000000 0x800000000 0x900000000 0xa00000000 0xb00000000 0xc00000000 0xd00000000 0xe00000000 0xf00000000
+            0x1000000000 0x1200000000 0x1400000000 0x1600000000 0x1800000000 0x1a00000000 0x1c00000000 0x1e00000000 0x2000000000 0x2400000000 0x2800000000 0x2c00000000 0x3000000000 0x3400000000 0x3800000000 0x3c00000000
+            0x4000000000 0x4800000000 0x5000000000 0x5800000000 0x6000000000 0x6800000000 0x7000000000 0x7800000000 0x8000000000 0x9000000000 0xa000000000 0xb000000000 0xc000000000 0xd000000000 0xe000000000 0xf000000000
+            0x10000000000 0x12000000000 0x14000000000 0x16000000000 0x18000000000 0x1a000000000 0x1c000000000 0x1e000000000 0x20000000000 0x24000000000 0x28000000000 0x2c000000000 0x30000000000 0x34000000000 0x38000000000 0x3c000000000
+            0x40000000000 0x48000000000 0x50000000000 0x58000000000 0x60000000000 0x68000000000 0x70000000000 0x78000000000 0x80000000000 0x90000000000 0xa0000000000 0xb0000000000 0xc0000000000 0xd0000000000 0xe0000000000 0xf0000000000
+            0x100000000000 0x120000000000 0x140000000000 0x160000000000 0x180000000000 0x1a0000000000 0x1c0000000000 0x1e0000000000 0x200000000000 0x240000000000 0x280000000000 0x2c0000000000 0x300000000000 0x340000000000 0x380000000000 0x3c0000000000
+            0x400000000000 0x480000000000 0x500000000000 0x580000000000 0x600000000000 0x680000000000 0x700000000000 0x780000000000 0x800000000000 0x900000000000 0xa00000000000 0xb00000000000 0xc00000000000 0xd00000000000 0xe00000000000 0xf00000000000
+            0x1000000000000 0x1200000000000 0x1400000000000 0x1600000000000 0x1800000000000 0x1a00000000000 0x1c00000000000 0x1e00000000000 0x2000000000000 0x2400000000000 0x2800000000000 0x2c00000000000 0x3000000000000 0x3400000000000 0x3800000000000 0x3c00000000000
+            0x4000000000000 0x4800000000000 0x5000000000000 0x5800000000000 0x6000000000000 0x6800000000000 0x7000000000000 0x7800000000000 0x8000000000000 0x9000000000000 0xa000000000000 0xb000000000000 0xc000000000000 0xd000000000000 0xe000000000000 0xf000000000000
+            0x10000000000000 0x12000000000000 0x14000000000000 0x16000000000000 0x18000000000000 0x1a000000000000 0x1c000000000000 0x1e000000000000 0x20000000000000 0x24000000000000 0x28000000000000 0x2c000000000000 0x30000000000000 0x34000000000000 0x38000000000000 0x3c000000000000
+            0x40000000000000 0x48000000000000 0x50000000000000 0x58000000000000 0x60000000000000 0x68000000000000 0x70000000000000 0x78000000000000 0x80000000000000 0x90000000000000 0xa0000000000000 0xb0000000000000 0xc0000000000000 0xd0000000000000 0xe0000000000000 0xf0000000000000
+            0x100000000000000 0x120000000000000 0x140000000000000 0x160000000000000 0x180000000000000 0x1a0000000000000 0x1c0000000000000 0x1e0000000000000 0x200000000000000 0x240000000000000 0x280000000000000 0x2c0000000000000 0x300000000000000 0x340000000000000 0x380000000000000 0x3c0000000000000
+            0x400000000000000 0x480000000000000 0x500000000000000 0x580000000000000 0x600000000000000 0x680000000000000 0x700000000000000 0x780000000000000 0x800000000000000 0x900000000000000 0xa00000000000000 0xb00000000000000 0xc00000000000000 0xd00000000000000 0xe00000000000000 0xf00000000000000
+            0x1000000000000000 0x1200000000000000 0x1400000000000000 0x1600000000000000 0x1800000000000000 0x1a00000000000000 0x1c00000000000000 0x1e00000000000000 0x2000000000000000 0x2400000000000000 0x2800000000000000 0x2c00000000000000 0x3000000000000000 0x3400000000000000 0x3800000000000000 0x3c00000000000000
+            0x4000000000000000 0x4800000000000000 0x5000000000000000 0x5800000000000000 0x6000000000000000 0x6800000000000000 0x7000000000000000 0x7800000000000000 0x8000000000000000 0x9000000000000000 0xa000000000000000 0xb000000000000000 0xc000000000000000 0xd000000000000000 0xe000000000000000 0xf000000000000000
+            0x10000000000000000 0x12000000000000000 0x14000000000000000 0x16000000000000000 0x18000000000000000 0x1a000000000000000 0x1c000000000000000 0x1e000000000000000 0x20000000000000000 0x24000000000000000 0x28000000000000000 0x2c000000000000000 0x30000000000000000 0x34000000000000000 0x38000000000000000 0x3c000000000000000
+            0x40000000000000000 0x48000000000000000 0x50000000000000000 0x58000000000000000 0x60000000000000000 0x68000000000000000 0x70000000000000000 0x78000000000000000 0x80000000000000000 0x90000000000000000 0xa0000000000000000 0xb0000000000000000 0xc0000000000000000 0xd0000000000000000 0xe0000000000000000 0xf0000000000000000
+            0x100000000000000000 0x120000000000000000 0x140000000000000000 0x160000000000000000 0x180000000000000000 0x1a0000000000000000 0x1c0000000000000000 0x1e0000000000000000 0x200000000000000000 0x240000000000000000 0x280000000000000000 0x2c0000000000000000 0x300000000000000000 0x340000000000000000 0x380000000000000000 0x3c0000000000000000
+            0x400000000000000000 0x480000000000000000 0x500000000000000000 0x580000000000000000 0x600000000000000000 0x680000000000000000 0x700000000000000000 0x780000000000000000 0x800000000000000000 0x900000000000000000 0xa00000000000000000 0xb00000000000000000 0xc00000000000000000 0xd00000000000000000 0xe00000000000000000 0xf00000000000000000
+            0x1000000000000000000 0x1200000000000000000 0x1400000000000000000 0x1600000000000000000 0x1800000000000000000 0x1a00000000000000000 0x1c00000000000000000 0x1e00000000000000000 0x2000000000000000000 0x2400000000000000000 0x2800000000000000000 0x2c00000000000000000 0x3000000000000000000 0x3400000000000000000 0x3800000000000000000 0x3c00000000000000000
+            0x4000000000000000000 0x4800000000000000000 0x5000000000000000000 0x5800000000000000000 0x6000000000000000000 0x6800000000000000000 0x7000000000000000000 0x7800000000000000000 0x8000000000000000000 0x9000000000000000000 0xa000000000000000000 0xb000000000000000000 0xc000000000000000000 0xd000000000000000000 0xe000000000000000000 0xf000000000000000000
+            0x10000000000000000000 0x12000000000000000000 0x14000000000000000000 0x16000000000000000000 0x18000000000000000000 0x1a000000000000000000 0x1c000000000000000000 0x1e000000000000000000 0x20000000000000000000 0x24000000000000000000 0x28000000000000000000 0x2c000000000000000000 0x30000000000000000000 0x34000000000000000000 0x38000000000000000000 0x3c000000000000000000
+            0x40000000000000000000 0x48000000000000000000 0x50000000000000000000 0x58000000000000000000 0x60000000000000000000 0x68000000000000000000 0x70000000000000000000 0x78000000000000000000 0x80000000000000000000 0x90000000000000000000 0xa0000000000000000000 0xb0000000000000000000 0xc0000000000000000000 0xd0000000000000000000 0xe0000000000000000000 0xf0000000000000000000
+            0x100000000000000000000 0x120000000000000000000 0x140000000000000000000 0x160000000000000000000 0x180000000000000000000 0x1a0000000000000000000 0x1c0000000000000000000 0x1e0000000000000000000 0x200000000000000000000 0x240000000000000000000 0x280000000000000000000 0x2c0000000000000000000 0x300000000000000000000 0x340000000000000000000 0x380000000000000000000 0x3c0000000000000000000
+            0x400000000000000000000 0x480000000000000000000 0x500000000000000000000 0x580000000000000000000 0x600000000000000000000 0x680000000000000000000 0x700000000000000000000 0x780000000000000000000 0x800000000000000000000 0x900000000000000000000 0xa00000000000000000000 0xb00000000000000000000 0xc00000000000000000000 0xd00000000000000000000 0xe00000000000000000000 0xf00000000000000000000
+            0x1000000000000000000000 0x1200000000000000000000 0x1400000000000000000000 0x1600000000000000000000 0x1800000000000000000000 0x1a00000000000000000000 0x1c00000000000000000000 0x1e00000000000000000000 0x2000000000000000000000 0x2400000000000000000000 0x2800000000000000000000 0x2c00000000000000000000 0x3000000000000000000000 0x3400000000000000000000 0x3800000000000000000000 0x3c00000000000000000000
+            0x4000000000000000000000 0x4800000000000000000000 0x5000000000000000000000 0x5800000000000000000000 0x6000000000000000000000 0x6800000000000000000000 0x7000000000000000000000 0x7800000000000000000000 0x8000000000000000000000 0x9000000000000000000000 0xa000000000000000000000 0xb000000000000000000000 0xc000000000000000000000 0xd000000000000000000000 0xe000000000000000000000 0xf000000000000000000000
+            0x10000000000000000000000 0x12000000000000000000000 0x14000000000000000000000 0x16000000000000000000000 0x18000000000000000000000 0x1a000000000000000000000 0x1c000000000000000000000 0x1e000000000000000000000 0x20000000000000000000000 0x24000000000000000000000 0x28000000000000000000000 0x2c000000000000000000000 0x30000000000000000000000 0x34000000000000000000000 0x38000000000000000000000 0x3c000000000000000000000
+            0x40000000000000000000000 0x48000000000000000000000 0x50000000000000000000000 0x58000000000000000000000 0x60000000000000000000000 0x68000000000000000000000 0x70000000000000000000000 0x78000000000000000000000 0x80000000000000000000000 0x90000000000000000000000 0xa0000000000000000000000 0xb0000000000000000000000 0xc0000000000000000000000 0xd0000000000000000000000 0xe0000000000000000000000 0xf0000000000000000000000
+            0x100000000000000000000000 0x120000000000000000000000 0x140000000000000000000000 0x160000000000000000000000 0x180000000000000000000000 0x1a0000000000000000000000 0x1c0000000000000000000000 0x1e0000000000000000000000 0x200000000000000000000000 0x240000000000000000000000 0x280000000000000000000000 0x2c0000000000000000000000 0x300000000000000000000000 0x340000000000000000000000 0x380000000000000000000000 0x3c0000000000000000000000
+            0x400000000000000000000000 0x480000000000000000000000 0x500000000000000000000000 0x580000000000000000000000 0x600000000000000000000000 0x680000000000000000000000 0x700000000000000000000000 0x780000000000000000000000 0x800000000000000000000000 0x900000000000000000000000 0xa00000000000000000000000 0xb00000000000000000000000 0xc00000000000000000000000 0xd00000000000000000000000 0xe00000000000000000000000 0xf00000000000000000000000
+            0x1000000000000000000000000 0x1200000000000000000000000 0x1400000000000000000000000 0x1600000000000000000000000 0x1800000000000000000000000 0x1a00000000000000000000000 0x1c00000000000000000000000 0x1e00000000000000000000000 0x2000000000000000000000000 0x2400000000000000000000000 0x2800000000000000000000000 0x2c00000000000000000000000 0x3000000000000000000000000 0x3400000000000000000000000 0x3800000000000000000000000 0x3c00000000000000000000000
+            0x4000000000000000000000000 0x4800000000000000000000000 0x5000000000000000000000000 0x5800000000000000000000000 0x6000000000000000000000000 0x6800000000000000000000000 0x7000000000000000000000000 0x7800000000000000000000000 0x8000000000000000000000000 0x9000000000000000000000000 0xa000000000000000000000000 0xb000000000000000000000000 0xc000000000000000000000000 0xd000000000000000000000000 0xe000000000000000000000000 0xf000000000000000000000000
+            0x10000000000000000000000000 0x12000000000000000000000000 0x14000000000000000000000000 0x16000000000000000000000000 0x18000000000000000000000000 0x1a000000000000000000000000 0x1c000000000000000000000000 0x1e000000000000000000000000 0x20000000000000000000000000 0x24000000000000000000000000 0x28000000000000000000000000 0x2c000000000000000000000000 0x30000000000000000000000000 0x34000000000000000000000000 0x38000000000000000000000000 0x3c000000000000000000000000
+            0x40000000000000000000000000 0x48000000000000000000000000 0x50000000000000000000000000 0x58000000000000000000000000 0x60000000000000000000000000 0x68000000000000000000000000 0x70000000000000000000000000 0x78000000000000000000000000 0x80000000000000000000000000 0x90000000000000000000000000 0xa0000000000000000000000000 0xb0000000000000000000000000 0xc0000000000000000000000000 0xd0000000000000000000000000 0xe0000000000000000000000000 0xf0000000000000000000000000
+            0x100000000000000000000000000 0x120000000000000000000000000 0x140000000000000000000000000 0x160000000000000000000000000 0x180000000000000000000000000 0x1a0000000000000000000000000 0x1c0000000000000000000000000 0x1e0000000000000000000000000 0x200000000000000000000000000 0x240000000000000000000000000 0x280000000000000000000000000 0x2c0000000000000000000000000 0x300000000000000000000000000 0x340000000000000000000000000 0x380000000000000000000000000 0x3c0000000000000000000000000
+            0x400000000000000000000000000 0x480000000000000000000000000 0x500000000000000000000000000 0x580000000000000000000000000 0x600000000000000000000000000 0x680000000000000000000000000 0x700000000000000000000000000 0x780000000000000000000000000 0x800000000000000000000000000 0x900000000000000000000000000 0xa00000000000000000000000000 0xb00000000000000000000000000 0xc00000000000000000000000000 0xd00000000000000000000000000 0xe00000000000000000000000000 0xf00000000000000000000000000
+            0x1000000000000000000000000000 0x1200000000000000000000000000 0x1400000000000000000000000000 0x1600000000000000000000000000 0x1800000000000000000000000000 0x1a00000000000000000000000000 0x1c00000000000000000000000000 0x1e00000000000000000000000000 0x2000000000000000000000000000 0x2400000000000000000000000000 0x2800000000000000000000000000 0x2c00000000000000000000000000 0x3000000000000000000000000000 0x3400000000000000000000000000 0x3800000000000000000000000000 0x3c00000000000000000000000000
+            0x4000000000000000000000000000 0x4800000000000000000000000000 0x5000000000000000000000000000 0x5800000000000000000000000000 0x6000000000000000000000000000 0x6800000000000000000000000000 0x7000000000000000000000000000 0x7800000000000000000000000000 0x8000000000000000000000000000 0x9000000000000000000000000000 0xa000000000000000000000000000 0xb000000000000000000000000000 0xc000000000000000000000000000 0xd000000000000000000000000000 0xe000000000000000000000000000 0xf000000000000000000000000000
+            0x10000000000000000000000000000 0x12000000000000000000000000000 0x14000000000000000000000000000 0x16000000000000000000000000000 0x18000000000000000000000000000 0x1a000000000000000000000000000 0x1c000000000000000000000000000 0x1e000000000000000000000000000 0x20000000000000000000000000000 0x24000000000000000000000000000 0x28000000000000000000000000000 0x2c000000000000000000000000000 0x30000000000000000000000000000 0x34000000000000000000000000000 0x38000000000000000000000000000 0x3c000000000000000000000000000
+            0x40000000000000000000000000000 0x48000000000000000000000000000 0x50000000000000000000000000000 0x58000000000000000000000000000 0x60000000000000000000000000000 0x68000000000000000000000000000 0x70000000000000000000000000000 0x78000000000000000000000000000 0x80000000000000000000000000000 0x90000000000000000000000000000 0xa0000000000000000000000000000 0xb0000000000000000000000000000 0xc0000000000000000000000000000 0xd0000000000000000000000000000 0xe0000000000000000000000000000 0xf0000000000000000000000000000
+            0x100000000000000000000000000000 0x120000000000000000000000000000 0x140000000000000000000000000000 0x160000000000000000000000000000 0x180000000000000000000000000000 0x1a0000000000000000000000000000 0x1c0000000000000000000000000000 0x1e0000000000000000000000000000 0x200000000000000000000000000000 0x240000000000000000000000000000 0x280000000000000000000000000000 0x2c0000000000000000000000000000 0x300000000000000000000000000000 0x340000000000000000000000000000 0x380000000000000000000000000000 0x3c0000000000000000000000000000
+            0x400000000000000000000000000000 0x480000000000000000000000000000 0x500000000000000000000000000000 0x580000000000000000000000000000 0x600000000000000000000000000000 0x680000000000000000000000000000 0x700000000000000000000000000000 0x780000000000000000000000000000 0x800000000000000000000000000000 0x900000000000000000000000000000 0xa00000000000000000000000000000 0xb00000000000000000000000000000 0xc00000000000000000000000000000 0xd00000000000000000000000000000 0xe00000000000000000000000000000 0xf00000000000000000000000000000
+            0x1000000000000000000000000000000 0x1200000000000000000000000000000 0x1400000000000000000000000000000 0x1600000000000000000000000000000 0x1800000000000000000000000000000 0x1a00000000000000000000000000000 0x1c00000000000000000000000000000 0x1e00000000000000000000000000000 0x2000000000000000000000000000000 0x2400000000000000000000000000000 0x2800000000000000000000000000000 0x2c00000000000000000000000000000 0x3000000000000000000000000000000 0x3400000000000000000000000000000 0x3800000000000000000000000000000 0x3c00000000000000000000000000000
+            0x4000000000000000000000000000000 0x4800000000000000000000000000000 0x5000000000000000000000000000000 0x5800000000000000000000000000000 0x6000000000000000000000000000000 0x6800000000000000000000000000000 0x7000000000000000000000000000000 0x7800000000000000000000000000000 0x8000000000000000000000000000000 0x9000000000000000000000000000000 0xa000000000000000000000000000000 0xb000000000000000000000000000000 0xc000000000000000000000000000000 0xd000000000000000000000000000000 0xe000000000000000000000000000000 0xf000000000000000000000000000000
+            0x10000000000000000000000000000000 0x12000000000000000000000000000000 0x14000000000000000000000000000000 0x16000000000000000000000000000000 0x18000000000000000000000000000000 0x1a000000000000000000000000000000 0x1c000000000000000000000000000000 0x1e000000000000000000000000000000 0x20000000000000000000000000000000 0x24000000000000000000000000000000 0x28000000000000000000000000000000 0x2c000000000000000000000000000000 0x30000000000000000000000000000000 0x34000000000000000000000000000000 0x38000000000000000000000000000000 0x3c000000000000000000000000000000
+            0x40000000000000000000000000000000 0x48000000000000000000000000000000 0x50000000000000000000000000000000 0x58000000000000000000000000000000 0x60000000000000000000000000000000 0x68000000000000000000000000000000 0x70000000000000000000000000000000 0x78000000000000000000000000000000 0x80000000000000000000000000000000 0x90000000000000000000000000000000 0xa0000000000000000000000000000000 0xb0000000000000000000000000000000 0xc0000000000000000000000000000000 0xd0000000000000000000000000000000 0xe0000000000000000000000000000000 0xf0000000000000000000000000000000
+            0xffffffffffffffffffffffffffffffff
+        }
+    };
+}
+
+// Each `size_tree_uN` macro passes the same candidates to `$mac` as a binary search tree, after
+// `$args`. A node `{ LEFT MID RIGHT }` has all candidates below `MID` in `LEFT` and the rest in
+// `RIGHT`. A leaf `[...]` is a short list of consecutive candidates.
+
+macro_rules! size_tree_u8 {
+    ($mac:ident { $($args:tt)* }) => {
+        $mac! {
+            $($args)*
+            {
+                {
+                    {
+                        {
+                            {
+                                [0 1 2 3 4 5 6 7]
+                                8
+                                [8 9 10 11 12 13 14 15]
+                            }
+                            16
+                            {
+                                [16 17 18 19 20 21 22 23]
+                                24
+                                [24 25 26 27 28 29 30 31]
+                            }
+                        }
+                        32
+                        {
+                            {
+                                [32 33 34 35 36 37 38 39]
+                                40
+                                [40 41 42 43 44 45 46 47]
+                            }
+                            48
+                            {
+                                [48 49 50 51 52 53 54 55]
+                                56
+                                [56 57 58 59 60 61 62 63]
+                            }
+                        }
+                    }
+                    64
+                    {
+                        {
+                            {
+                                [64 65 66 67 68 69 70 71]
+                                72
+                                [72 73 74 75 76 77 78 79]
+                            }
+                            80
+                            {
+                                [80 81 82 83 84 85 86 87]
+                                88
+                                [88 89 90 91 92 93 94 95]
+                            }
+                        }
+                        96
+                        {
+                            {
+                                [96 97 98 99 100 101 102 103]
+                                104
+                                [104 105 106 107 108 109 110 111]
+                            }
+                            112
+                            {
+                                [112 113 114 115 116 117 118 119]
+                                120
+                                [120 121 122 123 124 125 126 127]
+                            }
+                        }
+                    }
+                }
+                128
+                {
+                    {
+                        {
+                            {
+                                [128 129 130 131 132 133 134 135]
+                                136
+                                [136 137 138 139 140 141 142 143]
+                            }
+                            144
+                            {
+                                [144 145 146 147 148 149 150 151]
+                                152
+                                [152 153 154 155 156 157 158 159]
+                            }
+                        }
+                        160
+                        {
+                            {
+                                [160 161 162 163 164 165 166 167]
+                                168
+                                [168 169 170 171 172 173 174 175]
+                            }
+                            176
+                            {
+                                [176 177 178 179 180 181 182 183]
+                                184
+                                [184 185 186 187 188 189 190 191]
+                            }
+                        }
+                    }
+                    192
+                    {
+                        {
+                            {
+                                [192 193 194 195 196 197 198 199]
+                                200
+                                [200 201 202 203 204 205 206 207]
+                            }
+                            208
+                            {
+                                [208 209 210 211 212 213 214 215]
+                                216
+                                [216 217 218 219 220 221 222 223]
+                            }
+                        }
+                        224
+                        {
+                            {
+                                [224 225 226 227 228 229 230 231]
+                                232
+                                [232 233 234 235 236 237 238 239]
+                            }
+                            240
+                            {
+                                [240 241 242 243 244 245 246 247]
+                                248
+                                [248 249 250 251 252 253 254 255]
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! size_tree_u16 {
+    ($mac:ident { $($args:tt)* }) => {
+        $mac! {
+            $($args)*
+            {
+                {
+                    {
+                        {
+                            {
+                                [0 1 2 3 4 5 6 7]
+                                8
+                                [8 9 10 11 12 13 14 15]
+                            }
+                            16
+                            {
+                                [16 17 18 19 20 21 22 23]
+                                24
+                                {
+                                    [24 25 26 27 28 29 30 31]
+                                    32
+                                    [32 33 34 35 36 37 38 39]
+                                }
+                            }
+                        }
+                        40
+                        {
+                            {
+                                [40 41 42 43 44 45 46 47]
+                                48
+                                [48 49 50 51 52 53 54 55]
+                            }
+                            56
+                            {
+                                [56 57 58 59 60 61 62 63]
+                                64
+                                {
+                                    [64 65 66 67 68 69 70 71]
+                                    72
+                                    [72 73 74 75 76 77 78 79]
+                                }
+                            }
+                        }
+                    }
+                    80
+                    {
+                        {
+                            {
+                                [80 81 82 83 84 85 86 87]
+                                88
+                                [88 89 90 91 92 93 94 95]
+                            }
+                            96
+                            {
+                                [96 97 98 99 100 101 102 103]
+                                104
+                                {
+                                    [104 105 106 107 108 109 110 111]
+                                    112
+                                    [112 113 114 115 116 117 118 119]
+                                }
+                            }
+                        }
+                        120
+                        {
+                            {
+                                [120 121 122 123 124 125 126 127]
+                                128
+                                [128 129 130 131 132 133 134 135]
+                            }
+                            136
+                            {
+                                [136 137 138 139 140 141 142 143]
+                                144
+                                {
+                                    [144 145 146 147 148 149 150 151]
+                                    152
+                                    [152 153 154 155 156 157 158 159]
+                                }
+                            }
+                        }
+                    }
+                }
+                160
+                {
+                    {
+                        {
+                            {
+                                [160 161 162 163 164 165 166 167]
+                                168
+                                [168 169 170 171 172 173 174 175]
+                            }
+                            176
+                            {
+                                [176 177 178 179 180 181 182 183]
+                                184
+                                {
+                                    [184 185 186 187 188 189 190 191]
+                                    192
+                                    [192 193 194 195 196 197 198 199]
+                                }
+                            }
+                        }
+                        200
+                        {
+                            {
+                                [200 201 202 203 204 205 206 207]
+                                208
+                                [208 209 210 211 212 213 214 215]
+                            }
+                            216
+                            {
+                                [216 217 218 219 220 221 222 223]
+                                224
+                                {
+                                    [224 225 226 227 228 229 230 231]
+                                    232
+                                    [232 233 234 235 236 237 238 239]
+                                }
+                            }
+                        }
+                    }
+                    240
+                    {
+                        {
+                            {
+                                [240 241 242 243 244 245 246 247]
+                                248
+                                [248 249 250 251 252 253 254 255]
+                            }
+                            256
+                            {
+                                [256 0x120 0x140 0x160 0x180 0x1a0 0x1c0 0x1e0]
+                                0x200
+                                {
+                                    [0x200 0x240 0x280 0x2c0 0x300 0x340 0x380 0x3c0]
+                                    0x400
+                                    [0x400 0x480 0x500 0x580 0x600 0x680 0x700 0x780]
+                                }
+                            }
+                        }
+                        0x800
+                        {
+                            {
+                                [0x800 0x900 0xa00 0xb00 0xc00 0xd00 0xe00 0xf00]
+                                0x1000
+                                {
+                                    [0x1000 0x1200 0x1400 0x1600 0x1800 0x1a00 0x1c00 0x1e00]
+                                    0x2000
+                                    [0x2000 0x2400 0x2800 0x2c00 0x3000 0x3400 0x3800 0x3c00]
+                                }
+                            }
+                            0x4000
+                            {
+                                [0x4000 0x4800 0x5000 0x5800 0x6000 0x6800 0x7000 0x7800]
+                                0x8000
+                                {
+                                    [0x8000 0x9000 0xa000 0xb000 0xc000 0xd000 0xe000 0xf000]
+                                    0xffff
+                                    [0xffff]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! size_tree_u32 {
+    ($mac:ident { $($args:tt)* }) => {
+        $mac! {
+            $($args)*
+            {
+                {
+                    {
+                        {
+                            {
+                                [0 1 2 3 4 5 6 7]
+                                8
+                                {
+                                    [8 9 10 11 12 13 14 15]
+                                    16
+                                    [16 17 18 19 20 21 22 23]
+                                }
+                            }
+                            24
+                            {
+                                {
+                                    [24 25 26 27 28 29 30 31]
+                                    32
+                                    [32 33 34 35 36 37 38 39]
+                                }
+                                40
+                                {
+                                    [40 41 42 43 44 45 46 47]
+                                    48
+                                    [48 49 50 51 52 53 54 55]
+                                }
+                            }
+                        }
+                        56
+                        {
+                            {
+                                [56 57 58 59 60 61 62 63]
+                                64
+                                {
+                                    [64 65 66 67 68 69 70 71]
+                                    72
+                                    [72 73 74 75 76 77 78 79]
+                                }
+                            }
+                            80
+                            {
+                                {
+                                    [80 81 82 83 84 85 86 87]
+                                    88
+                                    [88 89 90 91 92 93 94 95]
+                                }
+                                96
+                                {
+                                    [96 97 98 99 100 101 102 103]
+                                    104
+                                    [104 105 106 107 108 109 110 111]
+                                }
+                            }
+                        }
+                    }
+                    112
+                    {
+                        {
+                            {
+                                [112 113 114 115 116 117 118 119]
+                                120
+                                {
+                                    [120 121 122 123 124 125 126 127]
+                                    128
+                                    [128 129 130 131 132 133 134 135]
+                                }
+                            }
+                            136
+                            {
+                                {
+                                    [136 137 138 139 140 141 142 143]
+                                    144
+                                    [144 145 146 147 148 149 150 151]
+                                }
+                                152
+                                {
+                                    [152 153 154 155 156 157 158 159]
+                                    160
+                                    [160 161 162 163 164 165 166 167]
+                                }
+                            }
+                        }
+                        168
+                        {
+                            {
+                                [168 169 170 171 172 173 174 175]
+                                176
+                                {
+                                    [176 177 178 179 180 181 182 183]
+                                    184
+                                    [184 185 186 187 188 189 190 191]
+                                }
+                            }
+                            192
+                            {
+                                {
+                                    [192 193 194 195 196 197 198 199]
+                                    200
+                                    [200 201 202 203 204 205 206 207]
+                                }
+                                208
+                                {
+                                    [208 209 210 211 212 213 214 215]
+                                    216
+                                    [216 217 218 219 220 221 222 223]
+                                }
+                            }
+                        }
+                    }
+                }
+                224
+                {
+                    {
+                        {
+                            {
+                                [224 225 226 227 228 229 230 231]
+                                232
+                                {
+                                    [232 233 234 235 236 237 238 239]
+                                    240
+                                    [240 241 242 243 244 245 246 247]
+                                }
+                            }
+                            248
+                            {
+                                {
+                                    [248 249 250 251 252 253 254 255]
+                                    256
+                                    [256 0x120 0x140 0x160 0x180 0x1a0 0x1c0 0x1e0]
+                                }
+                                0x200
+                                {
+                                    [0x200 0x240 0x280 0x2c0 0x300 0x340 0x380 0x3c0]
+                                    0x400
+                                    [0x400 0x480 0x500 0x580 0x600 0x680 0x700 0x780]
+                                }
+                            }
+                        }
+                        0x800
+                        {
+                            {
+                                [0x800 0x900 0xa00 0xb00 0xc00 0xd00 0xe00 0xf00]
+                                0x1000
+                                {
+                                    [0x1000 0x1200 0x1400 0x1600 0x1800 0x1a00 0x1c00 0x1e00]
+                                    0x2000
+                                    [0x2000 0x2400 0x2800 0x2c00 0x3000 0x3400 0x3800 0x3c00]
+                                }
+                            }
+                            0x4000
+                            {
+                                {
+                                    [0x4000 0x4800 0x5000 0x5800 0x6000 0x6800 0x7000 0x7800]
+                                    0x8000
+                                    [0x8000 0x9000 0xa000 0xb000 0xc000 0xd000 0xe000 0xf000]
+                                }
+                                0x10000
+                                {
+                                    [0x10000 0x12000 0x14000 0x16000 0x18000 0x1a000 0x1c000 0x1e000]
+                                    0x20000
+                                    [0x20000 0x24000 0x28000 0x2c000 0x30000 0x34000 0x38000 0x3c000]
+                                }
+                            }
+                        }
+                    }
+                    0x40000
+                    {
+                        {
+                            {
+                                [0x40000 0x48000 0x50000 0x58000 0x60000 0x68000 0x70000 0x78000]
+                                0x80000
+                                {
+                                    [0x80000 0x90000 0xa0000 0xb0000 0xc0000 0xd0000 0xe0000 0xf0000]
+                                    0x100000
+                                    [0x100000 0x120000 0x140000 0x160000 0x180000 0x1a0000 0x1c0000 0x1e0000]
+                                }
+                            }
+                            0x200000
+                            {
+                                {
+                                    [0x200000 0x240000 0x280000 0x2c0000 0x300000 0x340000 0x380000 0x3c0000]
+                                    0x400000
+                                    [0x400000 0x480000 0x500000 0x580000 0x600000 0x680000 0x700000 0x780000]
+                                }
+                                0x800000
+                                {
+                                    [0x800000 0x900000 0xa00000 0xb00000 0xc00000 0xd00000 0xe00000 0xf00000]
+                                    0x1000000
+                                    [0x1000000 0x1200000 0x1400000 0x1600000 0x1800000 0x1a00000 0x1c00000 0x1e00000]
+                                }
+                            }
+                        }
+                        0x2000000
+                        {
+                            {
+                                {
+                                    [0x2000000 0x2400000 0x2800000 0x2c00000 0x3000000 0x3400000 0x3800000 0x3c00000]
+                                    0x4000000
+                                    [0x4000000 0x4800000 0x5000000 0x5800000 0x6000000 0x6800000 0x7000000 0x7800000]
+                                }
+                                0x8000000
+                                {
+                                    [0x8000000 0x9000000 0xa000000 0xb000000 0xc000000 0xd000000 0xe000000 0xf000000]
+                                    0x10000000
+                                    [0x10000000 0x12000000 0x14000000 0x16000000 0x18000000 0x1a000000 0x1c000000 0x1e000000]
+                                }
+                            }
+                            0x20000000
+                            {
+                                {
+                                    [0x20000000 0x24000000 0x28000000 0x2c000000 0x30000000 0x34000000 0x38000000 0x3c000000]
+                                    0x40000000
+                                    [0x40000000 0x48000000 0x50000000 0x58000000 0x60000000 0x68000000 0x70000000 0x78000000]
+                                }
+                                0x80000000
+                                {
+                                    [0x80000000 0x90000000 0xa0000000 0xb0000000 0xc0000000 0xd0000000 0xe0000000 0xf0000000]
+                                    0xffffffff
+                                    [0xffffffff]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! size_tree_u64 {
+    ($mac:ident { $($args:tt)* }) => {
+        $mac! {
+            $($args)*
+            {
+                {
+                    {
+                        {
+                            {
+                                {
+                                    [0 1 2 3 4 5 6 7]
+                                    8
+                                    [8 9 10 11 12 13 14 15]
+                                }
+                                16
+                                {
+                                    [16 17 18 19 20 21 22 23]
+                                    24
+                                    {
+                                        [24 25 26 27 28 29 30 31]
+                                        32
+                                        [32 33 34 35 36 37 38 39]
+                                    }
+                                }
+                            }
+                            40
+                            {
+                                {
+                                    [40 41 42 43 44 45 46 47]
+                                    48
+                                    {
+                                        [48 49 50 51 52 53 54 55]
+                                        56
+                                        [56 57 58 59 60 61 62 63]
+                                    }
+                                }
+                                64
+                                {
+                                    [64 65 66 67 68 69 70 71]
+                                    72
+                                    {
+                                        [72 73 74 75 76 77 78 79]
+                                        80
+                                        [80 81 82 83 84 85 86 87]
+                                    }
+                                }
+                            }
+                        }
+                        88
+                        {
+                            {
+                                {
+                                    [88 89 90 91 92 93 94 95]
+                                    96
+                                    [96 97 98 99 100 101 102 103]
+                                }
+                                104
+                                {
+                                    [104 105 106 107 108 109 110 111]
+                                    112
+                                    {
+                                        [112 113 114 115 116 117 118 119]
+                                        120
+                                        [120 121 122 123 124 125 126 127]
+                                    }
+                                }
+                            }
+                            128
+                            {
+                                {
+                                    [128 129 130 131 132 133 134 135]
+                                    136
+                                    {
+                                        [136 137 138 139 140 141 142 143]
+                                        144
+                                        [144 145 146 147 148 149 150 151]
+                                    }
+                                }
+                                152
+                                {
+                                    [152 153 154 155 156 157 158 159]
+                                    160
+                                    {
+                                        [160 161 162 163 164 165 166 167]
+                                        168
+                                        [168 169 170 171 172 173 174 175]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    176
+                    {
+                        {
+                            {
+                                {
+                                    [176 177 178 179 180 181 182 183]
+                                    184
+                                    [184 185 186 187 188 189 190 191]
+                                }
+                                192
+                                {
+                                    [192 193 194 195 196 197 198 199]
+                                    200
+                                    {
+                                        [200 201 202 203 204 205 206 207]
+                                        208
+                                        [208 209 210 211 212 213 214 215]
+                                    }
+                                }
+                            }
+                            216
+                            {
+                                {
+                                    [216 217 218 219 220 221 222 223]
+                                    224
+                                    {
+                                        [224 225 226 227 228 229 230 231]
+                                        232
+                                        [232 233 234 235 236 237 238 239]
+                                    }
+                                }
+                                240
+                                {
+                                    [240 241 242 243 244 245 246 247]
+                                    248
+                                    {
+                                        [248 249 250 251 252 253 254 255]
+                                        256
+                                        [256 0x120 0x140 0x160 0x180 0x1a0 0x1c0 0x1e0]
+                                    }
+                                }
+                            }
+                        }
+                        0x200
+                        {
+                            {
+                                {
+                                    [0x200 0x240 0x280 0x2c0 0x300 0x340 0x380 0x3c0]
+                                    0x400
+                                    [0x400 0x480 0x500 0x580 0x600 0x680 0x700 0x780]
+                                }
+                                0x800
+                                {
+                                    [0x800 0x900 0xa00 0xb00 0xc00 0xd00 0xe00 0xf00]
+                                    0x1000
+                                    {
+                                        [0x1000 0x1200 0x1400 0x1600 0x1800 0x1a00 0x1c00 0x1e00]
+                                        0x2000
+                                        [0x2000 0x2400 0x2800 0x2c00 0x3000 0x3400 0x3800 0x3c00]
+                                    }
+                                }
+                            }
+                            0x4000
+                            {
+                                {
+                                    [0x4000 0x4800 0x5000 0x5800 0x6000 0x6800 0x7000 0x7800]
+                                    0x8000
+                                    {
+                                        [0x8000 0x9000 0xa000 0xb000 0xc000 0xd000 0xe000 0xf000]
+                                        0x10000
+                                        [0x10000 0x12000 0x14000 0x16000 0x18000 0x1a000 0x1c000 0x1e000]
+                                    }
+                                }
+                                0x20000
+                                {
+                                    [0x20000 0x24000 0x28000 0x2c000 0x30000 0x34000 0x38000 0x3c000]
+                                    0x40000
+                                    {
+                                        [0x40000 0x48000 0x50000 0x58000 0x60000 0x68000 0x70000 0x78000]
+                                        0x80000
+                                        [0x80000 0x90000 0xa0000 0xb0000 0xc0000 0xd0000 0xe0000 0xf0000]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                0x100000
+                {
+                    {
+                        {
+                            {
+                                {
+                                    [0x100000 0x120000 0x140000 0x160000 0x180000 0x1a0000 0x1c0000 0x1e0000]
+                                    0x200000
+                                    [0x200000 0x240000 0x280000 0x2c0000 0x300000 0x340000 0x380000 0x3c0000]
+                                }
+                                0x400000
+                                {
+                                    [0x400000 0x480000 0x500000 0x580000 0x600000 0x680000 0x700000 0x780000]
+                                    0x800000
+                                    {
+                                        [0x800000 0x900000 0xa00000 0xb00000 0xc00000 0xd00000 0xe00000 0xf00000]
+                                        0x1000000
+                                        [0x1000000 0x1200000 0x1400000 0x1600000 0x1800000 0x1a00000 0x1c00000 0x1e00000]
+                                    }
+                                }
+                            }
+                            0x2000000
+                            {
+                                {
+                                    [0x2000000 0x2400000 0x2800000 0x2c00000 0x3000000 0x3400000 0x3800000 0x3c00000]
+                                    0x4000000
+                                    {
+                                        [0x4000000 0x4800000 0x5000000 0x5800000 0x6000000 0x6800000 0x7000000 0x7800000]
+                                        0x8000000
+                                        [0x8000000 0x9000000 0xa000000 0xb000000 0xc000000 0xd000000 0xe000000 0xf000000]
+                                    }
+                                }
+                                0x10000000
+                                {
+                                    [0x10000000 0x12000000 0x14000000 0x16000000 0x18000000 0x1a000000 0x1c000000 0x1e000000]
+                                    0x20000000
+                                    {
+                                        [0x20000000 0x24000000 0x28000000 0x2c000000 0x30000000 0x34000000 0x38000000 0x3c000000]
+                                        0x40000000
+                                        [0x40000000 0x48000000 0x50000000 0x58000000 0x60000000 0x68000000 0x70000000 0x78000000]
+                                    }
+                                }
+                            }
+                        }
+                        0x80000000
+                        {
+                            {
+                                {
+                                    [0x80000000 0x90000000 0xa0000000 0xb0000000 0xc0000000 0xd0000000 0xe0000000 0xf0000000]
+                                    0x100000000
+                                    [0x100000000 0x120000000 0x140000000 0x160000000 0x180000000 0x1a0000000 0x1c0000000 0x1e0000000]
+                                }
+                                0x200000000
+                                {
+                                    [0x200000000 0x240000000 0x280000000 0x2c0000000 0x300000000 0x340000000 0x380000000 0x3c0000000]
+                                    0x400000000
+                                    {
+                                        [0x400000000 0x480000000 0x500000000 0x580000000 0x600000000 0x680000000 0x700000000 0x780000000]
+                                        0x800000000
+                                        [0x800000000 0x900000000 0xa00000000 0xb00000000 0xc00000000 0xd00000000 0xe00000000 0xf00000000]
+                                    }
+                                }
+                            }
+                            0x1000000000
+                            {
+                                {
+                                    [0x1000000000 0x1200000000 0x1400000000 0x1600000000 0x1800000000 0x1a00000000 0x1c00000000 0x1e00000000]
+                                    0x2000000000
+                                    {
+                                        [0x2000000000 0x2400000000 0x2800000000 0x2c00000000 0x3000000000 0x3400000000 0x3800000000 0x3c00000000]
+                                        0x4000000000
+                                        [0x4000000000 0x4800000000 0x5000000000 0x5800000000 0x6000000000 0x6800000000 0x7000000000 0x7800000000]
+                                    }
+                                }
+                                0x8000000000
+                                {
+                                    [0x8000000000 0x9000000000 0xa000000000 0xb000000000 0xc000000000 0xd000000000 0xe000000000 0xf000000000]
+                                    0x10000000000
+                                    {
+                                        [0x10000000000 0x12000000000 0x14000000000 0x16000000000 0x18000000000 0x1a000000000 0x1c000000000 0x1e000000000]
+                                        0x20000000000
+                                        [0x20000000000 0x24000000000 0x28000000000 0x2c000000000 0x30000000000 0x34000000000 0x38000000000 0x3c000000000]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    0x40000000000
+                    {
+                        {
+                            {
+                                {
+                                    [0x40000000000 0x48000000000 0x50000000000 0x58000000000 0x60000000000 0x68000000000 0x70000000000 0x78000000000]
+                                    0x80000000000
+                                    [0x80000000000 0x90000000000 0xa0000000000 0xb0000000000 0xc0000000000 0xd0000000000 0xe0000000000 0xf0000000000]
+                                }
+                                0x100000000000
+                                {
+                                    [0x100000000000 0x120000000000 0x140000000000 0x160000000000 0x180000000000 0x1a0000000000 0x1c0000000000 0x1e0000000000]
+                                    0x200000000000
+                                    {
+                                        [0x200000000000 0x240000000000 0x280000000000 0x2c0000000000 0x300000000000 0x340000000000 0x380000000000 0x3c0000000000]
+                                        0x400000000000
+                                        [0x400000000000 0x480000000000 0x500000000000 0x580000000000 0x600000000000 0x680000000000 0x700000000000 0x780000000000]
+                                    }
+                                }
+                            }
+                            0x800000000000
+                            {
+                                {
+                                    [0x800000000000 0x900000000000 0xa00000000000 0xb00000000000 0xc00000000000 0xd00000000000 0xe00000000000 0xf00000000000]
+                                    0x1000000000000
+                                    {
+                                        [0x1000000000000 0x1200000000000 0x1400000000000 0x1600000000000 0x1800000000000 0x1a00000000000 0x1c00000000000 0x1e00000000000]
+                                        0x2000000000000
+                                        [0x2000000000000 0x2400000000000 0x2800000000000 0x2c00000000000 0x3000000000000 0x3400000000000 0x3800000000000 0x3c00000000000]
+                                    }
+                                }
+                                0x4000000000000
+                                {
+                                    [0x4000000000000 0x4800000000000 0x5000000000000 0x5800000000000 0x6000000000000 0x6800000000000 0x7000000000000 0x7800000000000]
+                                    0x8000000000000
+                                    {
+                                        [0x8000000000000 0x9000000000000 0xa000000000000 0xb000000000000 0xc000000000000 0xd000000000000 0xe000000000000 0xf000000000000]
+                                        0x10000000000000
+                                        [0x10000000000000 0x12000000000000 0x14000000000000 0x16000000000000 0x18000000000000 0x1a000000000000 0x1c000000000000 0x1e000000000000]
+                                    }
+                                }
+                            }
+                        }
+                        0x20000000000000
+                        {
+                            {
+                                {
+                                    [0x20000000000000 0x24000000000000 0x28000000000000 0x2c000000000000 0x30000000000000 0x34000000000000 0x38000000000000 0x3c000000000000]
+                                    0x40000000000000
+                                    {
+                                        [0x40000000000000 0x48000000000000 0x50000000000000 0x58000000000000 0x60000000000000 0x68000000000000 0x70000000000000 0x78000000000000]
+                                        0x80000000000000
+                                        [0x80000000000000 0x90000000000000 0xa0000000000000 0xb0000000000000 0xc0000000000000 0xd0000000000000 0xe0000000000000 0xf0000000000000]
+                                    }
+                                }
+                                0x100000000000000
+                                {
+                                    [0x100000000000000 0x120000000000000 0x140000000000000 0x160000000000000 0x180000000000000 0x1a0000000000000 0x1c0000000000000 0x1e0000000000000]
+                                    0x200000000000000
+                                    {
+                                        [0x200000000000000 0x240000000000000 0x280000000000000 0x2c0000000000000 0x300000000000000 0x340000000000000 0x380000000000000 0x3c0000000000000]
+                                        0x400000000000000
+                                        [0x400000000000000 0x480000000000000 0x500000000000000 0x580000000000000 0x600000000000000 0x680000000000000 0x700000000000000 0x780000000000000]
+                                    }
+                                }
+                            }
+                            0x800000000000000
+                            {
+                                {
+                                    [0x800000000000000 0x900000000000000 0xa00000000000000 0xb00000000000000 0xc00000000000000 0xd00000000000000 0xe00000000000000 0xf00000000000000]
+                                    0x1000000000000000
+                                    {
+                                        [0x1000000000000000 0x1200000000000000 0x1400000000000000 0x1600000000000000 0x1800000000000000 0x1a00000000000000 0x1c00000000000000 0x1e00000000000000]
+                                        0x2000000000000000
+                                        [0x2000000000000000 0x2400000000000000 0x2800000000000000 0x2c00000000000000 0x3000000000000000 0x3400000000000000 0x3800000000000000 0x3c00000000000000]
+                                    }
+                                }
+                                0x4000000000000000
+                                {
+                                    [0x4000000000000000 0x4800000000000000 0x5000000000000000 0x5800000000000000 0x6000000000000000 0x6800000000000000 0x7000000000000000 0x7800000000000000]
+                                    0x8000000000000000
+                                    {
+                                        [0x8000000000000000 0x9000000000000000 0xa000000000000000 0xb000000000000000 0xc000000000000000 0xd000000000000000 0xe000000000000000 0xf000000000000000]
+                                        0xffffffffffffffff
+                                        [0xffffffffffffffff]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! size_tree_u128 {
+    ($mac:ident { $($args:tt)* }) => {
+        $mac! {
+            $($args)*
+            {
+                {
+                    {
+                        {
+                            {
+                                {
+                                    {
+                                        [0 1 2 3 4 5 6 7]
+                                        8
+                                        [8 9 10 11 12 13 14 15]
+                                    }
+                                    16
+                                    {
+                                        [16 17 18 19 20 21 22 23]
+                                        24
+                                        [24 25 26 27 28 29 30 31]
+                                    }
+                                }
+                                32
+                                {
+                                    {
+                                        [32 33 34 35 36 37 38 39]
+                                        40
+                                        [40 41 42 43 44 45 46 47]
+                                    }
+                                    48
+                                    {
+                                        [48 49 50 51 52 53 54 55]
+                                        56
+                                        {
+                                            [56 57 58 59 60 61 62 63]
+                                            64
+                                            [64 65 66 67 68 69 70 71]
+                                        }
+                                    }
+                                }
+                            }
+                            72
+                            {
+                                {
+                                    {
+                                        [72 73 74 75 76 77 78 79]
+                                        80
+                                        [80 81 82 83 84 85 86 87]
+                                    }
+                                    88
+                                    {
+                                        [88 89 90 91 92 93 94 95]
+                                        96
+                                        {
+                                            [96 97 98 99 100 101 102 103]
+                                            104
+                                            [104 105 106 107 108 109 110 111]
+                                        }
+                                    }
+                                }
+                                112
+                                {
+                                    {
+                                        [112 113 114 115 116 117 118 119]
+                                        120
+                                        [120 121 122 123 124 125 126 127]
+                                    }
+                                    128
+                                    {
+                                        [128 129 130 131 132 133 134 135]
+                                        136
+                                        {
+                                            [136 137 138 139 140 141 142 143]
+                                            144
+                                            [144 145 146 147 148 149 150 151]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        152
+                        {
+                            {
+                                {
+                                    {
+                                        [152 153 154 155 156 157 158 159]
+                                        160
+                                        [160 161 162 163 164 165 166 167]
+                                    }
+                                    168
+                                    {
+                                        [168 169 170 171 172 173 174 175]
+                                        176
+                                        [176 177 178 179 180 181 182 183]
+                                    }
+                                }
+                                184
+                                {
+                                    {
+                                        [184 185 186 187 188 189 190 191]
+                                        192
+                                        [192 193 194 195 196 197 198 199]
+                                    }
+                                    200
+                                    {
+                                        [200 201 202 203 204 205 206 207]
+                                        208
+                                        {
+                                            [208 209 210 211 212 213 214 215]
+                                            216
+                                            [216 217 218 219 220 221 222 223]
+                                        }
+                                    }
+                                }
+                            }
+                            224
+                            {
+                                {
+                                    {
+                                        [224 225 226 227 228 229 230 231]
+                                        232
+                                        [232 233 234 235 236 237 238 239]
+                                    }
+                                    240
+                                    {
+                                        [240 241 242 243 244 245 246 247]
+                                        248
+                                        {
+                                            [248 249 250 251 252 253 254 255]
+                                            256
+                                            [256 0x120 0x140 0x160 0x180 0x1a0 0x1c0 0x1e0]
+                                        }
+                                    }
+                                }
+                                0x200
+                                {
+                                    {
+                                        [0x200 0x240 0x280 0x2c0 0x300 0x340 0x380 0x3c0]
+                                        0x400
+                                        [0x400 0x480 0x500 0x580 0x600 0x680 0x700 0x780]
+                                    }
+                                    0x800
+                                    {
+                                        [0x800 0x900 0xa00 0xb00 0xc00 0xd00 0xe00 0xf00]
+                                        0x1000
+                                        {
+                                            [0x1000 0x1200 0x1400 0x1600 0x1800 0x1a00 0x1c00 0x1e00]
+                                            0x2000
+                                            [0x2000 0x2400 0x2800 0x2c00 0x3000 0x3400 0x3800 0x3c00]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    0x4000
+                    {
+                        {
+                            {
+                                {
+                                    {
+                                        [0x4000 0x4800 0x5000 0x5800 0x6000 0x6800 0x7000 0x7800]
+                                        0x8000
+                                        [0x8000 0x9000 0xa000 0xb000 0xc000 0xd000 0xe000 0xf000]
+                                    }
+                                    0x10000
+                                    {
+                                        [0x10000 0x12000 0x14000 0x16000 0x18000 0x1a000 0x1c000 0x1e000]
+                                        0x20000
+                                        [0x20000 0x24000 0x28000 0x2c000 0x30000 0x34000 0x38000 0x3c000]
+                                    }
+                                }
+                                0x40000
+                                {
+                                    {
+                                        [0x40000 0x48000 0x50000 0x58000 0x60000 0x68000 0x70000 0x78000]
+                                        0x80000
+                                        [0x80000 0x90000 0xa0000 0xb0000 0xc0000 0xd0000 0xe0000 0xf0000]
+                                    }
+                                    0x100000
+                                    {
+                                        [0x100000 0x120000 0x140000 0x160000 0x180000 0x1a0000 0x1c0000 0x1e0000]
+                                        0x200000
+                                        {
+                                            [0x200000 0x240000 0x280000 0x2c0000 0x300000 0x340000 0x380000 0x3c0000]
+                                            0x400000
+                                            [0x400000 0x480000 0x500000 0x580000 0x600000 0x680000 0x700000 0x780000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x800000
+                            {
+                                {
+                                    {
+                                        [0x800000 0x900000 0xa00000 0xb00000 0xc00000 0xd00000 0xe00000 0xf00000]
+                                        0x1000000
+                                        [0x1000000 0x1200000 0x1400000 0x1600000 0x1800000 0x1a00000 0x1c00000 0x1e00000]
+                                    }
+                                    0x2000000
+                                    {
+                                        [0x2000000 0x2400000 0x2800000 0x2c00000 0x3000000 0x3400000 0x3800000 0x3c00000]
+                                        0x4000000
+                                        {
+                                            [0x4000000 0x4800000 0x5000000 0x5800000 0x6000000 0x6800000 0x7000000 0x7800000]
+                                            0x8000000
+                                            [0x8000000 0x9000000 0xa000000 0xb000000 0xc000000 0xd000000 0xe000000 0xf000000]
+                                        }
+                                    }
+                                }
+                                0x10000000
+                                {
+                                    {
+                                        [0x10000000 0x12000000 0x14000000 0x16000000 0x18000000 0x1a000000 0x1c000000 0x1e000000]
+                                        0x20000000
+                                        [0x20000000 0x24000000 0x28000000 0x2c000000 0x30000000 0x34000000 0x38000000 0x3c000000]
+                                    }
+                                    0x40000000
+                                    {
+                                        [0x40000000 0x48000000 0x50000000 0x58000000 0x60000000 0x68000000 0x70000000 0x78000000]
+                                        0x80000000
+                                        {
+                                            [0x80000000 0x90000000 0xa0000000 0xb0000000 0xc0000000 0xd0000000 0xe0000000 0xf0000000]
+                                            0x100000000
+                                            [0x100000000 0x120000000 0x140000000 0x160000000 0x180000000 0x1a0000000 0x1c0000000 0x1e0000000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        0x200000000
+                        {
+                            {
+                                {
+                                    {
+                                        [0x200000000 0x240000000 0x280000000 0x2c0000000 0x300000000 0x340000000 0x380000000 0x3c0000000]
+                                        0x400000000
+                                        [0x400000000 0x480000000 0x500000000 0x580000000 0x600000000 0x680000000 0x700000000 0x780000000]
+                                    }
+                                    0x800000000
+                                    {
+                                        [0x800000000 0x900000000 0xa00000000 0xb00000000 0xc00000000 0xd00000000 0xe00000000 0xf00000000]
+                                        0x1000000000
+                                        [0x1000000000 0x1200000000 0x1400000000 0x1600000000 0x1800000000 0x1a00000000 0x1c00000000 0x1e00000000]
+                                    }
+                                }
+                                0x2000000000
+                                {
+                                    {
+                                        [0x2000000000 0x2400000000 0x2800000000 0x2c00000000 0x3000000000 0x3400000000 0x3800000000 0x3c00000000]
+                                        0x4000000000
+                                        [0x4000000000 0x4800000000 0x5000000000 0x5800000000 0x6000000000 0x6800000000 0x7000000000 0x7800000000]
+                                    }
+                                    0x8000000000
+                                    {
+                                        [0x8000000000 0x9000000000 0xa000000000 0xb000000000 0xc000000000 0xd000000000 0xe000000000 0xf000000000]
+                                        0x10000000000
+                                        {
+                                            [0x10000000000 0x12000000000 0x14000000000 0x16000000000 0x18000000000 0x1a000000000 0x1c000000000 0x1e000000000]
+                                            0x20000000000
+                                            [0x20000000000 0x24000000000 0x28000000000 0x2c000000000 0x30000000000 0x34000000000 0x38000000000 0x3c000000000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x40000000000
+                            {
+                                {
+                                    {
+                                        [0x40000000000 0x48000000000 0x50000000000 0x58000000000 0x60000000000 0x68000000000 0x70000000000 0x78000000000]
+                                        0x80000000000
+                                        [0x80000000000 0x90000000000 0xa0000000000 0xb0000000000 0xc0000000000 0xd0000000000 0xe0000000000 0xf0000000000]
+                                    }
+                                    0x100000000000
+                                    {
+                                        [0x100000000000 0x120000000000 0x140000000000 0x160000000000 0x180000000000 0x1a0000000000 0x1c0000000000 0x1e0000000000]
+                                        0x200000000000
+                                        {
+                                            [0x200000000000 0x240000000000 0x280000000000 0x2c0000000000 0x300000000000 0x340000000000 0x380000000000 0x3c0000000000]
+                                            0x400000000000
+                                            [0x400000000000 0x480000000000 0x500000000000 0x580000000000 0x600000000000 0x680000000000 0x700000000000 0x780000000000]
+                                        }
+                                    }
+                                }
+                                0x800000000000
+                                {
+                                    {
+                                        [0x800000000000 0x900000000000 0xa00000000000 0xb00000000000 0xc00000000000 0xd00000000000 0xe00000000000 0xf00000000000]
+                                        0x1000000000000
+                                        [0x1000000000000 0x1200000000000 0x1400000000000 0x1600000000000 0x1800000000000 0x1a00000000000 0x1c00000000000 0x1e00000000000]
+                                    }
+                                    0x2000000000000
+                                    {
+                                        [0x2000000000000 0x2400000000000 0x2800000000000 0x2c00000000000 0x3000000000000 0x3400000000000 0x3800000000000 0x3c00000000000]
+                                        0x4000000000000
+                                        {
+                                            [0x4000000000000 0x4800000000000 0x5000000000000 0x5800000000000 0x6000000000000 0x6800000000000 0x7000000000000 0x7800000000000]
+                                            0x8000000000000
+                                            [0x8000000000000 0x9000000000000 0xa000000000000 0xb000000000000 0xc000000000000 0xd000000000000 0xe000000000000 0xf000000000000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                0x10000000000000
+                {
+                    {
+                        {
+                            {
+                                {
+                                    {
+                                        [0x10000000000000 0x12000000000000 0x14000000000000 0x16000000000000 0x18000000000000 0x1a000000000000 0x1c000000000000 0x1e000000000000]
+                                        0x20000000000000
+                                        [0x20000000000000 0x24000000000000 0x28000000000000 0x2c000000000000 0x30000000000000 0x34000000000000 0x38000000000000 0x3c000000000000]
+                                    }
+                                    0x40000000000000
+                                    {
+                                        [0x40000000000000 0x48000000000000 0x50000000000000 0x58000000000000 0x60000000000000 0x68000000000000 0x70000000000000 0x78000000000000]
+                                        0x80000000000000
+                                        [0x80000000000000 0x90000000000000 0xa0000000000000 0xb0000000000000 0xc0000000000000 0xd0000000000000 0xe0000000000000 0xf0000000000000]
+                                    }
+                                }
+                                0x100000000000000
+                                {
+                                    {
+                                        [0x100000000000000 0x120000000000000 0x140000000000000 0x160000000000000 0x180000000000000 0x1a0000000000000 0x1c0000000000000 0x1e0000000000000]
+                                        0x200000000000000
+                                        [0x200000000000000 0x240000000000000 0x280000000000000 0x2c0000000000000 0x300000000000000 0x340000000000000 0x380000000000000 0x3c0000000000000]
+                                    }
+                                    0x400000000000000
+                                    {
+                                        [0x400000000000000 0x480000000000000 0x500000000000000 0x580000000000000 0x600000000000000 0x680000000000000 0x700000000000000 0x780000000000000]
+                                        0x800000000000000
+                                        {
+                                            [0x800000000000000 0x900000000000000 0xa00000000000000 0xb00000000000000 0xc00000000000000 0xd00000000000000 0xe00000000000000 0xf00000000000000]
+                                            0x1000000000000000
+                                            [0x1000000000000000 0x1200000000000000 0x1400000000000000 0x1600000000000000 0x1800000000000000 0x1a00000000000000 0x1c00000000000000 0x1e00000000000000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x2000000000000000
+                            {
+                                {
+                                    {
+                                        [0x2000000000000000 0x2400000000000000 0x2800000000000000 0x2c00000000000000 0x3000000000000000 0x3400000000000000 0x3800000000000000 0x3c00000000000000]
+                                        0x4000000000000000
+                                        [0x4000000000000000 0x4800000000000000 0x5000000000000000 0x5800000000000000 0x6000000000000000 0x6800000000000000 0x7000000000000000 0x7800000000000000]
+                                    }
+                                    0x8000000000000000
+                                    {
+                                        [0x8000000000000000 0x9000000000000000 0xa000000000000000 0xb000000000000000 0xc000000000000000 0xd000000000000000 0xe000000000000000 0xf000000000000000]
+                                        0x10000000000000000
+                                        {
+                                            [0x10000000000000000 0x12000000000000000 0x14000000000000000 0x16000000000000000 0x18000000000000000 0x1a000000000000000 0x1c000000000000000 0x1e000000000000000]
+                                            0x20000000000000000
+                                            [0x20000000000000000 0x24000000000000000 0x28000000000000000 0x2c000000000000000 0x30000000000000000 0x34000000000000000 0x38000000000000000 0x3c000000000000000]
+                                        }
+                                    }
+                                }
+                                0x40000000000000000
+                                {
+                                    {
+                                        [0x40000000000000000 0x48000000000000000 0x50000000000000000 0x58000000000000000 0x60000000000000000 0x68000000000000000 0x70000000000000000 0x78000000000000000]
+                                        0x80000000000000000
+                                        [0x80000000000000000 0x90000000000000000 0xa0000000000000000 0xb0000000000000000 0xc0000000000000000 0xd0000000000000000 0xe0000000000000000 0xf0000000000000000]
+                                    }
+                                    0x100000000000000000
+                                    {
+                                        [0x100000000000000000 0x120000000000000000 0x140000000000000000 0x160000000000000000 0x180000000000000000 0x1a0000000000000000 0x1c0000000000000000 0x1e0000000000000000]
+                                        0x200000000000000000
+                                        {
+                                            [0x200000000000000000 0x240000000000000000 0x280000000000000000 0x2c0000000000000000 0x300000000000000000 0x340000000000000000 0x380000000000000000 0x3c0000000000000000]
+                                            0x400000000000000000
+                                            [0x400000000000000000 0x480000000000000000 0x500000000000000000 0x580000000000000000 0x600000000000000000 0x680000000000000000 0x700000000000000000 0x780000000000000000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        0x800000000000000000
+                        {
+                            {
+                                {
+                                    {
+                                        [0x800000000000000000 0x900000000000000000 0xa00000000000000000 0xb00000000000000000 0xc00000000000000000 0xd00000000000000000 0xe00000000000000000 0xf00000000000000000]
+                                        0x1000000000000000000
+                                        [0x1000000000000000000 0x1200000000000000000 0x1400000000000000000 0x1600000000000000000 0x1800000000000000000 0x1a00000000000000000 0x1c00000000000000000 0x1e00000000000000000]
+                                    }
+                                    0x2000000000000000000
+                                    {
+                                        [0x2000000000000000000 0x2400000000000000000 0x2800000000000000000 0x2c00000000000000000 0x3000000000000000000 0x3400000000000000000 0x3800000000000000000 0x3c00000000000000000]
+                                        0x4000000000000000000
+                                        [0x4000000000000000000 0x4800000000000000000 0x5000000000000000000 0x5800000000000000000 0x6000000000000000000 0x6800000000000000000 0x7000000000000000000 0x7800000000000000000]
+                                    }
+                                }
+                                0x8000000000000000000
+                                {
+                                    {
+                                        [0x8000000000000000000 0x9000000000000000000 0xa000000000000000000 0xb000000000000000000 0xc000000000000000000 0xd000000000000000000 0xe000000000000000000 0xf000000000000000000]
+                                        0x10000000000000000000
+                                        [0x10000000000000000000 0x12000000000000000000 0x14000000000000000000 0x16000000000000000000 0x18000000000000000000 0x1a000000000000000000 0x1c000000000000000000 0x1e000000000000000000]
+                                    }
+                                    0x20000000000000000000
+                                    {
+                                        [0x20000000000000000000 0x24000000000000000000 0x28000000000000000000 0x2c000000000000000000 0x30000000000000000000 0x34000000000000000000 0x38000000000000000000 0x3c000000000000000000]
+                                        0x40000000000000000000
+                                        {
+                                            [0x40000000000000000000 0x48000000000000000000 0x50000000000000000000 0x58000000000000000000 0x60000000000000000000 0x68000000000000000000 0x70000000000000000000 0x78000000000000000000]
+                                            0x80000000000000000000
+                                            [0x80000000000000000000 0x90000000000000000000 0xa0000000000000000000 0xb0000000000000000000 0xc0000000000000000000 0xd0000000000000000000 0xe0000000000000000000 0xf0000000000000000000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x100000000000000000000
+                            {
+                                {
+                                    {
+                                        [0x100000000000000000000 0x120000000000000000000 0x140000000000000000000 0x160000000000000000000 0x180000000000000000000 0x1a0000000000000000000 0x1c0000000000000000000 0x1e0000000000000000000]
+                                        0x200000000000000000000
+                                        [0x200000000000000000000 0x240000000000000000000 0x280000000000000000000 0x2c0000000000000000000 0x300000000000000000000 0x340000000000000000000 0x380000000000000000000 0x3c0000000000000000000]
+                                    }
+                                    0x400000000000000000000
+                                    {
+                                        [0x400000000000000000000 0x480000000000000000000 0x500000000000000000000 0x580000000000000000000 0x600000000000000000000 0x680000000000000000000 0x700000000000000000000 0x780000000000000000000]
+                                        0x800000000000000000000
+                                        {
+                                            [0x800000000000000000000 0x900000000000000000000 0xa00000000000000000000 0xb00000000000000000000 0xc00000000000000000000 0xd00000000000000000000 0xe00000000000000000000 0xf00000000000000000000]
+                                            0x1000000000000000000000
+                                            [0x1000000000000000000000 0x1200000000000000000000 0x1400000000000000000000 0x1600000000000000000000 0x1800000000000000000000 0x1a00000000000000000000 0x1c00000000000000000000 0x1e00000000000000000000]
+                                        }
+                                    }
+                                }
+                                0x2000000000000000000000
+                                {
+                                    {
+                                        [0x2000000000000000000000 0x2400000000000000000000 0x2800000000000000000000 0x2c00000000000000000000 0x3000000000000000000000 0x3400000000000000000000 0x3800000000000000000000 0x3c00000000000000000000]
+                                        0x4000000000000000000000
+                                        [0x4000000000000000000000 0x4800000000000000000000 0x5000000000000000000000 0x5800000000000000000000 0x6000000000000000000000 0x6800000000000000000000 0x7000000000000000000000 0x7800000000000000000000]
+                                    }
+                                    0x8000000000000000000000
+                                    {
+                                        [0x8000000000000000000000 0x9000000000000000000000 0xa000000000000000000000 0xb000000000000000000000 0xc000000000000000000000 0xd000000000000000000000 0xe000000000000000000000 0xf000000000000000000000]
+                                        0x10000000000000000000000
+                                        {
+                                            [0x10000000000000000000000 0x12000000000000000000000 0x14000000000000000000000 0x16000000000000000000000 0x18000000000000000000000 0x1a000000000000000000000 0x1c000000000000000000000 0x1e000000000000000000000]
+                                            0x20000000000000000000000
+                                            [0x20000000000000000000000 0x24000000000000000000000 0x28000000000000000000000 0x2c000000000000000000000 0x30000000000000000000000 0x34000000000000000000000 0x38000000000000000000000 0x3c000000000000000000000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    0x40000000000000000000000
+                    {
+                        {
+                            {
+                                {
+                                    {
+                                        [0x40000000000000000000000 0x48000000000000000000000 0x50000000000000000000000 0x58000000000000000000000 0x60000000000000000000000 0x68000000000000000000000 0x70000000000000000000000 0x78000000000000000000000]
+                                        0x80000000000000000000000
+                                        [0x80000000000000000000000 0x90000000000000000000000 0xa0000000000000000000000 0xb0000000000000000000000 0xc0000000000000000000000 0xd0000000000000000000000 0xe0000000000000000000000 0xf0000000000000000000000]
+                                    }
+                                    0x100000000000000000000000
+                                    {
+                                        [0x100000000000000000000000 0x120000000000000000000000 0x140000000000000000000000 0x160000000000000000000000 0x180000000000000000000000 0x1a0000000000000000000000 0x1c0000000000000000000000 0x1e0000000000000000000000]
+                                        0x200000000000000000000000
+                                        [0x200000000000000000000000 0x240000000000000000000000 0x280000000000000000000000 0x2c0000000000000000000000 0x300000000000000000000000 0x340000000000000000000000 0x380000000000000000000000 0x3c0000000000000000000000]
+                                    }
+                                }
+                                0x400000000000000000000000
+                                {
+                                    {
+                                        [0x400000000000000000000000 0x480000000000000000000000 0x500000000000000000000000 0x580000000000000000000000 0x600000000000000000000000 0x680000000000000000000000 0x700000000000000000000000 0x780000000000000000000000]
+                                        0x800000000000000000000000
+                                        [0x800000000000000000000000 0x900000000000000000000000 0xa00000000000000000000000 0xb00000000000000000000000 0xc00000000000000000000000 0xd00000000000000000000000 0xe00000000000000000000000 0xf00000000000000000000000]
+                                    }
+                                    0x1000000000000000000000000
+                                    {
+                                        [0x1000000000000000000000000 0x1200000000000000000000000 0x1400000000000000000000000 0x1600000000000000000000000 0x1800000000000000000000000 0x1a00000000000000000000000 0x1c00000000000000000000000 0x1e00000000000000000000000]
+                                        0x2000000000000000000000000
+                                        {
+                                            [0x2000000000000000000000000 0x2400000000000000000000000 0x2800000000000000000000000 0x2c00000000000000000000000 0x3000000000000000000000000 0x3400000000000000000000000 0x3800000000000000000000000 0x3c00000000000000000000000]
+                                            0x4000000000000000000000000
+                                            [0x4000000000000000000000000 0x4800000000000000000000000 0x5000000000000000000000000 0x5800000000000000000000000 0x6000000000000000000000000 0x6800000000000000000000000 0x7000000000000000000000000 0x7800000000000000000000000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x8000000000000000000000000
+                            {
+                                {
+                                    {
+                                        [0x8000000000000000000000000 0x9000000000000000000000000 0xa000000000000000000000000 0xb000000000000000000000000 0xc000000000000000000000000 0xd000000000000000000000000 0xe000000000000000000000000 0xf000000000000000000000000]
+                                        0x10000000000000000000000000
+                                        [0x10000000000000000000000000 0x12000000000000000000000000 0x14000000000000000000000000 0x16000000000000000000000000 0x18000000000000000000000000 0x1a000000000000000000000000 0x1c000000000000000000000000 0x1e000000000000000000000000]
+                                    }
+                                    0x20000000000000000000000000
+                                    {
+                                        [0x20000000000000000000000000 0x24000000000000000000000000 0x28000000000000000000000000 0x2c000000000000000000000000 0x30000000000000000000000000 0x34000000000000000000000000 0x38000000000000000000000000 0x3c000000000000000000000000]
+                                        0x40000000000000000000000000
+                                        {
+                                            [0x40000000000000000000000000 0x48000000000000000000000000 0x50000000000000000000000000 0x58000000000000000000000000 0x60000000000000000000000000 0x68000000000000000000000000 0x70000000000000000000000000 0x78000000000000000000000000]
+                                            0x80000000000000000000000000
+                                            [0x80000000000000000000000000 0x90000000000000000000000000 0xa0000000000000000000000000 0xb0000000000000000000000000 0xc0000000000000000000000000 0xd0000000000000000000000000 0xe0000000000000000000000000 0xf0000000000000000000000000]
+                                        }
+                                    }
+                                }
+                                0x100000000000000000000000000
+                                {
+                                    {
+                                        [0x100000000000000000000000000 0x120000000000000000000000000 0x140000000000000000000000000 0x160000000000000000000000000 0x180000000000000000000000000 0x1a0000000000000000000000000 0x1c0000000000000000000000000 0x1e0000000000000000000000000]
+                                        0x200000000000000000000000000
+                                        [0x200000000000000000000000000 0x240000000000000000000000000 0x280000000000000000000000000 0x2c0000000000000000000000000 0x300000000000000000000000000 0x340000000000000000000000000 0x380000000000000000000000000 0x3c0000000000000000000000000]
+                                    }
+                                    0x400000000000000000000000000
+                                    {
+                                        [0x400000000000000000000000000 0x480000000000000000000000000 0x500000000000000000000000000 0x580000000000000000000000000 0x600000000000000000000000000 0x680000000000000000000000000 0x700000000000000000000000000 0x780000000000000000000000000]
+                                        0x800000000000000000000000000
+                                        {
+                                            [0x800000000000000000000000000 0x900000000000000000000000000 0xa00000000000000000000000000 0xb00000000000000000000000000 0xc00000000000000000000000000 0xd00000000000000000000000000 0xe00000000000000000000000000 0xf00000000000000000000000000]
+                                            0x1000000000000000000000000000
+                                            [0x1000000000000000000000000000 0x1200000000000000000000000000 0x1400000000000000000000000000 0x1600000000000000000000000000 0x1800000000000000000000000000 0x1a00000000000000000000000000 0x1c00000000000000000000000000 0x1e00000000000000000000000000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        0x2000000000000000000000000000
+                        {
+                            {
+                                {
+                                    {
+                                        [0x2000000000000000000000000000 0x2400000000000000000000000000 0x2800000000000000000000000000 0x2c00000000000000000000000000 0x3000000000000000000000000000 0x3400000000000000000000000000 0x3800000000000000000000000000 0x3c00000000000000000000000000]
+                                        0x4000000000000000000000000000
+                                        [0x4000000000000000000000000000 0x4800000000000000000000000000 0x5000000000000000000000000000 0x5800000000000000000000000000 0x6000000000000000000000000000 0x6800000000000000000000000000 0x7000000000000000000000000000 0x7800000000000000000000000000]
+                                    }
+                                    0x8000000000000000000000000000
+                                    {
+                                        [0x8000000000000000000000000000 0x9000000000000000000000000000 0xa000000000000000000000000000 0xb000000000000000000000000000 0xc000000000000000000000000000 0xd000000000000000000000000000 0xe000000000000000000000000000 0xf000000000000000000000000000]
+                                        0x10000000000000000000000000000
+                                        {
+                                            [0x10000000000000000000000000000 0x12000000000000000000000000000 0x14000000000000000000000000000 0x16000000000000000000000000000 0x18000000000000000000000000000 0x1a000000000000000000000000000 0x1c000000000000000000000000000 0x1e000000000000000000000000000]
+                                            0x20000000000000000000000000000
+                                            [0x20000000000000000000000000000 0x24000000000000000000000000000 0x28000000000000000000000000000 0x2c000000000000000000000000000 0x30000000000000000000000000000 0x34000000000000000000000000000 0x38000000000000000000000000000 0x3c000000000000000000000000000]
+                                        }
+                                    }
+                                }
+                                0x40000000000000000000000000000
+                                {
+                                    {
+                                        [0x40000000000000000000000000000 0x48000000000000000000000000000 0x50000000000000000000000000000 0x58000000000000000000000000000 0x60000000000000000000000000000 0x68000000000000000000000000000 0x70000000000000000000000000000 0x78000000000000000000000000000]
+                                        0x80000000000000000000000000000
+                                        [0x80000000000000000000000000000 0x90000000000000000000000000000 0xa0000000000000000000000000000 0xb0000000000000000000000000000 0xc0000000000000000000000000000 0xd0000000000000000000000000000 0xe0000000000000000000000000000 0xf0000000000000000000000000000]
+                                    }
+                                    0x100000000000000000000000000000
+                                    {
+                                        [0x100000000000000000000000000000 0x120000000000000000000000000000 0x140000000000000000000000000000 0x160000000000000000000000000000 0x180000000000000000000000000000 0x1a0000000000000000000000000000 0x1c0000000000000000000000000000 0x1e0000000000000000000000000000]
+                                        0x200000000000000000000000000000
+                                        {
+                                            [0x200000000000000000000000000000 0x240000000000000000000000000000 0x280000000000000000000000000000 0x2c0000000000000000000000000000 0x300000000000000000000000000000 0x340000000000000000000000000000 0x380000000000000000000000000000 0x3c0000000000000000000000000000]
+                                            0x400000000000000000000000000000
+                                            [0x400000000000000000000000000000 0x480000000000000000000000000000 0x500000000000000000000000000000 0x580000000000000000000000000000 0x600000000000000000000000000000 0x680000000000000000000000000000 0x700000000000000000000000000000 0x780000000000000000000000000000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x800000000000000000000000000000
+                            {
+                                {
+                                    {
+                                        [0x800000000000000000000000000000 0x900000000000000000000000000000 0xa00000000000000000000000000000 0xb00000000000000000000000000000 0xc00000000000000000000000000000 0xd00000000000000000000000000000 0xe00000000000000000000000000000 0xf00000000000000000000000000000]
+                                        0x1000000000000000000000000000000
+                                        [0x1000000000000000000000000000000 0x1200000000000000000000000000000 0x1400000000000000000000000000000 0x1600000000000000000000000000000 0x1800000000000000000000000000000 0x1a00000000000000000000000000000 0x1c00000000000000000000000000000 0x1e00000000000000000000000000000]
+                                    }
+                                    0x2000000000000000000000000000000
+                                    {
+                                        [0x2000000000000000000000000000000 0x2400000000000000000000000000000 0x2800000000000000000000000000000 0x2c00000000000000000000000000000 0x3000000000000000000000000000000 0x3400000000000000000000000000000 0x3800000000000000000000000000000 0x3c00000000000000000000000000000]
+                                        0x4000000000000000000000000000000
+                                        {
+                                            [0x4000000000000000000000000000000 0x4800000000000000000000000000000 0x5000000000000000000000000000000 0x5800000000000000000000000000000 0x6000000000000000000000000000000 0x6800000000000000000000000000000 0x7000000000000000000000000000000 0x7800000000000000000000000000000]
+                                            0x8000000000000000000000000000000
+                                            [0x8000000000000000000000000000000 0x9000000000000000000000000000000 0xa000000000000000000000000000000 0xb000000000000000000000000000000 0xc000000000000000000000000000000 0xd000000000000000000000000000000 0xe000000000000000000000000000000 0xf000000000000000000000000000000]
+                                        }
+                                    }
+                                }
+                                0x10000000000000000000000000000000
+                                {
+                                    {
+                                        [0x10000000000000000000000000000000 0x12000000000000000000000000000000 0x14000000000000000000000000000000 0x16000000000000000000000000000000 0x18000000000000000000000000000000 0x1a000000000000000000000000000000 0x1c000000000000000000000000000000 0x1e000000000000000000000000000000]
+                                        0x20000000000000000000000000000000
+                                        [0x20000000000000000000000000000000 0x24000000000000000000000000000000 0x28000000000000000000000000000000 0x2c000000000000000000000000000000 0x30000000000000000000000000000000 0x34000000000000000000000000000000 0x38000000000000000000000000000000 0x3c000000000000000000000000000000]
+                                    }
+                                    0x40000000000000000000000000000000
+                                    {
+                                        [0x40000000000000000000000000000000 0x48000000000000000000000000000000 0x50000000000000000000000000000000 0x58000000000000000000000000000000 0x60000000000000000000000000000000 0x68000000000000000000000000000000 0x70000000000000000000000000000000 0x78000000000000000000000000000000]
+                                        0x80000000000000000000000000000000
+                                        {
+                                            [0x80000000000000000000000000000000 0x90000000000000000000000000000000 0xa0000000000000000000000000000000 0xb0000000000000000000000000000000 0xc0000000000000000000000000000000 0xd0000000000000000000000000000000 0xe0000000000000000000000000000000 0xf0000000000000000000000000000000]
+                                            0xffffffffffffffffffffffffffffffff
+                                            [0xffffffffffffffffffffffffffffffff]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+// The `for_each_default_size_*` macros invoke the passed macro with the candidates of
+// `Ladder::DEFAULT` for the enabled features and the target, split by the values of `MAX_BOUND`:
+// - `dense`: up to `256`
+// - `16`: up to `1 << 16`, or up to `u16::MAX` on 16-bit targets
+// - `20`: up to `1 << 20`, unless `max-bound-64k` is enabled
+// - `31`: up to `1 << 31`, unless `max-bound-64k` or `max-bound-1m` is enabled
+// - `max`: up to `usize::MAX`, unless a `max-bound-*` feature or `portable-bounds` is enabled
+// The candidates of the ladder are filtered from those of `for_each_size_uN` like in
+// `Ladder::contains`, and every candidate is preceded by its refinements with
+// `ladder-step-1_0625`. Each range is a separate invocation, so the passed macro must expand to
+// statements. They are exported for use by `dispatch_upper_bound!`.
+
+#[cfg(feature = "exhaustive-small")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_dense {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79
+            80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+            96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+            112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
+            128 129 130 131 132 133 134 135 136 137 138 139 140 141 142 143
+            144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159
+            160 161 162 163 164 165 166 167 168 169 170 171 172 173 174 175
+            176 177 178 179 180 181 182 183 184 185 186 187 188 189 190 191
+            192 193 194 195 196 197 198 199 200 201 202 203 204 205 206 207
+            208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223
+            224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239
+            240 241 242 243 244 245 246 247 248 249 250 251 252 253 254 255
+            256
+        }
+    };
+}
+
+#[cfg(all(not(feature = "exhaustive-small"), feature = "ladder-step-1_0625"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_dense {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 65 68 70 73 75 78 80 81 84 87 90 93 96 98 102
+            105 109 112 116 120 124 128 130 135 140 145 150 155 160 162 168
+            174 180 186 192 196 203 210 217 224 232 240 248 256
+        }
+    };
+}
+
+#[cfg(all(
+    not(feature = "exhaustive-small"),
+    all(feature = "ladder-step-1_125", not(feature = "ladder-step-1_0625"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_dense {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 72 80 88 96 104 112 120 128 144 160 176 192 208 224 240
+            256
+        }
+    };
+}
+
+#[cfg(all(
+    not(feature = "exhaustive-small"),
+    all(
+        feature = "ladder-step-1_25",
+        not(any(feature = "ladder-step-1_125", feature = "ladder-step-1_0625"))
+    )
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_dense {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 80 96 112 128 160 192 224 256
+        }
+    };
+}
+
+#[cfg(all(
+    not(feature = "exhaustive-small"),
+    not(any(
+        feature = "ladder-step-1_25",
+        feature = "ladder-step-1_125",
+        feature = "ladder-step-1_0625"
+    ))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_dense {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 96 128 192 256
+        }
+    };
+}
+
+#[cfg(all(target_pointer_width = "16", feature = "ladder-step-1_0625"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_16 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x104 0x10e 0x118 0x122 0x12c 0x136 0x140 0x144 0x150 0x15c 0x168 0x174 0x180 0x188 0x196 0x1a4
+            0x1b2 0x1c0 0x1d0 0x1e0 0x1f0 0x200 0x208 0x21c 0x230 0x244 0x258 0x26c 0x280 0x288 0x2a0 0x2b8
+            0x2d0 0x2e8 0x300 0x310 0x32c 0x348 0x364 0x380 0x3a0 0x3c0 0x3e0 0x400 0x410 0x438 0x460 0x488
+            0x4b0 0x4d8 0x500 0x510 0x540 0x570 0x5a0 0x5d0 0x600 0x620 0x658 0x690 0x6c8 0x700 0x740 0x780
+            0x7c0 0x800 0x820 0x870 0x8c0 0x910 0x960 0x9b0 0xa00 0xa20 0xa80 0xae0 0xb40 0xba0 0xc00 0xc40
+            0xcb0 0xd20 0xd90 0xe00 0xe80 0xf00 0xf80 0x1000 0x1040 0x10e0 0x1180 0x1220 0x12c0 0x1360 0x1400 0x1440
+            0x1500 0x15c0 0x1680 0x1740 0x1800 0x1880 0x1960 0x1a40 0x1b20 0x1c00 0x1d00 0x1e00 0x1f00 0x2000 0x2080 0x21c0
+            0x2300 0x2440 0x2580 0x26c0 0x2800 0x2880 0x2a00 0x2b80 0x2d00 0x2e80 0x3000 0x3100 0x32c0 0x3480 0x3640 0x3800
+            0x3a00 0x3c00 0x3e00 0x4000 0x4100 0x4380 0x4600 0x4880 0x4b00 0x4d80 0x5000 0x5100 0x5400 0x5700 0x5a00 0x5d00
+            0x6000 0x6200 0x6580 0x6900 0x6c80 0x7000 0x7400 0x7800 0x7c00 0x8000 0x8200 0x8700 0x8c00 0x9100 0x9600 0x9b00
+            0xa000 0xa200 0xa800 0xae00 0xb400 0xba00 0xc000 0xc400 0xcb00 0xd200 0xd900 0xe000 0xe7ff 0xefff 0xf7ff 0xffff
+        }
+    };
+}
+
+#[cfg(all(not(target_pointer_width = "16"), feature = "ladder-step-1_0625"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_16 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x104 0x10e 0x118 0x122 0x12c 0x136 0x140 0x144 0x150 0x15c 0x168 0x174 0x180 0x188 0x196 0x1a4
+            0x1b2 0x1c0 0x1d0 0x1e0 0x1f0 0x200 0x208 0x21c 0x230 0x244 0x258 0x26c 0x280 0x288 0x2a0 0x2b8
+            0x2d0 0x2e8 0x300 0x310 0x32c 0x348 0x364 0x380 0x3a0 0x3c0 0x3e0 0x400 0x410 0x438 0x460 0x488
+            0x4b0 0x4d8 0x500 0x510 0x540 0x570 0x5a0 0x5d0 0x600 0x620 0x658 0x690 0x6c8 0x700 0x740 0x780
+            0x7c0 0x800 0x820 0x870 0x8c0 0x910 0x960 0x9b0 0xa00 0xa20 0xa80 0xae0 0xb40 0xba0 0xc00 0xc40
+            0xcb0 0xd20 0xd90 0xe00 0xe80 0xf00 0xf80 0x1000 0x1040 0x10e0 0x1180 0x1220 0x12c0 0x1360 0x1400 0x1440
+            0x1500 0x15c0 0x1680 0x1740 0x1800 0x1880 0x1960 0x1a40 0x1b20 0x1c00 0x1d00 0x1e00 0x1f00 0x2000 0x2080 0x21c0
+            0x2300 0x2440 0x2580 0x26c0 0x2800 0x2880 0x2a00 0x2b80 0x2d00 0x2e80 0x3000 0x3100 0x32c0 0x3480 0x3640 0x3800
+            0x3a00 0x3c00 0x3e00 0x4000 0x4100 0x4380 0x4600 0x4880 0x4b00 0x4d80 0x5000 0x5100 0x5400 0x5700 0x5a00 0x5d00
+            0x6000 0x6200 0x6580 0x6900 0x6c80 0x7000 0x7400 0x7800 0x7c00 0x8000 0x8200 0x8700 0x8c00 0x9100 0x9600 0x9b00
+            0xa000 0xa200 0xa800 0xae00 0xb400 0xba00 0xc000 0xc400 0xcb00 0xd200 0xd900 0xe000 0xe800 0xf000 0xf800 0x10000
+        }
+    };
+}
+
+#[cfg(all(
+    target_pointer_width = "16",
+    all(feature = "ladder-step-1_125", not(feature = "ladder-step-1_0625"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_16 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x120 0x140 0x160 0x180 0x1a0 0x1c0 0x1e0 0x200 0x240 0x280 0x2c0 0x300 0x340 0x380 0x3c0 0x400
+            0x480 0x500 0x580 0x600 0x680 0x700 0x780 0x800 0x900 0xa00 0xb00 0xc00 0xd00 0xe00 0xf00 0x1000
+            0x1200 0x1400 0x1600 0x1800 0x1a00 0x1c00 0x1e00 0x2000 0x2400 0x2800 0x2c00 0x3000 0x3400 0x3800 0x3c00 0x4000
+            0x4800 0x5000 0x5800 0x6000 0x6800 0x7000 0x7800 0x8000 0x9000 0xa000 0xb000 0xc000 0xd000 0xe000 0xf000 0xffff
+        }
+    };
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    all(feature = "ladder-step-1_125", not(feature = "ladder-step-1_0625"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_16 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x120 0x140 0x160 0x180 0x1a0 0x1c0 0x1e0 0x200 0x240 0x280 0x2c0 0x300 0x340 0x380 0x3c0 0x400
+            0x480 0x500 0x580 0x600 0x680 0x700 0x780 0x800 0x900 0xa00 0xb00 0xc00 0xd00 0xe00 0xf00 0x1000
+            0x1200 0x1400 0x1600 0x1800 0x1a00 0x1c00 0x1e00 0x2000 0x2400 0x2800 0x2c00 0x3000 0x3400 0x3800 0x3c00 0x4000
+            0x4800 0x5000 0x5800 0x6000 0x6800 0x7000 0x7800 0x8000 0x9000 0xa000 0xb000 0xc000 0xd000 0xe000 0xf000 0x10000
+        }
+    };
+}
+
+#[cfg(all(
+    target_pointer_width = "16",
+    all(
+        feature = "ladder-step-1_25",
+        not(any(feature = "ladder-step-1_125", feature = "ladder-step-1_0625"))
+    )
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_16 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x140 0x180 0x1c0 0x200 0x280 0x300 0x380 0x400 0x500 0x600 0x700 0x800 0xa00 0xc00 0xe00 0x1000
+            0x1400 0x1800 0x1c00 0x2000 0x2800 0x3000 0x3800 0x4000 0x5000 0x6000 0x7000 0x8000 0xa000 0xc000 0xe000 0xffff
+        }
+    };
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    all(
+        feature = "ladder-step-1_25",
+        not(any(feature = "ladder-step-1_125", feature = "ladder-step-1_0625"))
+    )
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_16 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x140 0x180 0x1c0 0x200 0x280 0x300 0x380 0x400 0x500 0x600 0x700 0x800 0xa00 0xc00 0xe00 0x1000
+            0x1400 0x1800 0x1c00 0x2000 0x2800 0x3000 0x3800 0x4000 0x5000 0x6000 0x7000 0x8000 0xa000 0xc000 0xe000 0x10000
+        }
+    };
+}
+
+#[cfg(all(
+    target_pointer_width = "16",
+    not(any(
+        feature = "ladder-step-1_25",
+        feature = "ladder-step-1_125",
+        feature = "ladder-step-1_0625"
+    ))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_16 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x180 0x200 0x300 0x400 0x600 0x800 0xc00 0x1000 0x1800 0x2000 0x3000 0x4000 0x6000 0x8000 0xc000 0xffff
+        }
+    };
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    not(any(
+        feature = "ladder-step-1_25",
+        feature = "ladder-step-1_125",
+        feature = "ladder-step-1_0625"
+    ))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_16 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x180 0x200 0x300 0x400 0x600 0x800 0xc00 0x1000 0x1800 0x2000 0x3000 0x4000 0x6000 0x8000 0xc000 0x10000
+        }
+    };
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    not(feature = "max-bound-64k"),
+    feature = "ladder-step-1_0625"
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_20 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x10400 0x10e00 0x11800 0x12200 0x12c00 0x13600 0x14000 0x14400 0x15000 0x15c00 0x16800 0x17400 0x18000 0x18800 0x19600 0x1a400
+            0x1b200 0x1c000 0x1d000 0x1e000 0x1f000 0x20000 0x20800 0x21c00 0x23000 0x24400 0x25800 0x26c00 0x28000 0x28800 0x2a000 0x2b800
+            0x2d000 0x2e800 0x30000 0x31000 0x32c00 0x34800 0x36400 0x38000 0x3a000 0x3c000 0x3e000 0x40000 0x41000 0x43800 0x46000 0x48800
+            0x4b000 0x4d800 0x50000 0x51000 0x54000 0x57000 0x5a000 0x5d000 0x60000 0x62000 0x65800 0x69000 0x6c800 0x70000 0x74000 0x78000
+            0x7c000 0x80000 0x82000 0x87000 0x8c000 0x91000 0x96000 0x9b000 0xa0000 0xa2000 0xa8000 0xae000 0xb4000 0xba000 0xc0000 0xc4000
+            0xcb000 0xd2000 0xd9000 0xe0000 0xe8000 0xf0000 0xf8000 0x100000
+        }
+    };
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    not(feature = "max-bound-64k"),
+    all(feature = "ladder-step-1_125", not(feature = "ladder-step-1_0625"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_20 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x12000 0x14000 0x16000 0x18000 0x1a000 0x1c000 0x1e000 0x20000 0x24000 0x28000 0x2c000 0x30000 0x34000 0x38000 0x3c000 0x40000
+            0x48000 0x50000 0x58000 0x60000 0x68000 0x70000 0x78000 0x80000 0x90000 0xa0000 0xb0000 0xc0000 0xd0000 0xe0000 0xf0000 0x100000
+        }
+    };
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    not(feature = "max-bound-64k"),
+    all(
+        feature = "ladder-step-1_25",
+        not(any(feature = "ladder-step-1_125", feature = "ladder-step-1_0625"))
+    )
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_20 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x14000 0x18000 0x1c000 0x20000 0x28000 0x30000 0x38000 0x40000 0x50000 0x60000 0x70000 0x80000 0xa0000 0xc0000 0xe0000 0x100000
+        }
+    };
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    not(feature = "max-bound-64k"),
+    not(any(
+        feature = "ladder-step-1_25",
+        feature = "ladder-step-1_125",
+        feature = "ladder-step-1_0625"
+    ))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_20 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x18000 0x20000 0x30000 0x40000 0x60000 0x80000 0xc0000 0x100000
+        }
+    };
+}
+
+#[cfg(any(target_pointer_width = "16", feature = "max-bound-64k"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_20 {
+    ($($mac:tt)*) => {};
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    not(any(feature = "max-bound-64k", feature = "max-bound-1m")),
+    feature = "ladder-step-1_0625"
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_31 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x104000 0x10e000 0x118000 0x122000 0x12c000 0x136000 0x140000 0x144000 0x150000 0x15c000 0x168000 0x174000 0x180000 0x188000 0x196000 0x1a4000
+            0x1b2000 0x1c0000 0x1d0000 0x1e0000 0x1f0000 0x200000 0x208000 0x21c000 0x230000 0x244000 0x258000 0x26c000 0x280000 0x288000 0x2a0000 0x2b8000
+            0x2d0000 0x2e8000 0x300000 0x310000 0x32c000 0x348000 0x364000 0x380000 0x3a0000 0x3c0000 0x3e0000 0x400000 0x410000 0x438000 0x460000 0x488000
+            0x4b0000 0x4d8000 0x500000 0x510000 0x540000 0x570000 0x5a0000 0x5d0000 0x600000 0x620000 0x658000 0x690000 0x6c8000 0x700000 0x740000 0x780000
+            0x7c0000 0x800000 0x820000 0x870000 0x8c0000 0x910000 0x960000 0x9b0000 0xa00000 0xa20000 0xa80000 0xae0000 0xb40000 0xba0000 0xc00000 0xc40000
+            0xcb0000 0xd20000 0xd90000 0xe00000 0xe80000 0xf00000 0xf80000 0x1000000 0x1040000 0x10e0000 0x1180000 0x1220000 0x12c0000 0x1360000 0x1400000 0x1440000
+            0x1500000 0x15c0000 0x1680000 0x1740000 0x1800000 0x1880000 0x1960000 0x1a40000 0x1b20000 0x1c00000 0x1d00000 0x1e00000 0x1f00000 0x2000000 0x2080000 0x21c0000
+            0x2300000 0x2440000 0x2580000 0x26c0000 0x2800000 0x2880000 0x2a00000 0x2b80000 0x2d00000 0x2e80000 0x3000000 0x3100000 0x32c0000 0x3480000 0x3640000 0x3800000
+            0x3a00000 0x3c00000 0x3e00000 0x4000000 0x4100000 0x4380000 0x4600000 0x4880000 0x4b00000 0x4d80000 0x5000000 0x5100000 0x5400000 0x5700000 0x5a00000 0x5d00000
+            0x6000000 0x6200000 0x6580000 0x6900000 0x6c80000 0x7000000 0x7400000 0x7800000 0x7c00000 0x8000000 0x8200000 0x8700000 0x8c00000 0x9100000 0x9600000 0x9b00000
+            0xa000000 0xa200000 0xa800000 0xae00000 0xb400000 0xba00000 0xc000000 0xc400000 0xcb00000 0xd200000 0xd900000 0xe000000 0xe800000 0xf000000 0xf800000 0x10000000
+            0x10400000 0x10e00000 0x11800000 0x12200000 0x12c00000 0x13600000 0x14000000 0x14400000 0x15000000 0x15c00000 0x16800000 0x17400000 0x18000000 0x18800000 0x19600000 0x1a400000
+            0x1b200000 0x1c000000 0x1d000000 0x1e000000 0x1f000000 0x20000000 0x20800000 0x21c00000 0x23000000 0x24400000 0x25800000 0x26c00000 0x28000000 0x28800000 0x2a000000 0x2b800000
+            0x2d000000 0x2e800000 0x30000000 0x31000000 0x32c00000 0x34800000 0x36400000 0x38000000 0x3a000000 0x3c000000 0x3e000000 0x40000000 0x41000000 0x43800000 0x46000000 0x48800000
+            0x4b000000 0x4d800000 0x50000000 0x51000000 0x54000000 0x57000000 0x5a000000 0x5d000000 0x60000000 0x62000000 0x65800000 0x69000000 0x6c800000 0x70000000 0x74000000 0x78000000
+            0x7c000000 0x80000000
+        }
+    };
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    not(any(feature = "max-bound-64k", feature = "max-bound-1m")),
+    all(feature = "ladder-step-1_125", not(feature = "ladder-step-1_0625"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_31 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x120000 0x140000 0x160000 0x180000 0x1a0000 0x1c0000 0x1e0000 0x200000 0x240000 0x280000 0x2c0000 0x300000 0x340000 0x380000 0x3c0000 0x400000
+            0x480000 0x500000 0x580000 0x600000 0x680000 0x700000 0x780000 0x800000 0x900000 0xa00000 0xb00000 0xc00000 0xd00000 0xe00000 0xf00000 0x1000000
+            0x1200000 0x1400000 0x1600000 0x1800000 0x1a00000 0x1c00000 0x1e00000 0x2000000 0x2400000 0x2800000 0x2c00000 0x3000000 0x3400000 0x3800000 0x3c00000 0x4000000
+            0x4800000 0x5000000 0x5800000 0x6000000 0x6800000 0x7000000 0x7800000 0x8000000 0x9000000 0xa000000 0xb000000 0xc000000 0xd000000 0xe000000 0xf000000 0x10000000
+            0x12000000 0x14000000 0x16000000 0x18000000 0x1a000000 0x1c000000 0x1e000000 0x20000000 0x24000000 0x28000000 0x2c000000 0x30000000 0x34000000 0x38000000 0x3c000000 0x40000000
+            0x48000000 0x50000000 0x58000000 0x60000000 0x68000000 0x70000000 0x78000000 0x80000000
+        }
+    };
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    not(any(feature = "max-bound-64k", feature = "max-bound-1m")),
+    all(
+        feature = "ladder-step-1_25",
+        not(any(feature = "ladder-step-1_125", feature = "ladder-step-1_0625"))
+    )
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_31 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x140000 0x180000 0x1c0000 0x200000 0x280000 0x300000 0x380000 0x400000 0x500000 0x600000 0x700000 0x800000 0xa00000 0xc00000 0xe00000 0x1000000
+            0x1400000 0x1800000 0x1c00000 0x2000000 0x2800000 0x3000000 0x3800000 0x4000000 0x5000000 0x6000000 0x7000000 0x8000000 0xa000000 0xc000000 0xe000000 0x10000000
+            0x14000000 0x18000000 0x1c000000 0x20000000 0x28000000 0x30000000 0x38000000 0x40000000 0x50000000 0x60000000 0x70000000 0x80000000
+        }
+    };
+}
+
+#[cfg(all(
+    not(target_pointer_width = "16"),
+    not(any(feature = "max-bound-64k", feature = "max-bound-1m")),
+    not(any(
+        feature = "ladder-step-1_25",
+        feature = "ladder-step-1_125",
+        feature = "ladder-step-1_0625"
+    ))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_31 {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x180000 0x200000 0x300000 0x400000 0x600000 0x800000 0xc00000 0x1000000 0x1800000 0x2000000 0x3000000 0x4000000 0x6000000 0x8000000 0xc000000 0x10000000
+            0x18000000 0x20000000 0x30000000 0x40000000 0x60000000 0x80000000
+        }
+    };
+}
+
+#[cfg(any(
+    target_pointer_width = "16",
+    feature = "max-bound-64k",
+    feature = "max-bound-1m"
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_31 {
+    ($($mac:tt)*) => {};
+}
+
+#[cfg(all(
+    target_pointer_width = "32",
+    not(any(
+        feature = "max-bound-64k",
+        feature = "max-bound-1m",
+        feature = "portable-bounds"
+    )),
+    feature = "ladder-step-1_0625"
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_max {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x82000000 0x87000000 0x8c000000 0x91000000 0x96000000 0x9b000000 0xa0000000 0xa2000000 0xa8000000 0xae000000 0xb4000000 0xba000000 0xc0000000 0xc4000000 0xcb000000 0xd2000000
+            0xd9000000 0xe0000000 0xe7ffffff 0xefffffff 0xf7ffffff 0xffffffff
+        }
+    };
+}
+
+#[cfg(all(
+    target_pointer_width = "64",
+    not(any(
+        feature = "max-bound-64k",
+        feature = "max-bound-1m",
+        feature = "portable-bounds"
+    )),
+    feature = "ladder-step-1_0625"
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_max {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x82000000 0x87000000 0x8c000000 0x91000000 0x96000000 0x9b000000 0xa0000000 0xa2000000 0xa8000000 0xae000000 0xb4000000 0xba000000 0xc0000000 0xc4000000 0xcb000000 0xd2000000
+            0xd9000000 0xe0000000 0xe8000000 0xf0000000 0xf8000000 0x100000000 0x104000000 0x10e000000 0x118000000 0x122000000 0x12c000000 0x136000000 0x140000000 0x144000000 0x150000000 0x15c000000
+            0x168000000 0x174000000 0x180000000 0x188000000 0x196000000 0x1a4000000 0x1b2000000 0x1c0000000 0x1d0000000 0x1e0000000 0x1f0000000 0x200000000 0x208000000 0x21c000000 0x230000000 0x244000000
+            0x258000000 0x26c000000 0x280000000 0x288000000 0x2a0000000 0x2b8000000 0x2d0000000 0x2e8000000 0x300000000 0x310000000 0x32c000000 0x348000000 0x364000000 0x380000000 0x3a0000000 0x3c0000000
+            0x3e0000000 0x400000000 0x410000000 0x438000000 0x460000000 0x488000000 0x4b0000000 0x4d8000000 0x500000000 0x510000000 0x540000000 0x570000000 0x5a0000000 0x5d0000000 0x600000000 0x620000000
+            0x658000000 0x690000000 0x6c8000000 0x700000000 0x740000000 0x780000000 0x7c0000000 0x800000000 0x820000000 0x870000000 0x8c0000000 0x910000000 0x960000000 0x9b0000000 0xa00000000 0xa20000000
+            0xa80000000 0xae0000000 0xb40000000 0xba0000000 0xc00000000 0xc40000000 0xcb0000000 0xd20000000 0xd90000000 0xe00000000 0xe80000000 0xf00000000 0xf80000000 0x1000000000 0x1040000000 0x10e0000000
+            0x1180000000 0x1220000000 0x12c0000000 0x1360000000 0x1400000000 0x1440000000 0x1500000000 0x15c0000000 0x1680000000 0x1740000000 0x1800000000 0x1880000000 0x1960000000 0x1a40000000 0x1b20000000 0x1c00000000
+            0x1d00000000 0x1e00000000 0x1f00000000 0x2000000000 0x2080000000 0x21c0000000 0x2300000000 0x2440000000 0x2580000000 0x26c0000000 0x2800000000 0x2880000000 0x2a00000000 0x2b80000000 0x2d00000000 0x2e80000000
+            0x3000000000 0x3100000000 0x32c0000000 0x3480000000 0x3640000000 0x3800000000 0x3a00000000 0x3c00000000 0x3e00000000 0x4000000000 0x4100000000 0x4380000000 0x4600000000 0x4880000000 0x4b00000000 0x4d80000000
+            0x5000000000 0x5100000000 0x5400000000 0x5700000000 0x5a00000000 0x5d00000000 0x6000000000 0x6200000000 0x6580000000 0x6900000000 0x6c80000000 0x7000000000 0x7400000000 0x7800000000 0x7c00000000 0x8000000000
+            0x8200000000 0x8700000000 0x8c00000000 0x9100000000 0x9600000000 0x9b00000000 0xa000000000 0xa200000000 0xa800000000 0xae00000000 0xb400000000 0xba00000000 0xc000000000 0xc400000000 0xcb00000000 0xd200000000
+            0xd900000000 0xe000000000 0xe800000000 0xf000000000 0xf800000000 0x10000000000 0x10400000000 0x10e00000000 0x11800000000 0x12200000000 0x12c00000000 0x13600000000 0x14000000000 0x14400000000 0x15000000000 0x15c00000000
+            0x16800000000 0x17400000000 0x18000000000 0x18800000000 0x19600000000 0x1a400000000 0x1b200000000 0x1c000000000 0x1d000000000 0x1e000000000 0x1f000000000 0x20000000000 0x20800000000 0x21c00000000 0x23000000000 0x24400000000
+            0x25800000000 0x26c00000000 0x28000000000 0x28800000000 0x2a000000000 0x2b800000000 0x2d000000000 0x2e800000000 0x30000000000 0x31000000000 0x32c00000000 0x34800000000 0x36400000000 0x38000000000 0x3a000000000 0x3c000000000
+            0x3e000000000 0x40000000000 0x41000000000 0x43800000000 0x46000000000 0x48800000000 0x4b000000000 0x4d800000000 0x50000000000 0x51000000000 0x54000000000 0x57000000000 0x5a000000000 0x5d000000000 0x60000000000 0x62000000000
+            0x65800000000 0x69000000000 0x6c800000000 0x70000000000 0x74000000000 0x78000000000 0x7c000000000 0x80000000000 0x82000000000 0x87000000000 0x8c000000000 0x91000000000 0x96000000000 0x9b000000000 0xa0000000000 0xa2000000000
+            0xa8000000000 0xae000000000 0xb4000000000 0xba000000000 0xc0000000000 0xc4000000000 0xcb000000000 0xd2000000000 0xd9000000000 0xe0000000000 0xe8000000000 0xf0000000000 0xf8000000000 0x100000000000 0x104000000000 0x10e000000000
+            0x118000000000 0x122000000000 0x12c000000000 0x136000000000 0x140000000000 0x144000000000 0x150000000000 0x15c000000000 0x168000000000 0x174000000000 0x180000000000 0x188000000000 0x196000000000 0x1a4000000000 0x1b2000000000 0x1c0000000000
+            0x1d0000000000 0x1e0000000000 0x1f0000000000 0x200000000000 0x208000000000 0x21c000000000 0x230000000000 0x244000000000 0x258000000000 0x26c000000000 0x280000000000 0x288000000000 0x2a0000000000 0x2b8000000000 0x2d0000000000 0x2e8000000000
+            0x300000000000 0x310000000000 0x32c000000000 0x348000000000 0x364000000000 0x380000000000 0x3a0000000000 0x3c0000000000 0x3e0000000000 0x400000000000 0x410000000000 0x438000000000 0x460000000000 0x488000000000 0x4b0000000000 0x4d8000000000
+            0x500000000000 0x510000000000 0x540000000000 0x570000000000 0x5a0000000000 0x5d0000000000 0x600000000000 0x620000000000 0x658000000000 0x690000000000 0x6c8000000000 0x700000000000 0x740000000000 0x780000000000 0x7c0000000000 0x800000000000
+            0x820000000000 0x870000000000 0x8c0000000000 0x910000000000 0x960000000000 0x9b0000000000 0xa00000000000 0xa20000000000 0xa80000000000 0xae0000000000 0xb40000000000 0xba0000000000 0xc00000000000 0xc40000000000 0xcb0000000000 0xd20000000000
+            0xd90000000000 0xe00000000000 0xe80000000000 0xf00000000000 0xf80000000000 0x1000000000000 0x1040000000000 0x10e0000000000 0x1180000000000 0x1220000000000 0x12c0000000000 0x1360000000000 0x1400000000000 0x1440000000000 0x1500000000000 0x15c0000000000
+            0x1680000000000 0x1740000000000 0x1800000000000 0x1880000000000 0x1960000000000 0x1a40000000000 0x1b20000000000 0x1c00000000000 0x1d00000000000 0x1e00000000000 0x1f00000000000 0x2000000000000 0x2080000000000 0x21c0000000000 0x2300000000000 0x2440000000000
+            0x2580000000000 0x26c0000000000 0x2800000000000 0x2880000000000 0x2a00000000000 0x2b80000000000 0x2d00000000000 0x2e80000000000 0x3000000000000 0x3100000000000 0x32c0000000000 0x3480000000000 0x3640000000000 0x3800000000000 0x3a00000000000 0x3c00000000000
+            0x3e00000000000 0x4000000000000 0x4100000000000 0x4380000000000 0x4600000000000 0x4880000000000 0x4b00000000000 0x4d80000000000 0x5000000000000 0x5100000000000 0x5400000000000 0x5700000000000 0x5a00000000000 0x5d00000000000 0x6000000000000 0x6200000000000
+            0x6580000000000 0x6900000000000 0x6c80000000000 0x7000000000000 0x7400000000000 0x7800000000000 0x7c00000000000 0x8000000000000 0x8200000000000 0x8700000000000 0x8c00000000000 0x9100000000000 0x9600000000000 0x9b00000000000 0xa000000000000 0xa200000000000
+            0xa800000000000 0xae00000000000 0xb400000000000 0xba00000000000 0xc000000000000 0xc400000000000 0xcb00000000000 0xd200000000000 0xd900000000000 0xe000000000000 0xe800000000000 0xf000000000000 0xf800000000000 0x10000000000000 0x10400000000000 0x10e00000000000
+            0x11800000000000 0x12200000000000 0x12c00000000000 0x13600000000000 0x14000000000000 0x14400000000000 0x15000000000000 0x15c00000000000 0x16800000000000 0x17400000000000 0x18000000000000 0x18800000000000 0x19600000000000 0x1a400000000000 0x1b200000000000 0x1c000000000000
+            0x1d000000000000 0x1e000000000000 0x1f000000000000 0x20000000000000 0x20800000000000 0x21c00000000000 0x23000000000000 0x24400000000000 0x25800000000000 0x26c00000000000 0x28000000000000 0x28800000000000 0x2a000000000000 0x2b800000000000 0x2d000000000000 0x2e800000000000
+            0x30000000000000 0x31000000000000 0x32c00000000000 0x34800000000000 0x36400000000000 0x38000000000000 0x3a000000000000 0x3c000000000000 0x3e000000000000 0x40000000000000 0x41000000000000 0x43800000000000 0x46000000000000 0x48800000000000 0x4b000000000000 0x4d800000000000
+            0x50000000000000 0x51000000000000 0x54000000000000 0x57000000000000 0x5a000000000000 0x5d000000000000 0x60000000000000 0x62000000000000 0x65800000000000 0x69000000000000 0x6c800000000000 0x70000000000000 0x74000000000000 0x78000000000000 0x7c000000000000 0x80000000000000
+            0x82000000000000 0x87000000000000 0x8c000000000000 0x91000000000000 0x96000000000000 0x9b000000000000 0xa0000000000000 0xa2000000000000 0xa8000000000000 0xae000000000000 0xb4000000000000 0xba000000000000 0xc0000000000000 0xc4000000000000 0xcb000000000000 0xd2000000000000
+            0xd9000000000000 0xe0000000000000 0xe8000000000000 0xf0000000000000 0xf8000000000000 0x100000000000000 0x104000000000000 0x10e000000000000 0x118000000000000 0x122000000000000 0x12c000000000000 0x136000000000000 0x140000000000000 0x144000000000000 0x150000000000000 0x15c000000000000
+            0x168000000000000 0x174000000000000 0x180000000000000 0x188000000000000 0x196000000000000 0x1a4000000000000 0x1b2000000000000 0x1c0000000000000 0x1d0000000000000 0x1e0000000000000 0x1f0000000000000 0x200000000000000 0x208000000000000 0x21c000000000000 0x230000000000000 0x244000000000000
+            0x258000000000000 0x26c000000000000 0x280000000000000 0x288000000000000 0x2a0000000000000 0x2b8000000000000 0x2d0000000000000 0x2e8000000000000 0x300000000000000 0x310000000000000 0x32c000000000000 0x348000000000000 0x364000000000000 0x380000000000000 0x3a0000000000000 0x3c0000000000000
+            0x3e0000000000000 0x400000000000000 0x410000000000000 0x438000000000000 0x460000000000000 0x488000000000000 0x4b0000000000000 0x4d8000000000000 0x500000000000000 0x510000000000000 0x540000000000000 0x570000000000000 0x5a0000000000000 0x5d0000000000000 0x600000000000000 0x620000000000000
+            0x658000000000000 0x690000000000000 0x6c8000000000000 0x700000000000000 0x740000000000000 0x780000000000000 0x7c0000000000000 0x800000000000000 0x820000000000000 0x870000000000000 0x8c0000000000000 0x910000000000000 0x960000000000000 0x9b0000000000000 0xa00000000000000 0xa20000000000000
+            0xa80000000000000 0xae0000000000000 0xb40000000000000 0xba0000000000000 0xc00000000000000 0xc40000000000000 0xcb0000000000000 0xd20000000000000 0xd90000000000000 0xe00000000000000 0xe80000000000000 0xf00000000000000 0xf80000000000000 0x1000000000000000 0x1040000000000000 0x10e0000000000000
+            0x1180000000000000 0x1220000000000000 0x12c0000000000000 0x1360000000000000 0x1400000000000000 0x1440000000000000 0x1500000000000000 0x15c0000000000000 0x1680000000000000 0x1740000000000000 0x1800000000000000 0x1880000000000000 0x1960000000000000 0x1a40000000000000 0x1b20000000000000 0x1c00000000000000
+            0x1d00000000000000 0x1e00000000000000 0x1f00000000000000 0x2000000000000000 0x2080000000000000 0x21c0000000000000 0x2300000000000000 0x2440000000000000 0x2580000000000000 0x26c0000000000000 0x2800000000000000 0x2880000000000000 0x2a00000000000000 0x2b80000000000000 0x2d00000000000000 0x2e80000000000000
+            0x3000000000000000 0x3100000000000000 0x32c0000000000000 0x3480000000000000 0x3640000000000000 0x3800000000000000 0x3a00000000000000 0x3c00000000000000 0x3e00000000000000 0x4000000000000000 0x4100000000000000 0x4380000000000000 0x4600000000000000 0x4880000000000000 0x4b00000000000000 0x4d80000000000000
+            0x5000000000000000 0x5100000000000000 0x5400000000000000 0x5700000000000000 0x5a00000000000000 0x5d00000000000000 0x6000000000000000 0x6200000000000000 0x6580000000000000 0x6900000000000000 0x6c80000000000000 0x7000000000000000 0x7400000000000000 0x7800000000000000 0x7c00000000000000 0x8000000000000000
+            0x8200000000000000 0x8700000000000000 0x8c00000000000000 0x9100000000000000 0x9600000000000000 0x9b00000000000000 0xa000000000000000 0xa200000000000000 0xa800000000000000 0xae00000000000000 0xb400000000000000 0xba00000000000000 0xc000000000000000 0xc400000000000000 0xcb00000000000000 0xd200000000000000
+            0xd900000000000000 0xe000000000000000 0xe7ffffffffffffff 0xefffffffffffffff 0xf7ffffffffffffff 0xffffffffffffffff
+        }
+    };
+}
+
+#[cfg(all(
+    target_pointer_width = "32",
+    not(any(
+        feature = "max-bound-64k",
+        feature = "max-bound-1m",
+        feature = "portable-bounds"
+    )),
+    all(feature = "ladder-step-1_125", not(feature = "ladder-step-1_0625"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_max {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x90000000 0xa0000000 0xb0000000 0xc0000000 0xd0000000 0xe0000000 0xf0000000 0xffffffff
+        }
+    };
+}
+
+#[cfg(all(
+    target_pointer_width = "64",
+    not(any(
+        feature = "max-bound-64k",
+        feature = "max-bound-1m",
+        feature = "portable-bounds"
+    )),
+    all(feature = "ladder-step-1_125", not(feature = "ladder-step-1_0625"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_max {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0x90000000 0xa0000000 0xb0000000 0xc0000000 0xd0000000 0xe0000000 0xf0000000 0x100000000 0x120000000 0x140000000 0x160000000 0x180000000 0x1a0000000 0x1c0000000 0x1e0000000 0x200000000
+            0x240000000 0x280000000 0x2c0000000 0x300000000 0x340000000 0x380000000 0x3c0000000 0x400000000 0x480000000 0x500000000 0x580000000 0x600000000 0x680000000 0x700000000 0x780000000 0x800000000
+            0x900000000 0xa00000000 0xb00000000 0xc00000000 0xd00000000 0xe00000000 0xf00000000 0x1000000000 0x1200000000 0x1400000000 0x1600000000 0x1800000000 0x1a00000000 0x1c00000000 0x1e00000000 0x2000000000
+            0x2400000000 0x2800000000 0x2c00000000 0x3000000000 0x3400000000 0x3800000000 0x3c00000000 0x4000000000 0x4800000000 0x5000000000 0x5800000000 0x6000000000 0x6800000000 0x7000000000 0x7800000000 0x8000000000
+            0x9000000000 0xa000000000 0xb000000000 0xc000000000 0xd000000000 0xe000000000 0xf000000000 0x10000000000 0x12000000000 0x14000000000 0x16000000000 0x18000000000 0x1a000000000 0x1c000000000 0x1e000000000 0x20000000000
+            0x24000000000 0x28000000000 0x2c000000000 0x30000000000 0x34000000000 0x38000000000 0x3c000000000 0x40000000000 0x48000000000 0x50000000000 0x58000000000 0x60000000000 0x68000000000 0x70000000000 0x78000000000 0x80000000000
+            0x90000000000 0xa0000000000 0xb0000000000 0xc0000000000 0xd0000000000 0xe0000000000 0xf0000000000 0x100000000000 0x120000000000 0x140000000000 0x160000000000 0x180000000000 0x1a0000000000 0x1c0000000000 0x1e0000000000 0x200000000000
+            0x240000000000 0x280000000000 0x2c0000000000 0x300000000000 0x340000000000 0x380000000000 0x3c0000000000 0x400000000000 0x480000000000 0x500000000000 0x580000000000 0x600000000000 0x680000000000 0x700000000000 0x780000000000 0x800000000000
+            0x900000000000 0xa00000000000 0xb00000000000 0xc00000000000 0xd00000000000 0xe00000000000 0xf00000000000 0x1000000000000 0x1200000000000 0x1400000000000 0x1600000000000 0x1800000000000 0x1a00000000000 0x1c00000000000 0x1e00000000000 0x2000000000000
+            0x2400000000000 0x2800000000000 0x2c00000000000 0x3000000000000 0x3400000000000 0x3800000000000 0x3c00000000000 0x4000000000000 0x4800000000000 0x5000000000000 0x5800000000000 0x6000000000000 0x6800000000000 0x7000000000000 0x7800000000000 0x8000000000000
+            0x9000000000000 0xa000000000000 0xb000000000000 0xc000000000000 0xd000000000000 0xe000000000000 0xf000000000000 0x10000000000000 0x12000000000000 0x14000000000000 0x16000000000000 0x18000000000000 0x1a000000000000 0x1c000000000000 0x1e000000000000 0x20000000000000
+            0x24000000000000 0x28000000000000 0x2c000000000000 0x30000000000000 0x34000000000000 0x38000000000000 0x3c000000000000 0x40000000000000 0x48000000000000 0x50000000000000 0x58000000000000 0x60000000000000 0x68000000000000 0x70000000000000 0x78000000000000 0x80000000000000
+            0x90000000000000 0xa0000000000000 0xb0000000000000 0xc0000000000000 0xd0000000000000 0xe0000000000000 0xf0000000000000 0x100000000000000 0x120000000000000 0x140000000000000 0x160000000000000 0x180000000000000 0x1a0000000000000 0x1c0000000000000 0x1e0000000000000 0x200000000000000
+            0x240000000000000 0x280000000000000 0x2c0000000000000 0x300000000000000 0x340000000000000 0x380000000000000 0x3c0000000000000 0x400000000000000 0x480000000000000 0x500000000000000 0x580000000000000 0x600000000000000 0x680000000000000 0x700000000000000 0x780000000000000 0x800000000000000
+            0x900000000000000 0xa00000000000000 0xb00000000000000 0xc00000000000000 0xd00000000000000 0xe00000000000000 0xf00000000000000 0x1000000000000000 0x1200000000000000 0x1400000000000000 0x1600000000000000 0x1800000000000000 0x1a00000000000000 0x1c00000000000000 0x1e00000000000000 0x2000000000000000
+            0x2400000000000000 0x2800000000000000 0x2c00000000000000 0x3000000000000000 0x3400000000000000 0x3800000000000000 0x3c00000000000000 0x4000000000000000 0x4800000000000000 0x5000000000000000 0x5800000000000000 0x6000000000000000 0x6800000000000000 0x7000000000000000 0x7800000000000000 0x8000000000000000
+            0x9000000000000000 0xa000000000000000 0xb000000000000000 0xc000000000000000 0xd000000000000000 0xe000000000000000 0xf000000000000000 0xffffffffffffffff
+        }
+    };
+}
+
+#[cfg(all(
+    target_pointer_width = "32",
+    not(any(
+        feature = "max-bound-64k",
+        feature = "max-bound-1m",
+        feature = "portable-bounds"
+    )),
+    all(
+        feature = "ladder-step-1_25",
+        not(any(feature = "ladder-step-1_125", feature = "ladder-step-1_0625"))
+    )
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_max {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0xa0000000 0xc0000000 0xe0000000 0xffffffff
+        }
+    };
+}
+
+#[cfg(all(
+    target_pointer_width = "64",
+    not(any(
+        feature = "max-bound-64k",
+        feature = "max-bound-1m",
+        feature = "portable-bounds"
+    )),
+    all(
+        feature = "ladder-step-1_25",
+        not(any(feature = "ladder-step-1_125", feature = "ladder-step-1_0625"))
+    )
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_max {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0xa0000000 0xc0000000 0xe0000000 0x100000000 0x140000000 0x180000000 0x1c0000000 0x200000000 0x280000000 0x300000000 0x380000000 0x400000000 0x500000000 0x600000000 0x700000000 0x800000000
+            0xa00000000 0xc00000000 0xe00000000 0x1000000000 0x1400000000 0x1800000000 0x1c00000000 0x2000000000 0x2800000000 0x3000000000 0x3800000000 0x4000000000 0x5000000000 0x6000000000 0x7000000000 0x8000000000
+            0xa000000000 0xc000000000 0xe000000000 0x10000000000 0x14000000000 0x18000000000 0x1c000000000 0x20000000000 0x28000000000 0x30000000000 0x38000000000 0x40000000000 0x50000000000 0x60000000000 0x70000000000 0x80000000000
+            0xa0000000000 0xc0000000000 0xe0000000000 0x100000000000 0x140000000000 0x180000000000 0x1c0000000000 0x200000000000 0x280000000000 0x300000000000 0x380000000000 0x400000000000 0x500000000000 0x600000000000 0x700000000000 0x800000000000
+            0xa00000000000 0xc00000000000 0xe00000000000 0x1000000000000 0x1400000000000 0x1800000000000 0x1c00000000000 0x2000000000000 0x2800000000000 0x3000000000000 0x3800000000000 0x4000000000000 0x5000000000000 0x6000000000000 0x7000000000000 0x8000000000000
+            0xa000000000000 0xc000000000000 0xe000000000000 0x10000000000000 0x14000000000000 0x18000000000000 0x1c000000000000 0x20000000000000 0x28000000000000 0x30000000000000 0x38000000000000 0x40000000000000 0x50000000000000 0x60000000000000 0x70000000000000 0x80000000000000
+            0xa0000000000000 0xc0000000000000 0xe0000000000000 0x100000000000000 0x140000000000000 0x180000000000000 0x1c0000000000000 0x200000000000000 0x280000000000000 0x300000000000000 0x380000000000000 0x400000000000000 0x500000000000000 0x600000000000000 0x700000000000000 0x800000000000000
+            0xa00000000000000 0xc00000000000000 0xe00000000000000 0x1000000000000000 0x1400000000000000 0x1800000000000000 0x1c00000000000000 0x2000000000000000 0x2800000000000000 0x3000000000000000 0x3800000000000000 0x4000000000000000 0x5000000000000000 0x6000000000000000 0x7000000000000000 0x8000000000000000
+            0xa000000000000000 0xc000000000000000 0xe000000000000000 0xffffffffffffffff
+        }
+    };
+}
+
+#[cfg(all(
+    target_pointer_width = "32",
+    not(any(
+        feature = "max-bound-64k",
+        feature = "max-bound-1m",
+        feature = "portable-bounds"
+    )),
+    not(any(
+        feature = "ladder-step-1_25",
+        feature = "ladder-step-1_125",
+        feature = "ladder-step-1_0625"
+    ))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_max {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0xc0000000 0xffffffff
+        }
+    };
+}
+
+#[cfg(all(
+    target_pointer_width = "64",
+    not(any(
+        feature = "max-bound-64k",
+        feature = "max-bound-1m",
+        feature = "portable-bounds"
+    )),
+    not(any(
+        feature = "ladder-step-1_25",
+        feature = "ladder-step-1_125",
+        feature = "ladder-step-1_0625"
+    ))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_max {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            0xc0000000 0x100000000 0x180000000 0x200000000 0x300000000 0x400000000 0x600000000 0x800000000 0xc00000000 0x1000000000 0x1800000000 0x2000000000 0x3000000000 0x4000000000 0x6000000000 0x8000000000
+            0xc000000000 0x10000000000 0x18000000000 0x20000000000 0x30000000000 0x40000000000 0x60000000000 0x80000000000 0xc0000000000 0x100000000000 0x180000000000 0x200000000000 0x300000000000 0x400000000000 0x600000000000 0x800000000000
+            0xc00000000000 0x1000000000000 0x1800000000000 0x2000000000000 0x3000000000000 0x4000000000000 0x6000000000000 0x8000000000000 0xc000000000000 0x10000000000000 0x18000000000000 0x20000000000000 0x30000000000000 0x40000000000000 0x60000000000000 0x80000000000000
+            0xc0000000000000 0x100000000000000 0x180000000000000 0x200000000000000 0x300000000000000 0x400000000000000 0x600000000000000 0x800000000000000 0xc00000000000000 0x1000000000000000 0x1800000000000000 0x2000000000000000 0x3000000000000000 0x4000000000000000 0x6000000000000000 0x8000000000000000
+            0xc000000000000000 0xffffffffffffffff
+        }
+    };
+}
+
+#[cfg(any(
+    target_pointer_width = "16",
+    feature = "max-bound-64k",
+    feature = "max-bound-1m",
+    feature = "portable-bounds"
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! for_each_default_size_max {
+    ($($mac:tt)*) => {};
+}
+
+// `refine_tree` passes the refinements of each `usize` candidate with at most 3 significant bits
+// to `$mac` like `size_tree`, but every leaf `[C ...]` consists of the candidate `C` followed by
+// its refinements other than `C`.
+
+#[cfg(all(feature = "ladder-step-1_0625", target_pointer_width = "16"))]
+macro_rules! refine_tree {
+    ($mac:ident { $($args:tt)* }) => {
+        $mac! {
+            $($args)*
+            {
+                {
+                    {
+                        {
+                            {
+                                [3 2]
+                                4
+                                {
+                                    [4 3]
+                                    5
+                                    [5 3 4]
+                                }
+                            }
+                            6
+                            {
+                                {
+                                    [6 4 5]
+                                    7
+                                    [7 4 5 6]
+                                }
+                                8
+                                {
+                                    [8 5 6 7]
+                                    10
+                                    [10 6 7 8 9]
+                                }
+                            }
+                        }
+                        12
+                        {
+                            {
+                                [12 7 8 9 10 11]
+                                14
+                                {
+                                    [14 8 9 10 11 12 13]
+                                    16
+                                    [16 9 10 11 12 13 14 15]
+                                }
+                            }
+                            20
+                            {
+                                {
+                                    [20 11 12 13 14 15 16 17 18 19]
+                                    24
+                                    [24 13 14 15 16 17 18 19 20 21 22 23]
+                                }
+                                28
+                                {
+                                    [28 15 16 17 18 19 20 21 22 23 24 25 26 27]
+                                    32
+                                    [32 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31]
+                                }
+                            }
+                        }
+                    }
+                    40
+                    {
+                        {
+                            {
+                                [40 22 23 24 25 27 28 29 30 32 33 34 35 37 38 39]
+                                48
+                                {
+                                    [48 26 27 29 30 32 33 35 36 38 39 41 42 44 45 47]
+                                    56
+                                    [56 30 32 34 35 37 39 41 42 44 46 48 49 51 53 55]
+                                }
+                            }
+                            64
+                            {
+                                {
+                                    [64 34 36 38 40 42 44 46 48 50 52 54 56 58 60 62]
+                                    80
+                                    [80 43 45 48 50 53 55 58 60 63 65 68 70 73 75 78]
+                                }
+                                96
+                                {
+                                    [96 51 54 57 60 63 66 69 72 75 78 81 84 87 90 93]
+                                    112
+                                    [112 60 63 67 70 74 77 81 84 88 91 95 98 102 105 109]
+                                }
+                            }
+                        }
+                        128
+                        {
+                            {
+                                {
+                                    [128 68 72 76 80 84 88 92 96 100 104 108 112 116 120 124]
+                                    160
+                                    [160 85 90 95 100 105 110 115 120 125 130 135 140 145 150 155]
+                                }
+                                192
+                                {
+                                    [192 102 108 114 120 126 132 138 144 150 156 162 168 174 180 186]
+                                    224
+                                    [224 119 126 133 140 147 154 161 168 175 182 189 196 203 210 217]
+                                }
+                            }
+                            256
+                            {
+                                {
+                                    [256 136 144 152 160 168 176 184 192 200 208 216 224 232 240 248]
+                                    0x140
+                                    [0x140 170 180 190 200 210 220 230 240 250 0x104 0x10e 0x118 0x122 0x12c 0x136]
+                                }
+                                0x180
+                                {
+                                    [0x180 204 216 228 240 252 0x108 0x114 0x120 0x12c 0x138 0x144 0x150 0x15c 0x168 0x174]
+                                    0x1c0
+                                    [0x1c0 238 252 0x10a 0x118 0x126 0x134 0x142 0x150 0x15e 0x16c 0x17a 0x188 0x196 0x1a4 0x1b2]
+                                }
+                            }
+                        }
+                    }
+                }
+                0x200
+                {
+                    {
+                        {
+                            {
+                                [0x200 0x110 0x120 0x130 0x140 0x150 0x160 0x170 0x180 0x190 0x1a0 0x1b0 0x1c0 0x1d0 0x1e0 0x1f0]
+                                0x280
+                                {
+                                    [0x280 0x154 0x168 0x17c 0x190 0x1a4 0x1b8 0x1cc 0x1e0 0x1f4 0x208 0x21c 0x230 0x244 0x258 0x26c]
+                                    0x300
+                                    [0x300 0x198 0x1b0 0x1c8 0x1e0 0x1f8 0x210 0x228 0x240 0x258 0x270 0x288 0x2a0 0x2b8 0x2d0 0x2e8]
+                                }
+                            }
+                            0x380
+                            {
+                                {
+                                    [0x380 0x1dc 0x1f8 0x214 0x230 0x24c 0x268 0x284 0x2a0 0x2bc 0x2d8 0x2f4 0x310 0x32c 0x348 0x364]
+                                    0x400
+                                    [0x400 0x220 0x240 0x260 0x280 0x2a0 0x2c0 0x2e0 0x300 0x320 0x340 0x360 0x380 0x3a0 0x3c0 0x3e0]
+                                }
+                                0x500
+                                {
+                                    [0x500 0x2a8 0x2d0 0x2f8 0x320 0x348 0x370 0x398 0x3c0 0x3e8 0x410 0x438 0x460 0x488 0x4b0 0x4d8]
+                                    0x600
+                                    [0x600 0x330 0x360 0x390 0x3c0 0x3f0 0x420 0x450 0x480 0x4b0 0x4e0 0x510 0x540 0x570 0x5a0 0x5d0]
+                                }
+                            }
+                        }
+                        0x700
+                        {
+                            {
+                                [0x700 0x3b8 0x3f0 0x428 0x460 0x498 0x4d0 0x508 0x540 0x578 0x5b0 0x5e8 0x620 0x658 0x690 0x6c8]
+                                0x800
+                                {
+                                    [0x800 0x440 0x480 0x4c0 0x500 0x540 0x580 0x5c0 0x600 0x640 0x680 0x6c0 0x700 0x740 0x780 0x7c0]
+                                    0xa00
+                                    [0xa00 0x550 0x5a0 0x5f0 0x640 0x690 0x6e0 0x730 0x780 0x7d0 0x820 0x870 0x8c0 0x910 0x960 0x9b0]
+                                }
+                            }
+                            0xc00
+                            {
+                                {
+                                    [0xc00 0x660 0x6c0 0x720 0x780 0x7e0 0x840 0x8a0 0x900 0x960 0x9c0 0xa20 0xa80 0xae0 0xb40 0xba0]
+                                    0xe00
+                                    [0xe00 0x770 0x7e0 0x850 0x8c0 0x930 0x9a0 0xa10 0xa80 0xaf0 0xb60 0xbd0 0xc40 0xcb0 0xd20 0xd90]
+                                }
+                                0x1000
+                                {
+                                    [0x1000 0x880 0x900 0x980 0xa00 0xa80 0xb00 0xb80 0xc00 0xc80 0xd00 0xd80 0xe00 0xe80 0xf00 0xf80]
+                                    0x1400
+                                    [0x1400 0xaa0 0xb40 0xbe0 0xc80 0xd20 0xdc0 0xe60 0xf00 0xfa0 0x1040 0x10e0 0x1180 0x1220 0x12c0 0x1360]
+                                }
+                            }
+                        }
+                    }
+                    0x1800
+                    {
+                        {
+                            {
+                                [0x1800 0xcc0 0xd80 0xe40 0xf00 0xfc0 0x1080 0x1140 0x1200 0x12c0 0x1380 0x1440 0x1500 0x15c0 0x1680 0x1740]
+                                0x1c00
+                                {
+                                    [0x1c00 0xee0 0xfc0 0x10a0 0x1180 0x1260 0x1340 0x1420 0x1500 0x15e0 0x16c0 0x17a0 0x1880 0x1960 0x1a40 0x1b20]
+                                    0x2000
+                                    [0x2000 0x1100 0x1200 0x1300 0x1400 0x1500 0x1600 0x1700 0x1800 0x1900 0x1a00 0x1b00 0x1c00 0x1d00 0x1e00 0x1f00]
+                                }
+                            }
+                            0x2800
+                            {
+                                {
+                                    [0x2800 0x1540 0x1680 0x17c0 0x1900 0x1a40 0x1b80 0x1cc0 0x1e00 0x1f40 0x2080 0x21c0 0x2300 0x2440 0x2580 0x26c0]
+                                    0x3000
+                                    [0x3000 0x1980 0x1b00 0x1c80 0x1e00 0x1f80 0x2100 0x2280 0x2400 0x2580 0x2700 0x2880 0x2a00 0x2b80 0x2d00 0x2e80]
+                                }
+                                0x3800
+                                {
+                                    [0x3800 0x1dc0 0x1f80 0x2140 0x2300 0x24c0 0x2680 0x2840 0x2a00 0x2bc0 0x2d80 0x2f40 0x3100 0x32c0 0x3480 0x3640]
+                                    0x4000
+                                    [0x4000 0x2200 0x2400 0x2600 0x2800 0x2a00 0x2c00 0x2e00 0x3000 0x3200 0x3400 0x3600 0x3800 0x3a00 0x3c00 0x3e00]
+                                }
+                            }
+                        }
+                        0x5000
+                        {
+                            {
+                                {
+                                    [0x5000 0x2a80 0x2d00 0x2f80 0x3200 0x3480 0x3700 0x3980 0x3c00 0x3e80 0x4100 0x4380 0x4600 0x4880 0x4b00 0x4d80]
+                                    0x6000
+                                    [0x6000 0x3300 0x3600 0x3900 0x3c00 0x3f00 0x4200 0x4500 0x4800 0x4b00 0x4e00 0x5100 0x5400 0x5700 0x5a00 0x5d00]
+                                }
+                                0x7000
+                                {
+                                    [0x7000 0x3b80 0x3f00 0x4280 0x4600 0x4980 0x4d00 0x5080 0x5400 0x5780 0x5b00 0x5e80 0x6200 0x6580 0x6900 0x6c80]
+                                    0x8000
+                                    [0x8000 0x4400 0x4800 0x4c00 0x5000 0x5400 0x5800 0x5c00 0x6000 0x6400 0x6800 0x6c00 0x7000 0x7400 0x7800 0x7c00]
+                                }
+                            }
+                            0xa000
+                            {
+                                {
+                                    [0xa000 0x5500 0x5a00 0x5f00 0x6400 0x6900 0x6e00 0x7300 0x7800 0x7d00 0x8200 0x8700 0x8c00 0x9100 0x9600 0x9b00]
+                                    0xc000
+                                    [0xc000 0x6600 0x6c00 0x7200 0x7800 0x7e00 0x8400 0x8a00 0x9000 0x9600 0x9c00 0xa200 0xa800 0xae00 0xb400 0xba00]
+                                }
+                                0xe000
+                                {
+                                    [0xe000 0x7700 0x7e00 0x8500 0x8c00 0x9300 0x9a00 0xa100 0xa800 0xaf00 0xb600 0xbd00 0xc400 0xcb00 0xd200 0xd900]
+                                    0xffff
+                                    [0xffff 0x87ff 0x8fff 0x97ff 0x9fff 0xa7ff 0xafff 0xb7ff 0xbfff 0xc7ff 0xcfff 0xd7ff 0xdfff 0xe7ff 0xefff 0xf7ff]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "ladder-step-1_0625", target_pointer_width = "32"))]
+macro_rules! refine_tree {
+    ($mac:ident { $($args:tt)* }) => {
+        $mac! {
+            $($args)*
+            {
+                {
+                    {
+                        {
+                            {
+                                {
+                                    [3 2]
+                                    4
+                                    {
+                                        [4 3]
+                                        5
+                                        [5 3 4]
+                                    }
+                                }
+                                6
+                                {
+                                    {
+                                        [6 4 5]
+                                        7
+                                        [7 4 5 6]
+                                    }
+                                    8
+                                    {
+                                        [8 5 6 7]
+                                        10
+                                        [10 6 7 8 9]
+                                    }
+                                }
+                            }
+                            12
+                            {
+                                {
+                                    {
+                                        [12 7 8 9 10 11]
+                                        14
+                                        [14 8 9 10 11 12 13]
+                                    }
+                                    16
+                                    {
+                                        [16 9 10 11 12 13 14 15]
+                                        20
+                                        [20 11 12 13 14 15 16 17 18 19]
+                                    }
+                                }
+                                24
+                                {
+                                    {
+                                        [24 13 14 15 16 17 18 19 20 21 22 23]
+                                        28
+                                        [28 15 16 17 18 19 20 21 22 23 24 25 26 27]
+                                    }
+                                    32
+                                    {
+                                        [32 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31]
+                                        40
+                                        [40 22 23 24 25 27 28 29 30 32 33 34 35 37 38 39]
+                                    }
+                                }
+                            }
+                        }
+                        48
+                        {
+                            {
+                                {
+                                    [48 26 27 29 30 32 33 35 36 38 39 41 42 44 45 47]
+                                    56
+                                    {
+                                        [56 30 32 34 35 37 39 41 42 44 46 48 49 51 53 55]
+                                        64
+                                        [64 34 36 38 40 42 44 46 48 50 52 54 56 58 60 62]
+                                    }
+                                }
+                                80
+                                {
+                                    {
+                                        [80 43 45 48 50 53 55 58 60 63 65 68 70 73 75 78]
+                                        96
+                                        [96 51 54 57 60 63 66 69 72 75 78 81 84 87 90 93]
+                                    }
+                                    112
+                                    {
+                                        [112 60 63 67 70 74 77 81 84 88 91 95 98 102 105 109]
+                                        128
+                                        [128 68 72 76 80 84 88 92 96 100 104 108 112 116 120 124]
+                                    }
+                                }
+                            }
+                            160
+                            {
+                                {
+                                    {
+                                        [160 85 90 95 100 105 110 115 120 125 130 135 140 145 150 155]
+                                        192
+                                        [192 102 108 114 120 126 132 138 144 150 156 162 168 174 180 186]
+                                    }
+                                    224
+                                    {
+                                        [224 119 126 133 140 147 154 161 168 175 182 189 196 203 210 217]
+                                        256
+                                        [256 136 144 152 160 168 176 184 192 200 208 216 224 232 240 248]
+                                    }
+                                }
+                                0x140
+                                {
+                                    {
+                                        [0x140 170 180 190 200 210 220 230 240 250 0x104 0x10e 0x118 0x122 0x12c 0x136]
+                                        0x180
+                                        [0x180 204 216 228 240 252 0x108 0x114 0x120 0x12c 0x138 0x144 0x150 0x15c 0x168 0x174]
+                                    }
+                                    0x1c0
+                                    {
+                                        [0x1c0 238 252 0x10a 0x118 0x126 0x134 0x142 0x150 0x15e 0x16c 0x17a 0x188 0x196 0x1a4 0x1b2]
+                                        0x200
+                                        [0x200 0x110 0x120 0x130 0x140 0x150 0x160 0x170 0x180 0x190 0x1a0 0x1b0 0x1c0 0x1d0 0x1e0 0x1f0]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    0x280
+                    {
+                        {
+                            {
+                                {
+                                    [0x280 0x154 0x168 0x17c 0x190 0x1a4 0x1b8 0x1cc 0x1e0 0x1f4 0x208 0x21c 0x230 0x244 0x258 0x26c]
+                                    0x300
+                                    {
+                                        [0x300 0x198 0x1b0 0x1c8 0x1e0 0x1f8 0x210 0x228 0x240 0x258 0x270 0x288 0x2a0 0x2b8 0x2d0 0x2e8]
+                                        0x380
+                                        [0x380 0x1dc 0x1f8 0x214 0x230 0x24c 0x268 0x284 0x2a0 0x2bc 0x2d8 0x2f4 0x310 0x32c 0x348 0x364]
+                                    }
+                                }
+                                0x400
+                                {
+                                    {
+                                        [0x400 0x220 0x240 0x260 0x280 0x2a0 0x2c0 0x2e0 0x300 0x320 0x340 0x360 0x380 0x3a0 0x3c0 0x3e0]
+                                        0x500
+                                        [0x500 0x2a8 0x2d0 0x2f8 0x320 0x348 0x370 0x398 0x3c0 0x3e8 0x410 0x438 0x460 0x488 0x4b0 0x4d8]
+                                    }
+                                    0x600
+                                    {
+                                        [0x600 0x330 0x360 0x390 0x3c0 0x3f0 0x420 0x450 0x480 0x4b0 0x4e0 0x510 0x540 0x570 0x5a0 0x5d0]
+                                        0x700
+                                        [0x700 0x3b8 0x3f0 0x428 0x460 0x498 0x4d0 0x508 0x540 0x578 0x5b0 0x5e8 0x620 0x658 0x690 0x6c8]
+                                    }
+                                }
+                            }
+                            0x800
+                            {
+                                {
+                                    {
+                                        [0x800 0x440 0x480 0x4c0 0x500 0x540 0x580 0x5c0 0x600 0x640 0x680 0x6c0 0x700 0x740 0x780 0x7c0]
+                                        0xa00
+                                        [0xa00 0x550 0x5a0 0x5f0 0x640 0x690 0x6e0 0x730 0x780 0x7d0 0x820 0x870 0x8c0 0x910 0x960 0x9b0]
+                                    }
+                                    0xc00
+                                    {
+                                        [0xc00 0x660 0x6c0 0x720 0x780 0x7e0 0x840 0x8a0 0x900 0x960 0x9c0 0xa20 0xa80 0xae0 0xb40 0xba0]
+                                        0xe00
+                                        [0xe00 0x770 0x7e0 0x850 0x8c0 0x930 0x9a0 0xa10 0xa80 0xaf0 0xb60 0xbd0 0xc40 0xcb0 0xd20 0xd90]
+                                    }
+                                }
+                                0x1000
+                                {
+                                    {
+                                        [0x1000 0x880 0x900 0x980 0xa00 0xa80 0xb00 0xb80 0xc00 0xc80 0xd00 0xd80 0xe00 0xe80 0xf00 0xf80]
+                                        0x1400
+                                        [0x1400 0xaa0 0xb40 0xbe0 0xc80 0xd20 0xdc0 0xe60 0xf00 0xfa0 0x1040 0x10e0 0x1180 0x1220 0x12c0 0x1360]
+                                    }
+                                    0x1800
+                                    {
+                                        [0x1800 0xcc0 0xd80 0xe40 0xf00 0xfc0 0x1080 0x1140 0x1200 0x12c0 0x1380 0x1440 0x1500 0x15c0 0x1680 0x1740]
+                                        0x1c00
+                                        [0x1c00 0xee0 0xfc0 0x10a0 0x1180 0x1260 0x1340 0x1420 0x1500 0x15e0 0x16c0 0x17a0 0x1880 0x1960 0x1a40 0x1b20]
+                                    }
+                                }
+                            }
+                        }
+                        0x2000
+                        {
+                            {
+                                {
+                                    {
+                                        [0x2000 0x1100 0x1200 0x1300 0x1400 0x1500 0x1600 0x1700 0x1800 0x1900 0x1a00 0x1b00 0x1c00 0x1d00 0x1e00 0x1f00]
+                                        0x2800
+                                        [0x2800 0x1540 0x1680 0x17c0 0x1900 0x1a40 0x1b80 0x1cc0 0x1e00 0x1f40 0x2080 0x21c0 0x2300 0x2440 0x2580 0x26c0]
+                                    }
+                                    0x3000
+                                    {
+                                        [0x3000 0x1980 0x1b00 0x1c80 0x1e00 0x1f80 0x2100 0x2280 0x2400 0x2580 0x2700 0x2880 0x2a00 0x2b80 0x2d00 0x2e80]
+                                        0x3800
+                                        [0x3800 0x1dc0 0x1f80 0x2140 0x2300 0x24c0 0x2680 0x2840 0x2a00 0x2bc0 0x2d80 0x2f40 0x3100 0x32c0 0x3480 0x3640]
+                                    }
+                                }
+                                0x4000
+                                {
+                                    {
+                                        [0x4000 0x2200 0x2400 0x2600 0x2800 0x2a00 0x2c00 0x2e00 0x3000 0x3200 0x3400 0x3600 0x3800 0x3a00 0x3c00 0x3e00]
+                                        0x5000
+                                        [0x5000 0x2a80 0x2d00 0x2f80 0x3200 0x3480 0x3700 0x3980 0x3c00 0x3e80 0x4100 0x4380 0x4600 0x4880 0x4b00 0x4d80]
+                                    }
+                                    0x6000
+                                    {
+                                        [0x6000 0x3300 0x3600 0x3900 0x3c00 0x3f00 0x4200 0x4500 0x4800 0x4b00 0x4e00 0x5100 0x5400 0x5700 0x5a00 0x5d00]
+                                        0x7000
+                                        [0x7000 0x3b80 0x3f00 0x4280 0x4600 0x4980 0x4d00 0x5080 0x5400 0x5780 0x5b00 0x5e80 0x6200 0x6580 0x6900 0x6c80]
+                                    }
+                                }
+                            }
+                            0x8000
+                            {
+                                {
+                                    {
+                                        [0x8000 0x4400 0x4800 0x4c00 0x5000 0x5400 0x5800 0x5c00 0x6000 0x6400 0x6800 0x6c00 0x7000 0x7400 0x7800 0x7c00]
+                                        0xa000
+                                        [0xa000 0x5500 0x5a00 0x5f00 0x6400 0x6900 0x6e00 0x7300 0x7800 0x7d00 0x8200 0x8700 0x8c00 0x9100 0x9600 0x9b00]
+                                    }
+                                    0xc000
+                                    {
+                                        [0xc000 0x6600 0x6c00 0x7200 0x7800 0x7e00 0x8400 0x8a00 0x9000 0x9600 0x9c00 0xa200 0xa800 0xae00 0xb400 0xba00]
+                                        0xe000
+                                        [0xe000 0x7700 0x7e00 0x8500 0x8c00 0x9300 0x9a00 0xa100 0xa800 0xaf00 0xb600 0xbd00 0xc400 0xcb00 0xd200 0xd900]
+                                    }
+                                }
+                                0x10000
+                                {
+                                    {
+                                        [0x10000 0x8800 0x9000 0x9800 0xa000 0xa800 0xb000 0xb800 0xc000 0xc800 0xd000 0xd800 0xe000 0xe800 0xf000 0xf800]
+                                        0x14000
+                                        [0x14000 0xaa00 0xb400 0xbe00 0xc800 0xd200 0xdc00 0xe600 0xf000 0xfa00 0x10400 0x10e00 0x11800 0x12200 0x12c00 0x13600]
+                                    }
+                                    0x18000
+                                    {
+                                        [0x18000 0xcc00 0xd800 0xe400 0xf000 0xfc00 0x10800 0x11400 0x12000 0x12c00 0x13800 0x14400 0x15000 0x15c00 0x16800 0x17400]
+                                        0x1c000
+                                        [0x1c000 0xee00 0xfc00 0x10a00 0x11800 0x12600 0x13400 0x14200 0x15000 0x15e00 0x16c00 0x17a00 0x18800 0x19600 0x1a400 0x1b200]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                0x20000
+                {
+                    {
+                        {
+                            {
+                                {
+                                    [0x20000 0x11000 0x12000 0x13000 0x14000 0x15000 0x16000 0x17000 0x18000 0x19000 0x1a000 0x1b000 0x1c000 0x1d000 0x1e000 0x1f000]
+                                    0x28000
+                                    {
+                                        [0x28000 0x15400 0x16800 0x17c00 0x19000 0x1a400 0x1b800 0x1cc00 0x1e000 0x1f400 0x20800 0x21c00 0x23000 0x24400 0x25800 0x26c00]
+                                        0x30000
+                                        [0x30000 0x19800 0x1b000 0x1c800 0x1e000 0x1f800 0x21000 0x22800 0x24000 0x25800 0x27000 0x28800 0x2a000 0x2b800 0x2d000 0x2e800]
+                                    }
+                                }
+                                0x38000
+                                {
+                                    {
+                                        [0x38000 0x1dc00 0x1f800 0x21400 0x23000 0x24c00 0x26800 0x28400 0x2a000 0x2bc00 0x2d800 0x2f400 0x31000 0x32c00 0x34800 0x36400]
+                                        0x40000
+                                        [0x40000 0x22000 0x24000 0x26000 0x28000 0x2a000 0x2c000 0x2e000 0x30000 0x32000 0x34000 0x36000 0x38000 0x3a000 0x3c000 0x3e000]
+                                    }
+                                    0x50000
+                                    {
+                                        [0x50000 0x2a800 0x2d000 0x2f800 0x32000 0x34800 0x37000 0x39800 0x3c000 0x3e800 0x41000 0x43800 0x46000 0x48800 0x4b000 0x4d800]
+                                        0x60000
+                                        [0x60000 0x33000 0x36000 0x39000 0x3c000 0x3f000 0x42000 0x45000 0x48000 0x4b000 0x4e000 0x51000 0x54000 0x57000 0x5a000 0x5d000]
+                                    }
+                                }
+                            }
+                            0x70000
+                            {
+                                {
+                                    {
+                                        [0x70000 0x3b800 0x3f000 0x42800 0x46000 0x49800 0x4d000 0x50800 0x54000 0x57800 0x5b000 0x5e800 0x62000 0x65800 0x69000 0x6c800]
+                                        0x80000
+                                        [0x80000 0x44000 0x48000 0x4c000 0x50000 0x54000 0x58000 0x5c000 0x60000 0x64000 0x68000 0x6c000 0x70000 0x74000 0x78000 0x7c000]
+                                    }
+                                    0xa0000
+                                    {
+                                        [0xa0000 0x55000 0x5a000 0x5f000 0x64000 0x69000 0x6e000 0x73000 0x78000 0x7d000 0x82000 0x87000 0x8c000 0x91000 0x96000 0x9b000]
+                                        0xc0000
+                                        [0xc0000 0x66000 0x6c000 0x72000 0x78000 0x7e000 0x84000 0x8a000 0x90000 0x96000 0x9c000 0xa2000 0xa8000 0xae000 0xb4000 0xba000]
+                                    }
+                                }
+                                0xe0000
+                                {
+                                    {
+                                        [0xe0000 0x77000 0x7e000 0x85000 0x8c000 0x93000 0x9a000 0xa1000 0xa8000 0xaf000 0xb6000 0xbd000 0xc4000 0xcb000 0xd2000 0xd9000]
+                                        0x100000
+                                        [0x100000 0x88000 0x90000 0x98000 0xa0000 0xa8000 0xb0000 0xb8000 0xc0000 0xc8000 0xd0000 0xd8000 0xe0000 0xe8000 0xf0000 0xf8000]
+                                    }
+                                    0x140000
+                                    {
+                                        [0x140000 0xaa000 0xb4000 0xbe000 0xc8000 0xd2000 0xdc000 0xe6000 0xf0000 0xfa000 0x104000 0x10e000 0x118000 0x122000 0x12c000 0x136000]
+                                        0x180000
+                                        [0x180000 0xcc000 0xd8000 0xe4000 0xf0000 0xfc000 0x108000 0x114000 0x120000 0x12c000 0x138000 0x144000 0x150000 0x15c000 0x168000 0x174000]
+                                    }
+                                }
+                            }
+                        }
+                        0x1c0000
+                        {
+                            {
+                                {
+                                    [0x1c0000 0xee000 0xfc000 0x10a000 0x118000 0x126000 0x134000 0x142000 0x150000 0x15e000 0x16c000 0x17a000 0x188000 0x196000 0x1a4000 0x1b2000]
+                                    0x200000
+                                    {
+                                        [0x200000 0x110000 0x120000 0x130000 0x140000 0x150000 0x160000 0x170000 0x180000 0x190000 0x1a0000 0x1b0000 0x1c0000 0x1d0000 0x1e0000 0x1f0000]
+                                        0x280000
+                                        [0x280000 0x154000 0x168000 0x17c000 0x190000 0x1a4000 0x1b8000 0x1cc000 0x1e0000 0x1f4000 0x208000 0x21c000 0x230000 0x244000 0x258000 0x26c000]
+                                    }
+                                }
+                                0x300000
+                                {
+                                    {
+                                        [0x300000 0x198000 0x1b0000 0x1c8000 0x1e0000 0x1f8000 0x210000 0x228000 0x240000 0x258000 0x270000 0x288000 0x2a0000 0x2b8000 0x2d0000 0x2e8000]
+                                        0x380000
+                                        [0x380000 0x1dc000 0x1f8000 0x214000 0x230000 0x24c000 0x268000 0x284000 0x2a0000 0x2bc000 0x2d8000 0x2f4000 0x310000 0x32c000 0x348000 0x364000]
+                                    }
+                                    0x400000
+                                    {
+                                        [0x400000 0x220000 0x240000 0x260000 0x280000 0x2a0000 0x2c0000 0x2e0000 0x300000 0x320000 0x340000 0x360000 0x380000 0x3a0000 0x3c0000 0x3e0000]
+                                        0x500000
+                                        [0x500000 0x2a8000 0x2d0000 0x2f8000 0x320000 0x348000 0x370000 0x398000 0x3c0000 0x3e8000 0x410000 0x438000 0x460000 0x488000 0x4b0000 0x4d8000]
+                                    }
+                                }
+                            }
+                            0x600000
+                            {
+                                {
+                                    {
+                                        [0x600000 0x330000 0x360000 0x390000 0x3c0000 0x3f0000 0x420000 0x450000 0x480000 0x4b0000 0x4e0000 0x510000 0x540000 0x570000 0x5a0000 0x5d0000]
+                                        0x700000
+                                        [0x700000 0x3b8000 0x3f0000 0x428000 0x460000 0x498000 0x4d0000 0x508000 0x540000 0x578000 0x5b0000 0x5e8000 0x620000 0x658000 0x690000 0x6c8000]
+                                    }
+                                    0x800000
+                                    {
+                                        [0x800000 0x440000 0x480000 0x4c0000 0x500000 0x540000 0x580000 0x5c0000 0x600000 0x640000 0x680000 0x6c0000 0x700000 0x740000 0x780000 0x7c0000]
+                                        0xa00000
+                                        [0xa00000 0x550000 0x5a0000 0x5f0000 0x640000 0x690000 0x6e0000 0x730000 0x780000 0x7d0000 0x820000 0x870000 0x8c0000 0x910000 0x960000 0x9b0000]
+                                    }
+                                }
+                                0xc00000
+                                {
+                                    {
+                                        [0xc00000 0x660000 0x6c0000 0x720000 0x780000 0x7e0000 0x840000 0x8a0000 0x900000 0x960000 0x9c0000 0xa20000 0xa80000 0xae0000 0xb40000 0xba0000]
+                                        0xe00000
+                                        [0xe00000 0x770000 0x7e0000 0x850000 0x8c0000 0x930000 0x9a0000 0xa10000 0xa80000 0xaf0000 0xb60000 0xbd0000 0xc40000 0xcb0000 0xd20000 0xd90000]
+                                    }
+                                    0x1000000
+                                    {
+                                        [0x1000000 0x880000 0x900000 0x980000 0xa00000 0xa80000 0xb00000 0xb80000 0xc00000 0xc80000 0xd00000 0xd80000 0xe00000 0xe80000 0xf00000 0xf80000]
+                                        0x1400000
+                                        [0x1400000 0xaa0000 0xb40000 0xbe0000 0xc80000 0xd20000 0xdc0000 0xe60000 0xf00000 0xfa0000 0x1040000 0x10e0000 0x1180000 0x1220000 0x12c0000 0x1360000]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    0x1800000
+                    {
+                        {
+                            {
+                                {
+                                    [0x1800000 0xcc0000 0xd80000 0xe40000 0xf00000 0xfc0000 0x1080000 0x1140000 0x1200000 0x12c0000 0x1380000 0x1440000 0x1500000 0x15c0000 0x1680000 0x1740000]
+                                    0x1c00000
+                                    {
+                                        [0x1c00000 0xee0000 0xfc0000 0x10a0000 0x1180000 0x1260000 0x1340000 0x1420000 0x1500000 0x15e0000 0x16c0000 0x17a0000 0x1880000 0x1960000 0x1a40000 0x1b20000]
+                                        0x2000000
+                                        [0x2000000 0x1100000 0x1200000 0x1300000 0x1400000 0x1500000 0x1600000 0x1700000 0x1800000 0x1900000 0x1a00000 0x1b00000 0x1c00000 0x1d00000 0x1e00000 0x1f00000]
+                                    }
+                                }
+                                0x2800000
+                                {
+                                    {
+                                        [0x2800000 0x1540000 0x1680000 0x17c0000 0x1900000 0x1a40000 0x1b80000 0x1cc0000 0x1e00000 0x1f40000 0x2080000 0x21c0000 0x2300000 0x2440000 0x2580000 0x26c0000]
+                                        0x3000000
+                                        [0x3000000 0x1980000 0x1b00000 0x1c80000 0x1e00000 0x1f80000 0x2100000 0x2280000 0x2400000 0x2580000 0x2700000 0x2880000 0x2a00000 0x2b80000 0x2d00000 0x2e80000]
+                                    }
+                                    0x3800000
+                                    {
+                                        [0x3800000 0x1dc0000 0x1f80000 0x2140000 0x2300000 0x24c0000 0x2680000 0x2840000 0x2a00000 0x2bc0000 0x2d80000 0x2f40000 0x3100000 0x32c0000 0x3480000 0x3640000]
+                                        0x4000000
+                                        [0x4000000 0x2200000 0x2400000 0x2600000 0x2800000 0x2a00000 0x2c00000 0x2e00000 0x3000000 0x3200000 0x3400000 0x3600000 0x3800000 0x3a00000 0x3c00000 0x3e00000]
+                                    }
+                                }
+                            }
+                            0x5000000
+                            {
+                                {
+                                    {
+                                        [0x5000000 0x2a80000 0x2d00000 0x2f80000 0x3200000 0x3480000 0x3700000 0x3980000 0x3c00000 0x3e80000 0x4100000 0x4380000 0x4600000 0x4880000 0x4b00000 0x4d80000]
+                                        0x6000000
+                                        [0x6000000 0x3300000 0x3600000 0x3900000 0x3c00000 0x3f00000 0x4200000 0x4500000 0x4800000 0x4b00000 0x4e00000 0x5100000 0x5400000 0x5700000 0x5a00000 0x5d00000]
+                                    }
+                                    0x7000000
+                                    {
+                                        [0x7000000 0x3b80000 0x3f00000 0x4280000 0x4600000 0x4980000 0x4d00000 0x5080000 0x5400000 0x5780000 0x5b00000 0x5e80000 0x6200000 0x6580000 0x6900000 0x6c80000]
+                                        0x8000000
+                                        [0x8000000 0x4400000 0x4800000 0x4c00000 0x5000000 0x5400000 0x5800000 0x5c00000 0x6000000 0x6400000 0x6800000 0x6c00000 0x7000000 0x7400000 0x7800000 0x7c00000]
+                                    }
+                                }
+                                0xa000000
+                                {
+                                    {
+                                        [0xa000000 0x5500000 0x5a00000 0x5f00000 0x6400000 0x6900000 0x6e00000 0x7300000 0x7800000 0x7d00000 0x8200000 0x8700000 0x8c00000 0x9100000 0x9600000 0x9b00000]
+                                        0xc000000
+                                        [0xc000000 0x6600000 0x6c00000 0x7200000 0x7800000 0x7e00000 0x8400000 0x8a00000 0x9000000 0x9600000 0x9c00000 0xa200000 0xa800000 0xae00000 0xb400000 0xba00000]
+                                    }
+                                    0xe000000
+                                    {
+                                        [0xe000000 0x7700000 0x7e00000 0x8500000 0x8c00000 0x9300000 0x9a00000 0xa100000 0xa800000 0xaf00000 0xb600000 0xbd00000 0xc400000 0xcb00000 0xd200000 0xd900000]
+                                        0x10000000
+                                        [0x10000000 0x8800000 0x9000000 0x9800000 0xa000000 0xa800000 0xb000000 0xb800000 0xc000000 0xc800000 0xd000000 0xd800000 0xe000000 0xe800000 0xf000000 0xf800000]
+                                    }
+                                }
+                            }
+                        }
+                        0x14000000
+                        {
+                            {
+                                {
+                                    {
+                                        [0x14000000 0xaa00000 0xb400000 0xbe00000 0xc800000 0xd200000 0xdc00000 0xe600000 0xf000000 0xfa00000 0x10400000 0x10e00000 0x11800000 0x12200000 0x12c00000 0x13600000]
+                                        0x18000000
+                                        [0x18000000 0xcc00000 0xd800000 0xe400000 0xf000000 0xfc00000 0x10800000 0x11400000 0x12000000 0x12c00000 0x13800000 0x14400000 0x15000000 0x15c00000 0x16800000 0x17400000]
+                                    }
+                                    0x1c000000
+                                    {
+                                        [0x1c000000 0xee00000 0xfc00000 0x10a00000 0x11800000 0x12600000 0x13400000 0x14200000 0x15000000 0x15e00000 0x16c00000 0x17a00000 0x18800000 0x19600000 0x1a400000 0x1b200000]
+                                        0x20000000
+                                        [0x20000000 0x11000000 0x12000000 0x13000000 0x14000000 0x15000000 0x16000000 0x17000000 0x18000000 0x19000000 0x1a000000 0x1b000000 0x1c000000 0x1d000000 0x1e000000 0x1f000000]
+                                    }
+                                }
+                                0x28000000
+                                {
+                                    {
+                                        [0x28000000 0x15400000 0x16800000 0x17c00000 0x19000000 0x1a400000 0x1b800000 0x1cc00000 0x1e000000 0x1f400000 0x20800000 0x21c00000 0x23000000 0x24400000 0x25800000 0x26c00000]
+                                        0x30000000
+                                        [0x30000000 0x19800000 0x1b000000 0x1c800000 0x1e000000 0x1f800000 0x21000000 0x22800000 0x24000000 0x25800000 0x27000000 0x28800000 0x2a000000 0x2b800000 0x2d000000 0x2e800000]
+                                    }
+                                    0x38000000
+                                    {
+                                        [0x38000000 0x1dc00000 0x1f800000 0x21400000 0x23000000 0x24c00000 0x26800000 0x28400000 0x2a000000 0x2bc00000 0x2d800000 0x2f400000 0x31000000 0x32c00000 0x34800000 0x36400000]
+                                        0x40000000
+                                        [0x40000000 0x22000000 0x24000000 0x26000000 0x28000000 0x2a000000 0x2c000000 0x2e000000 0x30000000 0x32000000 0x34000000 0x36000000 0x38000000 0x3a000000 0x3c000000 0x3e000000]
+                                    }
+                                }
+                            }
+                            0x50000000
+                            {
+                                {
+                                    {
+                                        [0x50000000 0x2a800000 0x2d000000 0x2f800000 0x32000000 0x34800000 0x37000000 0x39800000 0x3c000000 0x3e800000 0x41000000 0x43800000 0x46000000 0x48800000 0x4b000000 0x4d800000]
+                                        0x60000000
+                                        [0x60000000 0x33000000 0x36000000 0x39000000 0x3c000000 0x3f000000 0x42000000 0x45000000 0x48000000 0x4b000000 0x4e000000 0x51000000 0x54000000 0x57000000 0x5a000000 0x5d000000]
+                                    }
+                                    0x70000000
+                                    {
+                                        [0x70000000 0x3b800000 0x3f000000 0x42800000 0x46000000 0x49800000 0x4d000000 0x50800000 0x54000000 0x57800000 0x5b000000 0x5e800000 0x62000000 0x65800000 0x69000000 0x6c800000]
+                                        0x80000000
+                                        [0x80000000 0x44000000 0x48000000 0x4c000000 0x50000000 0x54000000 0x58000000 0x5c000000 0x60000000 0x64000000 0x68000000 0x6c000000 0x70000000 0x74000000 0x78000000 0x7c000000]
+                                    }
+                                }
+                                0xa0000000
+                                {
+                                    {
+                                        [0xa0000000 0x55000000 0x5a000000 0x5f000000 0x64000000 0x69000000 0x6e000000 0x73000000 0x78000000 0x7d000000 0x82000000 0x87000000 0x8c000000 0x91000000 0x96000000 0x9b000000]
+                                        0xc0000000
+                                        [0xc0000000 0x66000000 0x6c000000 0x72000000 0x78000000 0x7e000000 0x84000000 0x8a000000 0x90000000 0x96000000 0x9c000000 0xa2000000 0xa8000000 0xae000000 0xb4000000 0xba000000]
+                                    }
+                                    0xe0000000
+                                    {
+                                        [0xe0000000 0x77000000 0x7e000000 0x85000000 0x8c000000 0x93000000 0x9a000000 0xa1000000 0xa8000000 0xaf000000 0xb6000000 0xbd000000 0xc4000000 0xcb000000 0xd2000000 0xd9000000]
+                                        0xffffffff
+                                        [0xffffffff 0x87ffffff 0x8fffffff 0x97ffffff 0x9fffffff 0xa7ffffff 0xafffffff 0xb7ffffff 0xbfffffff 0xc7ffffff 0xcfffffff 0xd7ffffff 0xdfffffff 0xe7ffffff 0xefffffff 0xf7ffffff]
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "ladder-step-1_0625", target_pointer_width = "64"))]
+macro_rules! refine_tree {
+    ($mac:ident { $($args:tt)* }) => {
+        $mac! {
+            $($args)*
+            {
+                {
+                    {
+                        {
+                            {
+                                {
+                                    {
+                                        [3 2]
+                                        4
+                                        {
+                                            [4 3]
+                                            5
+                                            [5 3 4]
+                                        }
+                                    }
+                                    6
+                                    {
+                                        {
+                                            [6 4 5]
+                                            7
+                                            [7 4 5 6]
+                                        }
+                                        8
+                                        {
+                                            [8 5 6 7]
+                                            10
+                                            [10 6 7 8 9]
+                                        }
+                                    }
+                                }
+                                12
+                                {
+                                    {
+                                        {
+                                            [12 7 8 9 10 11]
+                                            14
+                                            [14 8 9 10 11 12 13]
+                                        }
+                                        16
+                                        {
+                                            [16 9 10 11 12 13 14 15]
+                                            20
+                                            [20 11 12 13 14 15 16 17 18 19]
+                                        }
+                                    }
+                                    24
+                                    {
+                                        {
+                                            [24 13 14 15 16 17 18 19 20 21 22 23]
+                                            28
+                                            [28 15 16 17 18 19 20 21 22 23 24 25 26 27]
+                                        }
+                                        32
+                                        {
+                                            [32 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31]
+                                            40
+                                            [40 22 23 24 25 27 28 29 30 32 33 34 35 37 38 39]
+                                        }
+                                    }
+                                }
+                            }
+                            48
+                            {
+                                {
+                                    {
+                                        {
+                                            [48 26 27 29 30 32 33 35 36 38 39 41 42 44 45 47]
+                                            56
+                                            [56 30 32 34 35 37 39 41 42 44 46 48 49 51 53 55]
+                                        }
+                                        64
+                                        {
+                                            [64 34 36 38 40 42 44 46 48 50 52 54 56 58 60 62]
+                                            80
+                                            [80 43 45 48 50 53 55 58 60 63 65 68 70 73 75 78]
+                                        }
+                                    }
+                                    96
+                                    {
+                                        {
+                                            [96 51 54 57 60 63 66 69 72 75 78 81 84 87 90 93]
+                                            112
+                                            [112 60 63 67 70 74 77 81 84 88 91 95 98 102 105 109]
+                                        }
+                                        128
+                                        {
+                                            [128 68 72 76 80 84 88 92 96 100 104 108 112 116 120 124]
+                                            160
+                                            [160 85 90 95 100 105 110 115 120 125 130 135 140 145 150 155]
+                                        }
+                                    }
+                                }
+                                192
+                                {
+                                    {
+                                        {
+                                            [192 102 108 114 120 126 132 138 144 150 156 162 168 174 180 186]
+                                            224
+                                            [224 119 126 133 140 147 154 161 168 175 182 189 196 203 210 217]
+                                        }
+                                        256
+                                        {
+                                            [256 136 144 152 160 168 176 184 192 200 208 216 224 232 240 248]
+                                            0x140
+                                            [0x140 170 180 190 200 210 220 230 240 250 0x104 0x10e 0x118 0x122 0x12c 0x136]
+                                        }
+                                    }
+                                    0x180
+                                    {
+                                        {
+                                            [0x180 204 216 228 240 252 0x108 0x114 0x120 0x12c 0x138 0x144 0x150 0x15c 0x168 0x174]
+                                            0x1c0
+                                            [0x1c0 238 252 0x10a 0x118 0x126 0x134 0x142 0x150 0x15e 0x16c 0x17a 0x188 0x196 0x1a4 0x1b2]
+                                        }
+                                        0x200
+                                        {
+                                            [0x200 0x110 0x120 0x130 0x140 0x150 0x160 0x170 0x180 0x190 0x1a0 0x1b0 0x1c0 0x1d0 0x1e0 0x1f0]
+                                            0x280
+                                            [0x280 0x154 0x168 0x17c 0x190 0x1a4 0x1b8 0x1cc 0x1e0 0x1f4 0x208 0x21c 0x230 0x244 0x258 0x26c]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        0x300
+                        {
+                            {
+                                {
+                                    {
+                                        [0x300 0x198 0x1b0 0x1c8 0x1e0 0x1f8 0x210 0x228 0x240 0x258 0x270 0x288 0x2a0 0x2b8 0x2d0 0x2e8]
+                                        0x380
+                                        {
+                                            [0x380 0x1dc 0x1f8 0x214 0x230 0x24c 0x268 0x284 0x2a0 0x2bc 0x2d8 0x2f4 0x310 0x32c 0x348 0x364]
+                                            0x400
+                                            [0x400 0x220 0x240 0x260 0x280 0x2a0 0x2c0 0x2e0 0x300 0x320 0x340 0x360 0x380 0x3a0 0x3c0 0x3e0]
+                                        }
+                                    }
+                                    0x500
+                                    {
+                                        {
+                                            [0x500 0x2a8 0x2d0 0x2f8 0x320 0x348 0x370 0x398 0x3c0 0x3e8 0x410 0x438 0x460 0x488 0x4b0 0x4d8]
+                                            0x600
+                                            [0x600 0x330 0x360 0x390 0x3c0 0x3f0 0x420 0x450 0x480 0x4b0 0x4e0 0x510 0x540 0x570 0x5a0 0x5d0]
+                                        }
+                                        0x700
+                                        {
+                                            [0x700 0x3b8 0x3f0 0x428 0x460 0x498 0x4d0 0x508 0x540 0x578 0x5b0 0x5e8 0x620 0x658 0x690 0x6c8]
+                                            0x800
+                                            [0x800 0x440 0x480 0x4c0 0x500 0x540 0x580 0x5c0 0x600 0x640 0x680 0x6c0 0x700 0x740 0x780 0x7c0]
+                                        }
+                                    }
+                                }
+                                0xa00
+                                {
+                                    {
+                                        {
+                                            [0xa00 0x550 0x5a0 0x5f0 0x640 0x690 0x6e0 0x730 0x780 0x7d0 0x820 0x870 0x8c0 0x910 0x960 0x9b0]
+                                            0xc00
+                                            [0xc00 0x660 0x6c0 0x720 0x780 0x7e0 0x840 0x8a0 0x900 0x960 0x9c0 0xa20 0xa80 0xae0 0xb40 0xba0]
+                                        }
+                                        0xe00
+                                        {
+                                            [0xe00 0x770 0x7e0 0x850 0x8c0 0x930 0x9a0 0xa10 0xa80 0xaf0 0xb60 0xbd0 0xc40 0xcb0 0xd20 0xd90]
+                                            0x1000
+                                            [0x1000 0x880 0x900 0x980 0xa00 0xa80 0xb00 0xb80 0xc00 0xc80 0xd00 0xd80 0xe00 0xe80 0xf00 0xf80]
+                                        }
+                                    }
+                                    0x1400
+                                    {
+                                        {
+                                            [0x1400 0xaa0 0xb40 0xbe0 0xc80 0xd20 0xdc0 0xe60 0xf00 0xfa0 0x1040 0x10e0 0x1180 0x1220 0x12c0 0x1360]
+                                            0x1800
+                                            [0x1800 0xcc0 0xd80 0xe40 0xf00 0xfc0 0x1080 0x1140 0x1200 0x12c0 0x1380 0x1440 0x1500 0x15c0 0x1680 0x1740]
+                                        }
+                                        0x1c00
+                                        {
+                                            [0x1c00 0xee0 0xfc0 0x10a0 0x1180 0x1260 0x1340 0x1420 0x1500 0x15e0 0x16c0 0x17a0 0x1880 0x1960 0x1a40 0x1b20]
+                                            0x2000
+                                            [0x2000 0x1100 0x1200 0x1300 0x1400 0x1500 0x1600 0x1700 0x1800 0x1900 0x1a00 0x1b00 0x1c00 0x1d00 0x1e00 0x1f00]
+                                        }
+                                    }
+                                }
+                            }
+                            0x2800
+                            {
+                                {
+                                    {
+                                        {
+                                            [0x2800 0x1540 0x1680 0x17c0 0x1900 0x1a40 0x1b80 0x1cc0 0x1e00 0x1f40 0x2080 0x21c0 0x2300 0x2440 0x2580 0x26c0]
+                                            0x3000
+                                            [0x3000 0x1980 0x1b00 0x1c80 0x1e00 0x1f80 0x2100 0x2280 0x2400 0x2580 0x2700 0x2880 0x2a00 0x2b80 0x2d00 0x2e80]
+                                        }
+                                        0x3800
+                                        {
+                                            [0x3800 0x1dc0 0x1f80 0x2140 0x2300 0x24c0 0x2680 0x2840 0x2a00 0x2bc0 0x2d80 0x2f40 0x3100 0x32c0 0x3480 0x3640]
+                                            0x4000
+                                            [0x4000 0x2200 0x2400 0x2600 0x2800 0x2a00 0x2c00 0x2e00 0x3000 0x3200 0x3400 0x3600 0x3800 0x3a00 0x3c00 0x3e00]
+                                        }
+                                    }
+                                    0x5000
+                                    {
+                                        {
+                                            [0x5000 0x2a80 0x2d00 0x2f80 0x3200 0x3480 0x3700 0x3980 0x3c00 0x3e80 0x4100 0x4380 0x4600 0x4880 0x4b00 0x4d80]
+                                            0x6000
+                                            [0x6000 0x3300 0x3600 0x3900 0x3c00 0x3f00 0x4200 0x4500 0x4800 0x4b00 0x4e00 0x5100 0x5400 0x5700 0x5a00 0x5d00]
+                                        }
+                                        0x7000
+                                        {
+                                            [0x7000 0x3b80 0x3f00 0x4280 0x4600 0x4980 0x4d00 0x5080 0x5400 0x5780 0x5b00 0x5e80 0x6200 0x6580 0x6900 0x6c80]
+                                            0x8000
+                                            [0x8000 0x4400 0x4800 0x4c00 0x5000 0x5400 0x5800 0x5c00 0x6000 0x6400 0x6800 0x6c00 0x7000 0x7400 0x7800 0x7c00]
+                                        }
+                                    }
+                                }
+                                0xa000
+                                {
+                                    {
+                                        {
+                                            [0xa000 0x5500 0x5a00 0x5f00 0x6400 0x6900 0x6e00 0x7300 0x7800 0x7d00 0x8200 0x8700 0x8c00 0x9100 0x9600 0x9b00]
+                                            0xc000
+                                            [0xc000 0x6600 0x6c00 0x7200 0x7800 0x7e00 0x8400 0x8a00 0x9000 0x9600 0x9c00 0xa200 0xa800 0xae00 0xb400 0xba00]
+                                        }
+                                        0xe000
+                                        {
+                                            [0xe000 0x7700 0x7e00 0x8500 0x8c00 0x9300 0x9a00 0xa100 0xa800 0xaf00 0xb600 0xbd00 0xc400 0xcb00 0xd200 0xd900]
+                                            0x10000
+                                            [0x10000 0x8800 0x9000 0x9800 0xa000 0xa800 0xb000 0xb800 0xc000 0xc800 0xd000 0xd800 0xe000 0xe800 0xf000 0xf800]
+                                        }
+                                    }
+                                    0x14000
+                                    {
+                                        {
+                                            [0x14000 0xaa00 0xb400 0xbe00 0xc800 0xd200 0xdc00 0xe600 0xf000 0xfa00 0x10400 0x10e00 0x11800 0x12200 0x12c00 0x13600]
+                                            0x18000
+                                            [0x18000 0xcc00 0xd800 0xe400 0xf000 0xfc00 0x10800 0x11400 0x12000 0x12c00 0x13800 0x14400 0x15000 0x15c00 0x16800 0x17400]
+                                        }
+                                        0x1c000
+                                        {
+                                            [0x1c000 0xee00 0xfc00 0x10a00 0x11800 0x12600 0x13400 0x14200 0x15000 0x15e00 0x16c00 0x17a00 0x18800 0x19600 0x1a400 0x1b200]
+                                            0x20000
+                                            [0x20000 0x11000 0x12000 0x13000 0x14000 0x15000 0x16000 0x17000 0x18000 0x19000 0x1a000 0x1b000 0x1c000 0x1d000 0x1e000 0x1f000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    0x28000
+                    {
+                        {
+                            {
+                                {
+                                    {
+                                        [0x28000 0x15400 0x16800 0x17c00 0x19000 0x1a400 0x1b800 0x1cc00 0x1e000 0x1f400 0x20800 0x21c00 0x23000 0x24400 0x25800 0x26c00]
+                                        0x30000
+                                        {
+                                            [0x30000 0x19800 0x1b000 0x1c800 0x1e000 0x1f800 0x21000 0x22800 0x24000 0x25800 0x27000 0x28800 0x2a000 0x2b800 0x2d000 0x2e800]
+                                            0x38000
+                                            [0x38000 0x1dc00 0x1f800 0x21400 0x23000 0x24c00 0x26800 0x28400 0x2a000 0x2bc00 0x2d800 0x2f400 0x31000 0x32c00 0x34800 0x36400]
+                                        }
+                                    }
+                                    0x40000
+                                    {
+                                        {
+                                            [0x40000 0x22000 0x24000 0x26000 0x28000 0x2a000 0x2c000 0x2e000 0x30000 0x32000 0x34000 0x36000 0x38000 0x3a000 0x3c000 0x3e000]
+                                            0x50000
+                                            [0x50000 0x2a800 0x2d000 0x2f800 0x32000 0x34800 0x37000 0x39800 0x3c000 0x3e800 0x41000 0x43800 0x46000 0x48800 0x4b000 0x4d800]
+                                        }
+                                        0x60000
+                                        {
+                                            [0x60000 0x33000 0x36000 0x39000 0x3c000 0x3f000 0x42000 0x45000 0x48000 0x4b000 0x4e000 0x51000 0x54000 0x57000 0x5a000 0x5d000]
+                                            0x70000
+                                            [0x70000 0x3b800 0x3f000 0x42800 0x46000 0x49800 0x4d000 0x50800 0x54000 0x57800 0x5b000 0x5e800 0x62000 0x65800 0x69000 0x6c800]
+                                        }
+                                    }
+                                }
+                                0x80000
+                                {
+                                    {
+                                        {
+                                            [0x80000 0x44000 0x48000 0x4c000 0x50000 0x54000 0x58000 0x5c000 0x60000 0x64000 0x68000 0x6c000 0x70000 0x74000 0x78000 0x7c000]
+                                            0xa0000
+                                            [0xa0000 0x55000 0x5a000 0x5f000 0x64000 0x69000 0x6e000 0x73000 0x78000 0x7d000 0x82000 0x87000 0x8c000 0x91000 0x96000 0x9b000]
+                                        }
+                                        0xc0000
+                                        {
+                                            [0xc0000 0x66000 0x6c000 0x72000 0x78000 0x7e000 0x84000 0x8a000 0x90000 0x96000 0x9c000 0xa2000 0xa8000 0xae000 0xb4000 0xba000]
+                                            0xe0000
+                                            [0xe0000 0x77000 0x7e000 0x85000 0x8c000 0x93000 0x9a000 0xa1000 0xa8000 0xaf000 0xb6000 0xbd000 0xc4000 0xcb000 0xd2000 0xd9000]
+                                        }
+                                    }
+                                    0x100000
+                                    {
+                                        {
+                                            [0x100000 0x88000 0x90000 0x98000 0xa0000 0xa8000 0xb0000 0xb8000 0xc0000 0xc8000 0xd0000 0xd8000 0xe0000 0xe8000 0xf0000 0xf8000]
+                                            0x140000
+                                            [0x140000 0xaa000 0xb4000 0xbe000 0xc8000 0xd2000 0xdc000 0xe6000 0xf0000 0xfa000 0x104000 0x10e000 0x118000 0x122000 0x12c000 0x136000]
+                                        }
+                                        0x180000
+                                        {
+                                            [0x180000 0xcc000 0xd8000 0xe4000 0xf0000 0xfc000 0x108000 0x114000 0x120000 0x12c000 0x138000 0x144000 0x150000 0x15c000 0x168000 0x174000]
+                                            0x1c0000
+                                            [0x1c0000 0xee000 0xfc000 0x10a000 0x118000 0x126000 0x134000 0x142000 0x150000 0x15e000 0x16c000 0x17a000 0x188000 0x196000 0x1a4000 0x1b2000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x200000
+                            {
+                                {
+                                    {
+                                        {
+                                            [0x200000 0x110000 0x120000 0x130000 0x140000 0x150000 0x160000 0x170000 0x180000 0x190000 0x1a0000 0x1b0000 0x1c0000 0x1d0000 0x1e0000 0x1f0000]
+                                            0x280000
+                                            [0x280000 0x154000 0x168000 0x17c000 0x190000 0x1a4000 0x1b8000 0x1cc000 0x1e0000 0x1f4000 0x208000 0x21c000 0x230000 0x244000 0x258000 0x26c000]
+                                        }
+                                        0x300000
+                                        {
+                                            [0x300000 0x198000 0x1b0000 0x1c8000 0x1e0000 0x1f8000 0x210000 0x228000 0x240000 0x258000 0x270000 0x288000 0x2a0000 0x2b8000 0x2d0000 0x2e8000]
+                                            0x380000
+                                            [0x380000 0x1dc000 0x1f8000 0x214000 0x230000 0x24c000 0x268000 0x284000 0x2a0000 0x2bc000 0x2d8000 0x2f4000 0x310000 0x32c000 0x348000 0x364000]
+                                        }
+                                    }
+                                    0x400000
+                                    {
+                                        {
+                                            [0x400000 0x220000 0x240000 0x260000 0x280000 0x2a0000 0x2c0000 0x2e0000 0x300000 0x320000 0x340000 0x360000 0x380000 0x3a0000 0x3c0000 0x3e0000]
+                                            0x500000
+                                            [0x500000 0x2a8000 0x2d0000 0x2f8000 0x320000 0x348000 0x370000 0x398000 0x3c0000 0x3e8000 0x410000 0x438000 0x460000 0x488000 0x4b0000 0x4d8000]
+                                        }
+                                        0x600000
+                                        {
+                                            [0x600000 0x330000 0x360000 0x390000 0x3c0000 0x3f0000 0x420000 0x450000 0x480000 0x4b0000 0x4e0000 0x510000 0x540000 0x570000 0x5a0000 0x5d0000]
+                                            0x700000
+                                            [0x700000 0x3b8000 0x3f0000 0x428000 0x460000 0x498000 0x4d0000 0x508000 0x540000 0x578000 0x5b0000 0x5e8000 0x620000 0x658000 0x690000 0x6c8000]
+                                        }
+                                    }
+                                }
+                                0x800000
+                                {
+                                    {
+                                        {
+                                            [0x800000 0x440000 0x480000 0x4c0000 0x500000 0x540000 0x580000 0x5c0000 0x600000 0x640000 0x680000 0x6c0000 0x700000 0x740000 0x780000 0x7c0000]
+                                            0xa00000
+                                            [0xa00000 0x550000 0x5a0000 0x5f0000 0x640000 0x690000 0x6e0000 0x730000 0x780000 0x7d0000 0x820000 0x870000 0x8c0000 0x910000 0x960000 0x9b0000]
+                                        }
+                                        0xc00000
+                                        {
+                                            [0xc00000 0x660000 0x6c0000 0x720000 0x780000 0x7e0000 0x840000 0x8a0000 0x900000 0x960000 0x9c0000 0xa20000 0xa80000 0xae0000 0xb40000 0xba0000]
+                                            0xe00000
+                                            [0xe00000 0x770000 0x7e0000 0x850000 0x8c0000 0x930000 0x9a0000 0xa10000 0xa80000 0xaf0000 0xb60000 0xbd0000 0xc40000 0xcb0000 0xd20000 0xd90000]
+                                        }
+                                    }
+                                    0x1000000
+                                    {
+                                        {
+                                            [0x1000000 0x880000 0x900000 0x980000 0xa00000 0xa80000 0xb00000 0xb80000 0xc00000 0xc80000 0xd00000 0xd80000 0xe00000 0xe80000 0xf00000 0xf80000]
+                                            0x1400000
+                                            [0x1400000 0xaa0000 0xb40000 0xbe0000 0xc80000 0xd20000 0xdc0000 0xe60000 0xf00000 0xfa0000 0x1040000 0x10e0000 0x1180000 0x1220000 0x12c0000 0x1360000]
+                                        }
+                                        0x1800000
+                                        {
+                                            [0x1800000 0xcc0000 0xd80000 0xe40000 0xf00000 0xfc0000 0x1080000 0x1140000 0x1200000 0x12c0000 0x1380000 0x1440000 0x1500000 0x15c0000 0x1680000 0x1740000]
+                                            0x1c00000
+                                            [0x1c00000 0xee0000 0xfc0000 0x10a0000 0x1180000 0x1260000 0x1340000 0x1420000 0x1500000 0x15e0000 0x16c0000 0x17a0000 0x1880000 0x1960000 0x1a40000 0x1b20000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        0x2000000
+                        {
+                            {
+                                {
+                                    {
+                                        {
+                                            [0x2000000 0x1100000 0x1200000 0x1300000 0x1400000 0x1500000 0x1600000 0x1700000 0x1800000 0x1900000 0x1a00000 0x1b00000 0x1c00000 0x1d00000 0x1e00000 0x1f00000]
+                                            0x2800000
+                                            [0x2800000 0x1540000 0x1680000 0x17c0000 0x1900000 0x1a40000 0x1b80000 0x1cc0000 0x1e00000 0x1f40000 0x2080000 0x21c0000 0x2300000 0x2440000 0x2580000 0x26c0000]
+                                        }
+                                        0x3000000
+                                        {
+                                            [0x3000000 0x1980000 0x1b00000 0x1c80000 0x1e00000 0x1f80000 0x2100000 0x2280000 0x2400000 0x2580000 0x2700000 0x2880000 0x2a00000 0x2b80000 0x2d00000 0x2e80000]
+                                            0x3800000
+                                            [0x3800000 0x1dc0000 0x1f80000 0x2140000 0x2300000 0x24c0000 0x2680000 0x2840000 0x2a00000 0x2bc0000 0x2d80000 0x2f40000 0x3100000 0x32c0000 0x3480000 0x3640000]
+                                        }
+                                    }
+                                    0x4000000
+                                    {
+                                        {
+                                            [0x4000000 0x2200000 0x2400000 0x2600000 0x2800000 0x2a00000 0x2c00000 0x2e00000 0x3000000 0x3200000 0x3400000 0x3600000 0x3800000 0x3a00000 0x3c00000 0x3e00000]
+                                            0x5000000
+                                            [0x5000000 0x2a80000 0x2d00000 0x2f80000 0x3200000 0x3480000 0x3700000 0x3980000 0x3c00000 0x3e80000 0x4100000 0x4380000 0x4600000 0x4880000 0x4b00000 0x4d80000]
+                                        }
+                                        0x6000000
+                                        {
+                                            [0x6000000 0x3300000 0x3600000 0x3900000 0x3c00000 0x3f00000 0x4200000 0x4500000 0x4800000 0x4b00000 0x4e00000 0x5100000 0x5400000 0x5700000 0x5a00000 0x5d00000]
+                                            0x7000000
+                                            [0x7000000 0x3b80000 0x3f00000 0x4280000 0x4600000 0x4980000 0x4d00000 0x5080000 0x5400000 0x5780000 0x5b00000 0x5e80000 0x6200000 0x6580000 0x6900000 0x6c80000]
+                                        }
+                                    }
+                                }
+                                0x8000000
+                                {
+                                    {
+                                        {
+                                            [0x8000000 0x4400000 0x4800000 0x4c00000 0x5000000 0x5400000 0x5800000 0x5c00000 0x6000000 0x6400000 0x6800000 0x6c00000 0x7000000 0x7400000 0x7800000 0x7c00000]
+                                            0xa000000
+                                            [0xa000000 0x5500000 0x5a00000 0x5f00000 0x6400000 0x6900000 0x6e00000 0x7300000 0x7800000 0x7d00000 0x8200000 0x8700000 0x8c00000 0x9100000 0x9600000 0x9b00000]
+                                        }
+                                        0xc000000
+                                        {
+                                            [0xc000000 0x6600000 0x6c00000 0x7200000 0x7800000 0x7e00000 0x8400000 0x8a00000 0x9000000 0x9600000 0x9c00000 0xa200000 0xa800000 0xae00000 0xb400000 0xba00000]
+                                            0xe000000
+                                            [0xe000000 0x7700000 0x7e00000 0x8500000 0x8c00000 0x9300000 0x9a00000 0xa100000 0xa800000 0xaf00000 0xb600000 0xbd00000 0xc400000 0xcb00000 0xd200000 0xd900000]
+                                        }
+                                    }
+                                    0x10000000
+                                    {
+                                        {
+                                            [0x10000000 0x8800000 0x9000000 0x9800000 0xa000000 0xa800000 0xb000000 0xb800000 0xc000000 0xc800000 0xd000000 0xd800000 0xe000000 0xe800000 0xf000000 0xf800000]
+                                            0x14000000
+                                            [0x14000000 0xaa00000 0xb400000 0xbe00000 0xc800000 0xd200000 0xdc00000 0xe600000 0xf000000 0xfa00000 0x10400000 0x10e00000 0x11800000 0x12200000 0x12c00000 0x13600000]
+                                        }
+                                        0x18000000
+                                        {
+                                            [0x18000000 0xcc00000 0xd800000 0xe400000 0xf000000 0xfc00000 0x10800000 0x11400000 0x12000000 0x12c00000 0x13800000 0x14400000 0x15000000 0x15c00000 0x16800000 0x17400000]
+                                            0x1c000000
+                                            [0x1c000000 0xee00000 0xfc00000 0x10a00000 0x11800000 0x12600000 0x13400000 0x14200000 0x15000000 0x15e00000 0x16c00000 0x17a00000 0x18800000 0x19600000 0x1a400000 0x1b200000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x20000000
+                            {
+                                {
+                                    {
+                                        {
+                                            [0x20000000 0x11000000 0x12000000 0x13000000 0x14000000 0x15000000 0x16000000 0x17000000 0x18000000 0x19000000 0x1a000000 0x1b000000 0x1c000000 0x1d000000 0x1e000000 0x1f000000]
+                                            0x28000000
+                                            [0x28000000 0x15400000 0x16800000 0x17c00000 0x19000000 0x1a400000 0x1b800000 0x1cc00000 0x1e000000 0x1f400000 0x20800000 0x21c00000 0x23000000 0x24400000 0x25800000 0x26c00000]
+                                        }
+                                        0x30000000
+                                        {
+                                            [0x30000000 0x19800000 0x1b000000 0x1c800000 0x1e000000 0x1f800000 0x21000000 0x22800000 0x24000000 0x25800000 0x27000000 0x28800000 0x2a000000 0x2b800000 0x2d000000 0x2e800000]
+                                            0x38000000
+                                            [0x38000000 0x1dc00000 0x1f800000 0x21400000 0x23000000 0x24c00000 0x26800000 0x28400000 0x2a000000 0x2bc00000 0x2d800000 0x2f400000 0x31000000 0x32c00000 0x34800000 0x36400000]
+                                        }
+                                    }
+                                    0x40000000
+                                    {
+                                        {
+                                            [0x40000000 0x22000000 0x24000000 0x26000000 0x28000000 0x2a000000 0x2c000000 0x2e000000 0x30000000 0x32000000 0x34000000 0x36000000 0x38000000 0x3a000000 0x3c000000 0x3e000000]
+                                            0x50000000
+                                            [0x50000000 0x2a800000 0x2d000000 0x2f800000 0x32000000 0x34800000 0x37000000 0x39800000 0x3c000000 0x3e800000 0x41000000 0x43800000 0x46000000 0x48800000 0x4b000000 0x4d800000]
+                                        }
+                                        0x60000000
+                                        {
+                                            [0x60000000 0x33000000 0x36000000 0x39000000 0x3c000000 0x3f000000 0x42000000 0x45000000 0x48000000 0x4b000000 0x4e000000 0x51000000 0x54000000 0x57000000 0x5a000000 0x5d000000]
+                                            0x70000000
+                                            [0x70000000 0x3b800000 0x3f000000 0x42800000 0x46000000 0x49800000 0x4d000000 0x50800000 0x54000000 0x57800000 0x5b000000 0x5e800000 0x62000000 0x65800000 0x69000000 0x6c800000]
+                                        }
+                                    }
+                                }
+                                0x80000000
+                                {
+                                    {
+                                        {
+                                            [0x80000000 0x44000000 0x48000000 0x4c000000 0x50000000 0x54000000 0x58000000 0x5c000000 0x60000000 0x64000000 0x68000000 0x6c000000 0x70000000 0x74000000 0x78000000 0x7c000000]
+                                            0xa0000000
+                                            [0xa0000000 0x55000000 0x5a000000 0x5f000000 0x64000000 0x69000000 0x6e000000 0x73000000 0x78000000 0x7d000000 0x82000000 0x87000000 0x8c000000 0x91000000 0x96000000 0x9b000000]
+                                        }
+                                        0xc0000000
+                                        {
+                                            [0xc0000000 0x66000000 0x6c000000 0x72000000 0x78000000 0x7e000000 0x84000000 0x8a000000 0x90000000 0x96000000 0x9c000000 0xa2000000 0xa8000000 0xae000000 0xb4000000 0xba000000]
+                                            0xe0000000
+                                            [0xe0000000 0x77000000 0x7e000000 0x85000000 0x8c000000 0x93000000 0x9a000000 0xa1000000 0xa8000000 0xaf000000 0xb6000000 0xbd000000 0xc4000000 0xcb000000 0xd2000000 0xd9000000]
+                                        }
+                                    }
+                                    0x100000000
+                                    {
+                                        {
+                                            [0x100000000 0x88000000 0x90000000 0x98000000 0xa0000000 0xa8000000 0xb0000000 0xb8000000 0xc0000000 0xc8000000 0xd0000000 0xd8000000 0xe0000000 0xe8000000 0xf0000000 0xf8000000]
+                                            0x140000000
+                                            [0x140000000 0xaa000000 0xb4000000 0xbe000000 0xc8000000 0xd2000000 0xdc000000 0xe6000000 0xf0000000 0xfa000000 0x104000000 0x10e000000 0x118000000 0x122000000 0x12c000000 0x136000000]
+                                        }
+                                        0x180000000
+                                        {
+                                            [0x180000000 0xcc000000 0xd8000000 0xe4000000 0xf0000000 0xfc000000 0x108000000 0x114000000 0x120000000 0x12c000000 0x138000000 0x144000000 0x150000000 0x15c000000 0x168000000 0x174000000]
+                                            0x1c0000000
+                                            [0x1c0000000 0xee000000 0xfc000000 0x10a000000 0x118000000 0x126000000 0x134000000 0x142000000 0x150000000 0x15e000000 0x16c000000 0x17a000000 0x188000000 0x196000000 0x1a4000000 0x1b2000000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                0x200000000
+                {
+                    {
+                        {
+                            {
+                                {
+                                    {
+                                        [0x200000000 0x110000000 0x120000000 0x130000000 0x140000000 0x150000000 0x160000000 0x170000000 0x180000000 0x190000000 0x1a0000000 0x1b0000000 0x1c0000000 0x1d0000000 0x1e0000000 0x1f0000000]
+                                        0x280000000
+                                        {
+                                            [0x280000000 0x154000000 0x168000000 0x17c000000 0x190000000 0x1a4000000 0x1b8000000 0x1cc000000 0x1e0000000 0x1f4000000 0x208000000 0x21c000000 0x230000000 0x244000000 0x258000000 0x26c000000]
+                                            0x300000000
+                                            [0x300000000 0x198000000 0x1b0000000 0x1c8000000 0x1e0000000 0x1f8000000 0x210000000 0x228000000 0x240000000 0x258000000 0x270000000 0x288000000 0x2a0000000 0x2b8000000 0x2d0000000 0x2e8000000]
+                                        }
+                                    }
+                                    0x380000000
+                                    {
+                                        {
+                                            [0x380000000 0x1dc000000 0x1f8000000 0x214000000 0x230000000 0x24c000000 0x268000000 0x284000000 0x2a0000000 0x2bc000000 0x2d8000000 0x2f4000000 0x310000000 0x32c000000 0x348000000 0x364000000]
+                                            0x400000000
+                                            [0x400000000 0x220000000 0x240000000 0x260000000 0x280000000 0x2a0000000 0x2c0000000 0x2e0000000 0x300000000 0x320000000 0x340000000 0x360000000 0x380000000 0x3a0000000 0x3c0000000 0x3e0000000]
+                                        }
+                                        0x500000000
+                                        {
+                                            [0x500000000 0x2a8000000 0x2d0000000 0x2f8000000 0x320000000 0x348000000 0x370000000 0x398000000 0x3c0000000 0x3e8000000 0x410000000 0x438000000 0x460000000 0x488000000 0x4b0000000 0x4d8000000]
+                                            0x600000000
+                                            [0x600000000 0x330000000 0x360000000 0x390000000 0x3c0000000 0x3f0000000 0x420000000 0x450000000 0x480000000 0x4b0000000 0x4e0000000 0x510000000 0x540000000 0x570000000 0x5a0000000 0x5d0000000]
+                                        }
+                                    }
+                                }
+                                0x700000000
+                                {
+                                    {
+                                        {
+                                            [0x700000000 0x3b8000000 0x3f0000000 0x428000000 0x460000000 0x498000000 0x4d0000000 0x508000000 0x540000000 0x578000000 0x5b0000000 0x5e8000000 0x620000000 0x658000000 0x690000000 0x6c8000000]
+                                            0x800000000
+                                            [0x800000000 0x440000000 0x480000000 0x4c0000000 0x500000000 0x540000000 0x580000000 0x5c0000000 0x600000000 0x640000000 0x680000000 0x6c0000000 0x700000000 0x740000000 0x780000000 0x7c0000000]
+                                        }
+                                        0xa00000000
+                                        {
+                                            [0xa00000000 0x550000000 0x5a0000000 0x5f0000000 0x640000000 0x690000000 0x6e0000000 0x730000000 0x780000000 0x7d0000000 0x820000000 0x870000000 0x8c0000000 0x910000000 0x960000000 0x9b0000000]
+                                            0xc00000000
+                                            [0xc00000000 0x660000000 0x6c0000000 0x720000000 0x780000000 0x7e0000000 0x840000000 0x8a0000000 0x900000000 0x960000000 0x9c0000000 0xa20000000 0xa80000000 0xae0000000 0xb40000000 0xba0000000]
+                                        }
+                                    }
+                                    0xe00000000
+                                    {
+                                        {
+                                            [0xe00000000 0x770000000 0x7e0000000 0x850000000 0x8c0000000 0x930000000 0x9a0000000 0xa10000000 0xa80000000 0xaf0000000 0xb60000000 0xbd0000000 0xc40000000 0xcb0000000 0xd20000000 0xd90000000]
+                                            0x1000000000
+                                            [0x1000000000 0x880000000 0x900000000 0x980000000 0xa00000000 0xa80000000 0xb00000000 0xb80000000 0xc00000000 0xc80000000 0xd00000000 0xd80000000 0xe00000000 0xe80000000 0xf00000000 0xf80000000]
+                                        }
+                                        0x1400000000
+                                        {
+                                            [0x1400000000 0xaa0000000 0xb40000000 0xbe0000000 0xc80000000 0xd20000000 0xdc0000000 0xe60000000 0xf00000000 0xfa0000000 0x1040000000 0x10e0000000 0x1180000000 0x1220000000 0x12c0000000 0x1360000000]
+                                            0x1800000000
+                                            [0x1800000000 0xcc0000000 0xd80000000 0xe40000000 0xf00000000 0xfc0000000 0x1080000000 0x1140000000 0x1200000000 0x12c0000000 0x1380000000 0x1440000000 0x1500000000 0x15c0000000 0x1680000000 0x1740000000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x1c00000000
+                            {
+                                {
+                                    {
+                                        {
+                                            [0x1c00000000 0xee0000000 0xfc0000000 0x10a0000000 0x1180000000 0x1260000000 0x1340000000 0x1420000000 0x1500000000 0x15e0000000 0x16c0000000 0x17a0000000 0x1880000000 0x1960000000 0x1a40000000 0x1b20000000]
+                                            0x2000000000
+                                            [0x2000000000 0x1100000000 0x1200000000 0x1300000000 0x1400000000 0x1500000000 0x1600000000 0x1700000000 0x1800000000 0x1900000000 0x1a00000000 0x1b00000000 0x1c00000000 0x1d00000000 0x1e00000000 0x1f00000000]
+                                        }
+                                        0x2800000000
+                                        {
+                                            [0x2800000000 0x1540000000 0x1680000000 0x17c0000000 0x1900000000 0x1a40000000 0x1b80000000 0x1cc0000000 0x1e00000000 0x1f40000000 0x2080000000 0x21c0000000 0x2300000000 0x2440000000 0x2580000000 0x26c0000000]
+                                            0x3000000000
+                                            [0x3000000000 0x1980000000 0x1b00000000 0x1c80000000 0x1e00000000 0x1f80000000 0x2100000000 0x2280000000 0x2400000000 0x2580000000 0x2700000000 0x2880000000 0x2a00000000 0x2b80000000 0x2d00000000 0x2e80000000]
+                                        }
+                                    }
+                                    0x3800000000
+                                    {
+                                        {
+                                            [0x3800000000 0x1dc0000000 0x1f80000000 0x2140000000 0x2300000000 0x24c0000000 0x2680000000 0x2840000000 0x2a00000000 0x2bc0000000 0x2d80000000 0x2f40000000 0x3100000000 0x32c0000000 0x3480000000 0x3640000000]
+                                            0x4000000000
+                                            [0x4000000000 0x2200000000 0x2400000000 0x2600000000 0x2800000000 0x2a00000000 0x2c00000000 0x2e00000000 0x3000000000 0x3200000000 0x3400000000 0x3600000000 0x3800000000 0x3a00000000 0x3c00000000 0x3e00000000]
+                                        }
+                                        0x5000000000
+                                        {
+                                            [0x5000000000 0x2a80000000 0x2d00000000 0x2f80000000 0x3200000000 0x3480000000 0x3700000000 0x3980000000 0x3c00000000 0x3e80000000 0x4100000000 0x4380000000 0x4600000000 0x4880000000 0x4b00000000 0x4d80000000]
+                                            0x6000000000
+                                            [0x6000000000 0x3300000000 0x3600000000 0x3900000000 0x3c00000000 0x3f00000000 0x4200000000 0x4500000000 0x4800000000 0x4b00000000 0x4e00000000 0x5100000000 0x5400000000 0x5700000000 0x5a00000000 0x5d00000000]
+                                        }
+                                    }
+                                }
+                                0x7000000000
+                                {
+                                    {
+                                        {
+                                            [0x7000000000 0x3b80000000 0x3f00000000 0x4280000000 0x4600000000 0x4980000000 0x4d00000000 0x5080000000 0x5400000000 0x5780000000 0x5b00000000 0x5e80000000 0x6200000000 0x6580000000 0x6900000000 0x6c80000000]
+                                            0x8000000000
+                                            [0x8000000000 0x4400000000 0x4800000000 0x4c00000000 0x5000000000 0x5400000000 0x5800000000 0x5c00000000 0x6000000000 0x6400000000 0x6800000000 0x6c00000000 0x7000000000 0x7400000000 0x7800000000 0x7c00000000]
+                                        }
+                                        0xa000000000
+                                        {
+                                            [0xa000000000 0x5500000000 0x5a00000000 0x5f00000000 0x6400000000 0x6900000000 0x6e00000000 0x7300000000 0x7800000000 0x7d00000000 0x8200000000 0x8700000000 0x8c00000000 0x9100000000 0x9600000000 0x9b00000000]
+                                            0xc000000000
+                                            [0xc000000000 0x6600000000 0x6c00000000 0x7200000000 0x7800000000 0x7e00000000 0x8400000000 0x8a00000000 0x9000000000 0x9600000000 0x9c00000000 0xa200000000 0xa800000000 0xae00000000 0xb400000000 0xba00000000]
+                                        }
+                                    }
+                                    0xe000000000
+                                    {
+                                        {
+                                            [0xe000000000 0x7700000000 0x7e00000000 0x8500000000 0x8c00000000 0x9300000000 0x9a00000000 0xa100000000 0xa800000000 0xaf00000000 0xb600000000 0xbd00000000 0xc400000000 0xcb00000000 0xd200000000 0xd900000000]
+                                            0x10000000000
+                                            [0x10000000000 0x8800000000 0x9000000000 0x9800000000 0xa000000000 0xa800000000 0xb000000000 0xb800000000 0xc000000000 0xc800000000 0xd000000000 0xd800000000 0xe000000000 0xe800000000 0xf000000000 0xf800000000]
+                                        }
+                                        0x14000000000
+                                        {
+                                            [0x14000000000 0xaa00000000 0xb400000000 0xbe00000000 0xc800000000 0xd200000000 0xdc00000000 0xe600000000 0xf000000000 0xfa00000000 0x10400000000 0x10e00000000 0x11800000000 0x12200000000 0x12c00000000 0x13600000000]
+                                            0x18000000000
+                                            [0x18000000000 0xcc00000000 0xd800000000 0xe400000000 0xf000000000 0xfc00000000 0x10800000000 0x11400000000 0x12000000000 0x12c00000000 0x13800000000 0x14400000000 0x15000000000 0x15c00000000 0x16800000000 0x17400000000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        0x1c000000000
+                        {
+                            {
+                                {
+                                    {
+                                        [0x1c000000000 0xee00000000 0xfc00000000 0x10a00000000 0x11800000000 0x12600000000 0x13400000000 0x14200000000 0x15000000000 0x15e00000000 0x16c00000000 0x17a00000000 0x18800000000 0x19600000000 0x1a400000000 0x1b200000000]
+                                        0x20000000000
+                                        {
+                                            [0x20000000000 0x11000000000 0x12000000000 0x13000000000 0x14000000000 0x15000000000 0x16000000000 0x17000000000 0x18000000000 0x19000000000 0x1a000000000 0x1b000000000 0x1c000000000 0x1d000000000 0x1e000000000 0x1f000000000]
+                                            0x28000000000
+                                            [0x28000000000 0x15400000000 0x16800000000 0x17c00000000 0x19000000000 0x1a400000000 0x1b800000000 0x1cc00000000 0x1e000000000 0x1f400000000 0x20800000000 0x21c00000000 0x23000000000 0x24400000000 0x25800000000 0x26c00000000]
+                                        }
+                                    }
+                                    0x30000000000
+                                    {
+                                        {
+                                            [0x30000000000 0x19800000000 0x1b000000000 0x1c800000000 0x1e000000000 0x1f800000000 0x21000000000 0x22800000000 0x24000000000 0x25800000000 0x27000000000 0x28800000000 0x2a000000000 0x2b800000000 0x2d000000000 0x2e800000000]
+                                            0x38000000000
+                                            [0x38000000000 0x1dc00000000 0x1f800000000 0x21400000000 0x23000000000 0x24c00000000 0x26800000000 0x28400000000 0x2a000000000 0x2bc00000000 0x2d800000000 0x2f400000000 0x31000000000 0x32c00000000 0x34800000000 0x36400000000]
+                                        }
+                                        0x40000000000
+                                        {
+                                            [0x40000000000 0x22000000000 0x24000000000 0x26000000000 0x28000000000 0x2a000000000 0x2c000000000 0x2e000000000 0x30000000000 0x32000000000 0x34000000000 0x36000000000 0x38000000000 0x3a000000000 0x3c000000000 0x3e000000000]
+                                            0x50000000000
+                                            [0x50000000000 0x2a800000000 0x2d000000000 0x2f800000000 0x32000000000 0x34800000000 0x37000000000 0x39800000000 0x3c000000000 0x3e800000000 0x41000000000 0x43800000000 0x46000000000 0x48800000000 0x4b000000000 0x4d800000000]
+                                        }
+                                    }
+                                }
+                                0x60000000000
+                                {
+                                    {
+                                        {
+                                            [0x60000000000 0x33000000000 0x36000000000 0x39000000000 0x3c000000000 0x3f000000000 0x42000000000 0x45000000000 0x48000000000 0x4b000000000 0x4e000000000 0x51000000000 0x54000000000 0x57000000000 0x5a000000000 0x5d000000000]
+                                            0x70000000000
+                                            [0x70000000000 0x3b800000000 0x3f000000000 0x42800000000 0x46000000000 0x49800000000 0x4d000000000 0x50800000000 0x54000000000 0x57800000000 0x5b000000000 0x5e800000000 0x62000000000 0x65800000000 0x69000000000 0x6c800000000]
+                                        }
+                                        0x80000000000
+                                        {
+                                            [0x80000000000 0x44000000000 0x48000000000 0x4c000000000 0x50000000000 0x54000000000 0x58000000000 0x5c000000000 0x60000000000 0x64000000000 0x68000000000 0x6c000000000 0x70000000000 0x74000000000 0x78000000000 0x7c000000000]
+                                            0xa0000000000
+                                            [0xa0000000000 0x55000000000 0x5a000000000 0x5f000000000 0x64000000000 0x69000000000 0x6e000000000 0x73000000000 0x78000000000 0x7d000000000 0x82000000000 0x87000000000 0x8c000000000 0x91000000000 0x96000000000 0x9b000000000]
+                                        }
+                                    }
+                                    0xc0000000000
+                                    {
+                                        {
+                                            [0xc0000000000 0x66000000000 0x6c000000000 0x72000000000 0x78000000000 0x7e000000000 0x84000000000 0x8a000000000 0x90000000000 0x96000000000 0x9c000000000 0xa2000000000 0xa8000000000 0xae000000000 0xb4000000000 0xba000000000]
+                                            0xe0000000000
+                                            [0xe0000000000 0x77000000000 0x7e000000000 0x85000000000 0x8c000000000 0x93000000000 0x9a000000000 0xa1000000000 0xa8000000000 0xaf000000000 0xb6000000000 0xbd000000000 0xc4000000000 0xcb000000000 0xd2000000000 0xd9000000000]
+                                        }
+                                        0x100000000000
+                                        {
+                                            [0x100000000000 0x88000000000 0x90000000000 0x98000000000 0xa0000000000 0xa8000000000 0xb0000000000 0xb8000000000 0xc0000000000 0xc8000000000 0xd0000000000 0xd8000000000 0xe0000000000 0xe8000000000 0xf0000000000 0xf8000000000]
+                                            0x140000000000
+                                            [0x140000000000 0xaa000000000 0xb4000000000 0xbe000000000 0xc8000000000 0xd2000000000 0xdc000000000 0xe6000000000 0xf0000000000 0xfa000000000 0x104000000000 0x10e000000000 0x118000000000 0x122000000000 0x12c000000000 0x136000000000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x180000000000
+                            {
+                                {
+                                    {
+                                        {
+                                            [0x180000000000 0xcc000000000 0xd8000000000 0xe4000000000 0xf0000000000 0xfc000000000 0x108000000000 0x114000000000 0x120000000000 0x12c000000000 0x138000000000 0x144000000000 0x150000000000 0x15c000000000 0x168000000000 0x174000000000]
+                                            0x1c0000000000
+                                            [0x1c0000000000 0xee000000000 0xfc000000000 0x10a000000000 0x118000000000 0x126000000000 0x134000000000 0x142000000000 0x150000000000 0x15e000000000 0x16c000000000 0x17a000000000 0x188000000000 0x196000000000 0x1a4000000000 0x1b2000000000]
+                                        }
+                                        0x200000000000
+                                        {
+                                            [0x200000000000 0x110000000000 0x120000000000 0x130000000000 0x140000000000 0x150000000000 0x160000000000 0x170000000000 0x180000000000 0x190000000000 0x1a0000000000 0x1b0000000000 0x1c0000000000 0x1d0000000000 0x1e0000000000 0x1f0000000000]
+                                            0x280000000000
+                                            [0x280000000000 0x154000000000 0x168000000000 0x17c000000000 0x190000000000 0x1a4000000000 0x1b8000000000 0x1cc000000000 0x1e0000000000 0x1f4000000000 0x208000000000 0x21c000000000 0x230000000000 0x244000000000 0x258000000000 0x26c000000000]
+                                        }
+                                    }
+                                    0x300000000000
+                                    {
+                                        {
+                                            [0x300000000000 0x198000000000 0x1b0000000000 0x1c8000000000 0x1e0000000000 0x1f8000000000 0x210000000000 0x228000000000 0x240000000000 0x258000000000 0x270000000000 0x288000000000 0x2a0000000000 0x2b8000000000 0x2d0000000000 0x2e8000000000]
+                                            0x380000000000
+                                            [0x380000000000 0x1dc000000000 0x1f8000000000 0x214000000000 0x230000000000 0x24c000000000 0x268000000000 0x284000000000 0x2a0000000000 0x2bc000000000 0x2d8000000000 0x2f4000000000 0x310000000000 0x32c000000000 0x348000000000 0x364000000000]
+                                        }
+                                        0x400000000000
+                                        {
+                                            [0x400000000000 0x220000000000 0x240000000000 0x260000000000 0x280000000000 0x2a0000000000 0x2c0000000000 0x2e0000000000 0x300000000000 0x320000000000 0x340000000000 0x360000000000 0x380000000000 0x3a0000000000 0x3c0000000000 0x3e0000000000]
+                                            0x500000000000
+                                            [0x500000000000 0x2a8000000000 0x2d0000000000 0x2f8000000000 0x320000000000 0x348000000000 0x370000000000 0x398000000000 0x3c0000000000 0x3e8000000000 0x410000000000 0x438000000000 0x460000000000 0x488000000000 0x4b0000000000 0x4d8000000000]
+                                        }
+                                    }
+                                }
+                                0x600000000000
+                                {
+                                    {
+                                        {
+                                            [0x600000000000 0x330000000000 0x360000000000 0x390000000000 0x3c0000000000 0x3f0000000000 0x420000000000 0x450000000000 0x480000000000 0x4b0000000000 0x4e0000000000 0x510000000000 0x540000000000 0x570000000000 0x5a0000000000 0x5d0000000000]
+                                            0x700000000000
+                                            [0x700000000000 0x3b8000000000 0x3f0000000000 0x428000000000 0x460000000000 0x498000000000 0x4d0000000000 0x508000000000 0x540000000000 0x578000000000 0x5b0000000000 0x5e8000000000 0x620000000000 0x658000000000 0x690000000000 0x6c8000000000]
+                                        }
+                                        0x800000000000
+                                        {
+                                            [0x800000000000 0x440000000000 0x480000000000 0x4c0000000000 0x500000000000 0x540000000000 0x580000000000 0x5c0000000000 0x600000000000 0x640000000000 0x680000000000 0x6c0000000000 0x700000000000 0x740000000000 0x780000000000 0x7c0000000000]
+                                            0xa00000000000
+                                            [0xa00000000000 0x550000000000 0x5a0000000000 0x5f0000000000 0x640000000000 0x690000000000 0x6e0000000000 0x730000000000 0x780000000000 0x7d0000000000 0x820000000000 0x870000000000 0x8c0000000000 0x910000000000 0x960000000000 0x9b0000000000]
+                                        }
+                                    }
+                                    0xc00000000000
+                                    {
+                                        {
+                                            [0xc00000000000 0x660000000000 0x6c0000000000 0x720000000000 0x780000000000 0x7e0000000000 0x840000000000 0x8a0000000000 0x900000000000 0x960000000000 0x9c0000000000 0xa20000000000 0xa80000000000 0xae0000000000 0xb40000000000 0xba0000000000]
+                                            0xe00000000000
+                                            [0xe00000000000 0x770000000000 0x7e0000000000 0x850000000000 0x8c0000000000 0x930000000000 0x9a0000000000 0xa10000000000 0xa80000000000 0xaf0000000000 0xb60000000000 0xbd0000000000 0xc40000000000 0xcb0000000000 0xd20000000000 0xd90000000000]
+                                        }
+                                        0x1000000000000
+                                        {
+                                            [0x1000000000000 0x880000000000 0x900000000000 0x980000000000 0xa00000000000 0xa80000000000 0xb00000000000 0xb80000000000 0xc00000000000 0xc80000000000 0xd00000000000 0xd80000000000 0xe00000000000 0xe80000000000 0xf00000000000 0xf80000000000]
+                                            0x1400000000000
+                                            [0x1400000000000 0xaa0000000000 0xb40000000000 0xbe0000000000 0xc80000000000 0xd20000000000 0xdc0000000000 0xe60000000000 0xf00000000000 0xfa0000000000 0x1040000000000 0x10e0000000000 0x1180000000000 0x1220000000000 0x12c0000000000 0x1360000000000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    0x1800000000000
+                    {
+                        {
+                            {
+                                {
+                                    {
+                                        [0x1800000000000 0xcc0000000000 0xd80000000000 0xe40000000000 0xf00000000000 0xfc0000000000 0x1080000000000 0x1140000000000 0x1200000000000 0x12c0000000000 0x1380000000000 0x1440000000000 0x1500000000000 0x15c0000000000 0x1680000000000 0x1740000000000]
+                                        0x1c00000000000
+                                        {
+                                            [0x1c00000000000 0xee0000000000 0xfc0000000000 0x10a0000000000 0x1180000000000 0x1260000000000 0x1340000000000 0x1420000000000 0x1500000000000 0x15e0000000000 0x16c0000000000 0x17a0000000000 0x1880000000000 0x1960000000000 0x1a40000000000 0x1b20000000000]
+                                            0x2000000000000
+                                            [0x2000000000000 0x1100000000000 0x1200000000000 0x1300000000000 0x1400000000000 0x1500000000000 0x1600000000000 0x1700000000000 0x1800000000000 0x1900000000000 0x1a00000000000 0x1b00000000000 0x1c00000000000 0x1d00000000000 0x1e00000000000 0x1f00000000000]
+                                        }
+                                    }
+                                    0x2800000000000
+                                    {
+                                        {
+                                            [0x2800000000000 0x1540000000000 0x1680000000000 0x17c0000000000 0x1900000000000 0x1a40000000000 0x1b80000000000 0x1cc0000000000 0x1e00000000000 0x1f40000000000 0x2080000000000 0x21c0000000000 0x2300000000000 0x2440000000000 0x2580000000000 0x26c0000000000]
+                                            0x3000000000000
+                                            [0x3000000000000 0x1980000000000 0x1b00000000000 0x1c80000000000 0x1e00000000000 0x1f80000000000 0x2100000000000 0x2280000000000 0x2400000000000 0x2580000000000 0x2700000000000 0x2880000000000 0x2a00000000000 0x2b80000000000 0x2d00000000000 0x2e80000000000]
+                                        }
+                                        0x3800000000000
+                                        {
+                                            [0x3800000000000 0x1dc0000000000 0x1f80000000000 0x2140000000000 0x2300000000000 0x24c0000000000 0x2680000000000 0x2840000000000 0x2a00000000000 0x2bc0000000000 0x2d80000000000 0x2f40000000000 0x3100000000000 0x32c0000000000 0x3480000000000 0x3640000000000]
+                                            0x4000000000000
+                                            [0x4000000000000 0x2200000000000 0x2400000000000 0x2600000000000 0x2800000000000 0x2a00000000000 0x2c00000000000 0x2e00000000000 0x3000000000000 0x3200000000000 0x3400000000000 0x3600000000000 0x3800000000000 0x3a00000000000 0x3c00000000000 0x3e00000000000]
+                                        }
+                                    }
+                                }
+                                0x5000000000000
+                                {
+                                    {
+                                        {
+                                            [0x5000000000000 0x2a80000000000 0x2d00000000000 0x2f80000000000 0x3200000000000 0x3480000000000 0x3700000000000 0x3980000000000 0x3c00000000000 0x3e80000000000 0x4100000000000 0x4380000000000 0x4600000000000 0x4880000000000 0x4b00000000000 0x4d80000000000]
+                                            0x6000000000000
+                                            [0x6000000000000 0x3300000000000 0x3600000000000 0x3900000000000 0x3c00000000000 0x3f00000000000 0x4200000000000 0x4500000000000 0x4800000000000 0x4b00000000000 0x4e00000000000 0x5100000000000 0x5400000000000 0x5700000000000 0x5a00000000000 0x5d00000000000]
+                                        }
+                                        0x7000000000000
+                                        {
+                                            [0x7000000000000 0x3b80000000000 0x3f00000000000 0x4280000000000 0x4600000000000 0x4980000000000 0x4d00000000000 0x5080000000000 0x5400000000000 0x5780000000000 0x5b00000000000 0x5e80000000000 0x6200000000000 0x6580000000000 0x6900000000000 0x6c80000000000]
+                                            0x8000000000000
+                                            [0x8000000000000 0x4400000000000 0x4800000000000 0x4c00000000000 0x5000000000000 0x5400000000000 0x5800000000000 0x5c00000000000 0x6000000000000 0x6400000000000 0x6800000000000 0x6c00000000000 0x7000000000000 0x7400000000000 0x7800000000000 0x7c00000000000]
+                                        }
+                                    }
+                                    0xa000000000000
+                                    {
+                                        {
+                                            [0xa000000000000 0x5500000000000 0x5a00000000000 0x5f00000000000 0x6400000000000 0x6900000000000 0x6e00000000000 0x7300000000000 0x7800000000000 0x7d00000000000 0x8200000000000 0x8700000000000 0x8c00000000000 0x9100000000000 0x9600000000000 0x9b00000000000]
+                                            0xc000000000000
+                                            [0xc000000000000 0x6600000000000 0x6c00000000000 0x7200000000000 0x7800000000000 0x7e00000000000 0x8400000000000 0x8a00000000000 0x9000000000000 0x9600000000000 0x9c00000000000 0xa200000000000 0xa800000000000 0xae00000000000 0xb400000000000 0xba00000000000]
+                                        }
+                                        0xe000000000000
+                                        {
+                                            [0xe000000000000 0x7700000000000 0x7e00000000000 0x8500000000000 0x8c00000000000 0x9300000000000 0x9a00000000000 0xa100000000000 0xa800000000000 0xaf00000000000 0xb600000000000 0xbd00000000000 0xc400000000000 0xcb00000000000 0xd200000000000 0xd900000000000]
+                                            0x10000000000000
+                                            [0x10000000000000 0x8800000000000 0x9000000000000 0x9800000000000 0xa000000000000 0xa800000000000 0xb000000000000 0xb800000000000 0xc000000000000 0xc800000000000 0xd000000000000 0xd800000000000 0xe000000000000 0xe800000000000 0xf000000000000 0xf800000000000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x14000000000000
+                            {
+                                {
+                                    {
+                                        {
+                                            [0x14000000000000 0xaa00000000000 0xb400000000000 0xbe00000000000 0xc800000000000 0xd200000000000 0xdc00000000000 0xe600000000000 0xf000000000000 0xfa00000000000 0x10400000000000 0x10e00000000000 0x11800000000000 0x12200000000000 0x12c00000000000 0x13600000000000]
+                                            0x18000000000000
+                                            [0x18000000000000 0xcc00000000000 0xd800000000000 0xe400000000000 0xf000000000000 0xfc00000000000 0x10800000000000 0x11400000000000 0x12000000000000 0x12c00000000000 0x13800000000000 0x14400000000000 0x15000000000000 0x15c00000000000 0x16800000000000 0x17400000000000]
+                                        }
+                                        0x1c000000000000
+                                        {
+                                            [0x1c000000000000 0xee00000000000 0xfc00000000000 0x10a00000000000 0x11800000000000 0x12600000000000 0x13400000000000 0x14200000000000 0x15000000000000 0x15e00000000000 0x16c00000000000 0x17a00000000000 0x18800000000000 0x19600000000000 0x1a400000000000 0x1b200000000000]
+                                            0x20000000000000
+                                            [0x20000000000000 0x11000000000000 0x12000000000000 0x13000000000000 0x14000000000000 0x15000000000000 0x16000000000000 0x17000000000000 0x18000000000000 0x19000000000000 0x1a000000000000 0x1b000000000000 0x1c000000000000 0x1d000000000000 0x1e000000000000 0x1f000000000000]
+                                        }
+                                    }
+                                    0x28000000000000
+                                    {
+                                        {
+                                            [0x28000000000000 0x15400000000000 0x16800000000000 0x17c00000000000 0x19000000000000 0x1a400000000000 0x1b800000000000 0x1cc00000000000 0x1e000000000000 0x1f400000000000 0x20800000000000 0x21c00000000000 0x23000000000000 0x24400000000000 0x25800000000000 0x26c00000000000]
+                                            0x30000000000000
+                                            [0x30000000000000 0x19800000000000 0x1b000000000000 0x1c800000000000 0x1e000000000000 0x1f800000000000 0x21000000000000 0x22800000000000 0x24000000000000 0x25800000000000 0x27000000000000 0x28800000000000 0x2a000000000000 0x2b800000000000 0x2d000000000000 0x2e800000000000]
+                                        }
+                                        0x38000000000000
+                                        {
+                                            [0x38000000000000 0x1dc00000000000 0x1f800000000000 0x21400000000000 0x23000000000000 0x24c00000000000 0x26800000000000 0x28400000000000 0x2a000000000000 0x2bc00000000000 0x2d800000000000 0x2f400000000000 0x31000000000000 0x32c00000000000 0x34800000000000 0x36400000000000]
+                                            0x40000000000000
+                                            [0x40000000000000 0x22000000000000 0x24000000000000 0x26000000000000 0x28000000000000 0x2a000000000000 0x2c000000000000 0x2e000000000000 0x30000000000000 0x32000000000000 0x34000000000000 0x36000000000000 0x38000000000000 0x3a000000000000 0x3c000000000000 0x3e000000000000]
+                                        }
+                                    }
+                                }
+                                0x50000000000000
+                                {
+                                    {
+                                        {
+                                            [0x50000000000000 0x2a800000000000 0x2d000000000000 0x2f800000000000 0x32000000000000 0x34800000000000 0x37000000000000 0x39800000000000 0x3c000000000000 0x3e800000000000 0x41000000000000 0x43800000000000 0x46000000000000 0x48800000000000 0x4b000000000000 0x4d800000000000]
+                                            0x60000000000000
+                                            [0x60000000000000 0x33000000000000 0x36000000000000 0x39000000000000 0x3c000000000000 0x3f000000000000 0x42000000000000 0x45000000000000 0x48000000000000 0x4b000000000000 0x4e000000000000 0x51000000000000 0x54000000000000 0x57000000000000 0x5a000000000000 0x5d000000000000]
+                                        }
+                                        0x70000000000000
+                                        {
+                                            [0x70000000000000 0x3b800000000000 0x3f000000000000 0x42800000000000 0x46000000000000 0x49800000000000 0x4d000000000000 0x50800000000000 0x54000000000000 0x57800000000000 0x5b000000000000 0x5e800000000000 0x62000000000000 0x65800000000000 0x69000000000000 0x6c800000000000]
+                                            0x80000000000000
+                                            [0x80000000000000 0x44000000000000 0x48000000000000 0x4c000000000000 0x50000000000000 0x54000000000000 0x58000000000000 0x5c000000000000 0x60000000000000 0x64000000000000 0x68000000000000 0x6c000000000000 0x70000000000000 0x74000000000000 0x78000000000000 0x7c000000000000]
+                                        }
+                                    }
+                                    0xa0000000000000
+                                    {
+                                        {
+                                            [0xa0000000000000 0x55000000000000 0x5a000000000000 0x5f000000000000 0x64000000000000 0x69000000000000 0x6e000000000000 0x73000000000000 0x78000000000000 0x7d000000000000 0x82000000000000 0x87000000000000 0x8c000000000000 0x91000000000000 0x96000000000000 0x9b000000000000]
+                                            0xc0000000000000
+                                            [0xc0000000000000 0x66000000000000 0x6c000000000000 0x72000000000000 0x78000000000000 0x7e000000000000 0x84000000000000 0x8a000000000000 0x90000000000000 0x96000000000000 0x9c000000000000 0xa2000000000000 0xa8000000000000 0xae000000000000 0xb4000000000000 0xba000000000000]
+                                        }
+                                        0xe0000000000000
+                                        {
+                                            [0xe0000000000000 0x77000000000000 0x7e000000000000 0x85000000000000 0x8c000000000000 0x93000000000000 0x9a000000000000 0xa1000000000000 0xa8000000000000 0xaf000000000000 0xb6000000000000 0xbd000000000000 0xc4000000000000 0xcb000000000000 0xd2000000000000 0xd9000000000000]
+                                            0x100000000000000
+                                            [0x100000000000000 0x88000000000000 0x90000000000000 0x98000000000000 0xa0000000000000 0xa8000000000000 0xb0000000000000 0xb8000000000000 0xc0000000000000 0xc8000000000000 0xd0000000000000 0xd8000000000000 0xe0000000000000 0xe8000000000000 0xf0000000000000 0xf8000000000000]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        0x140000000000000
+                        {
+                            {
+                                {
+                                    {
+                                        {
+                                            [0x140000000000000 0xaa000000000000 0xb4000000000000 0xbe000000000000 0xc8000000000000 0xd2000000000000 0xdc000000000000 0xe6000000000000 0xf0000000000000 0xfa000000000000 0x104000000000000 0x10e000000000000 0x118000000000000 0x122000000000000 0x12c000000000000 0x136000000000000]
+                                            0x180000000000000
+                                            [0x180000000000000 0xcc000000000000 0xd8000000000000 0xe4000000000000 0xf0000000000000 0xfc000000000000 0x108000000000000 0x114000000000000 0x120000000000000 0x12c000000000000 0x138000000000000 0x144000000000000 0x150000000000000 0x15c000000000000 0x168000000000000 0x174000000000000]
+                                        }
+                                        0x1c0000000000000
+                                        {
+                                            [0x1c0000000000000 0xee000000000000 0xfc000000000000 0x10a000000000000 0x118000000000000 0x126000000000000 0x134000000000000 0x142000000000000 0x150000000000000 0x15e000000000000 0x16c000000000000 0x17a000000000000 0x188000000000000 0x196000000000000 0x1a4000000000000 0x1b2000000000000]
+                                            0x200000000000000
+                                            [0x200000000000000 0x110000000000000 0x120000000000000 0x130000000000000 0x140000000000000 0x150000000000000 0x160000000000000 0x170000000000000 0x180000000000000 0x190000000000000 0x1a0000000000000 0x1b0000000000000 0x1c0000000000000 0x1d0000000000000 0x1e0000000000000 0x1f0000000000000]
+                                        }
+                                    }
+                                    0x280000000000000
+                                    {
+                                        {
+                                            [0x280000000000000 0x154000000000000 0x168000000000000 0x17c000000000000 0x190000000000000 0x1a4000000000000 0x1b8000000000000 0x1cc000000000000 0x1e0000000000000 0x1f4000000000000 0x208000000000000 0x21c000000000000 0x230000000000000 0x244000000000000 0x258000000000000 0x26c000000000000]
+                                            0x300000000000000
+                                            [0x300000000000000 0x198000000000000 0x1b0000000000000 0x1c8000000000000 0x1e0000000000000 0x1f8000000000000 0x210000000000000 0x228000000000000 0x240000000000000 0x258000000000000 0x270000000000000 0x288000000000000 0x2a0000000000000 0x2b8000000000000 0x2d0000000000000 0x2e8000000000000]
+                                        }
+                                        0x380000000000000
+                                        {
+                                            [0x380000000000000 0x1dc000000000000 0x1f8000000000000 0x214000000000000 0x230000000000000 0x24c000000000000 0x268000000000000 0x284000000000000 0x2a0000000000000 0x2bc000000000000 0x2d8000000000000 0x2f4000000000000 0x310000000000000 0x32c000000000000 0x348000000000000 0x364000000000000]
+                                            0x400000000000000
+                                            [0x400000000000000 0x220000000000000 0x240000000000000 0x260000000000000 0x280000000000000 0x2a0000000000000 0x2c0000000000000 0x2e0000000000000 0x300000000000000 0x320000000000000 0x340000000000000 0x360000000000000 0x380000000000000 0x3a0000000000000 0x3c0000000000000 0x3e0000000000000]
+                                        }
+                                    }
+                                }
+                                0x500000000000000
+                                {
+                                    {
+                                        {
+                                            [0x500000000000000 0x2a8000000000000 0x2d0000000000000 0x2f8000000000000 0x320000000000000 0x348000000000000 0x370000000000000 0x398000000000000 0x3c0000000000000 0x3e8000000000000 0x410000000000000 0x438000000000000 0x460000000000000 0x488000000000000 0x4b0000000000000 0x4d8000000000000]
+                                            0x600000000000000
+                                            [0x600000000000000 0x330000000000000 0x360000000000000 0x390000000000000 0x3c0000000000000 0x3f0000000000000 0x420000000000000 0x450000000000000 0x480000000000000 0x4b0000000000000 0x4e0000000000000 0x510000000000000 0x540000000000000 0x570000000000000 0x5a0000000000000 0x5d0000000000000]
+                                        }
+                                        0x700000000000000
+                                        {
+                                            [0x700000000000000 0x3b8000000000000 0x3f0000000000000 0x428000000000000 0x460000000000000 0x498000000000000 0x4d0000000000000 0x508000000000000 0x540000000000000 0x578000000000000 0x5b0000000000000 0x5e8000000000000 0x620000000000000 0x658000000000000 0x690000000000000 0x6c8000000000000]
+                                            0x800000000000000
+                                            [0x800000000000000 0x440000000000000 0x480000000000000 0x4c0000000000000 0x500000000000000 0x540000000000000 0x580000000000000 0x5c0000000000000 0x600000000000000 0x640000000000000 0x680000000000000 0x6c0000000000000 0x700000000000000 0x740000000000000 0x780000000000000 0x7c0000000000000]
+                                        }
+                                    }
+                                    0xa00000000000000
+                                    {
+                                        {
+                                            [0xa00000000000000 0x550000000000000 0x5a0000000000000 0x5f0000000000000 0x640000000000000 0x690000000000000 0x6e0000000000000 0x730000000000000 0x780000000000000 0x7d0000000000000 0x820000000000000 0x870000000000000 0x8c0000000000000 0x910000000000000 0x960000000000000 0x9b0000000000000]
+                                            0xc00000000000000
+                                            [0xc00000000000000 0x660000000000000 0x6c0000000000000 0x720000000000000 0x780000000000000 0x7e0000000000000 0x840000000000000 0x8a0000000000000 0x900000000000000 0x960000000000000 0x9c0000000000000 0xa20000000000000 0xa80000000000000 0xae0000000000000 0xb40000000000000 0xba0000000000000]
+                                        }
+                                        0xe00000000000000
+                                        {
+                                            [0xe00000000000000 0x770000000000000 0x7e0000000000000 0x850000000000000 0x8c0000000000000 0x930000000000000 0x9a0000000000000 0xa10000000000000 0xa80000000000000 0xaf0000000000000 0xb60000000000000 0xbd0000000000000 0xc40000000000000 0xcb0000000000000 0xd20000000000000 0xd90000000000000]
+                                            0x1000000000000000
+                                            [0x1000000000000000 0x880000000000000 0x900000000000000 0x980000000000000 0xa00000000000000 0xa80000000000000 0xb00000000000000 0xb80000000000000 0xc00000000000000 0xc80000000000000 0xd00000000000000 0xd80000000000000 0xe00000000000000 0xe80000000000000 0xf00000000000000 0xf80000000000000]
+                                        }
+                                    }
+                                }
+                            }
+                            0x1400000000000000
+                            {
+                                {
+                                    {
+                                        {
+                                            [0x1400000000000000 0xaa0000000000000 0xb40000000000000 0xbe0000000000000 0xc80000000000000 0xd20000000000000 0xdc0000000000000 0xe60000000000000 0xf00000000000000 0xfa0000000000000 0x1040000000000000 0x10e0000000000000 0x1180000000000000 0x1220000000000000 0x12c0000000000000 0x1360000000000000]
+                                            0x1800000000000000
+                                            [0x1800000000000000 0xcc0000000000000 0xd80000000000000 0xe40000000000000 0xf00000000000000 0xfc0000000000000 0x1080000000000000 0x1140000000000000 0x1200000000000000 0x12c0000000000000 0x1380000000000000 0x1440000000000000 0x1500000000000000 0x15c0000000000000 0x1680000000000000 0x1740000000000000]
+                                        }
+                                        0x1c00000000000000
+                                        {
+                                            [0x1c00000000000000 0xee0000000000000 0xfc0000000000000 0x10a0000000000000 0x1180000000000000 0x1260000000000000 0x1340000000000000 0x1420000000000000 0x1500000000000000 0x15e0000000000000 0x16c0000000000000 0x17a0000000000000 0x1880000000000000 0x1960000000000000 0x1a40000000000000 0x1b20000000000000]
+                                            0x2000000000000000
+                                            [0x2000000000000000 0x1100000000000000 0x1200000000000000 0x1300000000000000 0x1400000000000000 0x1500000000000000 0x1600000000000000 0x1700000000000000 0x1800000000000000 0x1900000000000000 0x1a00000000000000 0x1b00000000000000 0x1c00000000000000 0x1d00000000000000 0x1e00000000000000 0x1f00000000000000]
+                                        }
+                                    }
+                                    0x2800000000000000
+                                    {
+                                        {
+                                            [0x2800000000000000 0x1540000000000000 0x1680000000000000 0x17c0000000000000 0x1900000000000000 0x1a40000000000000 0x1b80000000000000 0x1cc0000000000000 0x1e00000000000000 0x1f40000000000000 0x2080000000000000 0x21c0000000000000 0x2300000000000000 0x2440000000000000 0x2580000000000000 0x26c0000000000000]
+                                            0x3000000000000000
+                                            [0x3000000000000000 0x1980000000000000 0x1b00000000000000 0x1c80000000000000 0x1e00000000000000 0x1f80000000000000 0x2100000000000000 0x2280000000000000 0x2400000000000000 0x2580000000000000 0x2700000000000000 0x2880000000000000 0x2a00000000000000 0x2b80000000000000 0x2d00000000000000 0x2e80000000000000]
+                                        }
+                                        0x3800000000000000
+                                        {
+                                            [0x3800000000000000 0x1dc0000000000000 0x1f80000000000000 0x2140000000000000 0x2300000000000000 0x24c0000000000000 0x2680000000000000 0x2840000000000000 0x2a00000000000000 0x2bc0000000000000 0x2d80000000000000 0x2f40000000000000 0x3100000000000000 0x32c0000000000000 0x3480000000000000 0x3640000000000000]
+                                            0x4000000000000000
+                                            [0x4000000000000000 0x2200000000000000 0x2400000000000000 0x2600000000000000 0x2800000000000000 0x2a00000000000000 0x2c00000000000000 0x2e00000000000000 0x3000000000000000 0x3200000000000000 0x3400000000000000 0x3600000000000000 0x3800000000000000 0x3a00000000000000 0x3c00000000000000 0x3e00000000000000]
+                                        }
+                                    }
+                                }
+                                0x5000000000000000
+                                {
+                                    {
+                                        {
+                                            [0x5000000000000000 0x2a80000000000000 0x2d00000000000000 0x2f80000000000000 0x3200000000000000 0x3480000000000000 0x3700000000000000 0x3980000000000000 0x3c00000000000000 0x3e80000000000000 0x4100000000000000 0x4380000000000000 0x4600000000000000 0x4880000000000000 0x4b00000000000000 0x4d80000000000000]
+                                            0x6000000000000000
+                                            [0x6000000000000000 0x3300000000000000 0x3600000000000000 0x3900000000000000 0x3c00000000000000 0x3f00000000000000 0x4200000000000000 0x4500000000000000 0x4800000000000000 0x4b00000000000000 0x4e00000000000000 0x5100000000000000 0x5400000000000000 0x5700000000000000 0x5a00000000000000 0x5d00000000000000]
+                                        }
+                                        0x7000000000000000
+                                        {
+                                            [0x7000000000000000 0x3b80000000000000 0x3f00000000000000 0x4280000000000000 0x4600000000000000 0x4980000000000000 0x4d00000000000000 0x5080000000000000 0x5400000000000000 0x5780000000000000 0x5b00000000000000 0x5e80000000000000 0x6200000000000000 0x6580000000000000 0x6900000000000000 0x6c80000000000000]
+                                            0x8000000000000000
+                                            [0x8000000000000000 0x4400000000000000 0x4800000000000000 0x4c00000000000000 0x5000000000000000 0x5400000000000000 0x5800000000000000 0x5c00000000000000 0x6000000000000000 0x6400000000000000 0x6800000000000000 0x6c00000000000000 0x7000000000000000 0x7400000000000000 0x7800000000000000 0x7c00000000000000]
+                                        }
+                                    }
+                                    0xa000000000000000
+                                    {
+                                        {
+                                            [0xa000000000000000 0x5500000000000000 0x5a00000000000000 0x5f00000000000000 0x6400000000000000 0x6900000000000000 0x6e00000000000000 0x7300000000000000 0x7800000000000000 0x7d00000000000000 0x8200000000000000 0x8700000000000000 0x8c00000000000000 0x9100000000000000 0x9600000000000000 0x9b00000000000000]
+                                            0xc000000000000000
+                                            [0xc000000000000000 0x6600000000000000 0x6c00000000000000 0x7200000000000000 0x7800000000000000 0x7e00000000000000 0x8400000000000000 0x8a00000000000000 0x9000000000000000 0x9600000000000000 0x9c00000000000000 0xa200000000000000 0xa800000000000000 0xae00000000000000 0xb400000000000000 0xba00000000000000]
+                                        }
+                                        0xe000000000000000
+                                        {
+                                            [0xe000000000000000 0x7700000000000000 0x7e00000000000000 0x8500000000000000 0x8c00000000000000 0x9300000000000000 0x9a00000000000000 0xa100000000000000 0xa800000000000000 0xaf00000000000000 0xb600000000000000 0xbd00000000000000 0xc400000000000000 0xcb00000000000000 0xd200000000000000 0xd900000000000000]
+                                            0xffffffffffffffff
+                                            [0xffffffffffffffff 0x87ffffffffffffff 0x8fffffffffffffff 0x97ffffffffffffff 0x9fffffffffffffff 0xa7ffffffffffffff 0xafffffffffffffff 0xb7ffffffffffffff 0xbfffffffffffffff 0xc7ffffffffffffff 0xcfffffffffffffff 0xd7ffffffffffffff 0xdfffffffffffffff 0xe7ffffffffffffff 0xefffffffffffffff 0xf7ffffffffffffff]
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+// `size_shifts` passes the `usize` candidates except for the maximum to `$mac` as `[N M E]` with
+// `N == M << E` and `M <= 1024`, followed by `; MAX BITS` where `MAX == (1 << BITS) - 1`.
+// `refine_shifts` passes the refinements that are not yielded by `for_each_size` in the same way,
+// except for those of the maximum, which are odd and too large to be converted. These are used to
+// map candidates to `typenum` integers.
+
+#[cfg(all(feature = "typenum", target_pointer_width = "16"))]
+macro_rules! size_shifts {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            [0 0 0] [1 1 0] [2 2 0] [3 3 0] [4 4 0] [5 5 0] [6 6 0] [7 7 0]
+            [8 8 0] [9 9 0] [10 10 0] [11 11 0] [12 12 0] [13 13 0] [14 14 0] [15 15 0]
+            [16 16 0] [17 17 0] [18 18 0] [19 19 0] [20 20 0] [21 21 0] [22 22 0] [23 23 0]
+            [24 24 0] [25 25 0] [26 26 0] [27 27 0] [28 28 0] [29 29 0] [30 30 0] [31 31 0]
+            [32 32 0] [33 33 0] [34 34 0] [35 35 0] [36 36 0] [37 37 0] [38 38 0] [39 39 0]
+            [40 40 0] [41 41 0] [42 42 0] [43 43 0] [44 44 0] [45 45 0] [46 46 0] [47 47 0]
+            [48 48 0] [49 49 0] [50 50 0] [51 51 0] [52 52 0] [53 53 0] [54 54 0] [55 55 0]
+            [56 56 0] [57 57 0] [58 58 0] [59 59 0] [60 60 0] [61 61 0] [62 62 0] [63 63 0]
+            [64 64 0] [65 65 0] [66 66 0] [67 67 0] [68 68 0] [69 69 0] [70 70 0] [71 71 0]
+            [72 72 0] [73 73 0] [74 74 0] [75 75 0] [76 76 0] [77 77 0] [78 78 0] [79 79 0]
+            [80 80 0] [81 81 0] [82 82 0] [83 83 0] [84 84 0] [85 85 0] [86 86 0] [87 87 0]
+            [88 88 0] [89 89 0] [90 90 0] [91 91 0] [92 92 0] [93 93 0] [94 94 0] [95 95 0]
+            [96 96 0] [97 97 0] [98 98 0] [99 99 0] [100 100 0] [101 101 0] [102 102 0] [103 103 0]
+            [104 104 0] [105 105 0] [106 106 0] [107 107 0] [108 108 0] [109 109 0] [110 110 0] [111 111 0]
+            [112 112 0] [113 113 0] [114 114 0] [115 115 0] [116 116 0] [117 117 0] [118 118 0] [119 119 0]
+            [120 120 0] [121 121 0] [122 122 0] [123 123 0] [124 124 0] [125 125 0] [126 126 0] [127 127 0]
+            [128 128 0] [129 129 0] [130 130 0] [131 131 0] [132 132 0] [133 133 0] [134 134 0] [135 135 0]
+            [136 136 0] [137 137 0] [138 138 0] [139 139 0] [140 140 0] [141 141 0] [142 142 0] [143 143 0]
+            [144 144 0] [145 145 0] [146 146 0] [147 147 0] [148 148 0] [149 149 0] [150 150 0] [151 151 0]
+            [152 152 0] [153 153 0] [154 154 0] [155 155 0] [156 156 0] [157 157 0] [158 158 0] [159 159 0]
+            [160 160 0] [161 161 0] [162 162 0] [163 163 0] [164 164 0] [165 165 0] [166 166 0] [167 167 0]
+            [168 168 0] [169 169 0] [170 170 0] [171 171 0] [172 172 0] [173 173 0] [174 174 0] [175 175 0]
+            [176 176 0] [177 177 0] [178 178 0] [179 179 0] [180 180 0] [181 181 0] [182 182 0] [183 183 0]
+            [184 184 0] [185 185 0] [186 186 0] [187 187 0] [188 188 0] [189 189 0] [190 190 0] [191 191 0]
+            [192 192 0] [193 193 0] [194 194 0] [195 195 0] [196 196 0] [197 197 0] [198 198 0] [199 199 0]
+            [200 200 0] [201 201 0] [202 202 0] [203 203 0] [204 204 0] [205 205 0] [206 206 0] [207 207 0]
+            [208 208 0] [209 209 0] [210 210 0] [211 211 0] [212 212 0] [213 213 0] [214 214 0] [215 215 0]
+            [216 216 0] [217 217 0] [218 218 0] [219 219 0] [220 220 0] [221 221 0] [222 222 0] [223 223 0]
+            [224 224 0] [225 225 0] [226 226 0] [227 227 0] [228 228 0] [229 229 0] [230 230 0] [231 231 0]
+            [232 232 0] [233 233 0] [234 234 0] [235 235 0] [236 236 0] [237 237 0] [238 238 0] [239 239 0]
+            [240 240 0] [241 241 0] [242 242 0] [243 243 0] [244 244 0] [245 245 0] [246 246 0] [247 247 0]
+            [248 248 0] [249 249 0] [250 250 0] [251 251 0] [252 252 0] [253 253 0] [254 254 0] [255 255 0]
+            [256 256 0] [0x120 288 0] [0x140 320 0] [0x160 352 0] [0x180 384 0] [0x1a0 416 0] [0x1c0 448 0] [0x1e0 480 0]
+            [0x200 512 0] [0x240 576 0] [0x280 640 0] [0x2c0 704 0] [0x300 768 0] [0x340 832 0] [0x380 896 0] [0x3c0 960 0]
+            [0x400 1024 0] [0x480 9 7] [0x500 5 8] [0x580 11 7] [0x600 3 9] [0x680 13 7] [0x700 7 8] [0x780 15 7]
+            [0x800 1 11] [0x900 9 8] [0xa00 5 9] [0xb00 11 8] [0xc00 3 10] [0xd00 13 8] [0xe00 7 9] [0xf00 15 8]
+            [0x1000 1 12] [0x1200 9 9] [0x1400 5 10] [0x1600 11 9] [0x1800 3 11] [0x1a00 13 9] [0x1c00 7 10] [0x1e00 15 9]
+            [0x2000 1 13] [0x2400 9 10] [0x2800 5 11] [0x2c00 11 10] [0x3000 3 12] [0x3400 13 10] [0x3800 7 11] [0x3c00 15 10]
+            [0x4000 1 14] [0x4800 9 11] [0x5000 5 12] [0x5800 11 11] [0x6000 3 13] [0x6800 13 11] [0x7000 7 12] [0x7800 15 11]
+            [0x8000 1 15] [0x9000 9 12] [0xa000 5 13] [0xb000 11 12] [0xc000 3 14] [0xd000 13 12] [0xe000 7 13] [0xf000 15 12]
+            ; 0xffff 16
+        }
+    };
+}
+
+#[cfg(all(feature = "typenum", target_pointer_width = "32"))]
+macro_rules! size_shifts {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            [0 0 0] [1 1 0] [2 2 0] [3 3 0] [4 4 0] [5 5 0] [6 6 0] [7 7 0]
+            [8 8 0] [9 9 0] [10 10 0] [11 11 0] [12 12 0] [13 13 0] [14 14 0] [15 15 0]
+            [16 16 0] [17 17 0] [18 18 0] [19 19 0] [20 20 0] [21 21 0] [22 22 0] [23 23 0]
+            [24 24 0] [25 25 0] [26 26 0] [27 27 0] [28 28 0] [29 29 0] [30 30 0] [31 31 0]
+            [32 32 0] [33 33 0] [34 34 0] [35 35 0] [36 36 0] [37 37 0] [38 38 0] [39 39 0]
+            [40 40 0] [41 41 0] [42 42 0] [43 43 0] [44 44 0] [45 45 0] [46 46 0] [47 47 0]
+            [48 48 0] [49 49 0] [50 50 0] [51 51 0] [52 52 0] [53 53 0] [54 54 0] [55 55 0]
+            [56 56 0] [57 57 0] [58 58 0] [59 59 0] [60 60 0] [61 61 0] [62 62 0] [63 63 0]
+            [64 64 0] [65 65 0] [66 66 0] [67 67 0] [68 68 0] [69 69 0] [70 70 0] [71 71 0]
+            [72 72 0] [73 73 0] [74 74 0] [75 75 0] [76 76 0] [77 77 0] [78 78 0] [79 79 0]
+            [80 80 0] [81 81 0] [82 82 0] [83 83 0] [84 84 0] [85 85 0] [86 86 0] [87 87 0]
+            [88 88 0] [89 89 0] [90 90 0] [91 91 0] [92 92 0] [93 93 0] [94 94 0] [95 95 0]
+            [96 96 0] [97 97 0] [98 98 0] [99 99 0] [100 100 0] [101 101 0] [102 102 0] [103 103 0]
+            [104 104 0] [105 105 0] [106 106 0] [107 107 0] [108 108 0] [109 109 0] [110 110 0] [111 111 0]
+            [112 112 0] [113 113 0] [114 114 0] [115 115 0] [116 116 0] [117 117 0] [118 118 0] [119 119 0]
+            [120 120 0] [121 121 0] [122 122 0] [123 123 0] [124 124 0] [125 125 0] [126 126 0] [127 127 0]
+            [128 128 0] [129 129 0] [130 130 0] [131 131 0] [132 132 0] [133 133 0] [134 134 0] [135 135 0]
+            [136 136 0] [137 137 0] [138 138 0] [139 139 0] [140 140 0] [141 141 0] [142 142 0] [143 143 0]
+            [144 144 0] [145 145 0] [146 146 0] [147 147 0] [148 148 0] [149 149 0] [150 150 0] [151 151 0]
+            [152 152 0] [153 153 0] [154 154 0] [155 155 0] [156 156 0] [157 157 0] [158 158 0] [159 159 0]
+            [160 160 0] [161 161 0] [162 162 0] [163 163 0] [164 164 0] [165 165 0] [166 166 0] [167 167 0]
+            [168 168 0] [169 169 0] [170 170 0] [171 171 0] [172 172 0] [173 173 0] [174 174 0] [175 175 0]
+            [176 176 0] [177 177 0] [178 178 0] [179 179 0] [180 180 0] [181 181 0] [182 182 0] [183 183 0]
+            [184 184 0] [185 185 0] [186 186 0] [187 187 0] [188 188 0] [189 189 0] [190 190 0] [191 191 0]
+            [192 192 0] [193 193 0] [194 194 0] [195 195 0] [196 196 0] [197 197 0] [198 198 0] [199 199 0]
+            [200 200 0] [201 201 0] [202 202 0] [203 203 0] [204 204 0] [205 205 0] [206 206 0] [207 207 0]
+            [208 208 0] [209 209 0] [210 210 0] [211 211 0] [212 212 0] [213 213 0] [214 214 0] [215 215 0]
+            [216 216 0] [217 217 0] [218 218 0] [219 219 0] [220 220 0] [221 221 0] [222 222 0] [223 223 0]
+            [224 224 0] [225 225 0] [226 226 0] [227 227 0] [228 228 0] [229 229 0] [230 230 0] [231 231 0]
+            [232 232 0] [233 233 0] [234 234 0] [235 235 0] [236 236 0] [237 237 0] [238 238 0] [239 239 0]
+            [240 240 0] [241 241 0] [242 242 0] [243 243 0] [244 244 0] [245 245 0] [246 246 0] [247 247 0]
+            [248 248 0] [249 249 0] [250 250 0] [251 251 0] [252 252 0] [253 253 0] [254 254 0] [255 255 0]
+            [256 256 0] [0x120 288 0] [0x140 320 0] [0x160 352 0] [0x180 384 0] [0x1a0 416 0] [0x1c0 448 0] [0x1e0 480 0]
+            [0x200 512 0] [0x240 576 0] [0x280 640 0] [0x2c0 704 0] [0x300 768 0] [0x340 832 0] [0x380 896 0] [0x3c0 960 0]
+            [0x400 1024 0] [0x480 9 7] [0x500 5 8] [0x580 11 7] [0x600 3 9] [0x680 13 7] [0x700 7 8] [0x780 15 7]
+            [0x800 1 11] [0x900 9 8] [0xa00 5 9] [0xb00 11 8] [0xc00 3 10] [0xd00 13 8] [0xe00 7 9] [0xf00 15 8]
+            [0x1000 1 12] [0x1200 9 9] [0x1400 5 10] [0x1600 11 9] [0x1800 3 11] [0x1a00 13 9] [0x1c00 7 10] [0x1e00 15 9]
+            [0x2000 1 13] [0x2400 9 10] [0x2800 5 11] [0x2c00 11 10] [0x3000 3 12] [0x3400 13 10] [0x3800 7 11] [0x3c00 15 10]
+            [0x4000 1 14] [0x4800 9 11] [0x5000 5 12] [0x5800 11 11] [0x6000 3 13] [0x6800 13 11] [0x7000 7 12] [0x7800 15 11]
+            [0x8000 1 15] [0x9000 9 12] [0xa000 5 13] [0xb000 11 12] [0xc000 3 14] [0xd000 13 12] [0xe000 7 13] [0xf000 15 12]
+            [0x10000 1 16] [0x12000 9 13] [0x14000 5 14] [0x16000 11 13] [0x18000 3 15] [0x1a000 13 13] [0x1c000 7 14] [0x1e000 15 13]
+            [0x20000 1 17] [0x24000 9 14] [0x28000 5 15] [0x2c000 11 14] [0x30000 3 16] [0x34000 13 14] [0x38000 7 15] [0x3c000 15 14]
+            [0x40000 1 18] [0x48000 9 15] [0x50000 5 16] [0x58000 11 15] [0x60000 3 17] [0x68000 13 15] [0x70000 7 16] [0x78000 15 15]
+            [0x80000 1 19] [0x90000 9 16] [0xa0000 5 17] [0xb0000 11 16] [0xc0000 3 18] [0xd0000 13 16] [0xe0000 7 17] [0xf0000 15 16]
+            [0x100000 1 20] [0x120000 9 17] [0x140000 5 18] [0x160000 11 17] [0x180000 3 19] [0x1a0000 13 17] [0x1c0000 7 18] [0x1e0000 15 17]
+            [0x200000 1 21] [0x240000 9 18] [0x280000 5 19] [0x2c0000 11 18] [0x300000 3 20] [0x340000 13 18] [0x380000 7 19] [0x3c0000 15 18]
+            [0x400000 1 22] [0x480000 9 19] [0x500000 5 20] [0x580000 11 19] [0x600000 3 21] [0x680000 13 19] [0x700000 7 20] [0x780000 15 19]
+            [0x800000 1 23] [0x900000 9 20] [0xa00000 5 21] [0xb00000 11 20] [0xc00000 3 22] [0xd00000 13 20] [0xe00000 7 21] [0xf00000 15 20]
+            [0x1000000 1 24] [0x1200000 9 21] [0x1400000 5 22] [0x1600000 11 21] [0x1800000 3 23] [0x1a00000 13 21] [0x1c00000 7 22] [0x1e00000 15 21]
+            [0x2000000 1 25] [0x2400000 9 22] [0x2800000 5 23] [0x2c00000 11 22] [0x3000000 3 24] [0x3400000 13 22] [0x3800000 7 23] [0x3c00000 15 22]
+            [0x4000000 1 26] [0x4800000 9 23] [0x5000000 5 24] [0x5800000 11 23] [0x6000000 3 25] [0x6800000 13 23] [0x7000000 7 24] [0x7800000 15 23]
+            [0x8000000 1 27] [0x9000000 9 24] [0xa000000 5 25] [0xb000000 11 24] [0xc000000 3 26] [0xd000000 13 24] [0xe000000 7 25] [0xf000000 15 24]
+            [0x10000000 1 28] [0x12000000 9 25] [0x14000000 5 26] [0x16000000 11 25] [0x18000000 3 27] [0x1a000000 13 25] [0x1c000000 7 26] [0x1e000000 15 25]
+            [0x20000000 1 29] [0x24000000 9 26] [0x28000000 5 27] [0x2c000000 11 26] [0x30000000 3 28] [0x34000000 13 26] [0x38000000 7 27] [0x3c000000 15 26]
+            [0x40000000 1 30] [0x48000000 9 27] [0x50000000 5 28] [0x58000000 11 27] [0x60000000 3 29] [0x68000000 13 27] [0x70000000 7 28] [0x78000000 15 27]
+            [0x80000000 1 31] [0x90000000 9 28] [0xa0000000 5 29] [0xb0000000 11 28] [0xc0000000 3 30] [0xd0000000 13 28] [0xe0000000 7 29] [0xf0000000 15 28]
+            ; 0xffffffff 32
+        }
+    };
+}
+
+#[cfg(all(feature = "typenum", target_pointer_width = "64"))]
+macro_rules! size_shifts {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            [0 0 0] [1 1 0] [2 2 0] [3 3 0] [4 4 0] [5 5 0] [6 6 0] [7 7 0]
+            [8 8 0] [9 9 0] [10 10 0] [11 11 0] [12 12 0] [13 13 0] [14 14 0] [15 15 0]
+            [16 16 0] [17 17 0] [18 18 0] [19 19 0] [20 20 0] [21 21 0] [22 22 0] [23 23 0]
+            [24 24 0] [25 25 0] [26 26 0] [27 27 0] [28 28 0] [29 29 0] [30 30 0] [31 31 0]
+            [32 32 0] [33 33 0] [34 34 0] [35 35 0] [36 36 0] [37 37 0] [38 38 0] [39 39 0]
+            [40 40 0] [41 41 0] [42 42 0] [43 43 0] [44 44 0] [45 45 0] [46 46 0] [47 47 0]
+            [48 48 0] [49 49 0] [50 50 0] [51 51 0] [52 52 0] [53 53 0] [54 54 0] [55 55 0]
+            [56 56 0] [57 57 0] [58 58 0] [59 59 0] [60 60 0] [61 61 0] [62 62 0] [63 63 0]
+            [64 64 0] [65 65 0] [66 66 0] [67 67 0] [68 68 0] [69 69 0] [70 70 0] [71 71 0]
+            [72 72 0] [73 73 0] [74 74 0] [75 75 0] [76 76 0] [77 77 0] [78 78 0] [79 79 0]
+            [80 80 0] [81 81 0] [82 82 0] [83 83 0] [84 84 0] [85 85 0] [86 86 0] [87 87 0]
+            [88 88 0] [89 89 0] [90 90 0] [91 91 0] [92 92 0] [93 93 0] [94 94 0] [95 95 0]
+            [96 96 0] [97 97 0] [98 98 0] [99 99 0] [100 100 0] [101 101 0] [102 102 0] [103 103 0]
+            [104 104 0] [105 105 0] [106 106 0] [107 107 0] [108 108 0] [109 109 0] [110 110 0] [111 111 0]
+            [112 112 0] [113 113 0] [114 114 0] [115 115 0] [116 116 0] [117 117 0] [118 118 0] [119 119 0]
+            [120 120 0] [121 121 0] [122 122 0] [123 123 0] [124 124 0] [125 125 0] [126 126 0] [127 127 0]
+            [128 128 0] [129 129 0] [130 130 0] [131 131 0] [132 132 0] [133 133 0] [134 134 0] [135 135 0]
+            [136 136 0] [137 137 0] [138 138 0] [139 139 0] [140 140 0] [141 141 0] [142 142 0] [143 143 0]
+            [144 144 0] [145 145 0] [146 146 0] [147 147 0] [148 148 0] [149 149 0] [150 150 0] [151 151 0]
+            [152 152 0] [153 153 0] [154 154 0] [155 155 0] [156 156 0] [157 157 0] [158 158 0] [159 159 0]
+            [160 160 0] [161 161 0] [162 162 0] [163 163 0] [164 164 0] [165 165 0] [166 166 0] [167 167 0]
+            [168 168 0] [169 169 0] [170 170 0] [171 171 0] [172 172 0] [173 173 0] [174 174 0] [175 175 0]
+            [176 176 0] [177 177 0] [178 178 0] [179 179 0] [180 180 0] [181 181 0] [182 182 0] [183 183 0]
+            [184 184 0] [185 185 0] [186 186 0] [187 187 0] [188 188 0] [189 189 0] [190 190 0] [191 191 0]
+            [192 192 0] [193 193 0] [194 194 0] [195 195 0] [196 196 0] [197 197 0] [198 198 0] [199 199 0]
+            [200 200 0] [201 201 0] [202 202 0] [203 203 0] [204 204 0] [205 205 0] [206 206 0] [207 207 0]
+            [208 208 0] [209 209 0] [210 210 0] [211 211 0] [212 212 0] [213 213 0] [214 214 0] [215 215 0]
+            [216 216 0] [217 217 0] [218 218 0] [219 219 0] [220 220 0] [221 221 0] [222 222 0] [223 223 0]
+            [224 224 0] [225 225 0] [226 226 0] [227 227 0] [228 228 0] [229 229 0] [230 230 0] [231 231 0]
+            [232 232 0] [233 233 0] [234 234 0] [235 235 0] [236 236 0] [237 237 0] [238 238 0] [239 239 0]
+            [240 240 0] [241 241 0] [242 242 0] [243 243 0] [244 244 0] [245 245 0] [246 246 0] [247 247 0]
+            [248 248 0] [249 249 0] [250 250 0] [251 251 0] [252 252 0] [253 253 0] [254 254 0] [255 255 0]
+            [256 256 0] [0x120 288 0] [0x140 320 0] [0x160 352 0] [0x180 384 0] [0x1a0 416 0] [0x1c0 448 0] [0x1e0 480 0]
+            [0x200 512 0] [0x240 576 0] [0x280 640 0] [0x2c0 704 0] [0x300 768 0] [0x340 832 0] [0x380 896 0] [0x3c0 960 0]
+            [0x400 1024 0] [0x480 9 7] [0x500 5 8] [0x580 11 7] [0x600 3 9] [0x680 13 7] [0x700 7 8] [0x780 15 7]
+            [0x800 1 11] [0x900 9 8] [0xa00 5 9] [0xb00 11 8] [0xc00 3 10] [0xd00 13 8] [0xe00 7 9] [0xf00 15 8]
+            [0x1000 1 12] [0x1200 9 9] [0x1400 5 10] [0x1600 11 9] [0x1800 3 11] [0x1a00 13 9] [0x1c00 7 10] [0x1e00 15 9]
+            [0x2000 1 13] [0x2400 9 10] [0x2800 5 11] [0x2c00 11 10] [0x3000 3 12] [0x3400 13 10] [0x3800 7 11] [0x3c00 15 10]
+            [0x4000 1 14] [0x4800 9 11] [0x5000 5 12] [0x5800 11 11] [0x6000 3 13] [0x6800 13 11] [0x7000 7 12] [0x7800 15 11]
+            [0x8000 1 15] [0x9000 9 12] [0xa000 5 13] [0xb000 11 12] [0xc000 3 14] [0xd000 13 12] [0xe000 7 13] [0xf000 15 12]
+            [0x10000 1 16] [0x12000 9 13] [0x14000 5 14] [0x16000 11 13] [0x18000 3 15] [0x1a000 13 13] [0x1c000 7 14] [0x1e000 15 13]
+            [0x20000 1 17] [0x24000 9 14] [0x28000 5 15] [0x2c000 11 14] [0x30000 3 16] [0x34000 13 14] [0x38000 7 15] [0x3c000 15 14]
+            [0x40000 1 18] [0x48000 9 15] [0x50000 5 16] [0x58000 11 15] [0x60000 3 17] [0x68000 13 15] [0x70000 7 16] [0x78000 15 15]
+            [0x80000 1 19] [0x90000 9 16] [0xa0000 5 17] [0xb0000 11 16] [0xc0000 3 18] [0xd0000 13 16] [0xe0000 7 17] [0xf0000 15 16]
+            [0x100000 1 20] [0x120000 9 17] [0x140000 5 18] [0x160000 11 17] [0x180000 3 19] [0x1a0000 13 17] [0x1c0000 7 18] [0x1e0000 15 17]
+            [0x200000 1 21] [0x240000 9 18] [0x280000 5 19] [0x2c0000 11 18] [0x300000 3 20] [0x340000 13 18] [0x380000 7 19] [0x3c0000 15 18]
+            [0x400000 1 22] [0x480000 9 19] [0x500000 5 20] [0x580000 11 19] [0x600000 3 21] [0x680000 13 19] [0x700000 7 20] [0x780000 15 19]
+            [0x800000 1 23] [0x900000 9 20] [0xa00000 5 21] [0xb00000 11 20] [0xc00000 3 22] [0xd00000 13 20] [0xe00000 7 21] [0xf00000 15 20]
+            [0x1000000 1 24] [0x1200000 9 21] [0x1400000 5 22] [0x1600000 11 21] [0x1800000 3 23] [0x1a00000 13 21] [0x1c00000 7 22] [0x1e00000 15 21]
+            [0x2000000 1 25] [0x2400000 9 22] [0x2800000 5 23] [0x2c00000 11 22] [0x3000000 3 24] [0x3400000 13 22] [0x3800000 7 23] [0x3c00000 15 22]
+            [0x4000000 1 26] [0x4800000 9 23] [0x5000000 5 24] [0x5800000 11 23] [0x6000000 3 25] [0x6800000 13 23] [0x7000000 7 24] [0x7800000 15 23]
+            [0x8000000 1 27] [0x9000000 9 24] [0xa000000 5 25] [0xb000000 11 24] [0xc000000 3 26] [0xd000000 13 24] [0xe000000 7 25] [0xf000000 15 24]
+            [0x10000000 1 28] [0x12000000 9 25] [0x14000000 5 26] [0x16000000 11 25] [0x18000000 3 27] [0x1a000000 13 25] [0x1c000000 7 26] [0x1e000000 15 25]
+            [0x20000000 1 29] [0x24000000 9 26] [0x28000000 5 27] [0x2c000000 11 26] [0x30000000 3 28] [0x34000000 13 26] [0x38000000 7 27] [0x3c000000 15 26]
+            [0x40000000 1 30] [0x48000000 9 27] [0x50000000 5 28] [0x58000000 11 27] [0x60000000 3 29] [0x68000000 13 27] [0x70000000 7 28] [0x78000000 15 27]
+            [0x80000000 1 31] [0x90000000 9 28] [0xa0000000 5 29] [0xb0000000 11 28] [0xc0000000 3 30] [0xd0000000 13 28] [0xe0000000 7 29] [0xf0000000 15 28]
+            [0x100000000 1 32] [0x120000000 9 29] [0x140000000 5 30] [0x160000000 11 29] [0x180000000 3 31] [0x1a0000000 13 29] [0x1c0000000 7 30] [0x1e0000000 15 29]
+            [0x200000000 1 33] [0x240000000 9 30] [0x280000000 5 31] [0x2c0000000 11 30] [0x300000000 3 32] [0x340000000 13 30] [0x380000000 7 31] [0x3c0000000 15 30]
+            [0x400000000 1 34] [0x480000000 9 31] [0x500000000 5 32] [0x580000000 11 31] [0x600000000 3 33] [0x680000000 13 31] [0x700000000 7 32] [0x780000000 15 31]
+            [0x800000000 1 35] [0x900000000 9 32] [0xa00000000 5 33] [0xb00000000 11 32] [0xc00000000 3 34] [0xd00000000 13 32] [0xe00000000 7 33] [0xf00000000 15 32]
+            [0x1000000000 1 36] [0x1200000000 9 33] [0x1400000000 5 34] [0x1600000000 11 33] [0x1800000000 3 35] [0x1a00000000 13 33] [0x1c00000000 7 34] [0x1e00000000 15 33]
+            [0x2000000000 1 37] [0x2400000000 9 34] [0x2800000000 5 35] [0x2c00000000 11 34] [0x3000000000 3 36] [0x3400000000 13 34] [0x3800000000 7 35] [0x3c00000000 15 34]
+            [0x4000000000 1 38] [0x4800000000 9 35] [0x5000000000 5 36] [0x5800000000 11 35] [0x6000000000 3 37] [0x6800000000 13 35] [0x7000000000 7 36] [0x7800000000 15 35]
+            [0x8000000000 1 39] [0x9000000000 9 36] [0xa000000000 5 37] [0xb000000000 11 36] [0xc000000000 3 38] [0xd000000000 13 36] [0xe000000000 7 37] [0xf000000000 15 36]
+            [0x10000000000 1 40] [0x12000000000 9 37] [0x14000000000 5 38] [0x16000000000 11 37] [0x18000000000 3 39] [0x1a000000000 13 37] [0x1c000000000 7 38] [0x1e000000000 15 37]
+            [0x20000000000 1 41] [0x24000000000 9 38] [0x28000000000 5 39] [0x2c000000000 11 38] [0x30000000000 3 40] [0x34000000000 13 38] [0x38000000000 7 39] [0x3c000000000 15 38]
+            [0x40000000000 1 42] [0x48000000000 9 39] [0x50000000000 5 40] [0x58000000000 11 39] [0x60000000000 3 41] [0x68000000000 13 39] [0x70000000000 7 40] [0x78000000000 15 39]
+            [0x80000000000 1 43] [0x90000000000 9 40] [0xa0000000000 5 41] [0xb0000000000 11 40] [0xc0000000000 3 42] [0xd0000000000 13 40] [0xe0000000000 7 41] [0xf0000000000 15 40]
+            [0x100000000000 1 44] [0x120000000000 9 41] [0x140000000000 5 42] [0x160000000000 11 41] [0x180000000000 3 43] [0x1a0000000000 13 41] [0x1c0000000000 7 42] [0x1e0000000000 15 41]
+            [0x200000000000 1 45] [0x240000000000 9 42] [0x280000000000 5 43] [0x2c0000000000 11 42] [0x300000000000 3 44] [0x340000000000 13 42] [0x380000000000 7 43] [0x3c0000000000 15 42]
+            [0x400000000000 1 46] [0x480000000000 9 43] [0x500000000000 5 44] [0x580000000000 11 43] [0x600000000000 3 45] [0x680000000000 13 43] [0x700000000000 7 44] [0x780000000000 15 43]
+            [0x800000000000 1 47] [0x900000000000 9 44] [0xa00000000000 5 45] [0xb00000000000 11 44] [0xc00000000000 3 46] [0xd00000000000 13 44] [0xe00000000000 7 45] [0xf00000000000 15 44]
+            [0x1000000000000 1 48] [0x1200000000000 9 45] [0x1400000000000 5 46] [0x1600000000000 11 45] [0x1800000000000 3 47] [0x1a00000000000 13 45] [0x1c00000000000 7 46] [0x1e00000000000 15 45]
+            [0x2000000000000 1 49] [0x2400000000000 9 46] [0x2800000000000 5 47] [0x2c00000000000 11 46] [0x3000000000000 3 48] [0x3400000000000 13 46] [0x3800000000000 7 47] [0x3c00000000000 15 46]
+            [0x4000000000000 1 50] [0x4800000000000 9 47] [0x5000000000000 5 48] [0x5800000000000 11 47] [0x6000000000000 3 49] [0x6800000000000 13 47] [0x7000000000000 7 48] [0x7800000000000 15 47]
+            [0x8000000000000 1 51] [0x9000000000000 9 48] [0xa000000000000 5 49] [0xb000000000000 11 48] [0xc000000000000 3 50] [0xd000000000000 13 48] [0xe000000000000 7 49] [0xf000000000000 15 48]
+            [0x10000000000000 1 52] [0x12000000000000 9 49] [0x14000000000000 5 50] [0x16000000000000 11 49] [0x18000000000000 3 51] [0x1a000000000000 13 49] [0x1c000000000000 7 50] [0x1e000000000000 15 49]
+            [0x20000000000000 1 53] [0x24000000000000 9 50] [0x28000000000000 5 51] [0x2c000000000000 11 50] [0x30000000000000 3 52] [0x34000000000000 13 50] [0x38000000000000 7 51] [0x3c000000000000 15 50]
+            [0x40000000000000 1 54] [0x48000000000000 9 51] [0x50000000000000 5 52] [0x58000000000000 11 51] [0x60000000000000 3 53] [0x68000000000000 13 51] [0x70000000000000 7 52] [0x78000000000000 15 51]
+            [0x80000000000000 1 55] [0x90000000000000 9 52] [0xa0000000000000 5 53] [0xb0000000000000 11 52] [0xc0000000000000 3 54] [0xd0000000000000 13 52] [0xe0000000000000 7 53] [0xf0000000000000 15 52]
+            [0x100000000000000 1 56] [0x120000000000000 9 53] [0x140000000000000 5 54] [0x160000000000000 11 53] [0x180000000000000 3 55] [0x1a0000000000000 13 53] [0x1c0000000000000 7 54] [0x1e0000000000000 15 53]
+            [0x200000000000000 1 57] [0x240000000000000 9 54] [0x280000000000000 5 55] [0x2c0000000000000 11 54] [0x300000000000000 3 56] [0x340000000000000 13 54] [0x380000000000000 7 55] [0x3c0000000000000 15 54]
+            [0x400000000000000 1 58] [0x480000000000000 9 55] [0x500000000000000 5 56] [0x580000000000000 11 55] [0x600000000000000 3 57] [0x680000000000000 13 55] [0x700000000000000 7 56] [0x780000000000000 15 55]
+            [0x800000000000000 1 59] [0x900000000000000 9 56] [0xa00000000000000 5 57] [0xb00000000000000 11 56] [0xc00000000000000 3 58] [0xd00000000000000 13 56] [0xe00000000000000 7 57] [0xf00000000000000 15 56]
+            [0x1000000000000000 1 60] [0x1200000000000000 9 57] [0x1400000000000000 5 58] [0x1600000000000000 11 57] [0x1800000000000000 3 59] [0x1a00000000000000 13 57] [0x1c00000000000000 7 58] [0x1e00000000000000 15 57]
+            [0x2000000000000000 1 61] [0x2400000000000000 9 58] [0x2800000000000000 5 59] [0x2c00000000000000 11 58] [0x3000000000000000 3 60] [0x3400000000000000 13 58] [0x3800000000000000 7 59] [0x3c00000000000000 15 58]
+            [0x4000000000000000 1 62] [0x4800000000000000 9 59] [0x5000000000000000 5 60] [0x5800000000000000 11 59] [0x6000000000000000 3 61] [0x6800000000000000 13 59] [0x7000000000000000 7 60] [0x7800000000000000 15 59]
+            [0x8000000000000000 1 63] [0x9000000000000000 9 60] [0xa000000000000000 5 61] [0xb000000000000000 11 60] [0xc000000000000000 3 62] [0xd000000000000000 13 60] [0xe000000000000000 7 61] [0xf000000000000000 15 60]
+            ; 0xffffffffffffffff 64
+        }
+    };
+}
+
+#[cfg(all(
+    feature = "typenum",
+    feature = "ladder-step-1_0625",
+    target_pointer_width = "16"
+))]
+macro_rules! refine_shifts {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            [0x104 260 0] [0x108 264 0] [0x10a 266 0] [0x10e 270 0] [0x110 272 0] [0x114 276 0] [0x118 280 0] [0x122 290 0]
+            [0x126 294 0] [0x12c 300 0] [0x130 304 0] [0x134 308 0] [0x136 310 0] [0x138 312 0] [0x142 322 0] [0x144 324 0]
+            [0x150 336 0] [0x154 340 0] [0x15c 348 0] [0x15e 350 0] [0x168 360 0] [0x16c 364 0] [0x170 368 0] [0x174 372 0]
+            [0x17a 378 0] [0x17c 380 0] [0x188 392 0] [0x190 400 0] [0x196 406 0] [0x198 408 0] [0x1a4 420 0] [0x1b0 432 0]
+            [0x1b2 434 0] [0x1b8 440 0] [0x1c8 456 0] [0x1cc 460 0] [0x1d0 464 0] [0x1dc 476 0] [0x1f0 496 0] [0x1f4 500 0]
+            [0x1f8 504 0] [0x208 520 0] [0x210 528 0] [0x214 532 0] [0x21c 540 0] [0x220 544 0] [0x228 552 0] [0x230 560 0]
+            [0x244 580 0] [0x24c 588 0] [0x258 600 0] [0x260 608 0] [0x268 616 0] [0x26c 620 0] [0x270 624 0] [0x284 644 0]
+            [0x288 648 0] [0x2a0 672 0] [0x2a8 680 0] [0x2b8 696 0] [0x2bc 700 0] [0x2d0 720 0] [0x2d8 728 0] [0x2e0 736 0]
+            [0x2e8 744 0] [0x2f4 756 0] [0x2f8 760 0] [0x310 784 0] [0x320 800 0] [0x32c 812 0] [0x330 816 0] [0x348 840 0]
+            [0x360 864 0] [0x364 868 0] [0x370 880 0] [0x390 912 0] [0x398 920 0] [0x3a0 928 0] [0x3b8 952 0] [0x3e0 992 0]
+            [0x3e8 1000 0] [0x3f0 1008 0] [0x410 65 4] [0x420 33 5] [0x428 133 3] [0x438 135 3] [0x440 17 6] [0x450 69 4]
+            [0x460 35 5] [0x488 145 3] [0x498 147 3] [0x4b0 75 4] [0x4c0 19 6] [0x4d0 77 4] [0x4d8 155 3] [0x4e0 39 5]
+            [0x508 161 3] [0x510 81 4] [0x540 21 6] [0x550 85 4] [0x570 87 4] [0x578 175 3] [0x5a0 45 5] [0x5b0 91 4]
+            [0x5c0 23 6] [0x5d0 93 4] [0x5e8 189 3] [0x5f0 95 4] [0x620 49 5] [0x640 25 6] [0x658 203 3] [0x660 51 5]
+            [0x690 105 4] [0x6c0 27 6] [0x6c8 217 3] [0x6e0 55 5] [0x720 57 5] [0x730 115 4] [0x740 29 6] [0x770 119 4]
+            [0x7c0 31 6] [0x7d0 125 4] [0x7e0 63 5] [0x820 65 5] [0x840 33 6] [0x850 133 4] [0x870 135 4] [0x880 17 7]
+            [0x8a0 69 5] [0x8c0 35 6] [0x910 145 4] [0x930 147 4] [0x960 75 5] [0x980 19 7] [0x9a0 77 5] [0x9b0 155 4]
+            [0x9c0 39 6] [0xa10 161 4] [0xa20 81 5] [0xa80 21 7] [0xaa0 85 5] [0xae0 87 5] [0xaf0 175 4] [0xb40 45 6]
+            [0xb60 91 5] [0xb80 23 7] [0xba0 93 5] [0xbd0 189 4] [0xbe0 95 5] [0xc40 49 6] [0xc80 25 7] [0xcb0 203 4]
+            [0xcc0 51 6] [0xd20 105 5] [0xd80 27 7] [0xd90 217 4] [0xdc0 55 6] [0xe40 57 6] [0xe60 115 5] [0xe80 29 7]
+            [0xee0 119 5] [0xf80 31 7] [0xfa0 125 5] [0xfc0 63 6] [0x1040 65 6] [0x1080 33 7] [0x10a0 133 5] [0x10e0 135 5]
+            [0x1100 17 8] [0x1140 69 6] [0x1180 35 7] [0x1220 145 5] [0x1260 147 5] [0x12c0 75 6] [0x1300 19 8] [0x1340 77 6]
+            [0x1360 155 5] [0x1380 39 7] [0x1420 161 5] [0x1440 81 6] [0x1500 21 8] [0x1540 85 6] [0x15c0 87 6] [0x15e0 175 5]
+            [0x1680 45 7] [0x16c0 91 6] [0x1700 23 8] [0x1740 93 6] [0x17a0 189 5] [0x17c0 95 6] [0x1880 49 7] [0x1900 25 8]
+            [0x1960 203 5] [0x1980 51 7] [0x1a40 105 6] [0x1b00 27 8] [0x1b20 217 5] [0x1b80 55 7] [0x1c80 57 7] [0x1cc0 115 6]
+            [0x1d00 29 8] [0x1dc0 119 6] [0x1f00 31 8] [0x1f40 125 6] [0x1f80 63 7] [0x2080 65 7] [0x2100 33 8] [0x2140 133 6]
+            [0x21c0 135 6] [0x2200 17 9] [0x2280 69 7] [0x2300 35 8] [0x2440 145 6] [0x24c0 147 6] [0x2580 75 7] [0x2600 19 9]
+            [0x2680 77 7] [0x26c0 155 6] [0x2700 39 8] [0x2840 161 6] [0x2880 81 7] [0x2a00 21 9] [0x2a80 85 7] [0x2b80 87 7]
+            [0x2bc0 175 6] [0x2d00 45 8] [0x2d80 91 7] [0x2e00 23 9] [0x2e80 93 7] [0x2f40 189 6] [0x2f80 95 7] [0x3100 49 8]
+            [0x3200 25 9] [0x32c0 203 6] [0x3300 51 8] [0x3480 105 7] [0x3600 27 9] [0x3640 217 6] [0x3700 55 8] [0x3900 57 8]
+            [0x3980 115 7] [0x3a00 29 9] [0x3b80 119 7] [0x3e00 31 9] [0x3e80 125 7] [0x3f00 63 8] [0x4100 65 8] [0x4200 33 9]
+            [0x4280 133 7] [0x4380 135 7] [0x4400 17 10] [0x4500 69 8] [0x4600 35 9] [0x4880 145 7] [0x4980 147 7] [0x4b00 75 8]
+            [0x4c00 19 10] [0x4d00 77 8] [0x4d80 155 7] [0x4e00 39 9] [0x5080 161 7] [0x5100 81 8] [0x5400 21 10] [0x5500 85 8]
+            [0x5700 87 8] [0x5780 175 7] [0x5a00 45 9] [0x5b00 91 8] [0x5c00 23 10] [0x5d00 93 8] [0x5e80 189 7] [0x5f00 95 8]
+            [0x6200 49 9] [0x6400 25 10] [0x6580 203 7] [0x6600 51 9] [0x6900 105 8] [0x6c00 27 10] [0x6c80 217 7] [0x6e00 55 9]
+            [0x7200 57 9] [0x7300 115 8] [0x7400 29 10] [0x7700 119 8] [0x7c00 31 10] [0x7d00 125 8] [0x7e00 63 9] [0x8200 65 9]
+            [0x8400 33 10] [0x8500 133 8] [0x8700 135 8] [0x8a00 69 9] [0x8c00 35 10] [0x9100 145 8] [0x9300 147 8] [0x9600 75 9]
+            [0x9a00 77 9] [0x9b00 155 8] [0x9c00 39 10] [0xa100 161 8] [0xa200 81 9] [0xa800 21 11] [0xae00 87 9] [0xaf00 175 8]
+            [0xb400 45 10] [0xb600 91 9] [0xba00 93 9] [0xbd00 189 8] [0xc400 49 10] [0xcb00 203 8] [0xd200 105 9] [0xd900 217 8]
+        }
+    };
+}
+
+#[cfg(all(
+    feature = "typenum",
+    feature = "ladder-step-1_0625",
+    target_pointer_width = "32"
+))]
+macro_rules! refine_shifts {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            [0x104 260 0] [0x108 264 0] [0x10a 266 0] [0x10e 270 0] [0x110 272 0] [0x114 276 0] [0x118 280 0] [0x122 290 0]
+            [0x126 294 0] [0x12c 300 0] [0x130 304 0] [0x134 308 0] [0x136 310 0] [0x138 312 0] [0x142 322 0] [0x144 324 0]
+            [0x150 336 0] [0x154 340 0] [0x15c 348 0] [0x15e 350 0] [0x168 360 0] [0x16c 364 0] [0x170 368 0] [0x174 372 0]
+            [0x17a 378 0] [0x17c 380 0] [0x188 392 0] [0x190 400 0] [0x196 406 0] [0x198 408 0] [0x1a4 420 0] [0x1b0 432 0]
+            [0x1b2 434 0] [0x1b8 440 0] [0x1c8 456 0] [0x1cc 460 0] [0x1d0 464 0] [0x1dc 476 0] [0x1f0 496 0] [0x1f4 500 0]
+            [0x1f8 504 0] [0x208 520 0] [0x210 528 0] [0x214 532 0] [0x21c 540 0] [0x220 544 0] [0x228 552 0] [0x230 560 0]
+            [0x244 580 0] [0x24c 588 0] [0x258 600 0] [0x260 608 0] [0x268 616 0] [0x26c 620 0] [0x270 624 0] [0x284 644 0]
+            [0x288 648 0] [0x2a0 672 0] [0x2a8 680 0] [0x2b8 696 0] [0x2bc 700 0] [0x2d0 720 0] [0x2d8 728 0] [0x2e0 736 0]
+            [0x2e8 744 0] [0x2f4 756 0] [0x2f8 760 0] [0x310 784 0] [0x320 800 0] [0x32c 812 0] [0x330 816 0] [0x348 840 0]
+            [0x360 864 0] [0x364 868 0] [0x370 880 0] [0x390 912 0] [0x398 920 0] [0x3a0 928 0] [0x3b8 952 0] [0x3e0 992 0]
+            [0x3e8 1000 0] [0x3f0 1008 0] [0x410 65 4] [0x420 33 5] [0x428 133 3] [0x438 135 3] [0x440 17 6] [0x450 69 4]
+            [0x460 35 5] [0x488 145 3] [0x498 147 3] [0x4b0 75 4] [0x4c0 19 6] [0x4d0 77 4] [0x4d8 155 3] [0x4e0 39 5]
+            [0x508 161 3] [0x510 81 4] [0x540 21 6] [0x550 85 4] [0x570 87 4] [0x578 175 3] [0x5a0 45 5] [0x5b0 91 4]
+            [0x5c0 23 6] [0x5d0 93 4] [0x5e8 189 3] [0x5f0 95 4] [0x620 49 5] [0x640 25 6] [0x658 203 3] [0x660 51 5]
+            [0x690 105 4] [0x6c0 27 6] [0x6c8 217 3] [0x6e0 55 5] [0x720 57 5] [0x730 115 4] [0x740 29 6] [0x770 119 4]
+            [0x7c0 31 6] [0x7d0 125 4] [0x7e0 63 5] [0x820 65 5] [0x840 33 6] [0x850 133 4] [0x870 135 4] [0x880 17 7]
+            [0x8a0 69 5] [0x8c0 35 6] [0x910 145 4] [0x930 147 4] [0x960 75 5] [0x980 19 7] [0x9a0 77 5] [0x9b0 155 4]
+            [0x9c0 39 6] [0xa10 161 4] [0xa20 81 5] [0xa80 21 7] [0xaa0 85 5] [0xae0 87 5] [0xaf0 175 4] [0xb40 45 6]
+            [0xb60 91 5] [0xb80 23 7] [0xba0 93 5] [0xbd0 189 4] [0xbe0 95 5] [0xc40 49 6] [0xc80 25 7] [0xcb0 203 4]
+            [0xcc0 51 6] [0xd20 105 5] [0xd80 27 7] [0xd90 217 4] [0xdc0 55 6] [0xe40 57 6] [0xe60 115 5] [0xe80 29 7]
+            [0xee0 119 5] [0xf80 31 7] [0xfa0 125 5] [0xfc0 63 6] [0x1040 65 6] [0x1080 33 7] [0x10a0 133 5] [0x10e0 135 5]
+            [0x1100 17 8] [0x1140 69 6] [0x1180 35 7] [0x1220 145 5] [0x1260 147 5] [0x12c0 75 6] [0x1300 19 8] [0x1340 77 6]
+            [0x1360 155 5] [0x1380 39 7] [0x1420 161 5] [0x1440 81 6] [0x1500 21 8] [0x1540 85 6] [0x15c0 87 6] [0x15e0 175 5]
+            [0x1680 45 7] [0x16c0 91 6] [0x1700 23 8] [0x1740 93 6] [0x17a0 189 5] [0x17c0 95 6] [0x1880 49 7] [0x1900 25 8]
+            [0x1960 203 5] [0x1980 51 7] [0x1a40 105 6] [0x1b00 27 8] [0x1b20 217 5] [0x1b80 55 7] [0x1c80 57 7] [0x1cc0 115 6]
+            [0x1d00 29 8] [0x1dc0 119 6] [0x1f00 31 8] [0x1f40 125 6] [0x1f80 63 7] [0x2080 65 7] [0x2100 33 8] [0x2140 133 6]
+            [0x21c0 135 6] [0x2200 17 9] [0x2280 69 7] [0x2300 35 8] [0x2440 145 6] [0x24c0 147 6] [0x2580 75 7] [0x2600 19 9]
+            [0x2680 77 7] [0x26c0 155 6] [0x2700 39 8] [0x2840 161 6] [0x2880 81 7] [0x2a00 21 9] [0x2a80 85 7] [0x2b80 87 7]
+            [0x2bc0 175 6] [0x2d00 45 8] [0x2d80 91 7] [0x2e00 23 9] [0x2e80 93 7] [0x2f40 189 6] [0x2f80 95 7] [0x3100 49 8]
+            [0x3200 25 9] [0x32c0 203 6] [0x3300 51 8] [0x3480 105 7] [0x3600 27 9] [0x3640 217 6] [0x3700 55 8] [0x3900 57 8]
+            [0x3980 115 7] [0x3a00 29 9] [0x3b80 119 7] [0x3e00 31 9] [0x3e80 125 7] [0x3f00 63 8] [0x4100 65 8] [0x4200 33 9]
+            [0x4280 133 7] [0x4380 135 7] [0x4400 17 10] [0x4500 69 8] [0x4600 35 9] [0x4880 145 7] [0x4980 147 7] [0x4b00 75 8]
+            [0x4c00 19 10] [0x4d00 77 8] [0x4d80 155 7] [0x4e00 39 9] [0x5080 161 7] [0x5100 81 8] [0x5400 21 10] [0x5500 85 8]
+            [0x5700 87 8] [0x5780 175 7] [0x5a00 45 9] [0x5b00 91 8] [0x5c00 23 10] [0x5d00 93 8] [0x5e80 189 7] [0x5f00 95 8]
+            [0x6200 49 9] [0x6400 25 10] [0x6580 203 7] [0x6600 51 9] [0x6900 105 8] [0x6c00 27 10] [0x6c80 217 7] [0x6e00 55 9]
+            [0x7200 57 9] [0x7300 115 8] [0x7400 29 10] [0x7700 119 8] [0x7c00 31 10] [0x7d00 125 8] [0x7e00 63 9] [0x8200 65 9]
+            [0x8400 33 10] [0x8500 133 8] [0x8700 135 8] [0x8800 17 11] [0x8a00 69 9] [0x8c00 35 10] [0x9100 145 8] [0x9300 147 8]
+            [0x9600 75 9] [0x9800 19 11] [0x9a00 77 9] [0x9b00 155 8] [0x9c00 39 10] [0xa100 161 8] [0xa200 81 9] [0xa800 21 11]
+            [0xaa00 85 9] [0xae00 87 9] [0xaf00 175 8] [0xb400 45 10] [0xb600 91 9] [0xb800 23 11] [0xba00 93 9] [0xbd00 189 8]
+            [0xbe00 95 9] [0xc400 49 10] [0xc800 25 11] [0xcb00 203 8] [0xcc00 51 10] [0xd200 105 9] [0xd800 27 11] [0xd900 217 8]
+            [0xdc00 55 10] [0xe400 57 10] [0xe600 115 9] [0xe800 29 11] [0xee00 119 9] [0xf800 31 11] [0xfa00 125 9] [0xfc00 63 10]
+            [0x10400 65 10] [0x10800 33 11] [0x10a00 133 9] [0x10e00 135 9] [0x11000 17 12] [0x11400 69 10] [0x11800 35 11] [0x12200 145 9]
+            [0x12600 147 9] [0x12c00 75 10] [0x13000 19 12] [0x13400 77 10] [0x13600 155 9] [0x13800 39 11] [0x14200 161 9] [0x14400 81 10]
+            [0x15000 21 12] [0x15400 85 10] [0x15c00 87 10] [0x15e00 175 9] [0x16800 45 11] [0x16c00 91 10] [0x17000 23 12] [0x17400 93 10]
+            [0x17a00 189 9] [0x17c00 95 10] [0x18800 49 11] [0x19000 25 12] [0x19600 203 9] [0x19800 51 11] [0x1a400 105 10] [0x1b000 27 12]
+            [0x1b200 217 9] [0x1b800 55 11] [0x1c800 57 11] [0x1cc00 115 10] [0x1d000 29 12] [0x1dc00 119 10] [0x1f000 31 12] [0x1f400 125 10]
+            [0x1f800 63 11] [0x20800 65 11] [0x21000 33 12] [0x21400 133 10] [0x21c00 135 10] [0x22000 17 13] [0x22800 69 11] [0x23000 35 12]
+            [0x24400 145 10] [0x24c00 147 10] [0x25800 75 11] [0x26000 19 13] [0x26800 77 11] [0x26c00 155 10] [0x27000 39 12] [0x28400 161 10]
+            [0x28800 81 11] [0x2a000 21 13] [0x2a800 85 11] [0x2b800 87 11] [0x2bc00 175 10] [0x2d000 45 12] [0x2d800 91 11] [0x2e000 23 13]
+            [0x2e800 93 11] [0x2f400 189 10] [0x2f800 95 11] [0x31000 49 12] [0x32000 25 13] [0x32c00 203 10] [0x33000 51 12] [0x34800 105 11]
+            [0x36000 27 13] [0x36400 217 10] [0x37000 55 12] [0x39000 57 12] [0x39800 115 11] [0x3a000 29 13] [0x3b800 119 11] [0x3e000 31 13]
+            [0x3e800 125 11] [0x3f000 63 12] [0x41000 65 12] [0x42000 33 13] [0x42800 133 11] [0x43800 135 11] [0x44000 17 14] [0x45000 69 12]
+            [0x46000 35 13] [0x48800 145 11] [0x49800 147 11] [0x4b000 75 12] [0x4c000 19 14] [0x4d000 77 12] [0x4d800 155 11] [0x4e000 39 13]
+            [0x50800 161 11] [0x51000 81 12] [0x54000 21 14] [0x55000 85 12] [0x57000 87 12] [0x57800 175 11] [0x5a000 45 13] [0x5b000 91 12]
+            [0x5c000 23 14] [0x5d000 93 12] [0x5e800 189 11] [0x5f000 95 12] [0x62000 49 13] [0x64000 25 14] [0x65800 203 11] [0x66000 51 13]
+            [0x69000 105 12] [0x6c000 27 14] [0x6c800 217 11] [0x6e000 55 13] [0x72000 57 13] [0x73000 115 12] [0x74000 29 14] [0x77000 119 12]
+            [0x7c000 31 14] [0x7d000 125 12] [0x7e000 63 13] [0x82000 65 13] [0x84000 33 14] [0x85000 133 12] [0x87000 135 12] [0x88000 17 15]
+            [0x8a000 69 13] [0x8c000 35 14] [0x91000 145 12] [0x93000 147 12] [0x96000 75 13] [0x98000 19 15] [0x9a000 77 13] [0x9b000 155 12]
+            [0x9c000 39 14] [0xa1000 161 12] [0xa2000 81 13] [0xa8000 21 15] [0xaa000 85 13] [0xae000 87 13] [0xaf000 175 12] [0xb4000 45 14]
+            [0xb6000 91 13] [0xb8000 23 15] [0xba000 93 13] [0xbd000 189 12] [0xbe000 95 13] [0xc4000 49 14] [0xc8000 25 15] [0xcb000 203 12]
+            [0xcc000 51 14] [0xd2000 105 13] [0xd8000 27 15] [0xd9000 217 12] [0xdc000 55 14] [0xe4000 57 14] [0xe6000 115 13] [0xe8000 29 15]
+            [0xee000 119 13] [0xf8000 31 15] [0xfa000 125 13] [0xfc000 63 14] [0x104000 65 14] [0x108000 33 15] [0x10a000 133 13] [0x10e000 135 13]
+            [0x110000 17 16] [0x114000 69 14] [0x118000 35 15] [0x122000 145 13] [0x126000 147 13] [0x12c000 75 14] [0x130000 19 16] [0x134000 77 14]
+            [0x136000 155 13] [0x138000 39 15] [0x142000 161 13] [0x144000 81 14] [0x150000 21 16] [0x154000 85 14] [0x15c000 87 14] [0x15e000 175 13]
+            [0x168000 45 15] [0x16c000 91 14] [0x170000 23 16] [0x174000 93 14] [0x17a000 189 13] [0x17c000 95 14] [0x188000 49 15] [0x190000 25 16]
+            [0x196000 203 13] [0x198000 51 15] [0x1a4000 105 14] [0x1b0000 27 16] [0x1b2000 217 13] [0x1b8000 55 15] [0x1c8000 57 15] [0x1cc000 115 14]
+            [0x1d0000 29 16] [0x1dc000 119 14] [0x1f0000 31 16] [0x1f4000 125 14] [0x1f8000 63 15] [0x208000 65 15] [0x210000 33 16] [0x214000 133 14]
+            [0x21c000 135 14] [0x220000 17 17] [0x228000 69 15] [0x230000 35 16] [0x244000 145 14] [0x24c000 147 14] [0x258000 75 15] [0x260000 19 17]
+            [0x268000 77 15] [0x26c000 155 14] [0x270000 39 16] [0x284000 161 14] [0x288000 81 15] [0x2a0000 21 17] [0x2a8000 85 15] [0x2b8000 87 15]
+            [0x2bc000 175 14] [0x2d0000 45 16] [0x2d8000 91 15] [0x2e0000 23 17] [0x2e8000 93 15] [0x2f4000 189 14] [0x2f8000 95 15] [0x310000 49 16]
+            [0x320000 25 17] [0x32c000 203 14] [0x330000 51 16] [0x348000 105 15] [0x360000 27 17] [0x364000 217 14] [0x370000 55 16] [0x390000 57 16]
+            [0x398000 115 15] [0x3a0000 29 17] [0x3b8000 119 15] [0x3e0000 31 17] [0x3e8000 125 15] [0x3f0000 63 16] [0x410000 65 16] [0x420000 33 17]
+            [0x428000 133 15] [0x438000 135 15] [0x440000 17 18] [0x450000 69 16] [0x460000 35 17] [0x488000 145 15] [0x498000 147 15] [0x4b0000 75 16]
+            [0x4c0000 19 18] [0x4d0000 77 16] [0x4d8000 155 15] [0x4e0000 39 17] [0x508000 161 15] [0x510000 81 16] [0x540000 21 18] [0x550000 85 16]
+            [0x570000 87 16] [0x578000 175 15] [0x5a0000 45 17] [0x5b0000 91 16] [0x5c0000 23 18] [0x5d0000 93 16] [0x5e8000 189 15] [0x5f0000 95 16]
+            [0x620000 49 17] [0x640000 25 18] [0x658000 203 15] [0x660000 51 17] [0x690000 105 16] [0x6c0000 27 18] [0x6c8000 217 15] [0x6e0000 55 17]
+            [0x720000 57 17] [0x730000 115 16] [0x740000 29 18] [0x770000 119 16] [0x7c0000 31 18] [0x7d0000 125 16] [0x7e0000 63 17] [0x820000 65 17]
+            [0x840000 33 18] [0x850000 133 16] [0x870000 135 16] [0x880000 17 19] [0x8a0000 69 17] [0x8c0000 35 18] [0x910000 145 16] [0x930000 147 16]
+            [0x960000 75 17] [0x980000 19 19] [0x9a0000 77 17] [0x9b0000 155 16] [0x9c0000 39 18] [0xa10000 161 16] [0xa20000 81 17] [0xa80000 21 19]
+            [0xaa0000 85 17] [0xae0000 87 17] [0xaf0000 175 16] [0xb40000 45 18] [0xb60000 91 17] [0xb80000 23 19] [0xba0000 93 17] [0xbd0000 189 16]
+            [0xbe0000 95 17] [0xc40000 49 18] [0xc80000 25 19] [0xcb0000 203 16] [0xcc0000 51 18] [0xd20000 105 17] [0xd80000 27 19] [0xd90000 217 16]
+            [0xdc0000 55 18] [0xe40000 57 18] [0xe60000 115 17] [0xe80000 29 19] [0xee0000 119 17] [0xf80000 31 19] [0xfa0000 125 17] [0xfc0000 63 18]
+            [0x1040000 65 18] [0x1080000 33 19] [0x10a0000 133 17] [0x10e0000 135 17] [0x1100000 17 20] [0x1140000 69 18] [0x1180000 35 19] [0x1220000 145 17]
+            [0x1260000 147 17] [0x12c0000 75 18] [0x1300000 19 20] [0x1340000 77 18] [0x1360000 155 17] [0x1380000 39 19] [0x1420000 161 17] [0x1440000 81 18]
+            [0x1500000 21 20] [0x1540000 85 18] [0x15c0000 87 18] [0x15e0000 175 17] [0x1680000 45 19] [0x16c0000 91 18] [0x1700000 23 20] [0x1740000 93 18]
+            [0x17a0000 189 17] [0x17c0000 95 18] [0x1880000 49 19] [0x1900000 25 20] [0x1960000 203 17] [0x1980000 51 19] [0x1a40000 105 18] [0x1b00000 27 20]
+            [0x1b20000 217 17] [0x1b80000 55 19] [0x1c80000 57 19] [0x1cc0000 115 18] [0x1d00000 29 20] [0x1dc0000 119 18] [0x1f00000 31 20] [0x1f40000 125 18]
+            [0x1f80000 63 19] [0x2080000 65 19] [0x2100000 33 20] [0x2140000 133 18] [0x21c0000 135 18] [0x2200000 17 21] [0x2280000 69 19] [0x2300000 35 20]
+            [0x2440000 145 18] [0x24c0000 147 18] [0x2580000 75 19] [0x2600000 19 21] [0x2680000 77 19] [0x26c0000 155 18] [0x2700000 39 20] [0x2840000 161 18]
+            [0x2880000 81 19] [0x2a00000 21 21] [0x2a80000 85 19] [0x2b80000 87 19] [0x2bc0000 175 18] [0x2d00000 45 20] [0x2d80000 91 19] [0x2e00000 23 21]
+            [0x2e80000 93 19] [0x2f40000 189 18] [0x2f80000 95 19] [0x3100000 49 20] [0x3200000 25 21] [0x32c0000 203 18] [0x3300000 51 20] [0x3480000 105 19]
+            [0x3600000 27 21] [0x3640000 217 18] [0x3700000 55 20] [0x3900000 57 20] [0x3980000 115 19] [0x3a00000 29 21] [0x3b80000 119 19] [0x3e00000 31 21]
+            [0x3e80000 125 19] [0x3f00000 63 20] [0x4100000 65 20] [0x4200000 33 21] [0x4280000 133 19] [0x4380000 135 19] [0x4400000 17 22] [0x4500000 69 20]
+            [0x4600000 35 21] [0x4880000 145 19] [0x4980000 147 19] [0x4b00000 75 20] [0x4c00000 19 22] [0x4d00000 77 20] [0x4d80000 155 19] [0x4e00000 39 21]
+            [0x5080000 161 19] [0x5100000 81 20] [0x5400000 21 22] [0x5500000 85 20] [0x5700000 87 20] [0x5780000 175 19] [0x5a00000 45 21] [0x5b00000 91 20]
+            [0x5c00000 23 22] [0x5d00000 93 20] [0x5e80000 189 19] [0x5f00000 95 20] [0x6200000 49 21] [0x6400000 25 22] [0x6580000 203 19] [0x6600000 51 21]
+            [0x6900000 105 20] [0x6c00000 27 22] [0x6c80000 217 19] [0x6e00000 55 21] [0x7200000 57 21] [0x7300000 115 20] [0x7400000 29 22] [0x7700000 119 20]
+            [0x7c00000 31 22] [0x7d00000 125 20] [0x7e00000 63 21] [0x8200000 65 21] [0x8400000 33 22] [0x8500000 133 20] [0x8700000 135 20] [0x8800000 17 23]
+            [0x8a00000 69 21] [0x8c00000 35 22] [0x9100000 145 20] [0x9300000 147 20] [0x9600000 75 21] [0x9800000 19 23] [0x9a00000 77 21] [0x9b00000 155 20]
+            [0x9c00000 39 22] [0xa100000 161 20] [0xa200000 81 21] [0xa800000 21 23] [0xaa00000 85 21] [0xae00000 87 21] [0xaf00000 175 20] [0xb400000 45 22]
+            [0xb600000 91 21] [0xb800000 23 23] [0xba00000 93 21] [0xbd00000 189 20] [0xbe00000 95 21] [0xc400000 49 22] [0xc800000 25 23] [0xcb00000 203 20]
+            [0xcc00000 51 22] [0xd200000 105 21] [0xd800000 27 23] [0xd900000 217 20] [0xdc00000 55 22] [0xe400000 57 22] [0xe600000 115 21] [0xe800000 29 23]
+            [0xee00000 119 21] [0xf800000 31 23] [0xfa00000 125 21] [0xfc00000 63 22] [0x10400000 65 22] [0x10800000 33 23] [0x10a00000 133 21] [0x10e00000 135 21]
+            [0x11000000 17 24] [0x11400000 69 22] [0x11800000 35 23] [0x12200000 145 21] [0x12600000 147 21] [0x12c00000 75 22] [0x13000000 19 24] [0x13400000 77 22]
+            [0x13600000 155 21] [0x13800000 39 23] [0x14200000 161 21] [0x14400000 81 22] [0x15000000 21 24] [0x15400000 85 22] [0x15c00000 87 22] [0x15e00000 175 21]
+            [0x16800000 45 23] [0x16c00000 91 22] [0x17000000 23 24] [0x17400000 93 22] [0x17a00000 189 21] [0x17c00000 95 22] [0x18800000 49 23] [0x19000000 25 24]
+            [0x19600000 203 21] [0x19800000 51 23] [0x1a400000 105 22] [0x1b000000 27 24] [0x1b200000 217 21] [0x1b800000 55 23] [0x1c800000 57 23] [0x1cc00000 115 22]
+            [0x1d000000 29 24] [0x1dc00000 119 22] [0x1f000000 31 24] [0x1f400000 125 22] [0x1f800000 63 23] [0x20800000 65 23] [0x21000000 33 24] [0x21400000 133 22]
+            [0x21c00000 135 22] [0x22000000 17 25] [0x22800000 69 23] [0x23000000 35 24] [0x24400000 145 22] [0x24c00000 147 22] [0x25800000 75 23] [0x26000000 19 25]
+            [0x26800000 77 23] [0x26c00000 155 22] [0x27000000 39 24] [0x28400000 161 22] [0x28800000 81 23] [0x2a000000 21 25] [0x2a800000 85 23] [0x2b800000 87 23]
+            [0x2bc00000 175 22] [0x2d000000 45 24] [0x2d800000 91 23] [0x2e000000 23 25] [0x2e800000 93 23] [0x2f400000 189 22] [0x2f800000 95 23] [0x31000000 49 24]
+            [0x32000000 25 25] [0x32c00000 203 22] [0x33000000 51 24] [0x34800000 105 23] [0x36000000 27 25] [0x36400000 217 22] [0x37000000 55 24] [0x39000000 57 24]
+            [0x39800000 115 23] [0x3a000000 29 25] [0x3b800000 119 23] [0x3e000000 31 25] [0x3e800000 125 23] [0x3f000000 63 24] [0x41000000 65 24] [0x42000000 33 25]
+            [0x42800000 133 23] [0x43800000 135 23] [0x44000000 17 26] [0x45000000 69 24] [0x46000000 35 25] [0x48800000 145 23] [0x49800000 147 23] [0x4b000000 75 24]
+            [0x4c000000 19 26] [0x4d000000 77 24] [0x4d800000 155 23] [0x4e000000 39 25] [0x50800000 161 23] [0x51000000 81 24] [0x54000000 21 26] [0x55000000 85 24]
+            [0x57000000 87 24] [0x57800000 175 23] [0x5a000000 45 25] [0x5b000000 91 24] [0x5c000000 23 26] [0x5d000000 93 24] [0x5e800000 189 23] [0x5f000000 95 24]
+            [0x62000000 49 25] [0x64000000 25 26] [0x65800000 203 23] [0x66000000 51 25] [0x69000000 105 24] [0x6c000000 27 26] [0x6c800000 217 23] [0x6e000000 55 25]
+            [0x72000000 57 25] [0x73000000 115 24] [0x74000000 29 26] [0x77000000 119 24] [0x7c000000 31 26] [0x7d000000 125 24] [0x7e000000 63 25] [0x82000000 65 25]
+            [0x84000000 33 26] [0x85000000 133 24] [0x87000000 135 24] [0x8a000000 69 25] [0x8c000000 35 26] [0x91000000 145 24] [0x93000000 147 24] [0x96000000 75 25]
+            [0x9a000000 77 25] [0x9b000000 155 24] [0x9c000000 39 26] [0xa1000000 161 24] [0xa2000000 81 25] [0xa8000000 21 27] [0xae000000 87 25] [0xaf000000 175 24]
+            [0xb4000000 45 26] [0xb6000000 91 25] [0xba000000 93 25] [0xbd000000 189 24] [0xc4000000 49 26] [0xcb000000 203 24] [0xd2000000 105 25] [0xd9000000 217 24]
+        }
+    };
+}
+
+#[cfg(all(
+    feature = "typenum",
+    feature = "ladder-step-1_0625",
+    target_pointer_width = "64"
+))]
+macro_rules! refine_shifts {
+    ($($mac:tt)*) => {
+        $($mac)*! {
+            [0x104 260 0] [0x108 264 0] [0x10a 266 0] [0x10e 270 0] [0x110 272 0] [0x114 276 0] [0x118 280 0] [0x122 290 0]
+            [0x126 294 0] [0x12c 300 0] [0x130 304 0] [0x134 308 0] [0x136 310 0] [0x138 312 0] [0x142 322 0] [0x144 324 0]
+            [0x150 336 0] [0x154 340 0] [0x15c 348 0] [0x15e 350 0] [0x168 360 0] [0x16c 364 0] [0x170 368 0] [0x174 372 0]
+            [0x17a 378 0] [0x17c 380 0] [0x188 392 0] [0x190 400 0] [0x196 406 0] [0x198 408 0] [0x1a4 420 0] [0x1b0 432 0]
+            [0x1b2 434 0] [0x1b8 440 0] [0x1c8 456 0] [0x1cc 460 0] [0x1d0 464 0] [0x1dc 476 0] [0x1f0 496 0] [0x1f4 500 0]
+            [0x1f8 504 0] [0x208 520 0] [0x210 528 0] [0x214 532 0] [0x21c 540 0] [0x220 544 0] [0x228 552 0] [0x230 560 0]
+            [0x244 580 0] [0x24c 588 0] [0x258 600 0] [0x260 608 0] [0x268 616 0] [0x26c 620 0] [0x270 624 0] [0x284 644 0]
+            [0x288 648 0] [0x2a0 672 0] [0x2a8 680 0] [0x2b8 696 0] [0x2bc 700 0] [0x2d0 720 0] [0x2d8 728 0] [0x2e0 736 0]
+            [0x2e8 744 0] [0x2f4 756 0] [0x2f8 760 0] [0x310 784 0] [0x320 800 0] [0x32c 812 0] [0x330 816 0] [0x348 840 0]
+            [0x360 864 0] [0x364 868 0] [0x370 880 0] [0x390 912 0] [0x398 920 0] [0x3a0 928 0] [0x3b8 952 0] [0x3e0 992 0]
+            [0x3e8 1000 0] [0x3f0 1008 0] [0x410 65 4] [0x420 33 5] [0x428 133 3] [0x438 135 3] [0x440 17 6] [0x450 69 4]
+            [0x460 35 5] [0x488 145 3] [0x498 147 3] [0x4b0 75 4] [0x4c0 19 6] [0x4d0 77 4] [0x4d8 155 3] [0x4e0 39 5]
+            [0x508 161 3] [0x510 81 4] [0x540 21 6] [0x550 85 4] [0x570 87 4] [0x578 175 3] [0x5a0 45 5] [0x5b0 91 4]
+            [0x5c0 23 6] [0x5d0 93 4] [0x5e8 189 3] [0x5f0 95 4] [0x620 49 5] [0x640 25 6] [0x658 203 3] [0x660 51 5]
+            [0x690 105 4] [0x6c0 27 6] [0x6c8 217 3] [0x6e0 55 5] [0x720 57 5] [0x730 115 4] [0x740 29 6] [0x770 119 4]
+            [0x7c0 31 6] [0x7d0 125 4] [0x7e0 63 5] [0x820 65 5] [0x840 33 6] [0x850 133 4] [0x870 135 4] [0x880 17 7]
+            [0x8a0 69 5] [0x8c0 35 6] [0x910 145 4] [0x930 147 4] [0x960 75 5] [0x980 19 7] [0x9a0 77 5] [0x9b0 155 4]
+            [0x9c0 39 6] [0xa10 161 4] [0xa20 81 5] [0xa80 21 7] [0xaa0 85 5] [0xae0 87 5] [0xaf0 175 4] [0xb40 45 6]
+            [0xb60 91 5] [0xb80 23 7] [0xba0 93 5] [0xbd0 189 4] [0xbe0 95 5] [0xc40 49 6] [0xc80 25 7] [0xcb0 203 4]
+            [0xcc0 51 6] [0xd20 105 5] [0xd80 27 7] [0xd90 217 4] [0xdc0 55 6] [0xe40 57 6] [0xe60 115 5] [0xe80 29 7]
+            [0xee0 119 5] [0xf80 31 7] [0xfa0 125 5] [0xfc0 63 6] [0x1040 65 6] [0x1080 33 7] [0x10a0 133 5] [0x10e0 135 5]
+            [0x1100 17 8] [0x1140 69 6] [0x1180 35 7] [0x1220 145 5] [0x1260 147 5] [0x12c0 75 6] [0x1300 19 8] [0x1340 77 6]
+            [0x1360 155 5] [0x1380 39 7] [0x1420 161 5] [0x1440 81 6] [0x1500 21 8] [0x1540 85 6] [0x15c0 87 6] [0x15e0 175 5]
+            [0x1680 45 7] [0x16c0 91 6] [0x1700 23 8] [0x1740 93 6] [0x17a0 189 5] [0x17c0 95 6] [0x1880 49 7] [0x1900 25 8]
+            [0x1960 203 5] [0x1980 51 7] [0x1a40 105 6] [0x1b00 27 8] [0x1b20 217 5] [0x1b80 55 7] [0x1c80 57 7] [0x1cc0 115 6]
+            [0x1d00 29 8] [0x1dc0 119 6] [0x1f00 31 8] [0x1f40 125 6] [0x1f80 63 7] [0x2080 65 7] [0x2100 33 8] [0x2140 133 6]
+            [0x21c0 135 6] [0x2200 17 9] [0x2280 69 7] [0x2300 35 8] [0x2440 145 6] [0x24c0 147 6] [0x2580 75 7] [0x2600 19 9]
+            [0x2680 77 7] [0x26c0 155 6] [0x2700 39 8] [0x2840 161 6] [0x2880 81 7] [0x2a00 21 9] [0x2a80 85 7] [0x2b80 87 7]
+            [0x2bc0 175 6] [0x2d00 45 8] [0x2d80 91 7] [0x2e00 23 9] [0x2e80 93 7] [0x2f40 189 6] [0x2f80 95 7] [0x3100 49 8]
+            [0x3200 25 9] [0x32c0 203 6] [0x3300 51 8] [0x3480 105 7] [0x3600 27 9] [0x3640 217 6] [0x3700 55 8] [0x3900 57 8]
+            [0x3980 115 7] [0x3a00 29 9] [0x3b80 119 7] [0x3e00 31 9] [0x3e80 125 7] [0x3f00 63 8] [0x4100 65 8] [0x4200 33 9]
+            [0x4280 133 7] [0x4380 135 7] [0x4400 17 10] [0x4500 69 8] [0x4600 35 9] [0x4880 145 7] [0x4980 147 7] [0x4b00 75 8]
+            [0x4c00 19 10] [0x4d00 77 8] [0x4d80 155 7] [0x4e00 39 9] [0x5080 161 7] [0x5100 81 8] [0x5400 21 10] [0x5500 85 8]
+            [0x5700 87 8] [0x5780 175 7] [0x5a00 45 9] [0x5b00 91 8] [0x5c00 23 10] [0x5d00 93 8] [0x5e80 189 7] [0x5f00 95 8]
+            [0x6200 49 9] [0x6400 25 10] [0x6580 203 7] [0x6600 51 9] [0x6900 105 8] [0x6c00 27 10] [0x6c80 217 7] [0x6e00 55 9]
+            [0x7200 57 9] [0x7300 115 8] [0x7400 29 10] [0x7700 119 8] [0x7c00 31 10] [0x7d00 125 8] [0x7e00 63 9] [0x8200 65 9]
+            [0x8400 33 10] [0x8500 133 8] [0x8700 135 8] [0x8800 17 11] [0x8a00 69 9] [0x8c00 35 10] [0x9100 145 8] [0x9300 147 8]
+            [0x9600 75 9] [0x9800 19 11] [0x9a00 77 9] [0x9b00 155 8] [0x9c00 39 10] [0xa100 161 8] [0xa200 81 9] [0xa800 21 11]
+            [0xaa00 85 9] [0xae00 87 9] [0xaf00 175 8] [0xb400 45 10] [0xb600 91 9] [0xb800 23 11] [0xba00 93 9] [0xbd00 189 8]
+            [0xbe00 95 9] [0xc400 49 10] [0xc800 25 11] [0xcb00 203 8] [0xcc00 51 10] [0xd200 105 9] [0xd800 27 11] [0xd900 217 8]
+            [0xdc00 55 10] [0xe400 57 10] [0xe600 115 9] [0xe800 29 11] [0xee00 119 9] [0xf800 31 11] [0xfa00 125 9] [0xfc00 63 10]
+            [0x10400 65 10] [0x10800 33 11] [0x10a00 133 9] [0x10e00 135 9] [0x11000 17 12] [0x11400 69 10] [0x11800 35 11] [0x12200 145 9]
+            [0x12600 147 9] [0x12c00 75 10] [0x13000 19 12] [0x13400 77 10] [0x13600 155 9] [0x13800 39 11] [0x14200 161 9] [0x14400 81 10]
+            [0x15000 21 12] [0x15400 85 10] [0x15c00 87 10] [0x15e00 175 9] [0x16800 45 11] [0x16c00 91 10] [0x17000 23 12] [0x17400 93 10]
+            [0x17a00 189 9] [0x17c00 95 10] [0x18800 49 11] [0x19000 25 12] [0x19600 203 9] [0x19800 51 11] [0x1a400 105 10] [0x1b000 27 12]
+            [0x1b200 217 9] [0x1b800 55 11] [0x1c800 57 11] [0x1cc00 115 10] [0x1d000 29 12] [0x1dc00 119 10] [0x1f000 31 12] [0x1f400 125 10]
+            [0x1f800 63 11] [0x20800 65 11] [0x21000 33 12] [0x21400 133 10] [0x21c00 135 10] [0x22000 17 13] [0x22800 69 11] [0x23000 35 12]
+            [0x24400 145 10] [0x24c00 147 10] [0x25800 75 11] [0x26000 19 13] [0x26800 77 11] [0x26c00 155 10] [0x27000 39 12] [0x28400 161 10]
+            [0x28800 81 11] [0x2a000 21 13] [0x2a800 85 11] [0x2b800 87 11] [0x2bc00 175 10] [0x2d000 45 12] [0x2d800 91 11] [0x2e000 23 13]
+            [0x2e800 93 11] [0x2f400 189 10] [0x2f800 95 11] [0x31000 49 12] [0x32000 25 13] [0x32c00 203 10] [0x33000 51 12] [0x34800 105 11]
+            [0x36000 27 13] [0x36400 217 10] [0x37000 55 12] [0x39000 57 12] [0x39800 115 11] [0x3a000 29 13] [0x3b800 119 11] [0x3e000 31 13]
+            [0x3e800 125 11] [0x3f000 63 12] [0x41000 65 12] [0x42000 33 13] [0x42800 133 11] [0x43800 135 11] [0x44000 17 14] [0x45000 69 12]
+            [0x46000 35 13] [0x48800 145 11] [0x49800 147 11] [0x4b000 75 12] [0x4c000 19 14] [0x4d000 77 12] [0x4d800 155 11] [0x4e000 39 13]
+            [0x50800 161 11] [0x51000 81 12] [0x54000 21 14] [0x55000 85 12] [0x57000 87 12] [0x57800 175 11] [0x5a000 45 13] [0x5b000 91 12]
+            [0x5c000 23 14] [0x5d000 93 12] [0x5e800 189 11] [0x5f000 95 12] [0x62000 49 13] [0x64000 25 14] [0x65800 203 11] [0x66000 51 13]
+            [0x69000 105 12] [0x6c000 27 14] [0x6c800 217 11] [0x6e000 55 13] [0x72000 57 13] [0x73000 115 12] [0x74000 29 14] [0x77000 119 12]
+            [0x7c000 31 14] [0x7d000 125 12] [0x7e000 63 13] [0x82000 65 13] [0x84000 33 14] [0x85000 133 12] [0x87000 135 12] [0x88000 17 15]
+            [0x8a000 69 13] [0x8c000 35 14] [0x91000 145 12] [0x93000 147 12] [0x96000 75 13] [0x98000 19 15] [0x9a000 77 13] [0x9b000 155 12]
+            [0x9c000 39 14] [0xa1000 161 12] [0xa2000 81 13] [0xa8000 21 15] [0xaa000 85 13] [0xae000 87 13] [0xaf000 175 12] [0xb4000 45 14]
+            [0xb6000 91 13] [0xb8000 23 15] [0xba000 93 13] [0xbd000 189 12] [0xbe000 95 13] [0xc4000 49 14] [0xc8000 25 15] [0xcb000 203 12]
+            [0xcc000 51 14] [0xd2000 105 13] [0xd8000 27 15] [0xd9000 217 12] [0xdc000 55 14] [0xe4000 57 14] [0xe6000 115 13] [0xe8000 29 15]
+            [0xee000 119 13] [0xf8000 31 15] [0xfa000 125 13] [0xfc000 63 14] [0x104000 65 14] [0x108000 33 15] [0x10a000 133 13] [0x10e000 135 13]
+            [0x110000 17 16] [0x114000 69 14] [0x118000 35 15] [0x122000 145 13] [0x126000 147 13] [0x12c000 75 14] [0x130000 19 16] [0x134000 77 14]
+            [0x136000 155 13] [0x138000 39 15] [0x142000 161 13] [0x144000 81 14] [0x150000 21 16] [0x154000 85 14] [0x15c000 87 14] [0x15e000 175 13]
+            [0x168000 45 15] [0x16c000 91 14] [0x170000 23 16] [0x174000 93 14] [0x17a000 189 13] [0x17c000 95 14] [0x188000 49 15] [0x190000 25 16]
+            [0x196000 203 13] [0x198000 51 15] [0x1a4000 105 14] [0x1b0000 27 16] [0x1b2000 217 13] [0x1b8000 55 15] [0x1c8000 57 15] [0x1cc000 115 14]
+            [0x1d0000 29 16] [0x1dc000 119 14] [0x1f0000 31 16] [0x1f4000 125 14] [0x1f8000 63 15] [0x208000 65 15] [0x210000 33 16] [0x214000 133 14]
+            [0x21c000 135 14] [0x220000 17 17] [0x228000 69 15] [0x230000 35 16] [0x244000 145 14] [0x24c000 147 14] [0x258000 75 15] [0x260000 19 17]
+            [0x268000 77 15] [0x26c000 155 14] [0x270000 39 16] [0x284000 161 14] [0x288000 81 15] [0x2a0000 21 17] [0x2a8000 85 15] [0x2b8000 87 15]
+            [0x2bc000 175 14] [0x2d0000 45 16] [0x2d8000 91 15] [0x2e0000 23 17] [0x2e8000 93 15] [0x2f4000 189 14] [0x2f8000 95 15] [0x310000 49 16]
+            [0x320000 25 17] [0x32c000 203 14] [0x330000 51 16] [0x348000 105 15] [0x360000 27 17] [0x364000 217 14] [0x370000 55 16] [0x390000 57 16]
+            [0x398000 115 15] [0x3a0000 29 17] [0x3b8000 119 15] [0x3e0000 31 17] [0x3e8000 125 15] [0x3f0000 63 16] [0x410000 65 16] [0x420000 33 17]
+            [0x428000 133 15] [0x438000 135 15] [0x440000 17 18] [0x450000 69 16] [0x460000 35 17] [0x488000 145 15] [0x498000 147 15] [0x4b0000 75 16]
+            [0x4c0000 19 18] [0x4d0000 77 16] [0x4d8000 155 15] [0x4e0000 39 17] [0x508000 161 15] [0x510000 81 16] [0x540000 21 18] [0x550000 85 16]
+            [0x570000 87 16] [0x578000 175 15] [0x5a0000 45 17] [0x5b0000 91 16] [0x5c0000 23 18] [0x5d0000 93 16] [0x5e8000 189 15] [0x5f0000 95 16]
+            [0x620000 49 17] [0x640000 25 18] [0x658000 203 15] [0x660000 51 17] [0x690000 105 16] [0x6c0000 27 18] [0x6c8000 217 15] [0x6e0000 55 17]
+            [0x720000 57 17] [0x730000 115 16] [0x740000 29 18] [0x770000 119 16] [0x7c0000 31 18] [0x7d0000 125 16] [0x7e0000 63 17] [0x820000 65 17]
+            [0x840000 33 18] [0x850000 133 16] [0x870000 135 16] [0x880000 17 19] [0x8a0000 69 17] [0x8c0000 35 18] [0x910000 145 16] [0x930000 147 16]
+            [0x960000 75 17] [0x980000 19 19] [0x9a0000 77 17] [0x9b0000 155 16] [0x9c0000 39 18] [0xa10000 161 16] [0xa20000 81 17] [0xa80000 21 19]
+            [0xaa0000 85 17] [0xae0000 87 17] [0xaf0000 175 16] [0xb40000 45 18] [0xb60000 91 17] [0xb80000 23 19] [0xba0000 93 17] [0xbd0000 189 16]
+            [0xbe0000 95 17] [0xc40000 49 18] [0xc80000 25 19] [0xcb0000 203 16] [0xcc0000 51 18] [0xd20000 105 17] [0xd80000 27 19] [0xd90000 217 16]
+            [0xdc0000 55 18] [0xe40000 57 18] [0xe60000 115 17] [0xe80000 29 19] [0xee0000 119 17] [0xf80000 31 19] [0xfa0000 125 17] [0xfc0000 63 18]
+            [0x1040000 65 18] [0x1080000 33 19] [0x10a0000 133 17] [0x10e0000 135 17] [0x1100000 17 20] [0x1140000 69 18] [0x1180000 35 19] [0x1220000 145 17]
+            [0x1260000 147 17] [0x12c0000 75 18] [0x1300000 19 20] [0x1340000 77 18] [0x1360000 155 17] [0x1380000 39 19] [0x1420000 161 17] [0x1440000 81 18]
+            [0x1500000 21 20] [0x1540000 85 18] [0x15c0000 87 18] [0x15e0000 175 17] [0x1680000 45 19] [0x16c0000 91 18] [0x1700000 23 20] [0x1740000 93 18]
+            [0x17a0000 189 17] [0x17c0000 95 18] [0x1880000 49 19] [0x1900000 25 20] [0x1960000 203 17] [0x1980000 51 19] [0x1a40000 105 18] [0x1b00000 27 20]
+            [0x1b20000 217 17] [0x1b80000 55 19] [0x1c80000 57 19] [0x1cc0000 115 18] [0x1d00000 29 20] [0x1dc0000 119 18] [0x1f00000 31 20] [0x1f40000 125 18]
+            [0x1f80000 63 19] [0x2080000 65 19] [0x2100000 33 20] [0x2140000 133 18] [0x21c0000 135 18] [0x2200000 17 21] [0x2280000 69 19] [0x2300000 35 20]
+            [0x2440000 145 18] [0x24c0000 147 18] [0x2580000 75 19] [0x2600000 19 21] [0x2680000 77 19] [0x26c0000 155 18] [0x2700000 39 20] [0x2840000 161 18]
+            [0x2880000 81 19] [0x2a00000 21 21] [0x2a80000 85 19] [0x2b80000 87 19] [0x2bc0000 175 18] [0x2d00000 45 20] [0x2d80000 91 19] [0x2e00000 23 21]
+            [0x2e80000 93 19] [0x2f40000 189 18] [0x2f80000 95 19] [0x3100000 49 20] [0x3200000 25 21] [0x32c0000 203 18] [0x3300000 51 20] [0x3480000 105 19]
+            [0x3600000 27 21] [0x3640000 217 18] [0x3700000 55 20] [0x3900000 57 20] [0x3980000 115 19] [0x3a00000 29 21] [0x3b80000 119 19] [0x3e00000 31 21]
+            [0x3e80000 125 19] [0x3f00000 63 20] [0x4100000 65 20] [0x4200000 33 21] [0x4280000 133 19] [0x4380000 135 19] [0x4400000 17 22] [0x4500000 69 20]
+            [0x4600000 35 21] [0x4880000 145 19] [0x4980000 147 19] [0x4b00000 75 20] [0x4c00000 19 22] [0x4d00000 77 20] [0x4d80000 155 19] [0x4e00000 39 21]
+            [0x5080000 161 19] [0x5100000 81 20] [0x5400000 21 22] [0x5500000 85 20] [0x5700000 87 20] [0x5780000 175 19] [0x5a00000 45 21] [0x5b00000 91 20]
+            [0x5c00000 23 22] [0x5d00000 93 20] [0x5e80000 189 19] [0x5f00000 95 20] [0x6200000 49 21] [0x6400000 25 22] [0x6580000 203 19] [0x6600000 51 21]
+            [0x6900000 105 20] [0x6c00000 27 22] [0x6c80000 217 19] [0x6e00000 55 21] [0x7200000 57 21] [0x7300000 115 20] [0x7400000 29 22] [0x7700000 119 20]
+            [0x7c00000 31 22] [0x7d00000 125 20] [0x7e00000 63 21] [0x8200000 65 21] [0x8400000 33 22] [0x8500000 133 20] [0x8700000 135 20] [0x8800000 17 23]
+            [0x8a00000 69 21] [0x8c00000 35 22] [0x9100000 145 20] [0x9300000 147 20] [0x9600000 75 21] [0x9800000 19 23] [0x9a00000 77 21] [0x9b00000 155 20]
+            [0x9c00000 39 22] [0xa100000 161 20] [0xa200000 81 21] [0xa800000 21 23] [0xaa00000 85 21] [0xae00000 87 21] [0xaf00000 175 20] [0xb400000 45 22]
+            [0xb600000 91 21] [0xb800000 23 23] [0xba00000 93 21] [0xbd00000 189 20] [0xbe00000 95 21] [0xc400000 49 22] [0xc800000 25 23] [0xcb00000 203 20]
+            [0xcc00000 51 22] [0xd200000 105 21] [0xd800000 27 23] [0xd900000 217 20] [0xdc00000 55 22] [0xe400000 57 22] [0xe600000 115 21] [0xe800000 29 23]
+            [0xee00000 119 21] [0xf800000 31 23] [0xfa00000 125 21] [0xfc00000 63 22] [0x10400000 65 22] [0x10800000 33 23] [0x10a00000 133 21] [0x10e00000 135 21]
+            [0x11000000 17 24] [0x11400000 69 22] [0x11800000 35 23] [0x12200000 145 21] [0x12600000 147 21] [0x12c00000 75 22] [0x13000000 19 24] [0x13400000 77 22]
+            [0x13600000 155 21] [0x13800000 39 23] [0x14200000 161 21] [0x14400000 81 22] [0x15000000 21 24] [0x15400000 85 22] [0x15c00000 87 22] [0x15e00000 175 21]
+            [0x16800000 45 23] [0x16c00000 91 22] [0x17000000 23 24] [0x17400000 93 22] [0x17a00000 189 21] [0x17c00000 95 22] [0x18800000 49 23] [0x19000000 25 24]
+            [0x19600000 203 21] [0x19800000 51 23] [0x1a400000 105 22] [0x1b000000 27 24] [0x1b200000 217 21] [0x1b800000 55 23] [0x1c800000 57 23] [0x1cc00000 115 22]
+            [0x1d000000 29 24] [0x1dc00000 119 22] [0x1f000000 31 24] [0x1f400000 125 22] [0x1f800000 63 23] [0x20800000 65 23] [0x21000000 33 24] [0x21400000 133 22]
+            [0x21c00000 135 22] [0x22000000 17 25] [0x22800000 69 23] [0x23000000 35 24] [0x24400000 145 22] [0x24c00000 147 22] [0x25800000 75 23] [0x26000000 19 25]
+            [0x26800000 77 23] [0x26c00000 155 22] [0x27000000 39 24] [0x28400000 161 22] [0x28800000 81 23] [0x2a000000 21 25] [0x2a800000 85 23] [0x2b800000 87 23]
+            [0x2bc00000 175 22] [0x2d000000 45 24] [0x2d800000 91 23] [0x2e000000 23 25] [0x2e800000 93 23] [0x2f400000 189 22] [0x2f800000 95 23] [0x31000000 49 24]
+            [0x32000000 25 25] [0x32c00000 203 22] [0x33000000 51 24] [0x34800000 105 23] [0x36000000 27 25] [0x36400000 217 22] [0x37000000 55 24] [0x39000000 57 24]
+            [0x39800000 115 23] [0x3a000000 29 25] [0x3b800000 119 23] [0x3e000000 31 25] [0x3e800000 125 23] [0x3f000000 63 24] [0x41000000 65 24] [0x42000000 33 25]
+            [0x42800000 133 23] [0x43800000 135 23] [0x44000000 17 26] [0x45000000 69 24] [0x46000000 35 25] [0x48800000 145 23] [0x49800000 147 23] [0x4b000000 75 24]
+            [0x4c000000 19 26] [0x4d000000 77 24] [0x4d800000 155 23] [0x4e000000 39 25] [0x50800000 161 23] [0x51000000 81 24] [0x54000000 21 26] [0x55000000 85 24]
+            [0x57000000 87 24] [0x57800000 175 23] [0x5a000000 45 25] [0x5b000000 91 24] [0x5c000000 23 26] [0x5d000000 93 24] [0x5e800000 189 23] [0x5f000000 95 24]
+            [0x62000000 49 25] [0x64000000 25 26] [0x65800000 203 23] [0x66000000 51 25] [0x69000000 105 24] [0x6c000000 27 26] [0x6c800000 217 23] [0x6e000000 55 25]
+            [0x72000000 57 25] [0x73000000 115 24] [0x74000000 29 26] [0x77000000 119 24] [0x7c000000 31 26] [0x7d000000 125 24] [0x7e000000 63 25] [0x82000000 65 25]
+            [0x84000000 33 26] [0x85000000 133 24] [0x87000000 135 24] [0x88000000 17 27] [0x8a000000 69 25] [0x8c000000 35 26] [0x91000000 145 24] [0x93000000 147 24]
+            [0x96000000 75 25] [0x98000000 19 27] [0x9a000000 77 25] [0x9b000000 155 24] [0x9c000000 39 26] [0xa1000000 161 24] [0xa2000000 81 25] [0xa8000000 21 27]
+            [0xaa000000 85 25] [0xae000000 87 25] [0xaf000000 175 24] [0xb4000000 45 26] [0xb6000000 91 25] [0xb8000000 23 27] [0xba000000 93 25] [0xbd000000 189 24]
+            [0xbe000000 95 25] [0xc4000000 49 26] [0xc8000000 25 27] [0xcb000000 203 24] [0xcc000000 51 26] [0xd2000000 105 25] [0xd8000000 27 27] [0xd9000000 217 24]
+            [0xdc000000 55 26] [0xe4000000 57 26] [0xe6000000 115 25] [0xe8000000 29 27] [0xee000000 119 25] [0xf8000000 31 27] [0xfa000000 125 25] [0xfc000000 63 26]
+            [0x104000000 65 26] [0x108000000 33 27] [0x10a000000 133 25] [0x10e000000 135 25] [0x110000000 17 28] [0x114000000 69 26] [0x118000000 35 27] [0x122000000 145 25]
+            [0x126000000 147 25] [0x12c000000 75 26] [0x130000000 19 28] [0x134000000 77 26] [0x136000000 155 25] [0x138000000 39 27] [0x142000000 161 25] [0x144000000 81 26]
+            [0x150000000 21 28] [0x154000000 85 26] [0x15c000000 87 26] [0x15e000000 175 25] [0x168000000 45 27] [0x16c000000 91 26] [0x170000000 23 28] [0x174000000 93 26]
+            [0x17a000000 189 25] [0x17c000000 95 26] [0x188000000 49 27] [0x190000000 25 28] [0x196000000 203 25] [0x198000000 51 27] [0x1a4000000 105 26] [0x1b0000000 27 28]
+            [0x1b2000000 217 25] [0x1b8000000 55 27] [0x1c8000000 57 27] [0x1cc000000 115 26] [0x1d0000000 29 28] [0x1dc000000 119 26] [0x1f0000000 31 28] [0x1f4000000 125 26]
+            [0x1f8000000 63 27] [0x208000000 65 27] [0x210000000 33 28] [0x214000000 133 26] [0x21c000000 135 26] [0x220000000 17 29] [0x228000000 69 27] [0x230000000 35 28]
+            [0x244000000 145 26] [0x24c000000 147 26] [0x258000000 75 27] [0x260000000 19 29] [0x268000000 77 27] [0x26c000000 155 26] [0x270000000 39 28] [0x284000000 161 26]
+            [0x288000000 81 27] [0x2a0000000 21 29] [0x2a8000000 85 27] [0x2b8000000 87 27] [0x2bc000000 175 26] [0x2d0000000 45 28] [0x2d8000000 91 27] [0x2e0000000 23 29]
+            [0x2e8000000 93 27] [0x2f4000000 189 26] [0x2f8000000 95 27] [0x310000000 49 28] [0x320000000 25 29] [0x32c000000 203 26] [0x330000000 51 28] [0x348000000 105 27]
+            [0x360000000 27 29] [0x364000000 217 26] [0x370000000 55 28] [0x390000000 57 28] [0x398000000 115 27] [0x3a0000000 29 29] [0x3b8000000 119 27] [0x3e0000000 31 29]
+            [0x3e8000000 125 27] [0x3f0000000 63 28] [0x410000000 65 28] [0x420000000 33 29] [0x428000000 133 27] [0x438000000 135 27] [0x440000000 17 30] [0x450000000 69 28]
+            [0x460000000 35 29] [0x488000000 145 27] [0x498000000 147 27] [0x4b0000000 75 28] [0x4c0000000 19 30] [0x4d0000000 77 28] [0x4d8000000 155 27] [0x4e0000000 39 29]
+            [0x508000000 161 27] [0x510000000 81 28] [0x540000000 21 30] [0x550000000 85 28] [0x570000000 87 28] [0x578000000 175 27] [0x5a0000000 45 29] [0x5b0000000 91 28]
+            [0x5c0000000 23 30] [0x5d0000000 93 28] [0x5e8000000 189 27] [0x5f0000000 95 28] [0x620000000 49 29] [0x640000000 25 30] [0x658000000 203 27] [0x660000000 51 29]
+            [0x690000000 105 28] [0x6c0000000 27 30] [0x6c8000000 217 27] [0x6e0000000 55 29] [0x720000000 57 29] [0x730000000 115 28] [0x740000000 29 30] [0x770000000 119 28]
+            [0x7c0000000 31 30] [0x7d0000000 125 28] [0x7e0000000 63 29] [0x820000000 65 29] [0x840000000 33 30] [0x850000000 133 28] [0x870000000 135 28] [0x880000000 17 31]
+            [0x8a0000000 69 29] [0x8c0000000 35 30] [0x910000000 145 28] [0x930000000 147 28] [0x960000000 75 29] [0x980000000 19 31] [0x9a0000000 77 29] [0x9b0000000 155 28]
+            [0x9c0000000 39 30] [0xa10000000 161 28] [0xa20000000 81 29] [0xa80000000 21 31] [0xaa0000000 85 29] [0xae0000000 87 29] [0xaf0000000 175 28] [0xb40000000 45 30]
+            [0xb60000000 91 29] [0xb80000000 23 31] [0xba0000000 93 29] [0xbd0000000 189 28] [0xbe0000000 95 29] [0xc40000000 49 30] [0xc80000000 25 31] [0xcb0000000 203 28]
+            [0xcc0000000 51 30] [0xd20000000 105 29] [0xd80000000 27 31] [0xd90000000 217 28] [0xdc0000000 55 30] [0xe40000000 57 30] [0xe60000000 115 29] [0xe80000000 29 31]
+            [0xee0000000 119 29] [0xf80000000 31 31] [0xfa0000000 125 29] [0xfc0000000 63 30] [0x1040000000 65 30] [0x1080000000 33 31] [0x10a0000000 133 29] [0x10e0000000 135 29]
+            [0x1100000000 17 32] [0x1140000000 69 30] [0x1180000000 35 31] [0x1220000000 145 29] [0x1260000000 147 29] [0x12c0000000 75 30] [0x1300000000 19 32] [0x1340000000 77 30]
+            [0x1360000000 155 29] [0x1380000000 39 31] [0x1420000000 161 29] [0x1440000000 81 30] [0x1500000000 21 32] [0x1540000000 85 30] [0x15c0000000 87 30] [0x15e0000000 175 29]
+            [0x1680000000 45 31] [0x16c0000000 91 30] [0x1700000000 23 32] [0x1740000000 93 30] [0x17a0000000 189 29] [0x17c0000000 95 30] [0x1880000000 49 31] [0x1900000000 25 32]
+            [0x1960000000 203 29] [0x1980000000 51 31] [0x1a40000000 105 30] [0x1b00000000 27 32] [0x1b20000000 217 29] [0x1b80000000 55 31] [0x1c80000000 57 31] [0x1cc0000000 115 30]
+            [0x1d00000000 29 32] [0x1dc0000000 119 30] [0x1f00000000 31 32] [0x1f40000000 125 30] [0x1f80000000 63 31] [0x2080000000 65 31] [0x2100000000 33 32] [0x2140000000 133 30]
+            [0x21c0000000 135 30] [0x2200000000 17 33] [0x2280000000 69 31] [0x2300000000 35 32] [0x2440000000 145 30] [0x24c0000000 147 30] [0x2580000000 75 31] [0x2600000000 19 33]
+            [0x2680000000 77 31] [0x26c0000000 155 30] [0x2700000000 39 32] [0x2840000000 161 30] [0x2880000000 81 31] [0x2a00000000 21 33] [0x2a80000000 85 31] [0x2b80000000 87 31]
+            [0x2bc0000000 175 30] [0x2d00000000 45 32] [0x2d80000000 91 31] [0x2e00000000 23 33] [0x2e80000000 93 31] [0x2f40000000 189 30] [0x2f80000000 95 31] [0x3100000000 49 32]
+            [0x3200000000 25 33] [0x32c0000000 203 30] [0x3300000000 51 32] [0x3480000000 105 31] [0x3600000000 27 33] [0x3640000000 217 30] [0x3700000000 55 32] [0x3900000000 57 32]
+            [0x3980000000 115 31] [0x3a00000000 29 33] [0x3b80000000 119 31] [0x3e00000000 31 33] [0x3e80000000 125 31] [0x3f00000000 63 32] [0x4100000000 65 32] [0x4200000000 33 33]
+            [0x4280000000 133 31] [0x4380000000 135 31] [0x4400000000 17 34] [0x4500000000 69 32] [0x4600000000 35 33] [0x4880000000 145 31] [0x4980000000 147 31] [0x4b00000000 75 32]
+            [0x4c00000000 19 34] [0x4d00000000 77 32] [0x4d80000000 155 31] [0x4e00000000 39 33] [0x5080000000 161 31] [0x5100000000 81 32] [0x5400000000 21 34] [0x5500000000 85 32]
+            [0x5700000000 87 32] [0x5780000000 175 31] [0x5a00000000 45 33] [0x5b00000000 91 32] [0x5c00000000 23 34] [0x5d00000000 93 32] [0x5e80000000 189 31] [0x5f00000000 95 32]
+            [0x6200000000 49 33] [0x6400000000 25 34] [0x6580000000 203 31] [0x6600000000 51 33] [0x6900000000 105 32] [0x6c00000000 27 34] [0x6c80000000 217 31] [0x6e00000000 55 33]
+            [0x7200000000 57 33] [0x7300000000 115 32] [0x7400000000 29 34] [0x7700000000 119 32] [0x7c00000000 31 34] [0x7d00000000 125 32] [0x7e00000000 63 33] [0x8200000000 65 33]
+            [0x8400000000 33 34] [0x8500000000 133 32] [0x8700000000 135 32] [0x8800000000 17 35] [0x8a00000000 69 33] [0x8c00000000 35 34] [0x9100000000 145 32] [0x9300000000 147 32]
+            [0x9600000000 75 33] [0x9800000000 19 35] [0x9a00000000 77 33] [0x9b00000000 155 32] [0x9c00000000 39 34] [0xa100000000 161 32] [0xa200000000 81 33] [0xa800000000 21 35]
+            [0xaa00000000 85 33] [0xae00000000 87 33] [0xaf00000000 175 32] [0xb400000000 45 34] [0xb600000000 91 33] [0xb800000000 23 35] [0xba00000000 93 33] [0xbd00000000 189 32]
+            [0xbe00000000 95 33] [0xc400000000 49 34] [0xc800000000 25 35] [0xcb00000000 203 32] [0xcc00000000 51 34] [0xd200000000 105 33] [0xd800000000 27 35] [0xd900000000 217 32]
+            [0xdc00000000 55 34] [0xe400000000 57 34] [0xe600000000 115 33] [0xe800000000 29 35] [0xee00000000 119 33] [0xf800000000 31 35] [0xfa00000000 125 33] [0xfc00000000 63 34]
+            [0x10400000000 65 34] [0x10800000000 33 35] [0x10a00000000 133 33] [0x10e00000000 135 33] [0x11000000000 17 36] [0x11400000000 69 34] [0x11800000000 35 35] [0x12200000000 145 33]
+            [0x12600000000 147 33] [0x12c00000000 75 34] [0x13000000000 19 36] [0x13400000000 77 34] [0x13600000000 155 33] [0x13800000000 39 35] [0x14200000000 161 33] [0x14400000000 81 34]
+            [0x15000000000 21 36] [0x15400000000 85 34] [0x15c00000000 87 34] [0x15e00000000 175 33] [0x16800000000 45 35] [0x16c00000000 91 34] [0x17000000000 23 36] [0x17400000000 93 34]
+            [0x17a00000000 189 33] [0x17c00000000 95 34] [0x18800000000 49 35] [0x19000000000 25 36] [0x19600000000 203 33] [0x19800000000 51 35] [0x1a400000000 105 34] [0x1b000000000 27 36]
+            [0x1b200000000 217 33] [0x1b800000000 55 35] [0x1c800000000 57 35] [0x1cc00000000 115 34] [0x1d000000000 29 36] [0x1dc00000000 119 34] [0x1f000000000 31 36] [0x1f400000000 125 34]
+            [0x1f800000000 63 35] [0x20800000000 65 35] [0x21000000000 33 36] [0x21400000000 133 34] [0x21c00000000 135 34] [0x22000000000 17 37] [0x22800000000 69 35] [0x23000000000 35 36]
+            [0x24400000000 145 34] [0x24c00000000 147 34] [0x25800000000 75 35] [0x26000000000 19 37] [0x26800000000 77 35] [0x26c00000000 155 34] [0x27000000000 39 36] [0x28400000000 161 34]
+            [0x28800000000 81 35] [0x2a000000000 21 37] [0x2a800000000 85 35] [0x2b800000000 87 35] [0x2bc00000000 175 34] [0x2d000000000 45 36] [0x2d800000000 91 35] [0x2e000000000 23 37]
+            [0x2e800000000 93 35] [0x2f400000000 189 34] [0x2f800000000 95 35] [0x31000000000 49 36] [0x32000000000 25 37] [0x32c00000000 203 34] [0x33000000000 51 36] [0x34800000000 105 35]
+            [0x36000000000 27 37] [0x36400000000 217 34] [0x37000000000 55 36] [0x39000000000 57 36] [0x39800000000 115 35] [0x3a000000000 29 37] [0x3b800000000 119 35] [0x3e000000000 31 37]
+            [0x3e800000000 125 35] [0x3f000000000 63 36] [0x41000000000 65 36] [0x42000000000 33 37] [0x42800000000 133 35] [0x43800000000 135 35] [0x44000000000 17 38] [0x45000000000 69 36]
+            [0x46000000000 35 37] [0x48800000000 145 35] [0x49800000000 147 35] [0x4b000000000 75 36] [0x4c000000000 19 38] [0x4d000000000 77 36] [0x4d800000000 155 35] [0x4e000000000 39 37]
+            [0x50800000000 161 35] [0x51000000000 81 36] [0x54000000000 21 38] [0x55000000000 85 36] [0x57000000000 87 36] [0x57800000000 175 35] [0x5a000000000 45 37] [0x5b000000000 91 36]
+            [0x5c000000000 23 38] [0x5d000000000 93 36] [0x5e800000000 189 35] [0x5f000000000 95 36] [0x62000000000 49 37] [0x64000000000 25 38] [0x65800000000 203 35] [0x66000000000 51 37]
+            [0x69000000000 105 36] [0x6c000000000 27 38] [0x6c800000000 217 35] [0x6e000000000 55 37] [0x72000000000 57 37] [0x73000000000 115 36] [0x74000000000 29 38] [0x77000000000 119 36]
+            [0x7c000000000 31 38] [0x7d000000000 125 36] [0x7e000000000 63 37] [0x82000000000 65 37] [0x84000000000 33 38] [0x85000000000 133 36] [0x87000000000 135 36] [0x88000000000 17 39]
+            [0x8a000000000 69 37] [0x8c000000000 35 38] [0x91000000000 145 36] [0x93000000000 147 36] [0x96000000000 75 37] [0x98000000000 19 39] [0x9a000000000 77 37] [0x9b000000000 155 36]
+            [0x9c000000000 39 38] [0xa1000000000 161 36] [0xa2000000000 81 37] [0xa8000000000 21 39] [0xaa000000000 85 37] [0xae000000000 87 37] [0xaf000000000 175 36] [0xb4000000000 45 38]
+            [0xb6000000000 91 37] [0xb8000000000 23 39] [0xba000000000 93 37] [0xbd000000000 189 36] [0xbe000000000 95 37] [0xc4000000000 49 38] [0xc8000000000 25 39] [0xcb000000000 203 36]
+            [0xcc000000000 51 38] [0xd2000000000 105 37] [0xd8000000000 27 39] [0xd9000000000 217 36] [0xdc000000000 55 38] [0xe4000000000 57 38] [0xe6000000000 115 37] [0xe8000000000 29 39]
+            [0xee000000000 119 37] [0xf8000000000 31 39] [0xfa000000000 125 37] [0xfc000000000 63 38] [0x104000000000 65 38] [0x108000000000 33 39] [0x10a000000000 133 37] [0x10e000000000 135 37]
+            [0x110000000000 17 40] [0x114000000000 69 38] [0x118000000000 35 39] [0x122000000000 145 37] [0x126000000000 147 37] [0x12c000000000 75 38] [0x130000000000 19 40] [0x134000000000 77 38]
+            [0x136000000000 155 37] [0x138000000000 39 39] [0x142000000000 161 37] [0x144000000000 81 38] [0x150000000000 21 40] [0x154000000000 85 38] [0x15c000000000 87 38] [0x15e000000000 175 37]
+            [0x168000000000 45 39] [0x16c000000000 91 38] [0x170000000000 23 40] [0x174000000000 93 38] [0x17a000000000 189 37] [0x17c000000000 95 38] [0x188000000000 49 39] [0x190000000000 25 40]
+            [0x196000000000 203 37] [0x198000000000 51 39] [0x1a4000000000 105 38] [0x1b0000000000 27 40] [0x1b2000000000 217 37] [0x1b8000000000 55 39] [0x1c8000000000 57 39] [0x1cc000000000 115 38]
+            [0x1d0000000000 29 40] [0x1dc000000000 119 38] [0x1f0000000000 31 40] [0x1f4000000000 125 38] [0x1f8000000000 63 39] [0x208000000000 65 39] [0x210000000000 33 40] [0x214000000000 133 38]
+            [0x21c000000000 135 38] [0x220000000000 17 41] [0x228000000000 69 39] [0x230000000000 35 40] [0x244000000000 145 38] [0x24c000000000 147 38] [0x258000000000 75 39] [0x260000000000 19 41]
+            [0x268000000000 77 39] [0x26c000000000 155 38] [0x270000000000 39 40] [0x284000000000 161 38] [0x288000000000 81 39] [0x2a0000000000 21 41] [0x2a8000000000 85 39] [0x2b8000000000 87 39]
+            [0x2bc000000000 175 38] [0x2d0000000000 45 40] [0x2d8000000000 91 39] [0x2e0000000000 23 41] [0x2e8000000000 93 39] [0x2f4000000000 189 38] [0x2f8000000000 95 39] [0x310000000000 49 40]
+            [0x320000000000 25 41] [0x32c000000000 203 38] [0x330000000000 51 40] [0x348000000000 105 39] [0x360000000000 27 41] [0x364000000000 217 38] [0x370000000000 55 40] [0x390000000000 57 40]
+            [0x398000000000 115 39] [0x3a0000000000 29 41] [0x3b8000000000 119 39] [0x3e0000000000 31 41] [0x3e8000000000 125 39] [0x3f0000000000 63 40] [0x410000000000 65 40] [0x420000000000 33 41]
+            [0x428000000000 133 39] [0x438000000000 135 39] [0x440000000000 17 42] [0x450000000000 69 40] [0x460000000000 35 41] [0x488000000000 145 39] [0x498000000000 147 39] [0x4b0000000000 75 40]
+            [0x4c0000000000 19 42] [0x4d0000000000 77 40] [0x4d8000000000 155 39] [0x4e0000000000 39 41] [0x508000000000 161 39] [0x510000000000 81 40] [0x540000000000 21 42] [0x550000000000 85 40]
+            [0x570000000000 87 40] [0x578000000000 175 39] [0x5a0000000000 45 41] [0x5b0000000000 91 40] [0x5c0000000000 23 42] [0x5d0000000000 93 40] [0x5e8000000000 189 39] [0x5f0000000000 95 40]
+            [0x620000000000 49 41] [0x640000000000 25 42] [0x658000000000 203 39] [0x660000000000 51 41] [0x690000000000 105 40] [0x6c0000000000 27 42] [0x6c8000000000 217 39] [0x6e0000000000 55 41]
+            [0x720000000000 57 41] [0x730000000000 115 40] [0x740000000000 29 42] [0x770000000000 119 40] [0x7c0000000000 31 42] [0x7d0000000000 125 40] [0x7e0000000000 63 41] [0x820000000000 65 41]
+            [0x840000000000 33 42] [0x850000000000 133 40] [0x870000000000 135 40] [0x880000000000 17 43] [0x8a0000000000 69 41] [0x8c0000000000 35 42] [0x910000000000 145 40] [0x930000000000 147 40]
+            [0x960000000000 75 41] [0x980000000000 19 43] [0x9a0000000000 77 41] [0x9b0000000000 155 40] [0x9c0000000000 39 42] [0xa10000000000 161 40] [0xa20000000000 81 41] [0xa80000000000 21 43]
+            [0xaa0000000000 85 41] [0xae0000000000 87 41] [0xaf0000000000 175 40] [0xb40000000000 45 42] [0xb60000000000 91 41] [0xb80000000000 23 43] [0xba0000000000 93 41] [0xbd0000000000 189 40]
+            [0xbe0000000000 95 41] [0xc40000000000 49 42] [0xc80000000000 25 43] [0xcb0000000000 203 40] [0xcc0000000000 51 42] [0xd20000000000 105 41] [0xd80000000000 27 43] [0xd90000000000 217 40]
+            [0xdc0000000000 55 42] [0xe40000000000 57 42] [0xe60000000000 115 41] [0xe80000000000 29 43] [0xee0000000000 119 41] [0xf80000000000 31 43] [0xfa0000000000 125 41] [0xfc0000000000 63 42]
+            [0x1040000000000 65 42] [0x1080000000000 33 43] [0x10a0000000000 133 41] [0x10e0000000000 135 41] [0x1100000000000 17 44] [0x1140000000000 69 42] [0x1180000000000 35 43] [0x1220000000000 145 41]
+            [0x1260000000000 147 41] [0x12c0000000000 75 42] [0x1300000000000 19 44] [0x1340000000000 77 42] [0x1360000000000 155 41] [0x1380000000000 39 43] [0x1420000000000 161 41] [0x1440000000000 81 42]
+            [0x1500000000000 21 44] [0x1540000000000 85 42] [0x15c0000000000 87 42] [0x15e0000000000 175 41] [0x1680000000000 45 43] [0x16c0000000000 91 42] [0x1700000000000 23 44] [0x1740000000000 93 42]
+            [0x17a0000000000 189 41] [0x17c0000000000 95 42] [0x1880000000000 49 43] [0x1900000000000 25 44] [0x1960000000000 203 41] [0x1980000000000 51 43] [0x1a40000000000 105 42] [0x1b00000000000 27 44]
+            [0x1b20000000000 217 41] [0x1b80000000000 55 43] [0x1c80000000000 57 43] [0x1cc0000000000 115 42] [0x1d00000000000 29 44] [0x1dc0000000000 119 42] [0x1f00000000000 31 44] [0x1f40000000000 125 42]
+            [0x1f80000000000 63 43] [0x2080000000000 65 43] [0x2100000000000 33 44] [0x2140000000000 133 42] [0x21c0000000000 135 42] [0x2200000000000 17 45] [0x2280000000000 69 43] [0x2300000000000 35 44]
+            [0x2440000000000 145 42] [0x24c0000000000 147 42] [0x2580000000000 75 43] [0x2600000000000 19 45] [0x2680000000000 77 43] [0x26c0000000000 155 42] [0x2700000000000 39 44] [0x2840000000000 161 42]
+            [0x2880000000000 81 43] [0x2a00000000000 21 45] [0x2a80000000000 85 43] [0x2b80000000000 87 43] [0x2bc0000000000 175 42] [0x2d00000000000 45 44] [0x2d80000000000 91 43] [0x2e00000000000 23 45]
+            [0x2e80000000000 93 43] [0x2f40000000000 189 42] [0x2f80000000000 95 43] [0x3100000000000 49 44] [0x3200000000000 25 45] [0x32c0000000000 203 42] [0x3300000000000 51 44] [0x3480000000000 105 43]
+            [0x3600000000000 27 45] [0x3640000000000 217 42] [0x3700000000000 55 44] [0x3900000000000 57 44] [0x3980000000000 115 43] [0x3a00000000000 29 45] [0x3b80000000000 119 43] [0x3e00000000000 31 45]
+            [0x3e80000000000 125 43] [0x3f00000000000 63 44] [0x4100000000000 65 44] [0x4200000000000 33 45] [0x4280000000000 133 43] [0x4380000000000 135 43] [0x4400000000000 17 46] [0x4500000000000 69 44]
+            [0x4600000000000 35 45] [0x4880000000000 145 43] [0x4980000000000 147 43] [0x4b00000000000 75 44] [0x4c00000000000 19 46] [0x4d00000000000 77 44] [0x4d80000000000 155 43] [0x4e00000000000 39 45]
+            [0x5080000000000 161 43] [0x5100000000000 81 44] [0x5400000000000 21 46] [0x5500000000000 85 44] [0x5700000000000 87 44] [0x5780000000000 175 43] [0x5a00000000000 45 45] [0x5b00000000000 91 44]
+            [0x5c00000000000 23 46] [0x5d00000000000 93 44] [0x5e80000000000 189 43] [0x5f00000000000 95 44] [0x6200000000000 49 45] [0x6400000000000 25 46] [0x6580000000000 203 43] [0x6600000000000 51 45]
+            [0x6900000000000 105 44] [0x6c00000000000 27 46] [0x6c80000000000 217 43] [0x6e00000000000 55 45] [0x7200000000000 57 45] [0x7300000000000 115 44] [0x7400000000000 29 46] [0x7700000000000 119 44]
+            [0x7c00000000000 31 46] [0x7d00000000000 125 44] [0x7e00000000000 63 45] [0x8200000000000 65 45] [0x8400000000000 33 46] [0x8500000000000 133 44] [0x8700000000000 135 44] [0x8800000000000 17 47]
+            [0x8a00000000000 69 45] [0x8c00000000000 35 46] [0x9100000000000 145 44] [0x9300000000000 147 44] [0x9600000000000 75 45] [0x9800000000000 19 47] [0x9a00000000000 77 45] [0x9b00000000000 155 44]
+            [0x9c00000000000 39 46] [0xa100000000000 161 44] [0xa200000000000 81 45] [0xa800000000000 21 47] [0xaa00000000000 85 45] [0xae00000000000 87 45] [0xaf00000000000 175 44] [0xb400000000000 45 46]
+            [0xb600000000000 91 45] [0xb800000000000 23 47] [0xba00000000000 93 45] [0xbd00000000000 189 44] [0xbe00000000000 95 45] [0xc400000000000 49 46] [0xc800000000000 25 47] [0xcb00000000000 203 44]
+            [0xcc00000000000 51 46] [0xd200000000000 105 45] [0xd800000000000 27 47] [0xd900000000000 217 44] [0xdc00000000000 55 46] [0xe400000000000 57 46] [0xe600000000000 115 45] [0xe800000000000 29 47]
+            [0xee00000000000 119 45] [0xf800000000000 31 47] [0xfa00000000000 125 45] [0xfc00000000000 63 46] [0x10400000000000 65 46] [0x10800000000000 33 47] [0x10a00000000000 133 45] [0x10e00000000000 135 45]
+            [0x11000000000000 17 48] [0x11400000000000 69 46] [0x11800000000000 35 47] [0x12200000000000 145 45] [0x12600000000000 147 45] [0x12c00000000000 75 46] [0x13000000000000 19 48] [0x13400000000000 77 46]
+            [0x13600000000000 155 45] [0x13800000000000 39 47] [0x14200000000000 161 45] [0x14400000000000 81 46] [0x15000000000000 21 48] [0x15400000000000 85 46] [0x15c00000000000 87 46] [0x15e00000000000 175 45]
+            [0x16800000000000 45 47] [0x16c00000000000 91 46] [0x17000000000000 23 48] [0x17400000000000 93 46] [0x17a00000000000 189 45] [0x17c00000000000 95 46] [0x18800000000000 49 47] [0x19000000000000 25 48]
+            [0x19600000000000 203 45] [0x19800000000000 51 47] [0x1a400000000000 105 46] [0x1b000000000000 27 48] [0x1b200000000000 217 45] [0x1b800000000000 55 47] [0x1c800000000000 57 47] [0x1cc00000000000 115 46]
+            [0x1d000000000000 29 48] [0x1dc00000000000 119 46] [0x1f000000000000 31 48] [0x1f400000000000 125 46] [0x1f800000000000 63 47] [0x20800000000000 65 47] [0x21000000000000 33 48] [0x21400000000000 133 46]
+            [0x21c00000000000 135 46] [0x22000000000000 17 49] [0x22800000000000 69 47] [0x23000000000000 35 48] [0x24400000000000 145 46] [0x24c00000000000 147 46] [0x25800000000000 75 47] [0x26000000000000 19 49]
+            [0x26800000000000 77 47] [0x26c00000000000 155 46] [0x27000000000000 39 48] [0x28400000000000 161 46] [0x28800000000000 81 47] [0x2a000000000000 21 49] [0x2a800000000000 85 47] [0x2b800000000000 87 47]
+            [0x2bc00000000000 175 46] [0x2d000000000000 45 48] [0x2d800000000000 91 47] [0x2e000000000000 23 49] [0x2e800000000000 93 47] [0x2f400000000000 189 46] [0x2f800000000000 95 47] [0x31000000000000 49 48]
+            [0x32000000000000 25 49] [0x32c00000000000 203 46] [0x33000000000000 51 48] [0x34800000000000 105 47] [0x36000000000000 27 49] [0x36400000000000 217 46] [0x37000000000000 55 48] [0x39000000000000 57 48]
+            [0x39800000000000 115 47] [0x3a000000000000 29 49] [0x3b800000000000 119 47] [0x3e000000000000 31 49] [0x3e800000000000 125 47] [0x3f000000000000 63 48] [0x41000000000000 65 48] [0x42000000000000 33 49]
+            [0x42800000000000 133 47] [0x43800000000000 135 47] [0x44000000000000 17 50] [0x45000000000000 69 48] [0x46000000000000 35 49] [0x48800000000000 145 47] [0x49800000000000 147 47] [0x4b000000000000 75 48]
+            [0x4c000000000000 19 50] [0x4d000000000000 77 48] [0x4d800000000000 155 47] [0x4e000000000000 39 49] [0x50800000000000 161 47] [0x51000000000000 81 48] [0x54000000000000 21 50] [0x55000000000000 85 48]
+            [0x57000000000000 87 48] [0x57800000000000 175 47] [0x5a000000000000 45 49] [0x5b000000000000 91 48] [0x5c000000000000 23 50] [0x5d000000000000 93 48] [0x5e800000000000 189 47] [0x5f000000000000 95 48]
+            [0x62000000000000 49 49] [0x64000000000000 25 50] [0x65800000000000 203 47] [0x66000000000000 51 49] [0x69000000000000 105 48] [0x6c000000000000 27 50] [0x6c800000000000 217 47] [0x6e000000000000 55 49]
+            [0x72000000000000 57 49] [0x73000000000000 115 48] [0x74000000000000 29 50] [0x77000000000000 119 48] [0x7c000000000000 31 50] [0x7d000000000000 125 48] [0x7e000000000000 63 49] [0x82000000000000 65 49]
+            [0x84000000000000 33 50] [0x85000000000000 133 48] [0x87000000000000 135 48] [0x88000000000000 17 51] [0x8a000000000000 69 49] [0x8c000000000000 35 50] [0x91000000000000 145 48] [0x93000000000000 147 48]
+            [0x96000000000000 75 49] [0x98000000000000 19 51] [0x9a000000000000 77 49] [0x9b000000000000 155 48] [0x9c000000000000 39 50] [0xa1000000000000 161 48] [0xa2000000000000 81 49] [0xa8000000000000 21 51]
+            [0xaa000000000000 85 49] [0xae000000000000 87 49] [0xaf000000000000 175 48] [0xb4000000000000 45 50] [0xb6000000000000 91 49] [0xb8000000000000 23 51] [0xba000000000000 93 49] [0xbd000000000000 189 48]
+            [0xbe000000000000 95 49] [0xc4000000000000 49 50] [0xc8000000000000 25 51] [0xcb000000000000 203 48] [0xcc000000000000 51 50] [0xd2000000000000 105 49] [0xd8000000000000 27 51] [0xd9000000000000 217 48]
+            [0xdc000000000000 55 50] [0xe4000000000000 57 50] [0xe6000000000000 115 49] [0xe8000000000000 29 51] [0xee000000000000 119 49] [0xf8000000000000 31 51] [0xfa000000000000 125 49] [0xfc000000000000 63 50]
+            [0x104000000000000 65 50] [0x108000000000000 33 51] [0x10a000000000000 133 49] [0x10e000000000000 135 49] [0x110000000000000 17 52] [0x114000000000000 69 50] [0x118000000000000 35 51] [0x122000000000000 145 49]
+            [0x126000000000000 147 49] [0x12c000000000000 75 50] [0x130000000000000 19 52] [0x134000000000000 77 50] [0x136000000000000 155 49] [0x138000000000000 39 51] [0x142000000000000 161 49] [0x144000000000000 81 50]
+            [0x150000000000000 21 52] [0x154000000000000 85 50] [0x15c000000000000 87 50] [0x15e000000000000 175 49] [0x168000000000000 45 51] [0x16c000000000000 91 50] [0x170000000000000 23 52] [0x174000000000000 93 50]
+            [0x17a000000000000 189 49] [0x17c000000000000 95 50] [0x188000000000000 49 51] [0x190000000000000 25 52] [0x196000000000000 203 49] [0x198000000000000 51 51] [0x1a4000000000000 105 50] [0x1b0000000000000 27 52]
+            [0x1b2000000000000 217 49] [0x1b8000000000000 55 51] [0x1c8000000000000 57 51] [0x1cc000000000000 115 50] [0x1d0000000000000 29 52] [0x1dc000000000000 119 50] [0x1f0000000000000 31 52] [0x1f4000000000000 125 50]
+            [0x1f8000000000000 63 51] [0x208000000000000 65 51] [0x210000000000000 33 52] [0x214000000000000 133 50] [0x21c000000000000 135 50] [0x220000000000000 17 53] [0x228000000000000 69 51] [0x230000000000000 35 52]
+            [0x244000000000000 145 50] [0x24c000000000000 147 50] [0x258000000000000 75 51] [0x260000000000000 19 53] [0x268000000000000 77 51] [0x26c000000000000 155 50] [0x270000000000000 39 52] [0x284000000000000 161 50]
+            [0x288000000000000 81 51] [0x2a0000000000000 21 53] [0x2a8000000000000 85 51] [0x2b8000000000000 87 51] [0x2bc000000000000 175 50] [0x2d0000000000000 45 52] [0x2d8000000000000 91 51] [0x2e0000000000000 23 53]
+            [0x2e8000000000000 93 51] [0x2f4000000000000 189 50] [0x2f8000000000000 95 51] [0x310000000000000 49 52] [0x320000000000000 25 53] [0x32c000000000000 203 50] [0x330000000000000 51 52] [0x348000000000000 105 51]
+            [0x360000000000000 27 53] [0x364000000000000 217 50] [0x370000000000000 55 52] [0x390000000000000 57 52] [0x398000000000000 115 51] [0x3a0000000000000 29 53] [0x3b8000000000000 119 51] [0x3e0000000000000 31 53]
+            [0x3e8000000000000 125 51] [0x3f0000000000000 63 52] [0x410000000000000 65 52] [0x420000000000000 33 53] [0x428000000000000 133 51] [0x438000000000000 135 51] [0x440000000000000 17 54] [0x450000000000000 69 52]
+            [0x460000000000000 35 53] [0x488000000000000 145 51] [0x498000000000000 147 51] [0x4b0000000000000 75 52] [0x4c0000000000000 19 54] [0x4d0000000000000 77 52] [0x4d8000000000000 155 51] [0x4e0000000000000 39 53]
+            [0x508000000000000 161 51] [0x510000000000000 81 52] [0x540000000000000 21 54] [0x550000000000000 85 52] [0x570000000000000 87 52] [0x578000000000000 175 51] [0x5a0000000000000 45 53] [0x5b0000000000000 91 52]
+            [0x5c0000000000000 23 54] [0x5d0000000000000 93 52] [0x5e8000000000000 189 51] [0x5f0000000000000 95 52] [0x620000000000000 49 53] [0x640000000000000 25 54] [0x658000000000000 203 51] [0x660000000000000 51 53]
+            [0x690000000000000 105 52] [0x6c0000000000000 27 54] [0x6c8000000000000 217 51] [0x6e0000000000000 55 53] [0x720000000000000 57 53] [0x730000000000000 115 52] [0x740000000000000 29 54] [0x770000000000000 119 52]
+            [0x7c0000000000000 31 54] [0x7d0000000000000 125 52] [0x7e0000000000000 63 53] [0x820000000000000 65 53] [0x840000000000000 33 54] [0x850000000000000 133 52] [0x870000000000000 135 52] [0x880000000000000 17 55]
+            [0x8a0000000000000 69 53] [0x8c0000000000000 35 54] [0x910000000000000 145 52] [0x930000000000000 147 52] [0x960000000000000 75 53] [0x980000000000000 19 55] [0x9a0000000000000 77 53] [0x9b0000000000000 155 52]
+            [0x9c0000000000000 39 54] [0xa10000000000000 161 52] [0xa20000000000000 81 53] [0xa80000000000000 21 55] [0xaa0000000000000 85 53] [0xae0000000000000 87 53] [0xaf0000000000000 175 52] [0xb40000000000000 45 54]
+            [0xb60000000000000 91 53] [0xb80000000000000 23 55] [0xba0000000000000 93 53] [0xbd0000000000000 189 52] [0xbe0000000000000 95 53] [0xc40000000000000 49 54] [0xc80000000000000 25 55] [0xcb0000000000000 203 52]
+            [0xcc0000000000000 51 54] [0xd20000000000000 105 53] [0xd80000000000000 27 55] [0xd90000000000000 217 52] [0xdc0000000000000 55 54] [0xe40000000000000 57 54] [0xe60000000000000 115 53] [0xe80000000000000 29 55]
+            [0xee0000000000000 119 53] [0xf80000000000000 31 55] [0xfa0000000000000 125 53] [0xfc0000000000000 63 54] [0x1040000000000000 65 54] [0x1080000000000000 33 55] [0x10a0000000000000 133 53] [0x10e0000000000000 135 53]
+            [0x1100000000000000 17 56] [0x1140000000000000 69 54] [0x1180000000000000 35 55] [0x1220000000000000 145 53] [0x1260000000000000 147 53] [0x12c0000000000000 75 54] [0x1300000000000000 19 56] [0x1340000000000000 77 54]
+            [0x1360000000000000 155 53] [0x1380000000000000 39 55] [0x1420000000000000 161 53] [0x1440000000000000 81 54] [0x1500000000000000 21 56] [0x1540000000000000 85 54] [0x15c0000000000000 87 54] [0x15e0000000000000 175 53]
+            [0x1680000000000000 45 55] [0x16c0000000000000 91 54] [0x1700000000000000 23 56] [0x1740000000000000 93 54] [0x17a0000000000000 189 53] [0x17c0000000000000 95 54] [0x1880000000000000 49 55] [0x1900000000000000 25 56]
+            [0x1960000000000000 203 53] [0x1980000000000000 51 55] [0x1a40000000000000 105 54] [0x1b00000000000000 27 56] [0x1b20000000000000 217 53] [0x1b80000000000000 55 55] [0x1c80000000000000 57 55] [0x1cc0000000000000 115 54]
+            [0x1d00000000000000 29 56] [0x1dc0000000000000 119 54] [0x1f00000000000000 31 56] [0x1f40000000000000 125 54] [0x1f80000000000000 63 55] [0x2080000000000000 65 55] [0x2100000000000000 33 56] [0x2140000000000000 133 54]
+            [0x21c0000000000000 135 54] [0x2200000000000000 17 57] [0x2280000000000000 69 55] [0x2300000000000000 35 56] [0x2440000000000000 145 54] [0x24c0000000000000 147 54] [0x2580000000000000 75 55] [0x2600000000000000 19 57]
+            [0x2680000000000000 77 55] [0x26c0000000000000 155 54] [0x2700000000000000 39 56] [0x2840000000000000 161 54] [0x2880000000000000 81 55] [0x2a00000000000000 21 57] [0x2a80000000000000 85 55] [0x2b80000000000000 87 55]
+            [0x2bc0000000000000 175 54] [0x2d00000000000000 45 56] [0x2d80000000000000 91 55] [0x2e00000000000000 23 57] [0x2e80000000000000 93 55] [0x2f40000000000000 189 54] [0x2f80000000000000 95 55] [0x3100000000000000 49 56]
+            [0x3200000000000000 25 57] [0x32c0000000000000 203 54] [0x3300000000000000 51 56] [0x3480000000000000 105 55] [0x3600000000000000 27 57] [0x3640000000000000 217 54] [0x3700000000000000 55 56] [0x3900000000000000 57 56]
+            [0x3980000000000000 115 55] [0x3a00000000000000 29 57] [0x3b80000000000000 119 55] [0x3e00000000000000 31 57] [0x3e80000000000000 125 55] [0x3f00000000000000 63 56] [0x4100000000000000 65 56] [0x4200000000000000 33 57]
+            [0x4280000000000000 133 55] [0x4380000000000000 135 55] [0x4400000000000000 17 58] [0x4500000000000000 69 56] [0x4600000000000000 35 57] [0x4880000000000000 145 55] [0x4980000000000000 147 55] [0x4b00000000000000 75 56]
+            [0x4c00000000000000 19 58] [0x4d00000000000000 77 56] [0x4d80000000000000 155 55] [0x4e00000000000000 39 57] [0x5080000000000000 161 55] [0x5100000000000000 81 56] [0x5400000000000000 21 58] [0x5500000000000000 85 56]
+            [0x5700000000000000 87 56] [0x5780000000000000 175 55] [0x5a00000000000000 45 57] [0x5b00000000000000 91 56] [0x5c00000000000000 23 58] [0x5d00000000000000 93 56] [0x5e80000000000000 189 55] [0x5f00000000000000 95 56]
+            [0x6200000000000000 49 57] [0x6400000000000000 25 58] [0x6580000000000000 203 55] [0x6600000000000000 51 57] [0x6900000000000000 105 56] [0x6c00000000000000 27 58] [0x6c80000000000000 217 55] [0x6e00000000000000 55 57]
+            [0x7200000000000000 57 57] [0x7300000000000000 115 56] [0x7400000000000000 29 58] [0x7700000000000000 119 56] [0x7c00000000000000 31 58] [0x7d00000000000000 125 56] [0x7e00000000000000 63 57] [0x8200000000000000 65 57]
+            [0x8400000000000000 33 58] [0x8500000000000000 133 56] [0x8700000000000000 135 56] [0x8a00000000000000 69 57] [0x8c00000000000000 35 58] [0x9100000000000000 145 56] [0x9300000000000000 147 56] [0x9600000000000000 75 57]
+            [0x9a00000000000000 77 57] [0x9b00000000000000 155 56] [0x9c00000000000000 39 58] [0xa100000000000000 161 56] [0xa200000000000000 81 57] [0xa800000000000000 21 59] [0xae00000000000000 87 57] [0xaf00000000000000 175 56]
+            [0xb400000000000000 45 58] [0xb600000000000000 91 57] [0xba00000000000000 93 57] [0xbd00000000000000 189 56] [0xc400000000000000 49 58] [0xcb00000000000000 203 56] [0xd200000000000000 105 57] [0xd900000000000000 217 56]
+        }
+    };
+}
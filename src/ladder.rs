@@ -0,0 +1,950 @@
+//! The candidates that upper bounds are selected from.
+//!
+//! This allows computing the same bounds as [`get_upper_bound`](crate::get_upper_bound) without
+//! defining an acceptor, e.g. to size a companion buffer consistently, and walking the
+//! candidates at runtime using [`candidates`], [`next_candidate`] and [`prev_candidate`].
+//!
+//! # Custom candidates
+//! The candidates can be replaced at build time by setting the `GUB_CANDIDATES` environment
+//! variable to a comma-separated list of values, e.g. `GUB_CANDIDATES="0,1,8,64,4096,1048576"`.
+//! The maximum value is always added. In this case, every ladder consists of the listed values
+//! that are multiples of its [`multiple_of`](Ladder::multiple_of) value, and the guarantees about
+//! the distance between candidates do not apply. This affects `usize` as well as the unsigned
+//! integer type of the same width.
+//!
+//! The list is read with `option_env!` and parsed during compilation of this crate, so cargo
+//! rebuilds it when the variable changes. Values can be decimal or hexadecimal with a `0x` prefix
+//! and may contain `_`. At most 31 values can be listed in addition to the maximum value. An
+//! invalid list is a compile error. The custom values are not yielded by
+//! [`for_each_candidate!`](crate::for_each_candidate) and cannot be converted to `typenum`
+//! integers.
+//!
+//! # 16-bit targets
+//! On targets with 16-bit pointers, such as AVR and MSP430, the candidates are the same as for
+//! `u16`, so there are far fewer of them, and desired values close to `usize::MAX` are common.
+//! The search for an upper bound never overflows: a desired value above the largest candidate
+//! below `usize::MAX`, e.g. above `0xc000` with the default ladder and no `ladder-step-*`
+//! feature, has the upper bound `usize::MAX`, or one of its refinements with the
+//! `ladder-step-1_0625` feature. The guarantees of [`AcceptUpperBound`](crate::AcceptUpperBound)
+//! about the distance between the desired value and its upper bound hold up to `usize::MAX`.
+
+use crate::implementation::IS_CUSTOM;
+
+/// A set of candidates that an upper bound can be selected from.
+///
+/// Every ladder is a subset of the candidates that this crate can dispatch to, which are:
+/// - every value up to `64`, or up to `256` with the `exhaustive-small` feature
+/// - every value with at most 4 significant bits, i.e. of the form `m * pow(2, e)` with `m < 16`
+/// - the maximum value of the bound type
+/// - with the `ladder-step-1_0625` feature, the refinements of the values with at most 3
+///   significant bits (see `Ladder::FINE`, which is only available with that feature)
+///
+/// The maximum value is part of every ladder, so that every desired value has an upper bound.
+/// The `max-bound-*` and `portable-bounds` features instead exclude all candidates above
+/// [`MAX_BOUND`], making desired values without an upper bound a compile error.
+///
+/// A ladder can be chosen per acceptor using [`AcceptUpperBound::LADDER`](crate::AcceptUpperBound::LADDER).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ladder {
+    /// All values up to this one are candidates.
+    dense_max: usize,
+    /// Larger candidates have at most this many significant bits.
+    significant_bits: u32,
+    /// All candidates except the maximum value are multiples of this.
+    multiple_of: usize,
+    /// All candidates except the maximum value are at least this.
+    min: usize,
+    /// Whether each candidate is preceded by its refinements, see [`refinement`]. These are
+    /// selected by a second dispatch after the candidate.
+    refine: bool,
+}
+
+impl Ladder {
+    /// The ladder that is used by default.
+    ///
+    /// It contains every value up to `64`, followed by candidates that are at most 50% apart.
+    /// The `ladder-step-1_25`, `ladder-step-1_125` and `ladder-step-1_0625` features reduce the gap
+    /// to 25%, 12.5% and 6.25% respectively. The `exhaustive-small` feature extends the first range
+    /// to every value up to `256`.
+    ///
+    /// ```
+    /// use generic_upper_bound::Ladder;
+    /// let exhaustive = cfg!(feature = "exhaustive-small");
+    /// assert!(Ladder::DEFAULT.contains(64));
+    /// assert_eq!(Ladder::DEFAULT.contains(255), exhaustive);
+    /// ```
+    pub const DEFAULT: Self = Self {
+        dense_max: if cfg!(feature = "exhaustive-small") {
+            256
+        } else {
+            64
+        },
+        significant_bits: if cfg!(feature = "ladder-step-1_0625") {
+            3
+        } else if cfg!(feature = "ladder-step-1_125") {
+            4
+        } else if cfg!(feature = "ladder-step-1_25") {
+            3
+        } else {
+            2
+        },
+        multiple_of: 1,
+        min: 0,
+        refine: cfg!(feature = "ladder-step-1_0625"),
+    };
+
+    /// A ladder with candidates that are at most 12.5% apart, regardless of enabled features.
+    pub const DENSE: Self = Self {
+        significant_bits: 4,
+        refine: false,
+        ..Self::DEFAULT
+    };
+
+    /// A ladder with candidates that are at most 6.25% apart. This is the default ladder with the
+    /// `ladder-step-1_0625` feature, which is required for this ladder.
+    ///
+    /// This is useful for acceptors with large outputs, where the overhead of the other ladders
+    /// results in a lot of unused static data. The upper bound is selected in two steps: First,
+    /// the smallest candidate `C` with at most 3 significant bits that is at least the desired
+    /// value, whose candidates are at most 25% apart. Then, the smallest of the 16 values that
+    /// subdivide the range from `C / 2` to `C` that is at least the desired value. Each step only
+    /// searches through a few candidates, so this is faster to evaluate than a ladder with the
+    /// same gaps in a single step.
+    ///
+    /// ```
+    /// use generic_upper_bound as gub;
+    /// use gub::Ladder;
+    /// struct Big<const N: usize>;
+    /// gub::impl_accept_upper_bound! {
+    ///     impl{const N: usize} Big<N>;
+    ///     const DESIRED_GENERIC: usize = N;
+    ///     const LADDER: Ladder = Ladder::FINE;
+    ///     const EVAL<const UPPER: usize>: usize = UPPER;
+    /// }
+    /// const DESIRED: usize = 17 << 10;
+    /// let upper = gub::eval_with_upper_bound::<Big<DESIRED>>();
+    /// assert_eq!(upper, 0x4600);
+    /// assert!(upper - DESIRED <= DESIRED / 16);
+    /// assert!(Ladder::FINE.contains(upper));
+    /// assert!(!Ladder::DENSE.contains(upper));
+    /// ```
+    #[cfg(feature = "ladder-step-1_0625")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ladder-step-1_0625")))]
+    pub const FINE: Self = Self {
+        significant_bits: 3,
+        refine: true,
+        ..Self::DEFAULT
+    };
+
+    /// A ladder consisting only of `0` and powers of two (as well as the maximum value).
+    ///
+    /// This minimizes the number of distinct bounds that are used, at the cost of up to 100%
+    /// overhead.
+    pub const SPARSE: Self = Self {
+        dense_max: 1,
+        significant_bits: 1,
+        multiple_of: 1,
+        min: 0,
+        refine: false,
+    };
+
+    /// A ladder consisting of `0` and multiples of `4096` that are at most 50% apart
+    /// (as well as the maximum value).
+    pub const PAGE_ALIGNED: Self = Self {
+        dense_max: 0,
+        significant_bits: 2,
+        multiple_of: 4096,
+        min: 0,
+        refine: false,
+    };
+
+    /// Returns a ladder with the candidates of `self` that are multiples of `n` (as well as the
+    /// maximum value).
+    ///
+    /// Works best if `n` is a power of two, since large candidates are mostly multiples of large
+    /// powers of two. For other values, the ladder may become very sparse.
+    ///
+    /// # Panics
+    /// If `n` is zero.
+    ///
+    /// ```
+    /// use generic_upper_bound::Ladder;
+    /// let ladder = Ladder::DEFAULT.multiple_of(16);
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert!(ladder.contains(48));
+    /// assert!(!ladder.contains(40));
+    /// assert!(ladder.contains(0));
+    /// ```
+    pub const fn multiple_of(self, n: usize) -> Self {
+        assert!(n != 0, "candidates cannot be multiples of 0");
+        Self {
+            multiple_of: self.multiple_of / gcd(self.multiple_of, n) * n,
+            ..self
+        }
+    }
+
+    /// Returns a ladder with the candidates of `self` that are at least `n` (as well as the
+    /// maximum value).
+    ///
+    /// This is a hint for acceptors whose desired value is known to always be large, e.g. at least
+    /// 4 KiB. The search for an upper bound starts at `n` instead of walking the small
+    /// candidates, and no `Eval<UPPER>` with `UPPER < n` is ever instantiated. Desired values
+    /// below `n` get the smallest candidate that is at least `n` as their upper bound, so the
+    /// guarantees about the distance to the desired value only hold from `n` on.
+    ///
+    /// ```
+    /// use generic_upper_bound as gub;
+    /// use gub::Ladder;
+    /// let ladder = Ladder::DEFAULT.starting_at(4096);
+    /// assert!(ladder.contains(4096));
+    /// assert!(!ladder.contains(37));
+    /// assert!(ladder.contains(usize::MAX) || gub::ladder::MAX_BOUND < usize::MAX);
+    ///
+    /// struct Page<const N: usize>;
+    /// gub::impl_accept_upper_bound! {
+    ///     impl{const N: usize} Page<N>;
+    ///     const DESIRED_GENERIC: usize = N;
+    ///     const LADDER: Ladder = Ladder::DEFAULT.starting_at(4096);
+    ///     const EVAL<const UPPER: usize>: usize = UPPER;
+    /// }
+    /// assert_eq!(gub::get_upper_bound::<Page<100>>(), 4096);
+    /// assert_eq!(gub::get_upper_bound::<Page<5000>>(), gub::ladder::upper_bound_for(5000));
+    /// ```
+    pub const fn starting_at(self, n: usize) -> Self {
+        Self {
+            min: if self.min > n { self.min } else { n },
+            ..self
+        }
+    }
+
+    /// Returns `self` with its parameters rescaled from bytes to counts of elements of `size`
+    /// bytes.
+    ///
+    /// This is intended for acceptors that output `[T; UPPER]` for a large `T`, where the unused
+    /// elements cost `size` bytes each. The dense range is divided by `size`, so that it
+    /// covers the same number of bytes, and [`multiple_of`](Self::multiple_of) and
+    /// [`starting_at`](Self::starting_at) are converted to element counts. A `size` of `0` is
+    /// treated as `1`.
+    ///
+    /// This is a rescaling, not a search by byte size: The candidates are still element counts
+    /// with the usual form, so larger candidates are as far apart relative to their size as
+    /// before, and the overhead in bytes is at most the same fraction of the output. The upper
+    /// bound is not chosen such that `UPPER * size` is a candidate of `self`.
+    ///
+    /// ```
+    /// use generic_upper_bound as gub;
+    /// use gub::Ladder;
+    /// let ladder = Ladder::DENSE.rescaled_for_elem_size(256);
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert!(Ladder::DENSE.contains(17));
+    /// assert!(!ladder.contains(17));
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert!(ladder.contains(18));
+    ///
+    /// // 256 elements of 16 bytes each are 4096 bytes
+    /// let ladder = Ladder::PAGE_ALIGNED.rescaled_for_elem_size(16);
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert!(ladder.contains(3 * 256));
+    /// assert!(!ladder.contains(128));
+    ///
+    /// struct Blocks<const N: usize>;
+    /// gub::impl_accept_upper_bound! {
+    ///     impl{const N: usize} Blocks<N>;
+    ///     const DESIRED_GENERIC: usize = N;
+    ///     const LADDER: Ladder =
+    ///         Ladder::DENSE.rescaled_for_elem_size(core::mem::size_of::<[u8; 256]>());
+    ///     const EVAL<const UPPER: usize>: &'static [[u8; 256]] = &[[0; 256]; UPPER];
+    /// }
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert_eq!(gub::get_upper_bound::<Blocks<17>>(), 18);
+    /// ```
+    pub const fn rescaled_for_elem_size(self, size: usize) -> Self {
+        let size = if size == 0 { 1 } else { size };
+        Self {
+            dense_max: self.dense_max / size,
+            significant_bits: self.significant_bits,
+            multiple_of: self.multiple_of / gcd(self.multiple_of, size),
+            min: self.min.div_ceil(size),
+            refine: self.refine,
+        }
+    }
+
+    /// Returns a ladder whose candidates are candidates of both `self` and `other`.
+    ///
+    /// This is used for acceptors that pass the same bound to several other acceptors.
+    ///
+    /// ```
+    /// use generic_upper_bound::Ladder;
+    /// let ladder = Ladder::DEFAULT.and(Ladder::PAGE_ALIGNED);
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert!(ladder.contains(2 * 4096));
+    /// assert!(!ladder.contains(37));
+    /// ```
+    pub const fn and(self, other: Self) -> Self {
+        Self {
+            dense_max: if self.dense_max < other.dense_max {
+                self.dense_max
+            } else {
+                other.dense_max
+            },
+            significant_bits: if self.significant_bits < other.significant_bits {
+                self.significant_bits
+            } else {
+                other.significant_bits
+            },
+            multiple_of: 1,
+            min: 0,
+            refine: self.refine && other.refine,
+        }
+        .multiple_of(self.multiple_of)
+        .multiple_of(other.multiple_of)
+        .starting_at(self.min)
+        .starting_at(other.min)
+    }
+
+    /// Returns whether `n` is a candidate of this ladder.
+    ///
+    /// ```
+    /// use generic_upper_bound::Ladder;
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert!(Ladder::DEFAULT.contains(37));
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert!(Ladder::DEFAULT.contains(384));
+    /// assert!(!Ladder::DEFAULT.contains(385));
+    /// assert!(!Ladder::SPARSE.contains(3));
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert!(Ladder::PAGE_ALIGNED.contains(3 * 4096));
+    /// assert!(Ladder::PAGE_ALIGNED.contains(generic_upper_bound::ladder::MAX_BOUND));
+    /// ```
+    pub const fn contains(&self, n: usize) -> bool {
+        n <= MAX_BOUND
+            && (!IS_CUSTOM || crate::implementation::is_candidate(n))
+            && (self.accepts(n as u128, usize::MAX as u128) || self.is_refinement(n))
+    }
+
+    /// Returns whether the candidates are refined, see [`refinement`]. Custom candidates are
+    /// never refined.
+    pub(crate) const fn is_refined(&self) -> bool {
+        self.refine && !IS_CUSTOM
+    }
+
+    /// Returns the ladder with the candidates of `self` that are not refinements.
+    pub(crate) const fn coarse(self) -> Self {
+        Self {
+            refine: false,
+            ..self
+        }
+    }
+
+    /// Returns whether `n` is a refinement of the smallest candidate of the coarse ladder that is
+    /// at least `n`, and a multiple of `multiple_of` that is at least `min`.
+    const fn is_refinement(&self, n: usize) -> bool {
+        if !self.is_refined() || n % self.multiple_of != 0 || n < self.min {
+            return false;
+        }
+        let coarse = match self.coarse().closed_form_upper_bound(n) {
+            Some(coarse) => coarse,
+            None => return false,
+        };
+        let mut k = 1;
+        while k < 16 {
+            if refinement(coarse, k) == n {
+                return true;
+            }
+            k += 1;
+        }
+        false
+    }
+
+    /// Like [`Self::contains`], for a bound type with maximum value `max`. Assumes that `n` is
+    /// one of the values yielded by the `for_each_size` macros.
+    pub(crate) const fn accepts(&self, n: u128, max: u128) -> bool {
+        if n == max {
+            return true;
+        }
+        if n % self.multiple_of as u128 != 0 || n < self.min as u128 {
+            return false;
+        }
+        if IS_CUSTOM && max == usize::MAX as u128 {
+            return true;
+        }
+        n <= self.dense_max as u128
+            || 128 - n.leading_zeros() - n.trailing_zeros() <= self.significant_bits
+    }
+
+    /// Returns the smallest `usize` candidate that is at least `desired`, computed from the
+    /// parameters of the ladder instead of by scanning the values yielded by `for_each_size`.
+    /// Assumes that there are no custom candidates.
+    pub(crate) const fn closed_form_upper_bound(&self, desired: usize) -> Option<usize> {
+        let coarse = match self.coarse_upper_bound(desired) {
+            Some(coarse) => coarse,
+            None => return None,
+        };
+        if !self.is_refined() {
+            return Some(coarse);
+        }
+        // `coarse` is the last refinement, and the refinements are in ascending order
+        let mut k = 1;
+        while k < 16 {
+            let n = refinement(coarse, k);
+            if n >= desired && n >= self.min && n % self.multiple_of == 0 {
+                return Some(n);
+            }
+            k += 1;
+        }
+        Some(coarse)
+    }
+
+    /// Like [`Self::closed_form_upper_bound`], but ignores the refinements.
+    const fn coarse_upper_bound(&self, desired: usize) -> Option<usize> {
+        match self.coarse_upper_bound_in(desired as u128, usize::MAX as u128, MAX_BOUND as u128) {
+            Some(n) => Some(n as usize),
+            None => None,
+        }
+    }
+
+    /// Like [`Self::coarse_upper_bound`], for a bound type with maximum value `max` whose
+    /// candidates are limited to `max_bound`. Only the `usize` search is used, but computing it
+    /// for other widths allows testing the search for 16-bit targets on the host.
+    pub(crate) const fn coarse_upper_bound_in(
+        &self,
+        desired: u128,
+        max: u128,
+        max_bound: u128,
+    ) -> Option<u128> {
+        let m = self.multiple_of as u128;
+        let dense_max = self.dense_max as u128;
+        let desired = if desired < self.min as u128 {
+            self.min as u128
+        } else {
+            desired
+        };
+        // the dense range
+        if desired <= dense_max {
+            if let Some(n) = round_up_to_multiple(desired, m) {
+                if n <= dense_max && n <= max_bound {
+                    return Some(n);
+                }
+            }
+        }
+        // The candidates above the dense range have at most `significant_bits` significant bits
+        // and are multiples of `m`. Rounding up to a multiple of the power of two in `m` first
+        // preserves this, since rounding up to fewer significant bits only ever adds trailing
+        // zeros. The odd part of `m` is rarely larger than one, so it is handled by searching.
+        let odd = m >> m.trailing_zeros();
+        let mut n = if desired > dense_max {
+            desired
+        } else {
+            dense_max + 1
+        };
+        let found = loop {
+            n = match round_up_to_multiple(n, 1 << m.trailing_zeros()) {
+                Some(n) => n,
+                None => break None,
+            };
+            n = match round_up_to_significant_bits(n, self.significant_bits) {
+                Some(n) => n,
+                None => break None,
+            };
+            if n > max_bound {
+                break None;
+            }
+            if n % odd == 0 {
+                break Some(n);
+            }
+            n = match n.checked_add(1) {
+                Some(n) => n,
+                None => break None,
+            };
+        };
+        match found {
+            Some(n) => Some(n),
+            None if max_bound == max => Some(max),
+            None => None,
+        }
+    }
+}
+
+/// Returns the `k`-th of the 16 refinements of the candidate `c`, which is `c / 2 + k * c / 32`
+/// rounded up, without overflowing.
+///
+/// Refined ladders use these to subdivide the range from `c / 2` to `c`. For every desired value
+/// in that range, one of them is at most `c / 32 + 1` larger, i.e. less than 6.25% above large
+/// desired values. The last one, for `k == 16`, is `c` itself. The same formula was used to
+/// generate the dispatch to the refinements in `src/implementation/for_each_size.rs`.
+const fn refinement(c: usize, k: usize) -> usize {
+    c / 2 + k * (c / 32) + (k * (c % 32)).div_ceil(32)
+}
+
+/// Returns the smallest multiple of `m` that is at least `n`.
+const fn round_up_to_multiple(n: u128, m: u128) -> Option<u128> {
+    match n % m {
+        0 => Some(n),
+        rem => n.checked_add(m - rem),
+    }
+}
+
+/// Returns the smallest value that is at least `n` and has at most `bits` significant bits.
+const fn round_up_to_significant_bits(n: u128, bits: u32) -> Option<u128> {
+    let len = u128::BITS - n.leading_zeros();
+    if len <= bits {
+        return Some(n);
+    }
+    let shift = len - bits;
+    let mantissa = (n >> shift) + (n & ((1 << shift) - 1) != 0) as u128;
+    if mantissa >> (u128::BITS - shift) != 0 {
+        return None;
+    }
+    Some(mantissa << shift)
+}
+
+/// The largest candidate of any ladder.
+///
+/// This is `usize::MAX`, unless one of the following features is enabled to guard against
+/// accidentally large bounds (e.g. on targets with little memory):
+/// - `max-bound-1m`: `1 << 20`
+/// - `max-bound-64k`: `1 << 16`. Takes precedence over `max-bound-1m`.
+///
+/// The `portable-bounds` feature sets it to `1 << 31` instead, unless one of the above is enabled.
+/// All candidates up to that value are independent of the pointer width of the target, so
+/// [`get_upper_bound`](crate::get_upper_bound) returns the same value on 32-bit and 64-bit targets.
+/// This does not apply to [`AcceptUpperBoundOf<usize>`](crate::AcceptUpperBoundOf).
+///
+/// On 16-bit targets, all of these values are larger than `usize::MAX`, so the features have no
+/// effect there and this is always `usize::MAX`.
+///
+/// Acceptors whose desired value has no candidate up to this bound fail to compile.
+///
+/// ```
+/// use generic_upper_bound::ladder::{CANDIDATES, MAX_BOUND};
+/// if cfg!(feature = "portable-bounds") && MAX_BOUND > 1 << 20 {
+///     assert_eq!(MAX_BOUND, 1 << 31);
+///     assert!(CANDIDATES.iter().all(|&n| n <= u32::MAX as usize));
+/// }
+/// ```
+pub const MAX_BOUND: usize = if cfg!(feature = "max-bound-64k") {
+    saturating_pow2(16)
+} else if cfg!(feature = "max-bound-1m") {
+    saturating_pow2(20)
+} else if cfg!(feature = "portable-bounds") {
+    saturating_pow2(31)
+} else {
+    usize::MAX
+};
+
+/// The largest desired value for which the guarantees of
+/// [`AcceptUpperBound`](crate::AcceptUpperBound) can be checked without overflow.
+///
+/// This is `usize::MAX / 2`, or [`MAX_BOUND`] if that is smaller. For desired values up to this
+/// one, `DESIRED_GENERIC <= UPPER < 2 * DESIRED_GENERIC` holds for the default ladder and
+/// `2 * DESIRED_GENERIC` fits in a `usize`. Larger desired values still have an upper bound unless
+/// they exceed [`MAX_BOUND`], but it is usually `usize::MAX`, which is too large for the length of
+/// an array of non-zero-sized elements.
+///
+/// [`get_supported_upper_bound`](crate::get_supported_upper_bound) and
+/// [`try_get_supported_upper_bound`](crate::try_get_supported_upper_bound) reject larger desired
+/// values.
+///
+/// ```
+/// use generic_upper_bound::ladder::{MAX_BOUND, MAX_SUPPORTED_DESIRED};
+/// if MAX_BOUND == usize::MAX {
+///     #[cfg(target_pointer_width = "16")]
+///     assert_eq!(MAX_SUPPORTED_DESIRED, 0x7fff);
+///     #[cfg(target_pointer_width = "32")]
+///     assert_eq!(MAX_SUPPORTED_DESIRED, 0x7fff_ffff);
+///     #[cfg(target_pointer_width = "64")]
+///     assert_eq!(MAX_SUPPORTED_DESIRED, 0x7fff_ffff_ffff_ffff);
+/// } else {
+///     assert_eq!(MAX_SUPPORTED_DESIRED, MAX_BOUND.min(usize::MAX / 2));
+/// }
+/// ```
+pub const MAX_SUPPORTED_DESIRED: usize = if MAX_BOUND < usize::MAX / 2 {
+    MAX_BOUND
+} else {
+    usize::MAX / 2
+};
+
+/// Returns `1 << bits`, or `usize::MAX` if that does not fit in a `usize`.
+const fn saturating_pow2(bits: u32) -> usize {
+    if bits < usize::BITS {
+        1 << bits
+    } else {
+        usize::MAX
+    }
+}
+
+// Checks the closed form at the top of the range during compilation for the target. This is
+// where the search would overflow, and on 16-bit targets such as `avr-none` or `msp430-none-elf`,
+// desired values in this range are common. The search itself is checked against the `u16`
+// candidates on every target (see `implementation`), while these assertions check the constants
+// of the target. CI evaluates them for a 16-bit target with
+// `cargo +nightly check --target msp430-none-elf -Zbuild-std=core`, which needs the `rust-src`
+// component. The closed form is not used for custom candidates.
+const _: () = if !IS_CUSTOM {
+    let refined = Ladder::DEFAULT;
+    let ladder = refined.coarse();
+    let bits = ladder.significant_bits;
+    // the largest candidate below the maximum value
+    let top = ((1 << bits) - 1) << (usize::BITS - bits);
+    if MAX_BOUND == usize::MAX {
+        // the guarantees hold up to the largest supported desired value
+        let max = MAX_SUPPORTED_DESIRED;
+        assert!(max == usize::MAX / 2);
+        assert!(matches!(
+            refined.closed_form_upper_bound(max),
+            Some(n) if n != usize::MAX && n / 2 < max
+        ));
+        assert!(matches!(ladder.closed_form_upper_bound(top), Some(n) if n == top));
+        assert!(matches!(
+            ladder.closed_form_upper_bound(top + 1),
+            Some(usize::MAX)
+        ));
+        // the refinements of the maximum value are less than 6.25% apart as well
+        assert!(
+            !refined.is_refined()
+                || matches!(
+                    refined.closed_form_upper_bound(top + 1),
+                    Some(n) if n - (top + 1) < (top + 1) / 16
+                )
+        );
+        assert!(matches!(
+            ladder.closed_form_upper_bound(usize::MAX),
+            Some(usize::MAX)
+        ));
+    } else {
+        // with `portable-bounds` on 32-bit targets, `MAX_BOUND` is `usize::MAX / 2 + 1`
+        assert!(MAX_SUPPORTED_DESIRED == MAX_BOUND || MAX_SUPPORTED_DESIRED == usize::MAX / 2);
+        assert!(matches!(ladder.closed_form_upper_bound(MAX_BOUND), Some(n) if n == MAX_BOUND));
+        assert!(ladder.closed_form_upper_bound(MAX_BOUND + 1).is_none());
+        assert!(ladder.closed_form_upper_bound(usize::MAX).is_none());
+    }
+};
+
+/// The candidates of [`Ladder::DEFAULT`], in ascending order.
+///
+/// ```
+/// use generic_upper_bound::ladder::{CANDIDATES, MAX_BOUND};
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(CANDIDATES[..65], core::array::from_fn::<usize, 65, _>(|i| i));
+/// assert!(CANDIDATES.windows(2).all(|w| w[0] < w[1]));
+/// assert_eq!(CANDIDATES.last(), Some(&MAX_BOUND));
+/// ```
+pub const CANDIDATES: &[usize] = crate::implementation::DEFAULT_CANDIDATES;
+
+/// Returns the smallest element of [`CANDIDATES`] that is at least `desired`.
+///
+/// This is the value that [`get_upper_bound`](crate::get_upper_bound) returns for acceptors that
+/// use [`Ladder::DEFAULT`].
+///
+/// # Panics
+/// If `desired` is larger than [`MAX_BOUND`].
+///
+/// ```
+/// use generic_upper_bound::ladder;
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(ladder::upper_bound_for(37), 37);
+/// let bound = ladder::upper_bound_for(1000);
+/// assert!(bound >= 1000 && ladder::CANDIDATES.contains(&bound));
+/// ```
+pub const fn upper_bound_for(desired: usize) -> usize {
+    crate::implementation::upper_bound_for(desired, Ladder::DEFAULT)
+}
+
+/// Returns an iterator over [`CANDIDATES`], in ascending order.
+///
+/// ```
+/// use generic_upper_bound::ladder;
+/// let small: Vec<_> = ladder::candidates().skip_while(|&n| n < 60).take(7).collect();
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(small[..5], [60, 61, 62, 63, 64]);
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert!(small[5] > 64);
+/// ```
+pub fn candidates() -> impl DoubleEndedIterator<Item = usize> + ExactSizeIterator {
+    CANDIDATES.iter().copied()
+}
+
+/// Returns the smallest element of [`CANDIDATES`] that is larger than `n`.
+///
+/// # Panics
+/// If `n` is at least [`MAX_BOUND`].
+///
+/// ```
+/// use generic_upper_bound::ladder;
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(ladder::next_candidate(37), 38);
+/// let next = ladder::next_candidate(1000);
+/// assert_eq!(next, ladder::upper_bound_for(1001));
+/// assert_eq!(ladder::prev_candidate(ladder::next_candidate(next)), next);
+/// ```
+pub const fn next_candidate(n: usize) -> usize {
+    if n >= MAX_BOUND {
+        panic_with!(
+            "there is no candidate larger than `ladder::MAX_BOUND`",
+            "n" = n,
+        )
+    }
+    upper_bound_for(n + 1)
+}
+
+/// Returns the largest element of [`CANDIDATES`] that is smaller than `n`.
+///
+/// # Panics
+/// If there is no such candidate, e.g. if `n` is `0`.
+///
+/// ```
+/// use generic_upper_bound::ladder;
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(ladder::prev_candidate(37), 36);
+/// assert_eq!(ladder::prev_candidate(ladder::MAX_BOUND), ladder::CANDIDATES[ladder::CANDIDATES.len() - 2]);
+/// ```
+pub const fn prev_candidate(n: usize) -> usize {
+    if CANDIDATES[0] >= n {
+        panic_with!("there is no candidate smaller than the value", "n" = n)
+    }
+    // Invariant: `CANDIDATES[lo] < n <= CANDIDATES[hi]`, where `CANDIDATES[CANDIDATES.len()]`
+    // is treated as infinity.
+    let (mut lo, mut hi) = (0, CANDIDATES.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if CANDIDATES[mid] < n {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    CANDIDATES[lo]
+}
+
+/// Returns the capacity for a runtime buffer of `len` elements, which is the smallest element of
+/// [`CANDIDATES`] that is at least `len`.
+///
+/// This is the same as [`upper_bound_for`], so buffers sized with it at runtime match the
+/// buffers of acceptors that use [`Ladder::DEFAULT`]. It can be called at runtime as well as in
+/// const contexts. See also [`with_runtime_upper_bound`](crate::with_runtime_upper_bound).
+#[cfg_attr(
+    feature = "alloc",
+    doc = "[`LadderBuf`] sizes its buffer with this function."
+)]
+///
+/// # Panics
+/// If `len` is larger than [`MAX_BOUND`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::ladder::round_up_capacity;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// const PLANNED: usize = round_up_capacity(5000);
+/// assert_eq!(PLANNED, gub::get_upper_bound::<Len<5000>>());
+/// let len = "x".repeat(5000).len();
+/// assert_eq!(round_up_capacity(len), PLANNED);
+/// ```
+pub const fn round_up_capacity(len: usize) -> usize {
+    upper_bound_for(len)
+}
+
+/// Like [`round_up_capacity`], but returns `None` instead of panicking if `len` is larger than
+/// [`MAX_BOUND`].
+///
+/// ```
+/// use generic_upper_bound::ladder::{try_round_up_capacity, MAX_BOUND};
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(try_round_up_capacity(37), Some(37));
+/// assert_eq!(try_round_up_capacity(MAX_BOUND), Some(MAX_BOUND));
+/// # if MAX_BOUND < usize::MAX {
+/// assert_eq!(try_round_up_capacity(MAX_BOUND + 1), None);
+/// # }
+/// ```
+pub const fn try_round_up_capacity(len: usize) -> Option<usize> {
+    crate::implementation::try_upper_bound_for(len, Ladder::DEFAULT)
+}
+
+/// A heap-allocated buffer whose capacity is always an element of [`CANDIDATES`].
+///
+/// This sizes runtime buffers with the same candidates as the compile-time bounds, so that
+/// memory usage can be planned using the same values. The capacity is grown to the next
+/// [`round_up_capacity`] when the buffer is full. Use
+/// [`with_runtime_upper_bound`](crate::with_runtime_upper_bound) for buffers on the stack.
+///
+/// [`capacity`](Self::capacity) returns the candidate that was requested from the allocator
+/// using [`Vec::reserve_exact`], which usually allocates exactly that many elements.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use generic_upper_bound::ladder::{round_up_capacity, LadderBuf};
+/// let mut buf = LadderBuf::with_capacity(100);
+/// assert_eq!(buf.capacity(), round_up_capacity(100));
+/// buf.extend(0..1000);
+/// assert_eq!(buf.len(), 1000);
+/// assert_eq!(buf.capacity(), round_up_capacity(1000));
+/// assert_eq!(buf[999], 999);
+/// let clone = buf.clone();
+/// assert_eq!(clone.capacity(), buf.capacity());
+/// assert_eq!(clone, buf);
+/// let items: Vec<i32> = buf.into_vec();
+/// assert_eq!(items.len(), 1000);
+/// ```
+///
+/// [`Vec::reserve_exact`]: alloc::vec::Vec::reserve_exact
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct LadderBuf<T> {
+    items: alloc::vec::Vec<T>,
+    capacity: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> LadderBuf<T> {
+    /// Creates an empty buffer without allocating.
+    pub const fn new() -> Self {
+        Self {
+            items: alloc::vec::Vec::new(),
+            capacity: 0,
+        }
+    }
+
+    /// Creates an empty buffer with the capacity [`round_up_capacity(len)`](round_up_capacity).
+    ///
+    /// # Panics
+    /// If `len` is larger than [`MAX_BOUND`].
+    pub fn with_capacity(len: usize) -> Self {
+        let mut buf = Self::new();
+        buf.reserve(len);
+        buf
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the capacity, which is `0` or an element of [`CANDIDATES`].
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Ensures that the capacity is at least `len() + additional`, growing it to
+    /// [`round_up_capacity`] of that value if necessary.
+    ///
+    /// # Panics
+    /// If the new length would be larger than [`MAX_BOUND`].
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = match self.len().checked_add(additional) {
+            Some(needed) => needed,
+            None => panic!("the capacity overflows `usize`"),
+        };
+        if needed > self.capacity {
+            self.capacity = round_up_capacity(needed);
+            self.items.reserve_exact(self.capacity - self.len());
+        }
+    }
+
+    /// Appends an element, growing the capacity to the next candidate if the buffer is full.
+    pub fn push(&mut self, item: T) {
+        self.reserve(1);
+        self.items.push(item);
+    }
+
+    /// Removes all elements, keeping the capacity.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Returns the elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.items
+    }
+
+    /// Returns the underlying `Vec`.
+    pub fn into_vec(self) -> alloc::vec::Vec<T> {
+        self.items
+    }
+}
+
+// The capacity is not derived: a clone reserves the same capacity, which `Vec::clone` does not,
+// and buffers with different capacities but the same elements are equal.
+#[cfg(feature = "alloc")]
+impl<T: Clone> Clone for LadderBuf<T> {
+    fn clone(&self) -> Self {
+        let mut items = alloc::vec::Vec::with_capacity(self.capacity);
+        items.extend_from_slice(&self.items);
+        Self {
+            items,
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: PartialEq> PartialEq for LadderBuf<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Eq> Eq for LadderBuf<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T: core::hash::Hash> core::hash::Hash for LadderBuf<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.items.hash(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Default for LadderBuf<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> core::ops::Deref for LadderBuf<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> core::ops::DerefMut for LadderBuf<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.items
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Extend<T> for LadderBuf<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+const fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
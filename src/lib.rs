@@ -3,6 +3,9 @@
 #![allow(clippy::absurd_extreme_comparisons)]
 #![allow(unused_comparisons)]
 #![allow(rustdoc::redundant_explicit_links)]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs, freeze))]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 
 //! This crate allows performing const calculations with the help of a generic const `usize`
 //! that is a reasonable upper bound of some desired associated const `usize`.
@@ -10,9 +13,12 @@
 //! The API of this crate is structed as follows:
 //! - [`AcceptUpperBound`](crate::AcceptUpperBound) is the heart of this crate. Implementors use it to specify which
 //!   generic const they want to be passed to them and what to do with any given upper bound for it.
-//!   It can be implemented conveniently using [`impl_accept_upper_bound!`](crate::impl_accept_upper_bound).
+//!   It can be implemented conveniently using [`impl_accept_upper_bound!`](crate::impl_accept_upper_bound),
+//!   or declared together with the implementing type using [`define_acceptor!`](crate::define_acceptor).
 //! - [`eval_with_upper_bound`](crate::eval_with_upper_bound) is used to get the result of evaluating an upper bound acceptor
-//!   with the best-effort upper bound that this crate can offer.
+//!   with the best-effort upper bound that this crate can offer. Several acceptors can be evaluated
+//!   with one shared upper bound by grouping them in a tuple. One-off acceptors can be defined and
+//!   evaluated inline using [`eval!`](crate::eval).
 //!
 //! While you cannot use this to write a function with a signature that returns e.g. `[T; M + N]`
 //! with generic `M` and `N`, you can use it to temporarily get an array of size `M + N`, use it
@@ -30,38 +36,16 @@
 //!
 //!     const DESIRED_GENERIC: usize = A::SOME_STR.len() + B::SOME_STR.len();
 //!
-//!     const EVAL<const UPPER: usize>: &'static [u8] = &{
-//!         let l = A::SOME_STR.as_bytes();
-//!         let r = B::SOME_STR.as_bytes();
-//!         let mut out = [0; UPPER];
-//!         let mut off = 0;
-//!         // after 1.86, use split_at_mut and copy_from_slice
-//!         let mut i = 0;
-//!         while i < l.len() {
-//!             out[off] = l[i];
-//!             off += 1;
-//!             i += 1;
-//!         }
-//!         i = 0;
-//!         while i < r.len() {
-//!             out[off] = r[i];
-//!             off += 1;
-//!             i += 1;
-//!         }
-//!         out
-//!     };
+//!     const EVAL<const UPPER: usize>: &'static [u8] = &gub::build::BytesWriter::<UPPER>::new()
+//!         .write_str(A::SOME_STR)
+//!         .write_str(B::SOME_STR)
+//!         .finish()
+//!         .0;
 //! }
 //! impl<A: MyTrait, B: MyTrait> MyTrait for (A, B) {
 //!     // evaluate the upper bound acceptor, trim trailing nul bytes
 //!     // and convert to string
-//!     const SOME_STR: &'static str = match core::str::from_utf8(
-//!         gub::eval_with_upper_bound::<Concat<A, B>>()
-//!             .split_at(gub::desired_generic::<Concat<A, B>>())
-//!             .0,
-//!     ) {
-//!         Ok(s) => s,
-//!         _ => unreachable!(),
-//!     };
+//!     const SOME_STR: &'static str = gub::eval_str::<Concat<A, B>>();
 //! }
 //! impl MyTrait for () {
 //!     const SOME_STR: &'static str = "ABC";
@@ -76,14 +60,94 @@
 //! due to the overhead from the inexact upper bound used for each concatenation (which will
 //! likely affect the final binary size).
 //!
-//! See the [`const-util`](https://docs.rs/const-util/latest/const_util/) crate for an
-//! implementation of this.
+//! This crate implements this as [`adapters::concat_strs`](crate::adapters::concat_strs). See also the
+//! [`const-util`](https://docs.rs/const-util/latest/const_util/) crate.
+//!
+//! # Features
+//! - `ladder-step-1_25`: Make [`Ladder::DEFAULT`](crate::Ladder::DEFAULT) use candidates that are
+//!   at most 25% apart instead of 50%, making [`get_upper_bound`](crate::get_upper_bound) tighter.
+//! - `ladder-step-1_125`: Use candidates that are at most 12.5% apart. Takes precedence over
+//!   `ladder-step-1_25`.
+//! - `ladder-step-1_0625`: Use candidates that are at most 6.25% apart. Takes precedence over
+//!   the other `ladder-step` features. The bound is first selected from the candidates of
+//!   `ladder-step-1_25` and then refined by a second dispatch, see `Ladder::FINE`, which is only
+//!   available with this feature. The bounds of
+//!   [`AcceptUpperBound2`](crate::AcceptUpperBound2), [`AcceptLowerBound`](crate::AcceptLowerBound)
+//!   and [`AcceptUpperBoundOf`](crate::AcceptUpperBoundOf) are not refined.
+//! - `exhaustive-small`: Make every value up to `256` a candidate instead of every value up to
+//!   `64`, so that bounds for small desired values are exact.
+//!
+//! - `max-bound-1m`, `max-bound-64k`: Exclude candidates above `1 << 20` and `1 << 16`
+//!   respectively, so that acceptors with larger desired values fail to compile. See
+//!   [`ladder::MAX_BOUND`](crate::ladder::MAX_BOUND).
+//! - `portable-bounds`: Exclude candidates above `1 << 31`. The remaining candidates are the same
+//!   on 32-bit and 64-bit targets, so outputs that are padded to the upper bound are identical
+//!   across them.
+//!
+//! - `const_panic`: Include the values involved (e.g. the desired value and the upper bound) in
+//!   the messages of panics during const evaluation, using the `const_panic` crate. The acceptor
+//!   that failed is named by the compiler in the resulting error.
+//!
+//! - `nightly`: Enable `eval_with_exact_bound`, which uses the unstable `generic_const_exprs`
+//!   feature to evaluate an acceptor without any overhead, and `eval_ref` and `AcceptConstParam`,
+//!   which selects a const parameter of an arbitrary type. Requires a nightly compiler.
+//!
+//! - `rust-1_87`: Use const APIs that were stabilized in Rust 1.87, such as `copy_from_slice`, in
+//!   [`build`](crate::build) and the built-in adapters. This speeds up const evaluation of large
+//!   outputs. Requires Rust 1.87.
+//!
+//! - `konst`: Use the const functions of the `konst` crate to compare the keys of the table
+//!   adapters and to slice the pieces of [`adapters::split`](crate::adapters::split), which takes
+//!   fewer const evaluation steps for large inputs. Substring search does not use `konst`, since
+//!   its search misses matches that start inside a partial match.
+//!
+//! - `alloc`: Enable [`eval_to_vec`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/fn.eval_to_vec.html),
+//!   [`eval_boxed_slice`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/fn.eval_boxed_slice.html), [`eval_string`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/fn.eval_string.html) and
+//!   [`eval_cstring`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/fn.eval_cstring.html), which copy the trimmed output of an acceptor into an
+//!   exactly-sized owned buffer, and [`ladder::LadderBuf`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/ladder/struct.LadderBuf.html), a
+//!   heap-allocated buffer whose capacity is always a candidate.
+//!
+//! - `heapless`: Enable [`eval_heapless`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/fn.eval_heapless.html), which collects the trimmed
+//!   output of an acceptor into a `heapless::Vec`.
+//!
+//! - `arrayvec`, `tinyvec`: Enable [`eval_arrayvec`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/fn.eval_arrayvec.html) and
+//!   [`eval_tinyvec`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/fn.eval_tinyvec.html) respectively, which work like `eval_heapless`.
+//!
+//! - `bytemuck`: Enable [`eval_pod_trimmed`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/fn.eval_pod_trimmed.html), which views the trimmed
+//!   output of an acceptor as a slice of another `Pod` type.
+//!
+//! - `typenum`: Enable the [`typenum`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/typenum/index.html) module, which converts between
+//!   candidates and `typenum` integers.
+//!
+//! - `fmt`: Enable the [`fmt`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/fmt/index.html) module and [`impl_format!`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/macro.impl_format.html),
+//!   which formats templates with generic-dependent arguments at compile time.
+//!
+//! - `testing`: Enable the [`testing`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/testing/index.html) module, which checks that acceptors do not
+//!   depend on the exact upper bound, and [`bound_report!`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/macro.bound_report.html).
+//!
+//! - `macros`: Enable the [`acceptor`](https://docs.rs/generic-upper-bound/latest/generic_upper_bound/attr.acceptor.html) attribute, which turns a generic `const fn`
+//!   into an acceptor.
 //!
 //! # MSRV
 //! The MSRV is 1.78. This is to allow this crate to be used as a workaround for the breaking change
 //! to const promotion that was introduced by that version.
+//!
+//! The `rust-1_87` feature enables faster implementations of some internals that need a newer
+//! compiler, such as copying slices with `copy_from_slice` in [`build::BytesWriter`]. This only
+//! affects the speed of const evaluation, not the API.
+//!
+//! # Build systems
+//! This crate has no build script. The default candidates are pregenerated for each
+//! `target_pointer_width`, and the `GUB_CANDIDATES` environment variable (see [`ladder`](crate::ladder))
+//! is read with `option_env!` while compiling this crate, which cargo tracks. With other build
+//! systems, such as Bazel, the variable has to be set in the environment of the `rustc` invocation
+//! that compiles this crate, e.g. using `rustc_env` in `rules_rust`.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 pub extern crate type_const;
+use core::{ffi::CStr, marker::PhantomData, mem::MaybeUninit, num::NonZeroUsize};
+
 pub use type_const::{value_of as const_value, Const, TypeOf};
 
 /// Allows implementing a callback pattern that accepts an upper bound for a desired generic const
@@ -96,33 +160,768 @@ pub use type_const::{value_of as const_value, Const, TypeOf};
 ///
 /// When passed to [`eval_with_upper_bound`], [`Eval::<UPPER>::VALUE`](Const::VALUE) will be evaluated
 /// with a parameter `UPPER` that satisfies `DESIRED_GENERIC <= UPPER < 2 * DESIRED_GENERIC`.
+/// If `DESIRED_GENERIC <= 64`, then `UPPER == DESIRED_GENERIC` (see [`is_exact`]). The
+/// `exhaustive-small` feature extends this to `DESIRED_GENERIC <= 256`.
+///
+/// The `ladder-step-1_25`, `ladder-step-1_125` and `ladder-step-1_0625` features tighten this to
+/// `UPPER < 1.25 * DESIRED_GENERIC`, `UPPER < 1.125 * DESIRED_GENERIC` and
+/// `UPPER < 1.0625 * DESIRED_GENERIC` respectively, at the cost of more candidates to search
+/// through during const evaluation. `ladder-step-1_0625` searches in two steps of up to 16
+/// candidates each, see `Ladder::FINE`, which is only available with that feature.
+///
+/// These guarantees apply to the default [`Self::LADDER`]. Other ladders document their own.
+/// They hold for all desired values, but for values above `usize::MAX / 2`, the expression
+/// `2 * DESIRED_GENERIC` overflows. Use the equivalent `UPPER / 2 < DESIRED_GENERIC` to check
+/// them in code, in particular on 16-bit targets, where this affects desired values above
+/// `32767`. See the [`ladder`] module for details. Such desired values are above
+/// [`ladder::MAX_SUPPORTED_DESIRED`] and usually have the upper bound `usize::MAX`. Use
+/// [`get_supported_upper_bound`] to reject them.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an upper bound acceptor",
+    label = "`{Self}` does not implement `AcceptUpperBound`",
+    note = "implement it using `generic_upper_bound::impl_accept_upper_bound!` or declare the type with `generic_upper_bound::define_acceptor!`",
+    note = "`Eval` must be a generic associated type: `type Eval<const UPPER: usize>: Const<Type = Self::Output>`"
+)]
 pub trait AcceptUpperBound {
     /// The output type of the evaluation.
     type Output;
 
     /// The desired value and lower bound that the implementor wants to be passed to [`Self::Eval`].
+    ///
+    /// This is evaluated once per implementor: Computing the bound, evaluating, trimming the
+    /// output and the combinators and macros of this crate all read the same promoted copy of it.
+    /// Use [`desired_generic`] instead of reading this constant directly to share that copy. If
+    /// the computation is expensive and needed elsewhere as well (e.g. in `EVAL`), move it into a
+    /// separate [`Const`], which is also only evaluated once:
+    ///
+    /// ```
+    /// use generic_upper_bound as gub;
+    /// // The number of non-zero entries of a large table
+    /// struct NonZero<const N: usize>;
+    /// impl<const N: usize> gub::Const for NonZero<N> {
+    ///     type Type = usize;
+    ///     const VALUE: Self::Type = {
+    ///         let (mut i, mut count) = (0, 0);
+    ///         while i < N {
+    ///             if i % 3 != 0 {
+    ///                 count += 1;
+    ///             }
+    ///             i += 1;
+    ///         }
+    ///         count
+    ///     };
+    /// }
+    /// struct Compressed<const N: usize>;
+    /// gub::impl_accept_upper_bound! {
+    ///     impl{const N: usize} Compressed<N>;
+    ///     const DESIRED_GENERIC: usize = gub::const_value::<NonZero<N>>();
+    ///     const EVAL<const UPPER: usize>: &'static [usize] = &{
+    ///         let mut out = [0; UPPER];
+    ///         let (mut i, mut j) = (0, 0);
+    ///         // `NonZero<N>` is not evaluated again here
+    ///         while j < gub::const_value::<NonZero<N>>() {
+    ///             if i % 3 != 0 {
+    ///                 out[j] = i;
+    ///                 j += 1;
+    ///             }
+    ///             i += 1;
+    ///         }
+    ///         out
+    ///     };
+    /// }
+    /// assert_eq!(gub::eval_trimmed::<Compressed<7>, _>(), [1, 2, 4, 5]);
+    /// ```
     const DESIRED_GENERIC: usize;
 
     /// Evals the constant by mapping a generic parameter that is at least the desired value
     /// to the output value. `const_value::<Eval<UPPER>>()` should be indistinguishable for
     /// all *possible* generic parameters passed to this.
     type Eval<const UPPER: usize>: Const<Type = Self::Output>;
+
+    /// The set of candidates that `UPPER` is selected from.
+    ///
+    /// This allows acceptors with very different size profiles to use different ladders without
+    /// changing the crate-wide default. Acceptors whose desired value is always large can skip the
+    /// small candidates using [`Ladder::starting_at`]. Acceptors with large elements can size the
+    /// dense range in bytes using [`Ladder::rescaled_for_elem_size`].
+    ///
+    /// ```
+    /// use generic_upper_bound as gub;
+    /// struct Table<const N: usize>;
+    /// gub::impl_accept_upper_bound! {
+    ///     impl{const N: usize} Table<N>;
+    ///     const DESIRED_GENERIC: usize = N;
+    ///     const LADDER: gub::Ladder = gub::Ladder::PAGE_ALIGNED;
+    ///     const EVAL<const UPPER: usize>: () = ();
+    /// }
+    /// assert_eq!(gub::get_upper_bound::<Table<100>>(), 4096);
+    /// # #[cfg(not(gub_custom_candidates))]
+    /// assert_eq!(gub::get_upper_bound::<Table<5000>>(), 8192);
+    /// ```
+    const LADDER: Ladder = Ladder::DEFAULT;
+}
+
+/// Forwards to `A`, so that acceptors can be passed through generic code as type-level markers.
+///
+/// ```
+/// use core::marker::PhantomData;
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = N;
+/// }
+/// assert_eq!(gub::eval_with_upper_bound::<PhantomData<Len<100>>>(), 100);
+/// assert_eq!(gub::get_upper_bound::<&Len<100>>(), gub::get_upper_bound::<Len<100>>());
+/// assert_eq!(gub::desired_generic::<&mut PhantomData<Len<7>>>(), 7);
+/// ```
+impl<A: AcceptUpperBound> AcceptUpperBound for PhantomData<A> {
+    type Output = A::Output;
+    const DESIRED_GENERIC: usize = desired_generic::<A>();
+    const LADDER: Ladder = A::LADDER;
+    type Eval<const UPPER: usize> = A::Eval<UPPER>;
+}
+
+/// Forwards to `A`.
+impl<A: AcceptUpperBound> AcceptUpperBound for &A {
+    type Output = A::Output;
+    const DESIRED_GENERIC: usize = desired_generic::<A>();
+    const LADDER: Ladder = A::LADDER;
+    type Eval<const UPPER: usize> = A::Eval<UPPER>;
+}
+
+/// Forwards to `A`.
+impl<A: AcceptUpperBound> AcceptUpperBound for &mut A {
+    type Output = A::Output;
+    const DESIRED_GENERIC: usize = desired_generic::<A>();
+    const LADDER: Ladder = A::LADDER;
+    type Eval<const UPPER: usize> = A::Eval<UPPER>;
 }
 
 struct Impl<A>(A);
+struct Impl2<A>(A);
+struct ImplLower<A>(A);
+
+/// Panics with a message, followed by the given named values if the `const_panic` feature is
+/// enabled. Formatting values is otherwise not possible during const evaluation.
+macro_rules! panic_with {
+    ($msg:literal $(, $name:literal = $value:expr)* $(,)?) => {{
+        #[cfg(feature = "const_panic")]
+        ::const_panic::concat_panic!($msg $(, "; ", $name, " = ", $value)*);
+        #[cfg(not(feature = "const_panic"))]
+        {
+            $(let _ = $value;)*
+            ::core::panic!($msg);
+        }
+    }};
+}
 
 mod implementation;
+#[doc(hidden)]
+pub use implementation::{CUSTOM_LEN as __CUSTOM_LEN, SLOTS as __SLOTS};
+
+pub mod ladder;
+pub use ladder::Ladder;
+
+pub mod adapters;
+pub mod build;
+pub mod combinators;
+pub mod consts;
+#[cfg(feature = "fmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fmt")))]
+pub mod fmt;
+pub mod math;
+pub mod serialize;
+pub mod slice;
+pub mod str;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+#[cfg(feature = "typenum")]
+#[cfg_attr(docsrs, doc(cfg(feature = "typenum")))]
+pub mod typenum;
+
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use generic_upper_bound_macros::acceptor;
 
 /// Returns [`AcceptUpperBound::DESIRED_GENERIC`].
+///
+/// This reads the same copy of the value that is used to select the upper bound, so the
+/// constant is not evaluated again.
 pub const fn desired_generic<A: AcceptUpperBound>() -> usize {
     Impl::<A>::DESIRED
 }
 
 /// Returns the parameter that [`eval_with_upper_bound`] passes to [`AcceptUpperBound::Eval`].
+///
+/// # Panics
+/// If the desired value has no upper bound. See [`try_get_upper_bound`].
 pub const fn get_upper_bound<A: AcceptUpperBound>() -> usize {
     Impl::<A>::ACTUAL
 }
 
+/// Returns the parameter that [`eval_with_upper_bound`] passes to [`AcceptUpperBound::Eval`], or
+/// `None` if the desired value has no upper bound.
+///
+/// The maximum value of `usize` is a candidate of every ladder, so this only returns `None` if
+/// one of the `max-bound-*` or `portable-bounds` features is enabled and the desired value is
+/// larger than
+/// [`ladder::MAX_BOUND`]. In particular, desired values larger than `usize::MAX / 2` still have an
+/// upper bound by default, but arrays of that length are too large unless their elements are
+/// zero-sized, so evaluating the acceptor usually fails. Note that on 16-bit targets, this already
+/// happens for desired values larger than `32767`.
+///
+/// This allows libraries to fall back to a different implementation instead of failing to
+/// compile, e.g. if a generic parameter makes the desired value too large for the target.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::try_get_upper_bound::<Len<37>>(), Some(37));
+/// let huge = gub::try_get_upper_bound::<Len<{ usize::MAX - 1 }>>();
+/// if gub::ladder::MAX_BOUND == usize::MAX {
+///     assert_eq!(huge, Some(usize::MAX));
+/// } else {
+///     assert_eq!(huge, None);
+/// }
+/// ```
+pub const fn try_get_upper_bound<A: AcceptUpperBound>() -> Option<usize> {
+    Impl::<A>::TRY_ACTUAL
+}
+
+/// Returns [`get_upper_bound`], checking that the desired value is at most
+/// [`ladder::MAX_SUPPORTED_DESIRED`].
+///
+/// Unlike [`get_upper_bound`], this rejects desired values above `usize::MAX / 2` with a
+/// descriptive message, instead of returning an upper bound (usually `usize::MAX`) that is too
+/// large for an array.
+///
+/// # Panics
+/// If the desired value is larger than [`ladder::MAX_SUPPORTED_DESIRED`] or has no upper bound.
+/// See [`try_get_supported_upper_bound`].
+///
+/// ```compile_fail
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// const _: usize = gub::get_supported_upper_bound::<Len<{ usize::MAX / 2 + 1 }>>();
+/// ```
+pub const fn get_supported_upper_bound<A: AcceptUpperBound>() -> usize {
+    Impl::<A>::SUPPORTED
+}
+
+/// Returns [`get_upper_bound`], or `None` if the desired value is larger than
+/// [`ladder::MAX_SUPPORTED_DESIRED`] or has no upper bound.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::ladder::MAX_SUPPORTED_DESIRED;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// let max = gub::try_get_supported_upper_bound::<Len<MAX_SUPPORTED_DESIRED>>().unwrap();
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert!(max >= MAX_SUPPORTED_DESIRED && max / 2 < MAX_SUPPORTED_DESIRED);
+/// assert_eq!(gub::try_get_supported_upper_bound::<Len<{ MAX_SUPPORTED_DESIRED + 1 }>>(), None);
+/// assert_eq!(gub::try_get_supported_upper_bound::<Len<{ usize::MAX }>>(), None);
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::try_get_supported_upper_bound::<Len<37>>(), Some(37));
+/// ```
+pub const fn try_get_supported_upper_bound<A: AcceptUpperBound>() -> Option<usize> {
+    Impl::<A>::TRY_SUPPORTED
+}
+
+/// Returns the smallest candidate of the [ladder](AcceptUpperBound::LADDER) of `A` that is at
+/// least both the desired value and `floor`.
+///
+/// This is useful if the bound needs to satisfy an independently known minimum, such as a
+/// protocol minimum or a hardware granularity. Use [`eval_with_upper_bound_at_least`] to evaluate
+/// `A` with this bound.
+///
+/// # Panics
+/// If there is no such candidate. See [`try_get_upper_bound`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::get_upper_bound_at_least::<Len<37>>(16), 37);
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::get_upper_bound_at_least::<Len<5>>(48), 48);
+/// ```
+pub const fn get_upper_bound_at_least<A: AcceptUpperBound>(floor: usize) -> usize {
+    implementation::upper_bound_for(implementation::max(Impl::<A>::DESIRED, floor), A::LADDER)
+}
+
+/// Returns whether [`get_upper_bound`] is equal to [`desired_generic`].
+///
+/// This is always the case if the desired value is at most `64` (or `256` with the
+/// `exhaustive-small` feature), since every value in that range is a candidate. If this returns
+/// `true`, the output of [`eval_with_upper_bound`] does not need to be trimmed.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert!(gub::is_exact::<Len<37>>());
+/// assert!(gub::is_exact::<Len<4096>>());
+/// assert!(!gub::is_exact::<Len<4097>>());
+/// ```
+pub const fn is_exact<A: AcceptUpperBound>() -> bool {
+    Impl::<A>::ACTUAL == Impl::<A>::DESIRED
+}
+
+/// Returns the difference between [`get_upper_bound`] and [`desired_generic`].
+///
+/// This is the number of elements by which arrays of length `UPPER` are larger than necessary.
+/// See [`assert_max_overhead!`] for a compile-time check.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::overhead::<Len<37>>(), 0);
+/// assert_eq!(gub::overhead::<Len<4097>>(), gub::get_upper_bound::<Len<4097>>() - 4097);
+/// ```
+pub const fn overhead<A: AcceptUpperBound>() -> usize {
+    Impl::<A>::ACTUAL - Impl::<A>::DESIRED
+}
+
+/// Returns whether [`get_upper_bound`] elements of `elem_size` bytes each fit into `budget`
+/// bytes.
+///
+/// This is the check of [`assert_output_budget!`], for use in other const assertions.
+///
+/// Like [`get_upper_bound`], this fails to compile instead of returning `false` if the desired
+/// value is larger than [`ladder::MAX_BOUND`], which is only possible with one of the
+/// `max-bound-*` or `portable-bounds` features. Use [`try_get_upper_bound`] to handle that case.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// # #[cfg(not(gub_custom_candidates))]
+/// const _: () = assert!(gub::fits_output_budget::<Len<1000>>(4, 8192));
+/// assert!(!gub::fits_output_budget::<Len<4097>>(1, 4097));
+/// assert!(!gub::fits_output_budget::<Len<{ gub::ladder::MAX_BOUND }>>(usize::MAX, usize::MAX));
+/// ```
+pub const fn fits_output_budget<A: AcceptUpperBound>(elem_size: usize, budget: usize) -> bool {
+    match Impl::<A>::ACTUAL.checked_mul(elem_size) {
+        Some(size) => size <= budget,
+        None => false,
+    }
+}
+
+/// Information about the upper bound that is selected for an acceptor, as returned by
+/// [`bound_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundInfo {
+    /// The desired value, see [`desired_generic`].
+    pub desired: usize,
+    /// The upper bound, see [`get_upper_bound`].
+    pub actual: usize,
+    /// The position of `actual` among the candidates of the acceptor's
+    /// [ladder](AcceptUpperBound::LADDER), starting at `0`. Acceptors with the same ladder and
+    /// index are evaluated with the same bound.
+    pub ladder_index: usize,
+    /// Whether `actual` is equal to `desired`, see [`is_exact`].
+    pub is_exact: bool,
+    /// The difference between `actual` and `desired`, see [`overhead`].
+    pub overhead: usize,
+}
+
+/// Returns information about the upper bound of `A` for audits, tests and diagnostics.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// let info = gub::bound_info::<Len<37>>();
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!((info.actual, info.ladder_index, info.overhead), (37, 37, 0));
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert!(info.is_exact);
+///
+/// const INFO: gub::BoundInfo = gub::bound_info::<Len<4097>>();
+/// assert_eq!(INFO.actual, gub::ladder::CANDIDATES[INFO.ladder_index]);
+/// assert_eq!(INFO.actual - INFO.desired, INFO.overhead);
+/// ```
+pub const fn bound_info<A: AcceptUpperBound>() -> BoundInfo {
+    BoundInfo {
+        desired: Impl::<A>::DESIRED,
+        actual: Impl::<A>::ACTUAL,
+        ladder_index: Impl::<A>::LADDER_INDEX,
+        is_exact: is_exact::<A>(),
+        overhead: overhead::<A>(),
+    }
+}
+
+/// An [`AcceptUpperBound`] with output `T` whose type was erased, so that it can be selected at
+/// runtime.
+///
+/// This stores the [`desired_generic`] and [`get_upper_bound`] of the acceptor, as well as a
+/// table of functions with one entry for each element of [`ladder::CANDIDATES`]. The entries of
+/// the candidates from the desired value up to a maximum evaluate the acceptor with that
+/// candidate as an explicit bound (see [`eval_with_explicit_bound`]), so a larger buffer can be
+/// selected at runtime, e.g. to leave room for appending to the output. Every such candidate
+/// monomorphizes the acceptor once, so the maximum should be kept small.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::{adapters::ConcatStrs, ErasedAcceptor};
+/// struct Greeting;
+/// impl gub::Const for Greeting {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["Hello", ", ", "World"];
+/// }
+/// struct Farewell;
+/// impl gub::Const for Farewell {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["Bye"];
+/// }
+/// const PLUGINS: [ErasedAcceptor<&[u8]>; 2] = [
+///     ErasedAcceptor::new::<ConcatStrs<Greeting>>(),
+///     ErasedAcceptor::with_max::<ConcatStrs<Farewell>, 8>(),
+/// ];
+/// let plugin = PLUGINS[std::hint::black_box(0)];
+/// assert_eq!(plugin.desired(), 12);
+/// assert_eq!(&plugin.eval()[..plugin.desired()], b"Hello, World");
+///
+/// let plugin = PLUGINS[std::hint::black_box(1)];
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(plugin.upper_bounds(), [3, 4, 5, 6, 7, 8]);
+/// let bound = plugin.upper_bounds()[plugin.upper_bounds().len() - 1];
+/// assert_eq!(plugin.eval_at(bound).map(<[u8]>::len), Some(bound));
+/// assert_eq!(plugin.eval_at(2), None);
+/// ```
+pub struct ErasedAcceptor<T: 'static> {
+    desired: usize,
+    actual: usize,
+    eval: fn() -> T,
+    table: &'static [fn() -> T],
+    start: usize,
+    end: usize,
+}
+
+impl<T: 'static> ErasedAcceptor<T> {
+    /// Erases the type of `A`.
+    ///
+    /// The table only contains the entry of [`actual`](Self::actual), if it is an element of
+    /// [`ladder::CANDIDATES`].
+    pub const fn new<A: AcceptUpperBound<Output = T>>() -> Self {
+        Self::from_table::<A, implementation::ActualOf<A>>()
+    }
+
+    /// Erases the type of `A`, keeping the entries of all candidates up to `MAX` in the table.
+    pub const fn with_max<A: AcceptUpperBound<Output = T>, const MAX: usize>() -> Self {
+        Self::from_table::<A, consts::ConstUsize<MAX>>()
+    }
+
+    const fn from_table<A: AcceptUpperBound<Output = T>, M: Const<Type = usize>>() -> Self {
+        Self {
+            desired: Impl::<A>::DESIRED,
+            actual: Impl::<A>::ACTUAL,
+            eval: eval_with_upper_bound::<A>,
+            table: implementation::ErasedTable::<A, M>::FNS,
+            start: implementation::ErasedTable::<A, M>::START,
+            end: implementation::ErasedTable::<A, M>::END,
+        }
+    }
+
+    /// Returns the desired value of the acceptor.
+    pub const fn desired(&self) -> usize {
+        self.desired
+    }
+
+    /// Returns the upper bound that the acceptor is evaluated with.
+    pub const fn actual(&self) -> usize {
+        self.actual
+    }
+
+    /// Returns the candidates that the table has an entry for, in ascending order.
+    pub const fn upper_bounds(&self) -> &'static [usize] {
+        ladder::CANDIDATES
+            .split_at(self.end)
+            .0
+            .split_at(self.start)
+            .1
+    }
+
+    /// Returns the entry of the table for `upper`, if `upper` is one of the
+    /// [`upper_bounds`](Self::upper_bounds).
+    pub const fn get(&self, upper: usize) -> Option<fn() -> T> {
+        let mut i = self.start;
+        while i < self.end {
+            if ladder::CANDIDATES[i] == upper {
+                return Some(self.table[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Evaluates the acceptor, like [`eval_with_upper_bound`].
+    pub fn eval(&self) -> T {
+        (self.eval)()
+    }
+
+    /// Evaluates the acceptor with the bound `upper`, if it is one of the
+    /// [`upper_bounds`](Self::upper_bounds).
+    pub fn eval_at(&self, upper: usize) -> Option<T> {
+        self.get(upper).map(|f| f())
+    }
+}
+
+impl<T: 'static> Clone for ErasedAcceptor<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: 'static> Copy for ErasedAcceptor<T> {}
+impl<T: 'static> core::fmt::Debug for ErasedAcceptor<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ErasedAcceptor")
+            .field("desired", &self.desired)
+            .field("actual", &self.actual)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A zero-sized proof that `UPPER` is a valid upper bound for `A`.
+///
+/// A value of this type can only be created if `UPPER` is at least [`desired_generic::<A>()`]
+/// and a candidate of [`A::LADDER`](AcceptUpperBound::LADDER). This allows `EVAL` bodies and
+/// helper functions to rely on these invariants without checking them again, e.g. to take the
+/// first `desired_generic` elements of an array of length `UPPER` without a bounds check.
+///
+/// Note that the bounds passed by [`eval_with_explicit_bound`] and
+/// [`eval_with_pow2_upper_bound`] are not necessarily candidates, so `EVAL` bodies that create a
+/// witness fail to compile when evaluated with such a bound.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::BoundWitness;
+/// struct Squares<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Squares<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: u64 = {
+///         let witness = BoundWitness::<Self, UPPER>::new();
+///         let mut squares = [0; UPPER];
+///         let mut i = 0;
+///         while i < UPPER {
+///             squares[i] = (i * i) as u64;
+///             i += 1;
+///         }
+///         sum(witness.prefix(&squares))
+///     };
+/// }
+/// const fn sum(items: &[u64]) -> u64 {
+///     let (mut i, mut sum) = (0, 0);
+///     while i < items.len() {
+///         sum += items[i];
+///         i += 1;
+///     }
+///     sum
+/// }
+/// assert_eq!(gub::eval_with_upper_bound::<Squares<100>>(), 328350);
+/// ```
+///
+/// ```compile_fail
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// const _: gub::BoundWitness<Len<100>, 99> = gub::BoundWitness::new();
+/// ```
+pub struct BoundWitness<A, const UPPER: usize>(PhantomData<fn() -> A>);
+
+impl<A: AcceptUpperBound, const UPPER: usize> BoundWitness<A, UPPER> {
+    /// Creates a witness.
+    ///
+    /// # Panics
+    /// If `UPPER` is smaller than the desired value of `A` or not a candidate of its ladder.
+    // reason: a `Default` impl that can panic would be surprising
+    #[allow(clippy::new_without_default)]
+    #[track_caller]
+    pub const fn new() -> Self {
+        match Self::try_new() {
+            Some(witness) => witness,
+            None => panic_with!(
+                "`UPPER` is not a valid upper bound for the acceptor",
+                "UPPER" = UPPER,
+                "desired" = Impl::<A>::DESIRED,
+            ),
+        }
+    }
+
+    /// Creates a witness, or returns `None` if `UPPER` is smaller than the desired value of `A` or
+    /// not a candidate of its ladder.
+    pub const fn try_new() -> Option<Self> {
+        if UPPER >= Impl::<A>::DESIRED && A::LADDER.contains(UPPER) {
+            Some(Self(PhantomData))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the desired value of `A`, which is at most `UPPER`.
+    pub const fn desired(self) -> usize {
+        Impl::<A>::DESIRED
+    }
+
+    /// Returns `UPPER - desired`, which cannot overflow.
+    pub const fn overhead(self) -> usize {
+        UPPER - Impl::<A>::DESIRED
+    }
+
+    /// Returns the first [`desired`](Self::desired) elements of `items`.
+    pub const fn prefix<T>(self, items: &[T; UPPER]) -> &[T] {
+        // SAFETY: The witness guarantees that the desired value is at most `UPPER`.
+        unsafe { core::slice::from_raw_parts(items.as_ptr(), Impl::<A>::DESIRED) }
+    }
+}
+
+impl<A, const UPPER: usize> Clone for BoundWitness<A, UPPER> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<A, const UPPER: usize> Copy for BoundWitness<A, UPPER> {}
+impl<A, const UPPER: usize> core::fmt::Debug for BoundWitness<A, UPPER> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BoundWitness")
+            .field("UPPER", &UPPER)
+            .finish()
+    }
+}
+
+/// An [`AcceptUpperBound`] whose desired value is always a candidate of its
+/// [ladder](AcceptUpperBound::LADDER), so that [`get_upper_bound`] is equal to
+/// [`desired_generic`].
+///
+/// This is the case e.g. for desired values that are powers of two or at most `64`. Implementing
+/// this trait allows using [`eval_exact`], whose output does not need to be trimmed, and
+/// [`exact_bound`].
+///
+/// # Safety
+/// [`get_upper_bound::<Self>()`](get_upper_bound) must be equal to
+/// [`desired_generic::<Self>()`](desired_generic) for all generic parameters of the
+/// implementation. Unsafe code may rely on this, e.g. on the length of an array of length `UPPER`
+/// being the desired value. With `debug_assertions`, [`eval_exact`] and [`exact_bound`] check
+/// this during const evaluation.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// /// A table with `1 << BITS` entries.
+/// struct Table<const BITS: u32>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const BITS: u32} Table<BITS>;
+///     const DESIRED_GENERIC: usize = 1 << BITS;
+///     const EVAL<const UPPER: usize>: &'static [u16] = &{
+///         let mut out = [0; UPPER];
+///         let mut i = 0;
+///         while i < UPPER {
+///             out[i] = (i as u16).reverse_bits() >> (16 - BITS);
+///             i += 1;
+///         }
+///         out
+///     };
+/// }
+/// // SAFETY: Every power of two is a candidate of the default ladder.
+/// unsafe impl<const BITS: u32> gub::ExactUpperBound for Table<BITS> {}
+///
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::eval_exact::<Table<2>>(), [0, 2, 1, 3]);
+/// # #[cfg(not(gub_custom_candidates))]
+/// const LEN: usize = gub::exact_bound::<Table<10>>();
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::eval_exact::<Table<10>>().len(), LEN);
+/// ```
+///
+/// ```compile_fail
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// // Wrong: 4097 is not a candidate
+/// unsafe impl<const N: usize> gub::ExactUpperBound for Len<N> {}
+/// # #[cfg(not(debug_assertions))]
+/// # compile_error!("the check is only done with `debug_assertions`");
+/// let _ = gub::exact_bound::<Len<4097>>();
+/// ```
+pub unsafe trait ExactUpperBound: AcceptUpperBound {}
+
+// SAFETY: The forwarding impls have the same desired value and ladder as `A`.
+unsafe impl<A: ExactUpperBound> ExactUpperBound for PhantomData<A> {}
+// SAFETY: See above.
+unsafe impl<A: ExactUpperBound> ExactUpperBound for &A {}
+// SAFETY: See above.
+unsafe impl<A: ExactUpperBound> ExactUpperBound for &mut A {}
+
+/// Returns the upper bound of an [`ExactUpperBound`], which is its desired value.
+///
+/// # Panics
+/// With `debug_assertions`, if the implementation of [`ExactUpperBound`] is wrong.
+pub const fn exact_bound<A: ExactUpperBound>() -> usize {
+    const_value::<implementation::ExactBound<A>>()
+}
+
+/// Evaluates an [`ExactUpperBound`].
+///
+/// This is the same as [`eval_with_upper_bound`], but since the upper bound is the desired value,
+/// the output does not need to be trimmed.
+///
+/// # Panics
+/// With `debug_assertions`, if the implementation of [`ExactUpperBound`] is wrong.
+pub const fn eval_exact<A: ExactUpperBound>() -> A::Output {
+    const_value::<implementation::EvalExact<A>>()
+}
+
 /// Evaluates [`AcceptUpperBound`].
 ///
 /// In the language of `generic_const_exprs`, this function returns
@@ -131,64 +930,2839 @@ pub const fn eval_with_upper_bound<A: AcceptUpperBound>() -> A::Output {
     Impl::<A>::EVAL
 }
 
-/// Implements [`AcceptUpperBound`] by generating a hidden [`Const`] implementor.
+/// Evaluates [`AcceptUpperBound`] and returns the output together with
+/// [`desired_generic`], which is the length of the meaningful prefix for most acceptors.
 ///
-/// Generic parameters are passed in braces (`{...}`) after `impl` and cannot have a trailing
-/// comma. Where bounds are optionally passed in braces after the implementing type.
+/// This is the same as evaluating [`WithLen<A>`](combinators::WithLen).
 ///
-/// The example from the [crate level documentation](crate) can be written manually like this:
+/// # Example
 /// ```
 /// use generic_upper_bound as gub;
-/// pub trait MyTrait {
-///     const SOME_STR: &'static str;
+/// struct Digits<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Digits<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &{
+///         let mut out = [0; UPPER];
+///         let mut i = 0;
+///         while i < N {
+///             out[i] = b'0' + (i % 10) as u8;
+///             i += 1;
+///         }
+///         out
+///     };
 /// }
-/// impl<A: MyTrait, B: MyTrait> MyTrait for (A, B) {
-///     const SOME_STR: &'static str = match core::str::from_utf8(
-///         gub::eval_with_upper_bound::<Concat<A, B>>()
-///             .split_at(gub::desired_generic::<Concat<A, B>>())
-///             .0,
-///     ) {
-///         Ok(s) => s,
-///         _ => unreachable!(),
+/// let (digits, len) = gub::eval_with_len::<Digits<12>>();
+/// assert_eq!(&digits[..len], b"012345678901");
+/// ```
+pub const fn eval_with_len<A: AcceptUpperBound>() -> (A::Output, usize) {
+    eval_with_upper_bound::<combinators::WithLen<A>>()
+}
+
+/// Evaluates [`AcceptUpperBound`] if the desired value has an upper bound.
+///
+/// This returns `None` exactly if [`try_get_upper_bound`] does. In that case,
+/// [`AcceptUpperBound::Eval`] is not evaluated. Otherwise, this returns the same value as
+/// [`eval_with_upper_bound`]. Note that evaluating the acceptor can still fail, e.g. if it tries
+/// to create an array that is too large. See [`try_eval_with_upper_bound`] for acceptors whose
+/// output signals failure instead.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Sum<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Sum<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = {
+///         let mut digits = [0; UPPER];
+///         let mut i = 0;
+///         while i < N {
+///             digits[i] = i % 10;
+///             i += 1;
+///         }
+///         let mut sum = 0;
+///         while i > 0 {
+///             i -= 1;
+///             sum += digits[i];
+///         }
+///         sum
 ///     };
 /// }
+/// assert_eq!(gub::try_eval::<Sum<12>>(), Some(46));
+/// ```
+pub const fn try_eval<A: AcceptUpperBound>() -> Option<A::Output> {
+    const_value::<implementation::TryEval<A>>()
+}
+
+/// Evaluates an [`AcceptUpperBound`] that signals failure by outputting an [`Err`].
 ///
-/// struct Concat<A, B>(A, B);
-/// impl<A: MyTrait, B: MyTrait> gub::AcceptUpperBound for Concat<A, B> {
-///     type Output = &'static [u8];
-///     // Want to be passed at least the total length of the strings
-///     const DESIRED_GENERIC: usize = A::SOME_STR.len() + B::SOME_STR.len();
-///     // Decide on what to do with each generic const
-///     type Eval<const UPPER: usize> = ConcatImpl<A, B, UPPER>;
+/// This is the same as [`eval_with_upper_bound`], but makes the intent explicit and lets the
+/// caller handle the error. Use [`unwrap_eval_with_upper_bound`] to turn errors into compile errors
+/// instead.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Checked<const A: usize, const B: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const A: usize, const B: usize} Checked<A, B>;
+///     const DESIRED_GENERIC: usize = 1;
+///     const EVAL<const UPPER: usize>: Result<usize, &'static str> = match A.checked_mul(B) {
+///         Some(n) => Ok(n),
+///         None => Err("the product overflows"),
+///     };
 /// }
-/// struct ConcatImpl<A, B, const N: usize>(A, B);
-/// impl<A: MyTrait, B: MyTrait, const N: usize> gub::Const for ConcatImpl<A, B, N> {
-///     type Type = &'static [u8];
-///     const VALUE: Self::Type = panic!("...");
+/// assert_eq!(gub::try_eval_with_upper_bound::<Checked<6, 7>, _, _>(), Ok(42));
+/// assert_eq!(
+///     gub::try_eval_with_upper_bound::<Checked<{ usize::MAX }, 2>, _, _>(),
+///     Err("the product overflows"),
+/// );
+/// ```
+pub const fn try_eval_with_upper_bound<A, T, E>() -> Result<T, E>
+where
+    A: AcceptUpperBound<Output = Result<T, E>>,
+{
+    Impl::<A>::EVAL
+}
+
+/// Evaluates an [`AcceptUpperBound`] that outputs a [`Result`] with an error message and unwraps
+/// it.
+///
+/// The value must be [`Copy`], since other values cannot be unwrapped during const evaluation.
+///
+/// # Panics
+/// If the output is an [`Err`], with its message. This happens during const evaluation, so it
+/// results in a compile error even if this function is called at runtime. The error names the
+/// acceptor that failed.
+///
+/// ```compile_fail
+/// use generic_upper_bound as gub;
+/// struct Checked<const A: usize, const B: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const A: usize, const B: usize} Checked<A, B>;
+///     const DESIRED_GENERIC: usize = 1;
+///     const EVAL<const UPPER: usize>: Result<usize, &'static str> = match A.checked_mul(B) {
+///         Some(n) => Ok(n),
+///         None => Err("the product overflows"),
+///     };
 /// }
+/// assert_eq!(gub::unwrap_eval_with_upper_bound::<Checked<6, 7>, _>(), 42);
+/// gub::unwrap_eval_with_upper_bound::<Checked<{ usize::MAX }, 2>, _>();
 /// ```
-#[macro_export]
-macro_rules! impl_accept_upper_bound {
-    {
-        $(#[$meta:meta])*
-        impl{$($params:tt)*} $Self:ty $({ $($where_bounds:tt)* })?;
+pub const fn unwrap_eval_with_upper_bound<A, T: Copy>() -> T
+where
+    A: AcceptUpperBound<Output = Result<T, &'static str>>,
+{
+    const_value::<implementation::EvalUnwrap<A, T>>()
+}
 
-        const DESIRED_GENERIC: $usize_d:ty = $DESIRED_GENERIC:expr;
-        const EVAL<const $UPPER:ident: $usize_e:ty>: $Output:ty = $EVAL:expr;
+/// Evaluates an [`AcceptUpperBound`] that outputs a slice and trims it to [`desired_generic`]
+/// elements.
+///
+/// This removes the trailing padding that results from the inexact upper bound.
+///
+/// # Panics
+/// If the output is shorter than the desired value. This check happens during const evaluation,
+/// so it results in a compile error even if this function is called at runtime.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Squares<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Squares<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u64] = &{
+///         let mut out = [0; UPPER];
+///         let mut i = 0;
+///         while i < UPPER {
+///             out[i] = (i * i) as u64;
+///             i += 1;
+///         }
+///         out
+///     };
+/// }
+/// assert_eq!(gub::eval_trimmed::<Squares<4>, _>(), [0, 1, 4, 9]);
+/// assert_eq!(gub::eval_trimmed::<Squares<1000>, _>().len(), 1000);
+/// ```
+pub const fn eval_trimmed<A: AcceptUpperBound<Output = &'static [T]>, T: 'static>() -> &'static [T]
+{
+    const_value::<implementation::EvalTrimmed<A, T>>()
+}
 
-    } => {
-        const _: () = {
-            pub struct __Eval<__Eval, const $UPPER: $usize_e>(__Eval);
-            impl<$($params)*, const $UPPER: $usize_e> $crate::Const for __Eval<$Self, $UPPER> $($($where_bounds)*)? {
-                type Type = $Output;
-                const VALUE: Self::Type = $EVAL;
-            }
-            $(#[$meta])*
-            impl<$($params)*> $crate::AcceptUpperBound for $Self $($($where_bounds)*)? {
-                type Output = $Output;
-                const DESIRED_GENERIC: $usize_d = $DESIRED_GENERIC;
-                type Eval<const $UPPER: $usize_e> = __Eval<Self, $UPPER>;
-            }
-        };
-    };
+/// Evaluates an [`AcceptUpperBound`] that outputs a slice and copies the first
+/// [`desired_generic`] elements into an array of length `N`.
+///
+/// The remaining elements are zero. Use [`eval_into_array_with`] to choose a different value.
+/// This is useful for passing the result to APIs that take a const generic length, if such a
+/// length is known to be large enough.
+///
+/// # Panics
+/// If `N` is smaller than the desired value or the output is shorter than the desired value.
+/// This check happens during const evaluation, so it results in a compile error even if this
+/// function is called at runtime.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Squares<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Squares<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u64] = &{
+///         let mut out = [0; UPPER];
+///         let mut i = 0;
+///         while i < UPPER {
+///             out[i] = (i * i) as u64;
+///             i += 1;
+///         }
+///         out
+///     };
+/// }
+/// const SQUARES: [u64; 6] = gub::eval_into_array::<Squares<4>, _, 6>();
+/// assert_eq!(SQUARES, [0, 1, 4, 9, 0, 0]);
+/// assert_eq!(gub::eval_into_array::<Squares<3>, u64, 3>(), [0, 1, 4]);
+/// ```
+///
+/// The array must be able to hold the desired value:
+/// ```compile_fail
+/// use generic_upper_bound as gub;
+/// struct Repeat<const B: u8>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const B: u8} Repeat<B>;
+///     const DESIRED_GENERIC: usize = 3;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[B; UPPER];
+/// }
+/// let _ = gub::eval_into_array::<Repeat<1>, u8, 2>();
+/// ```
+pub const fn eval_into_array<
+    A: AcceptUpperBound<Output = &'static [T]>,
+    T: adapters::SliceElem,
+    const N: usize,
+>() -> [T; N] {
+    const_value::<implementation::EvalIntoArray<A, T, adapters::ZeroOf<T>, N>>()
+}
+
+/// Like [`eval_into_array`], but fills the remaining elements with `F::VALUE`.
+///
+/// # Panics
+/// In the same cases as [`eval_into_array`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Repeat<const B: u8>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const B: u8} Repeat<B>;
+///     const DESIRED_GENERIC: usize = 3;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[B; UPPER];
+/// }
+/// let padded = gub::eval_into_array_with::<Repeat<b'x'>, u8, gub::consts::ConstU8<b' '>, 5>();
+/// assert_eq!(&padded, b"xxx  ");
+/// ```
+pub const fn eval_into_array_with<
+    A: AcceptUpperBound<Output = &'static [T]>,
+    T: Copy + 'static,
+    F: Const<Type = T>,
+    const N: usize,
+>() -> [T; N] {
+    const_value::<implementation::EvalIntoArray<A, T, F, N>>()
+}
+
+/// Evaluates an [`AcceptUpperBound`] that outputs bytes and converts the first
+/// [`desired_generic`] of them to a string.
+///
+/// This removes the trailing padding that results from the inexact upper bound. See the
+/// [crate level documentation](crate) for an example.
+///
+/// # Panics
+/// If the output is shorter than the desired value or if the trimmed output is not valid UTF-8.
+/// This check happens during const evaluation, so it results in a compile error even if this
+/// function is called at runtime.
+///
+/// ```compile_fail
+/// use generic_upper_bound as gub;
+/// struct Repeat<const B: u8>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const B: u8} Repeat<B>;
+///     const DESIRED_GENERIC: usize = 3;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[B; UPPER];
+/// }
+/// let _ = gub::eval_str::<Repeat<0xff>>();
+/// ```
+pub const fn eval_str<A: AcceptUpperBound<Output = &'static [u8]>>() -> &'static str {
+    const_value::<implementation::EvalStr<A>>()
+}
+
+/// Evaluates an [`AcceptUpperBound`] that outputs bytes and converts the first
+/// [`desired_generic`] of them to a C string.
+///
+/// The trimmed output must end with the NUL terminator and must not contain any other NUL bytes.
+/// See [`adapters::concat_cstr`](crate::adapters::concat_cstr) for a convenient way to build such an output.
+///
+/// # Panics
+/// If the output is shorter than the desired value or if the trimmed output is not a valid
+/// C string. This check happens during const evaluation, so it results in a compile error even
+/// if this function is called at runtime.
+pub const fn eval_cstr<A: AcceptUpperBound<Output = &'static [u8]>>() -> &'static CStr {
+    const_value::<implementation::EvalCStr<A>>()
+}
+
+/// Evaluates an [`AcceptUpperBound`] that outputs a slice and copies the first
+/// [`desired_generic`] elements into a [`Vec`](alloc::vec::Vec).
+///
+/// This is the owned version of [`eval_trimmed`]. Requires the `alloc` feature.
+///
+/// # Panics
+/// In the same cases as [`eval_trimmed`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Squares<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Squares<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u64] = &{
+///         let mut out = [0; UPPER];
+///         let mut i = 0;
+///         while i < UPPER {
+///             out[i] = (i * i) as u64;
+///             i += 1;
+///         }
+///         out
+///     };
+/// }
+/// let squares: Vec<u64> = gub::eval_to_vec::<Squares<1000>, _>();
+/// assert_eq!(squares.len(), 1000);
+/// let squares: Box<[u64]> = gub::eval_boxed_slice::<Squares<4>, _>();
+/// assert_eq!(*squares, [0, 1, 4, 9]);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn eval_to_vec<A, T>() -> alloc::vec::Vec<T>
+where
+    A: AcceptUpperBound<Output = &'static [T]>,
+    T: Clone + 'static,
+{
+    eval_trimmed::<A, T>().to_vec()
+}
+
+/// Like [`eval_to_vec`], but returns a boxed slice. Requires the `alloc` feature.
+///
+/// # Panics
+/// In the same cases as [`eval_trimmed`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn eval_boxed_slice<A, T>() -> alloc::boxed::Box<[T]>
+where
+    A: AcceptUpperBound<Output = &'static [T]>,
+    T: Clone + 'static,
+{
+    eval_trimmed::<A, T>().into()
+}
+
+/// Like [`eval_str`], but copies the result into a [`String`](alloc::string::String). Requires
+/// the `alloc` feature.
+///
+/// # Panics
+/// In the same cases as [`eval_str`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::ConcatStrs;
+/// struct Parts;
+/// impl gub::Const for Parts {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["foo", "bar"];
+/// }
+/// let s: String = gub::eval_string::<ConcatStrs<Parts>>();
+/// assert_eq!(s, "foobar");
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn eval_string<A: AcceptUpperBound<Output = &'static [u8]>>() -> alloc::string::String {
+    eval_str::<A>().into()
+}
+
+/// Like [`eval_cstr`], but copies the result into a [`CString`](alloc::ffi::CString). Requires
+/// the `alloc` feature.
+///
+/// # Panics
+/// In the same cases as [`eval_cstr`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn eval_cstring<A: AcceptUpperBound<Output = &'static [u8]>>() -> alloc::ffi::CString {
+    eval_cstr::<A>().into()
+}
+
+/// Evaluates an [`AcceptUpperBound`] that outputs a slice and collects the first
+/// [`desired_generic`] elements into a [`heapless::Vec`].
+///
+/// This carries the length with the data, so there is no need to trim the output at the use site.
+/// Since `heapless::Vec` cannot be built in const contexts, the acceptor still outputs a slice and
+/// the conversion happens at runtime.
+///
+/// Requires the `heapless` feature.
+///
+/// # Panics
+/// If the desired value is larger than `N`, or in the same cases as [`eval_trimmed`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Squares<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Squares<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u64] = &{
+///         let mut b = gub::build::ArrayBuilder::<u64, UPPER>::new::<Zero>();
+///         while b.len() < N {
+///             b = b.push((b.len() * b.len()) as u64);
+///         }
+///         b.finish().0
+///     };
+/// }
+/// struct Zero;
+/// impl gub::Const for Zero {
+///     type Type = u64;
+///     const VALUE: Self::Type = 0;
+/// }
+/// let squares: heapless::Vec<u64, 8> = gub::eval_heapless::<Squares<4>, _, 8>();
+/// assert_eq!(squares, [0, 1, 4, 9]);
+/// ```
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+pub fn eval_heapless<A, T, const N: usize>() -> heapless::Vec<T, N>
+where
+    A: AcceptUpperBound<Output = &'static [T]>,
+    T: Clone + 'static,
+{
+    match heapless::Vec::from_slice(eval_trimmed::<A, T>()) {
+        Ok(vec) => vec,
+        Err(()) => panic!("the desired value is larger than the capacity"),
+    }
+}
+
+/// Evaluates an [`AcceptUpperBound`] that outputs a slice and collects the first
+/// [`desired_generic`] elements into an [`arrayvec::ArrayVec`].
+///
+/// This works like [`eval_heapless`]. Requires the `arrayvec` feature.
+///
+/// # Panics
+/// If the desired value is larger than `N`, or in the same cases as [`eval_trimmed`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Digits<const N: u32>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: u32} Digits<N>;
+///     const DESIRED_GENERIC: usize = N as usize;
+///     const EVAL<const UPPER: usize>: &'static [u32] = &{
+///         let mut out = [0; UPPER];
+///         let mut i = 0;
+///         while i < N {
+///             out[i as usize] = i % 10;
+///             i += 1;
+///         }
+///         out
+///     };
+/// }
+/// let digits: arrayvec::ArrayVec<u32, 16> = gub::eval_arrayvec::<Digits<12>, _, 16>();
+/// assert_eq!(digits.as_slice(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1]);
+/// ```
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+pub fn eval_arrayvec<A, T, const N: usize>() -> arrayvec::ArrayVec<T, N>
+where
+    A: AcceptUpperBound<Output = &'static [T]>,
+    T: Clone + 'static,
+{
+    let items = eval_trimmed::<A, T>();
+    assert!(
+        items.len() <= N,
+        "the desired value is larger than the capacity"
+    );
+    items.iter().cloned().collect()
+}
+
+/// Evaluates an [`AcceptUpperBound`] that outputs a slice and collects the first
+/// [`desired_generic`] elements into a [`tinyvec::ArrayVec`].
+///
+/// This works like [`eval_heapless`]. Requires the `tinyvec` feature.
+///
+/// # Panics
+/// If the desired value is larger than `N`, or in the same cases as [`eval_trimmed`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Digits<const N: u32>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: u32} Digits<N>;
+///     const DESIRED_GENERIC: usize = N as usize;
+///     const EVAL<const UPPER: usize>: &'static [u32] = &{
+///         let mut out = [0; UPPER];
+///         let mut i = 0;
+///         while i < N {
+///             out[i as usize] = i % 10;
+///             i += 1;
+///         }
+///         out
+///     };
+/// }
+/// let digits: tinyvec::ArrayVec<[u32; 16]> = gub::eval_tinyvec::<Digits<12>, _, 16>();
+/// assert_eq!(digits.as_slice(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1]);
+/// ```
+#[cfg(feature = "tinyvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tinyvec")))]
+pub fn eval_tinyvec<A, T, const N: usize>() -> tinyvec::ArrayVec<[T; N]>
+where
+    A: AcceptUpperBound<Output = &'static [T]>,
+    T: Clone + Default + 'static,
+{
+    let items = eval_trimmed::<A, T>();
+    assert!(
+        items.len() <= N,
+        "the desired value is larger than the capacity"
+    );
+    let mut vec = tinyvec::ArrayVec::new();
+    vec.extend_from_slice(items);
+    vec
+}
+
+/// Evaluates an [`AcceptUpperBound`] that outputs a slice of [`bytemuck::Pod`] data, trims it to
+/// [`desired_generic`] elements and views the result as a slice of `T`.
+///
+/// This allows building a table with one element type (e.g. `u32`) and using it as another
+/// (e.g. bytes) without writing a trimming acceptor for each combination. Requires the `bytemuck`
+/// feature.
+///
+/// # Panics
+/// If the trimmed output cannot be viewed as a slice of `T` (see [`bytemuck::try_cast_slice`]),
+/// or in the same cases as [`eval_trimmed`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Powers<const BASE: u32, const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const BASE: u32, const N: usize} Powers<BASE, N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u32] = &{
+///         let mut out = [0; UPPER];
+///         let mut i = 0;
+///         while i < N {
+///             out[i] = BASE.pow(i as u32);
+///             i += 1;
+///         }
+///         out
+///     };
+/// }
+/// let powers: &[u32] = gub::eval_pod_trimmed::<Powers<16, 3>, u32>();
+/// assert_eq!(powers, [1, 16, 256]);
+/// let bytes: &[u8] = gub::eval_pod_trimmed::<Powers<16, 3>, u8>();
+/// assert_eq!(bytes, [1, 16, 256].map(u32::to_ne_bytes).concat());
+/// ```
+///
+/// The trimmed output must have a whole number of `T`s in it:
+/// ```should_panic
+/// use generic_upper_bound as gub;
+/// struct Bytes<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Bytes<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[0; UPPER];
+/// }
+/// let _ = gub::eval_pod_trimmed::<Bytes<3>, u16>();
+/// ```
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+pub fn eval_pod_trimmed<A, T>() -> &'static [T]
+where
+    A: AcceptUpperBound,
+    A::Output: PodSlice,
+    T: bytemuck::Pod,
+{
+    <A::Output as PodSlice>::__cast_trimmed::<A, T>()
+}
+
+/// A slice of [`bytemuck::Pod`] data, as accepted by [`eval_pod_trimmed`].
+///
+/// This trait is sealed and implemented for `&'static [S]` where `S: Pod`.
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+pub trait PodSlice: sealed::Sealed + Copy + 'static {
+    #[doc(hidden)]
+    fn __cast_trimmed<A: AcceptUpperBound<Output = Self>, T: bytemuck::Pod>() -> &'static [T];
+}
+#[cfg(feature = "bytemuck")]
+impl<S: bytemuck::Pod> sealed::Sealed for &'static [S] {}
+#[cfg(feature = "bytemuck")]
+impl<S: bytemuck::Pod> PodSlice for &'static [S] {
+    fn __cast_trimmed<A: AcceptUpperBound<Output = Self>, T: bytemuck::Pod>() -> &'static [T] {
+        match bytemuck::try_cast_slice(eval_trimmed::<A, S>()) {
+            Ok(items) => items,
+            Err(err) => panic!(
+                "cannot view the output of `{}` as `[{}]`: {err}",
+                core::any::type_name::<A>(),
+                core::any::type_name::<T>(),
+            ),
+        }
+    }
+}
+
+/// Returns the parameter that [`eval_with_pow2_upper_bound`] passes to [`AcceptUpperBound::Eval`].
+///
+/// This is the smallest power of two that is at least [`desired_generic`], so it satisfies
+/// `UPPER.is_power_of_two()` and `DESIRED_GENERIC <= UPPER < 2 * DESIRED_GENERIC`, unless
+/// `DESIRED_GENERIC` is `0`, in which case `UPPER` is `1`. [`AcceptUpperBound::LADDER`] is ignored.
+///
+/// # Panics
+/// If `DESIRED_GENERIC` is larger than the largest power of two that fits in a `usize`.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// assert_eq!(gub::get_pow2_upper_bound::<Len<0>>(), 1);
+/// assert_eq!(gub::get_pow2_upper_bound::<Len<37>>(), 64);
+/// assert_eq!(gub::get_pow2_upper_bound::<Len<4096>>(), 4096);
+/// ```
+pub const fn get_pow2_upper_bound<A: AcceptUpperBound>() -> usize {
+    Impl::<A>::ACTUAL_POW2
+}
+
+/// Returns the parameter that [`eval_with_pow2_upper_bound`] passes to
+/// [`AcceptUpperBound::Eval`], or `None` if there is no such power of two.
+///
+/// This returns `None` if the desired value is larger than `usize::MAX / 2 + 1`, the largest power
+/// of two that fits in a `usize`, or larger than [`ladder::MAX_BOUND`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// assert_eq!(gub::try_get_pow2_upper_bound::<Len<37>>(), Some(64));
+/// assert_eq!(gub::try_get_pow2_upper_bound::<Len<{ usize::MAX / 2 + 2 }>>(), None);
+/// ```
+pub const fn try_get_pow2_upper_bound<A: AcceptUpperBound>() -> Option<usize> {
+    Impl::<A>::TRY_ACTUAL_POW2
+}
+
+/// Evaluates [`AcceptUpperBound`] with a power of two.
+///
+/// This is useful for data structures that require a power-of-two capacity, such as hash tables
+/// and ring buffers. In the language of `generic_const_exprs`, this function returns
+/// `const_value::<F::Eval<{ get_pow2_upper_bound::<F>() }>>()`
+pub const fn eval_with_pow2_upper_bound<A: AcceptUpperBound>() -> A::Output {
+    Impl::<A>::EVAL_POW2
+}
+
+/// Evaluates [`AcceptUpperBound`] with an upper bound that is at least `FLOOR`.
+///
+/// In the language of `generic_const_exprs`, this function returns
+/// `const_value::<F::Eval<{ get_upper_bound_at_least::<F>(FLOOR) }>>()`
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// // a packet of at least 64 bytes
+/// assert_eq!(gub::eval_with_upper_bound_at_least::<Len<20>, 64>(), 64);
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::eval_with_upper_bound_at_least::<Len<40>, 16>(), 40);
+/// ```
+pub const fn eval_with_upper_bound_at_least<A: AcceptUpperBound, const FLOOR: usize>() -> A::Output
+{
+    eval_with_upper_bound::<implementation::AtLeast<A, FLOOR>>()
+}
+
+/// Evaluates [`AcceptUpperBound`] with an explicitly chosen `UPPER`, skipping the ladder.
+///
+/// This is useful if a good bound is already known from context, e.g. a crate-level cap.
+///
+/// # Panics
+/// If `UPPER < DESIRED_GENERIC`. This check happens during const evaluation, so it results in
+/// a compile error even if this function is called at runtime.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = [0u8; UPPER].split_at(N).0.len();
+/// }
+/// assert_eq!(gub::eval_with_explicit_bound::<Len<100>, 256>(), 100);
+/// ```
+/// ```compile_fail
+/// # use generic_upper_bound as gub;
+/// # struct Len<const N: usize>;
+/// # gub::impl_accept_upper_bound! {
+/// #     impl{const N: usize} Len<N>;
+/// #     const DESIRED_GENERIC: usize = N;
+/// #     const EVAL<const UPPER: usize>: usize = [0u8; UPPER].split_at(N).0.len();
+/// # }
+/// gub::eval_with_explicit_bound::<Len<300>, 256>();
+/// ```
+pub const fn eval_with_explicit_bound<A: AcceptUpperBound, const UPPER: usize>() -> A::Output {
+    const_value::<implementation::EvalExplicit<A, UPPER>>()
+}
+
+/// Receives the upper bound of an [`AcceptUpperBound`] as a const generic parameter.
+///
+/// The output of an acceptor cannot depend on `UPPER` in its type. A visitor can instead
+/// construct values of types that depend on `UPPER`, such as `[T; UPPER]` or a container with a
+/// const generic capacity, and return them behind a type that does not, such as a slice or a
+/// trait object. See [`visit_with_upper_bound`].
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a bound visitor",
+    label = "`{Self}` does not implement `BoundVisitor`",
+    note = "`Visit` must be a generic associated type: `type Visit<const UPPER: usize>: Const<Type = Self::Output>`"
+)]
+pub trait BoundVisitor {
+    /// The output of the visit.
+    type Output;
+
+    /// Maps the upper bound to the output.
+    ///
+    /// Unlike [`AcceptUpperBound::Eval`], the output may depend on `UPPER`. Note that the
+    /// upper bound depends on the enabled features of this crate (see [`Ladder::DEFAULT`]).
+    type Visit<const UPPER: usize>: Const<Type = Self::Output>;
+}
+
+/// Calls `V::Visit` with the upper bound of `A`.
+///
+/// In the language of `generic_const_exprs`, this function returns
+/// `const_value::<V::Visit<{ get_upper_bound::<A>() }>>()`. `A::Eval` is not evaluated.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// trait Storage {
+///     fn capacity(&self) -> usize;
+/// }
+/// struct Buffer<const CAP: usize>([u8; CAP]);
+/// impl<const CAP: usize> Storage for Buffer<CAP> {
+///     fn capacity(&self) -> usize {
+///         CAP
+///     }
+/// }
+///
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// struct MakeBuffer;
+/// impl gub::BoundVisitor for MakeBuffer {
+///     type Output = &'static dyn Storage;
+///     type Visit<const UPPER: usize> = BufferOf<UPPER>;
+/// }
+/// struct BufferOf<const CAP: usize>;
+/// impl<const CAP: usize> gub::Const for BufferOf<CAP> {
+///     type Type = &'static dyn Storage;
+///     const VALUE: Self::Type = &Buffer([0; CAP]);
+/// }
+///
+/// let buffer = gub::visit_with_upper_bound::<Len<37>, MakeBuffer>();
+/// assert_eq!(buffer.capacity(), gub::get_upper_bound::<Len<37>>());
+/// assert!(buffer.capacity() >= 37);
+/// ```
+pub const fn visit_with_upper_bound<A: AcceptUpperBound, V: BoundVisitor>() -> V::Output {
+    eval_with_upper_bound::<implementation::Visited<A, V>>()
+}
+
+/// Calls `f` with an uninitialized stack buffer of `len` elements.
+///
+/// This applies the idea of this crate at runtime: The buffer is allocated with the smallest
+/// candidate of [`Ladder::DEFAULT`] that is at least `len` as its length, so only one function is
+/// instantiated per candidate instead of one per possible length. Candidates above `MAX` are
+/// replaced by `MAX`, so no buffer is larger than `MAX` elements.
+///
+/// To evaluate an expression that depends on the candidate, use [`dispatch_upper_bound!`].
+///
+/// # Panics
+/// If `len` is larger than `MAX` or [`ladder::MAX_BOUND`].
+///
+/// ```
+/// use core::mem::MaybeUninit;
+/// use generic_upper_bound as gub;
+/// fn reversed(s: &str) -> String {
+///     // a string has at most as many chars as bytes
+///     gub::with_runtime_upper_bound::<char, 4096, _>(s.len(), |buf| {
+///         let mut len = 0;
+///         for (slot, c) in buf.iter_mut().zip(s.chars().rev()) {
+///             *slot = MaybeUninit::new(c);
+///             len += 1;
+///         }
+///         // SAFETY: The first `len` elements were initialized above.
+///         buf[..len].iter().map(|c| unsafe { c.assume_init() }).collect()
+///     })
+/// }
+/// assert_eq!(reversed("hello"), "olleh");
+/// assert_eq!(reversed(&"ab".repeat(500)), "ba".repeat(500));
+/// ```
+pub fn with_runtime_upper_bound<T, const MAX: usize, R>(
+    len: usize,
+    f: impl FnOnce(&mut [MaybeUninit<T>]) -> R,
+) -> R {
+    implementation::with_runtime_upper_bound::<T, R, _, MAX>(len, f)
+}
+
+/// Evaluates [`AcceptUpperBound`] with `UPPER == DESIRED_GENERIC`, using the unstable
+/// `generic_const_exprs` feature.
+///
+/// This bypasses the ladder entirely, so there is no overhead and no dispatch. However, unlike
+/// [`eval_with_upper_bound`], using this with a generic acceptor requires the caller to add a
+/// `where [(); A::DESIRED_GENERIC]:` bound, which is why this is a separate function rather than
+/// a replacement backend.
+///
+/// Requires the `nightly` feature. The calling crate needs to enable `generic_const_exprs` as well.
+///
+/// ```
+/// #![feature(generic_const_exprs)]
+/// #![allow(incomplete_features)]
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// assert_eq!(gub::eval_with_exact_bound::<Len<1000>>(), 1000);
+/// ```
+#[cfg(feature = "nightly")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
+pub const fn eval_with_exact_bound<A: AcceptUpperBound>() -> A::Output
+where
+    [(); A::DESIRED_GENERIC]:,
+{
+    const_value::<A::Eval<{ A::DESIRED_GENERIC }>>()
+}
+
+/// Evaluates [`AcceptUpperBound`] and returns a `'static` reference to the result.
+///
+/// For large outputs, such as multi-kilobyte arrays, this avoids copying the result at every use
+/// site, since every call refers to the same promoted allocation.
+///
+/// Requires the `nightly` feature, since borrowing a generic value for `'static` in a const
+/// requires proving that it has no interior mutability, which is only possible using the
+/// unstable `Freeze` trait.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Table<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Table<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: [u16; 4096] = {
+///         let mut out = [0; 4096];
+///         let mut i = 0;
+///         while i < UPPER && i < out.len() {
+///             out[i] = i as u16;
+///             i += 1;
+///         }
+///         out
+///     };
+/// }
+/// let table: &'static [u16; 4096] = gub::eval_ref::<Table<100>>();
+/// assert!(core::ptr::eq(table, gub::eval_ref::<Table<100>>()));
+/// assert_eq!(table[99], 99);
+/// ```
+#[cfg(feature = "nightly")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
+pub const fn eval_ref<A: AcceptUpperBound>() -> &'static A::Output
+where
+    A::Output: core::marker::Freeze + 'static,
+{
+    Impl::<A>::EVAL_REF
+}
+
+/// Like [`AcceptUpperBound`], but selects a const parameter of an arbitrary type from a finite set
+/// of candidates.
+///
+/// The candidates are tried in order, and the first one that the implementor accepts is passed
+/// to the evaluation, just like the smallest accepted candidate of a [`Ladder`] is passed to
+/// [`AcceptUpperBound::Eval`]. This allows selecting a configuration value, such as a variant of
+/// an enum or a string, rather than a size.
+///
+/// This is most easily implemented using [`impl_accept_const_param!`], which generates the
+/// dispatch to the candidates. Requires the `nightly` feature. Const parameters of types other
+/// than integers, `bool` and `char` additionally require the calling crate to enable
+/// `adt_const_params` (and `unsized_const_params` for `&'static str`).
+#[cfg(feature = "nightly")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a const parameter acceptor",
+    label = "`{Self}` does not implement `AcceptConstParam`",
+    note = "implement it using `generic_upper_bound::impl_accept_const_param!`"
+)]
+pub trait AcceptConstParam {
+    /// The type of the const parameter.
+    type Param: 'static;
+
+    /// The output type of the evaluation.
+    type Output;
+
+    /// The candidates, in order of preference.
+    const CANDIDATES: &'static [Self::Param];
+
+    /// The index of the first accepted candidate, or `None` if no candidate is accepted.
+    const SELECTED: Option<usize>;
+
+    /// The evaluation with the selected candidate as the const parameter.
+    type Eval: Const<Type = Self::Output>;
+}
+
+/// Returns the candidate that [`eval_with_const_param`] passes to the evaluation of an
+/// [`AcceptConstParam`].
+///
+/// # Panics
+/// If no candidate is accepted. This check happens during const evaluation, so it results in a
+/// compile error even if this function is called at runtime.
+#[cfg(feature = "nightly")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
+pub const fn get_const_param<A: AcceptConstParam>() -> &'static A::Param {
+    match A::SELECTED {
+        Some(i) => &A::CANDIDATES[i],
+        None => panic!("none of the candidates is accepted"),
+    }
+}
+
+/// Evaluates an [`AcceptConstParam`] with the first accepted candidate.
+///
+/// # Panics
+/// If no candidate is accepted. This check happens during const evaluation, so it results in a
+/// compile error even if this function is called at runtime.
+#[cfg(feature = "nightly")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
+pub const fn eval_with_const_param<A: AcceptConstParam>() -> A::Output {
+    const_value::<A::Eval>()
+}
+
+/// Like [`AcceptUpperBound`], but accepts two independent upper bounds at once.
+///
+/// Each bound is selected separately from its own desired value, in the same way as the bound
+/// of an [`AcceptUpperBound`]. This avoids the slack of encoding two unrelated sizes (e.g.
+/// `M + N` and `M * N`) into a single desired value.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// struct SumAndProduct<const M: usize, const N: usize>;
+/// impl<const M: usize, const N: usize> gub::AcceptUpperBound2 for SumAndProduct<M, N> {
+///     type Output = (usize, usize);
+///     const DESIRED_GENERIC1: usize = M + N;
+///     const DESIRED_GENERIC2: usize = M * N;
+///     type Eval<const UPPER1: usize, const UPPER2: usize> = Lens<M, N, UPPER1, UPPER2>;
+/// }
+/// struct Lens<const M: usize, const N: usize, const UPPER1: usize, const UPPER2: usize>;
+/// impl<const M: usize, const N: usize, const UPPER1: usize, const UPPER2: usize> gub::Const
+///     for Lens<M, N, UPPER1, UPPER2>
+/// {
+///     type Type = (usize, usize);
+///     const VALUE: Self::Type = (
+///         [0u8; UPPER1].split_at(M + N).0.len(),
+///         [0u8; UPPER2].split_at(M * N).0.len(),
+///     );
+/// }
+/// let (upper1, upper2) = gub::get_upper_bounds2::<SumAndProduct<30, 40>>();
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert!(70 <= upper1 && upper1 < 2 * 70);
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert!(1200 <= upper2 && upper2 < 2 * 1200);
+/// assert_eq!(gub::eval_with_upper_bounds2::<SumAndProduct<30, 40>>(), (70, 1200));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an acceptor of two upper bounds",
+    label = "`{Self}` does not implement `AcceptUpperBound2`",
+    note = "`Eval` must be a generic associated type: `type Eval<const UPPER1: usize, const UPPER2: usize>: Const<Type = Self::Output>`"
+)]
+pub trait AcceptUpperBound2 {
+    /// The output type of the evaluation.
+    type Output;
+
+    /// The desired value and lower bound for `UPPER1`.
+    const DESIRED_GENERIC1: usize;
+
+    /// The desired value and lower bound for `UPPER2`.
+    const DESIRED_GENERIC2: usize;
+
+    /// Evals the constant by mapping two generic parameters that are at least the respective
+    /// desired values to the output value. See [`AcceptUpperBound::Eval`].
+    type Eval<const UPPER1: usize, const UPPER2: usize>: Const<Type = Self::Output>;
+}
+
+/// Returns [`AcceptUpperBound2::DESIRED_GENERIC1`] and [`AcceptUpperBound2::DESIRED_GENERIC2`].
+pub const fn desired_generics2<A: AcceptUpperBound2>() -> (usize, usize) {
+    Impl2::<A>::DESIRED
+}
+
+/// Returns the parameters that [`eval_with_upper_bounds2`] passes to [`AcceptUpperBound2::Eval`].
+pub const fn get_upper_bounds2<A: AcceptUpperBound2>() -> (usize, usize) {
+    Impl2::<A>::ACTUAL
+}
+
+/// Evaluates [`AcceptUpperBound2`].
+///
+/// In the language of `generic_const_exprs`, this function returns
+/// `const_value::<F::Eval<{ get_upper_bounds2::<F>().0 }, { get_upper_bounds2::<F>().1 }>>()`
+pub const fn eval_with_upper_bounds2<A: AcceptUpperBound2>() -> A::Output {
+    Impl2::<A>::EVAL
+}
+
+/// The mirror image of [`AcceptUpperBound`]: Accepts a generic const that is at most, rather
+/// than at least, the desired value.
+///
+/// This is useful for computations that must not exceed the desired value, such as element
+/// counts that must not overrun a source buffer.
+///
+/// When passed to [`eval_with_lower_bound`], [`Eval::<LOWER>::VALUE`](Const::VALUE) will be evaluated
+/// with a parameter `LOWER` that satisfies `DESIRED_GENERIC / 2 < LOWER <= DESIRED_GENERIC`,
+/// unless `DESIRED_GENERIC` is `0`, in which case `LOWER` is `0` as well.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// struct AtMost<const N: usize>;
+/// impl<const N: usize> gub::AcceptLowerBound for AtMost<N> {
+///     type Output = bool;
+///     const DESIRED_GENERIC: usize = N;
+///     type Eval<const LOWER: usize> = Fits<N, LOWER>;
+/// }
+/// struct Fits<const N: usize, const LOWER: usize>;
+/// impl<const N: usize, const LOWER: usize> gub::Const for Fits<N, LOWER> {
+///     type Type = bool;
+///     const VALUE: Self::Type = [0u8; LOWER].len() <= N;
+/// }
+/// let exhaustive = cfg!(feature = "exhaustive-small");
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::get_lower_bound::<AtMost<100>>(), if exhaustive { 100 } else { 96 });
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::get_lower_bound::<AtMost<5>>(), 5);
+/// assert!(gub::eval_with_lower_bound::<AtMost<100>>());
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a lower bound acceptor",
+    label = "`{Self}` does not implement `AcceptLowerBound`",
+    note = "`Eval` must be a generic associated type: `type Eval<const LOWER: usize>: Const<Type = Self::Output>`"
+)]
+pub trait AcceptLowerBound {
+    /// The output type of the evaluation.
+    type Output;
+
+    /// The desired value and upper bound that the implementor wants to be passed to [`Self::Eval`].
+    const DESIRED_GENERIC: usize;
+
+    /// Evals the constant by mapping a generic parameter that is at most the desired value
+    /// to the output value. `const_value::<Eval<LOWER>>()` should be indistinguishable for
+    /// all *possible* generic parameters passed to this.
+    type Eval<const LOWER: usize>: Const<Type = Self::Output>;
+}
+
+/// Returns the parameter that [`eval_with_lower_bound`] passes to [`AcceptLowerBound::Eval`].
+pub const fn get_lower_bound<A: AcceptLowerBound>() -> usize {
+    ImplLower::<A>::ACTUAL
+}
+
+/// Evaluates [`AcceptLowerBound`].
+///
+/// In the language of `generic_const_exprs`, this function returns
+/// `const_value::<F::Eval<{ get_lower_bound::<F>() }>>()`
+pub const fn eval_with_lower_bound<A: AcceptLowerBound>() -> A::Output {
+    ImplLower::<A>::EVAL
+}
+
+/// Like [`AcceptUpperBound`], but with a desired value that is never zero.
+///
+/// This is useful for capacities that must not be zero, such as those of ring buffers or tables
+/// that are indexed modulo their length. Since `0` is never a candidate, `UPPER` is always at
+/// least `1` when passed to [`Self::Eval`], so `[T; UPPER]` always has a first element and
+/// `UPPER` can be used as a divisor. [`nonzero`] converts it to a [`NonZeroUsize`] without any
+/// runtime checks.
+///
+/// When passed to [`eval_with_nonzero_upper_bound`], [`Eval::<UPPER>::VALUE`](Const::VALUE) will
+/// be evaluated with the same parameter `UPPER` as an [`AcceptUpperBound`] with the same desired
+/// value and ladder.
+///
+/// # Example
+/// ```
+/// use core::num::NonZeroUsize;
+/// use generic_upper_bound as gub;
+/// /// The slots of a ring buffer for at least `N` items, where `N` is clamped to `1`.
+/// struct Ring<const N: usize>;
+/// impl<const N: usize> gub::AcceptNonZeroUpperBound for Ring<N> {
+///     type Output = &'static [usize];
+///     const DESIRED_GENERIC: NonZeroUsize = match NonZeroUsize::new(N) {
+///         Some(n) => n,
+///         None => NonZeroUsize::MIN,
+///     };
+///     type Eval<const UPPER: usize> = RingImpl<UPPER>;
+/// }
+/// struct RingImpl<const UPPER: usize>;
+/// impl<const UPPER: usize> gub::Const for RingImpl<UPPER> {
+///     type Type = &'static [usize];
+///     const VALUE: Self::Type = &{
+///         // the successor of each slot, wrapping around at the end
+///         let cap = gub::nonzero::<UPPER>().get();
+///         let mut next = [0; UPPER];
+///         let mut i = 0;
+///         while i < UPPER {
+///             next[i] = (i + 1) % cap;
+///             i += 1;
+///         }
+///         next
+///     };
+/// }
+/// assert_eq!(gub::get_nonzero_upper_bound::<Ring<0>>().get(), 1);
+/// assert_eq!(gub::eval_with_nonzero_upper_bound::<Ring<0>>(), [0]);
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::eval_with_nonzero_upper_bound::<Ring<3>>(), [1, 2, 0]);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a non-zero upper bound acceptor",
+    label = "`{Self}` does not implement `AcceptNonZeroUpperBound`",
+    note = "`Eval` must be a generic associated type: `type Eval<const UPPER: usize>: Const<Type = Self::Output>`"
+)]
+pub trait AcceptNonZeroUpperBound {
+    /// The output type of the evaluation.
+    type Output;
+
+    /// The desired value and lower bound that the implementor wants to be passed to [`Self::Eval`].
+    const DESIRED_GENERIC: NonZeroUsize;
+
+    /// Evals the constant by mapping a generic parameter that is at least the desired value
+    /// to the output value. See [`AcceptUpperBound::Eval`].
+    type Eval<const UPPER: usize>: Const<Type = Self::Output>;
+
+    /// The set of candidates that `UPPER` is selected from. See [`AcceptUpperBound::LADDER`].
+    const LADDER: Ladder = Ladder::DEFAULT;
+}
+
+/// Returns the parameter that [`eval_with_nonzero_upper_bound`] passes to
+/// [`AcceptNonZeroUpperBound::Eval`].
+pub const fn get_nonzero_upper_bound<A: AcceptNonZeroUpperBound>() -> NonZeroUsize {
+    nonzero_or_unreachable(get_upper_bound::<implementation::NonZeroAcceptor<A>>())
+}
+
+/// Evaluates [`AcceptNonZeroUpperBound`].
+///
+/// In the language of `generic_const_exprs`, this function returns
+/// `const_value::<F::Eval<{ get_nonzero_upper_bound::<F>().get() }>>()`
+pub const fn eval_with_nonzero_upper_bound<A: AcceptNonZeroUpperBound>() -> A::Output {
+    eval_with_upper_bound::<implementation::NonZeroAcceptor<A>>()
+}
+
+/// Returns `N` as a [`NonZeroUsize`].
+///
+/// The conversion happens during const evaluation, so this has no runtime cost. It is intended
+/// for the `UPPER` parameter of [`AcceptNonZeroUpperBound::Eval`].
+///
+/// # Panics
+/// If `N` is zero. This check happens during const evaluation, so it results in a compile error
+/// even if this function is called at runtime.
+///
+/// ```compile_fail
+/// let _ = generic_upper_bound::nonzero::<0>();
+/// ```
+pub const fn nonzero<const N: usize>() -> NonZeroUsize {
+    const_value::<implementation::NonZeroConst<N>>()
+}
+
+const fn nonzero_or_unreachable(n: usize) -> NonZeroUsize {
+    match NonZeroUsize::new(n) {
+        Some(n) => n,
+        None => unreachable!(),
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An unsigned integer type that can be used as the bound type of [`AcceptUpperBoundOf`].
+///
+/// This trait is sealed and implemented for `u8`, `u16`, `u32`, `u64`, `u128` and `usize`.
+/// Each of these types has its own ladder of candidates, ending at the type's maximum value.
+pub trait UbInt: sealed::Sealed + Copy {
+    #[doc(hidden)]
+    type __Impl<A: AcceptUpperBoundOf<Self>>: implementation::ImplOfInt<Self, Output = A::Output>;
+}
+
+/// Like [`AcceptUpperBound`], but with a desired value of type `I` instead of `usize`.
+///
+/// The type of a const parameter cannot depend on a generic parameter, so the upper bound is
+/// passed to [`Self::Eval`] as a `u128` regardless of `I`. It is always representable as an `I`.
+///
+/// When passed to [`eval_with_upper_bound_of`], [`Eval::<UPPER>::VALUE`](Const::VALUE) will be
+/// evaluated with a parameter `UPPER` that satisfies `DESIRED_GENERIC <= UPPER < 2 * DESIRED_GENERIC`,
+/// unless that would exceed `I::MAX`, in which case `I::MAX` is used.
+///
+/// # Example
+/// ```
+/// use generic_upper_bound as gub;
+/// struct LowBits<const N: u8>;
+/// impl<const N: u8> gub::AcceptUpperBoundOf<u8> for LowBits<N> {
+///     type Output = u128;
+///     const DESIRED_GENERIC: u8 = N;
+///     type Eval<const UPPER: u128> = LowBitsImpl<N, UPPER>;
+/// }
+/// struct LowBitsImpl<const N: u8, const UPPER: u128>;
+/// impl<const N: u8, const UPPER: u128> gub::Const for LowBitsImpl<N, UPPER> {
+///     type Type = u128;
+///     const VALUE: Self::Type = u128::MAX >> (128 - N as u32);
+/// }
+/// let upper = gub::get_upper_bound_of::<u8, LowBits<100>>();
+/// assert!(100 <= upper && upper < 200);
+/// let upper = gub::get_upper_bound_of::<u8, LowBits<200>>();
+/// assert!(200 <= upper && upper <= u8::MAX);
+/// assert_eq!(gub::eval_with_upper_bound_of::<u8, LowBits<4>>(), 0b1111);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an upper bound acceptor for `{I}`",
+    label = "`{Self}` does not implement `AcceptUpperBoundOf<{I}>`",
+    note = "`Eval` must be a generic associated type: `type Eval<const UPPER: u128>: Const<Type = Self::Output>`"
+)]
+pub trait AcceptUpperBoundOf<I: UbInt> {
+    /// The output type of the evaluation.
+    type Output;
+
+    /// The desired value and lower bound that the implementor wants to be passed to [`Self::Eval`].
+    const DESIRED_GENERIC: I;
+
+    /// Evals the constant by mapping a generic parameter that is at least the desired value
+    /// to the output value. See [`AcceptUpperBound::Eval`].
+    type Eval<const UPPER: u128>: Const<Type = Self::Output>;
+}
+
+/// Returns [`AcceptUpperBoundOf::DESIRED_GENERIC`].
+pub const fn desired_generic_of<I: UbInt, A: AcceptUpperBoundOf<I>>() -> I {
+    <I::__Impl<A> as implementation::ImplOfInt<I>>::DESIRED
+}
+
+/// Returns the parameter that [`eval_with_upper_bound_of`] passes to [`AcceptUpperBoundOf::Eval`].
+pub const fn get_upper_bound_of<I: UbInt, A: AcceptUpperBoundOf<I>>() -> I {
+    <I::__Impl<A> as implementation::ImplOfInt<I>>::ACTUAL
+}
+
+/// Evaluates [`AcceptUpperBoundOf`].
+///
+/// This is the equivalent of [`eval_with_upper_bound`] for bound types other than `usize`.
+pub const fn eval_with_upper_bound_of<I: UbInt, A: AcceptUpperBoundOf<I>>() -> A::Output {
+    <I::__Impl<A> as implementation::ImplOfInt<I>>::EVAL
+}
+
+/// Implements [`AcceptUpperBound`] by generating a hidden [`Const`] implementor.
+///
+/// Generic parameters are passed in braces (`{...}`) after `impl` and may have a trailing comma.
+/// Where bounds are optionally passed after the implementing type, either as a normal `where`
+/// clause or in braces (`{ where ... }`). Lifetime and const parameters are supported as well.
+///
+/// Like in a handwritten implementation, `Self` refers to the implementing type in both
+/// `DESIRED_GENERIC` and `EVAL`:
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Fill<const N: usize, const B: u8>;
+/// impl<const N: usize, const B: u8> Fill<N, B> {
+///     const LEN: usize = N;
+///     const BYTE: u8 = B;
+/// }
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize, const B: u8} Fill<N, B>;
+///
+///     const DESIRED_GENERIC: usize = Self::LEN;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[Self::BYTE; UPPER];
+/// }
+/// assert_eq!(gub::eval_trimmed::<Fill<3, 7>, u8>(), [7; 3]);
+/// ```
+///
+/// Several implementations can be passed in the same invocation, in which case they share the
+/// hidden [`Const`] implementor.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// trait Named {
+///     const NAME: &'static str;
+/// }
+/// struct Padded<'a, T, const N: usize>(&'a T);
+/// gub::impl_accept_upper_bound! {
+///     impl{'a, T, const N: usize,} Padded<'a, T, N> where T: Named;
+///
+///     const DESIRED_GENERIC: usize = T::NAME.len() + N;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// impl Named for () {
+///     const NAME: &'static str = "unit";
+/// }
+/// assert!(gub::eval_with_upper_bound::<Padded<'static, (), 3>>() >= 7);
+/// assert!(gub::eval_with_upper_bound::<Padded<'_, (), 100>>() >= 104);
+/// ```
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Zeros<const N: usize>;
+/// struct Ones<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Zeros<N>;
+///
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[0; UPPER];
+///
+///     /// Like `Zeros`, but filled with ones.
+///     impl{const N: usize} Ones<N>;
+///
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[1; UPPER];
+/// }
+/// assert_eq!(gub::eval_trimmed::<Zeros<3>, u8>(), [0; 3]);
+/// assert_eq!(gub::eval_trimmed::<Ones<3>, u8>(), [1; 3]);
+/// ```
+///
+/// Attributes before `impl` are applied to all generated items of that implementation, so that
+/// e.g. `#[cfg(...)]` removes it entirely. The hidden [`Const`] implementor is normally defined in
+/// an anonymous scope. To make it nameable, e.g. for debugging, it can be declared before the first
+/// implementation as `struct Name;`, optionally with attributes and a visibility. It is then
+/// defined as `Name<A, const UPPER: usize>`, where `A` is the implementing type, and must be at
+/// least as visible as all implementing types.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// pub struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     /// Evaluates `Len<N>` with bound `UPPER`.
+///     pub struct LenEval;
+///
+///     impl{const N: usize} Len<N>;
+///
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+///
+///     #[cfg(any())]
+///     impl{} DoesNotExist;
+///
+///     const DESIRED_GENERIC: usize = 0;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// assert_eq!(gub::const_value::<LenEval<Len<3>, 4>>(), 4);
+/// ```
+///
+/// # Diagnostics
+/// `DESIRED_GENERIC` and `EVAL` are placed into the generated items unchanged, so errors in them
+/// point at the code passed to the macro rather than into its expansion. The most common mistakes
+/// are calling functions or operators that are not `const`:
+/// ```compile_fail,E0015
+/// use generic_upper_bound as gub;
+/// struct Sqrt<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Sqrt<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     // error: cannot call non-const method `f64::sqrt` in constants
+///     const EVAL<const UPPER: usize>: usize = (UPPER as f64).sqrt() as usize;
+/// }
+/// ```
+/// creating values with destructors, such as collections:
+/// ```compile_fail,E0493
+/// use generic_upper_bound as gub;
+/// struct Names<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Names<N>;
+///     // error: destructor of `String` cannot be evaluated at compile-time
+///     const DESIRED_GENERIC: usize = String::new().len() + N;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// ```
+/// using items of a generic parameter without the corresponding bound:
+/// ```compile_fail,E0599
+/// use generic_upper_bound as gub;
+/// trait Named {
+///     const NAME: &'static str;
+/// }
+/// struct NameLen<T>(T);
+/// gub::impl_accept_upper_bound! {
+///     // help: consider restricting type parameter `T` with trait `Named`
+///     impl{T} NameLen<T>;
+///     const DESIRED_GENERIC: usize = T::NAME.len();
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// ```
+/// and returning a value of the wrong type:
+/// ```compile_fail,E0308
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     // error: expected `&[u8]`, found `[u8; UPPER]`
+///     const EVAL<const UPPER: usize>: &'static [u8] = [0; UPPER];
+/// }
+/// ```
+/// Misspelled item names are reported by the macro:
+/// ```compile_fail
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     // error: no rules expected `DESIRED`
+///     const DESIRED: usize = N;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// ```
+/// Panics and other errors during evaluation are only reported once the acceptor is used, and
+/// point at the expression that failed:
+/// ```compile_fail,E0080
+/// use generic_upper_bound as gub;
+/// struct Third<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Third<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     // error: index out of bounds: the length is 3 but the index is 3
+///     const EVAL<const UPPER: usize>: u8 = [1, 2, 3][UPPER];
+/// }
+/// let _ = gub::eval_with_upper_bound::<Third<3>>();
+/// ```
+///
+/// The example from the [crate level documentation](crate) can be written manually like this:
+/// ```
+/// use generic_upper_bound as gub;
+/// pub trait MyTrait {
+///     const SOME_STR: &'static str;
+/// }
+/// impl<A: MyTrait, B: MyTrait> MyTrait for (A, B) {
+///     const SOME_STR: &'static str = match core::str::from_utf8(
+///         gub::eval_with_upper_bound::<Concat<A, B>>()
+///             .split_at(gub::desired_generic::<Concat<A, B>>())
+///             .0,
+///     ) {
+///         Ok(s) => s,
+///         _ => unreachable!(),
+///     };
+/// }
+///
+/// struct Concat<A, B>(A, B);
+/// impl<A: MyTrait, B: MyTrait> gub::AcceptUpperBound for Concat<A, B> {
+///     type Output = &'static [u8];
+///     // Want to be passed at least the total length of the strings
+///     const DESIRED_GENERIC: usize = A::SOME_STR.len() + B::SOME_STR.len();
+///     // Decide on what to do with each generic const
+///     type Eval<const UPPER: usize> = ConcatImpl<A, B, UPPER>;
+/// }
+/// struct ConcatImpl<A, B, const N: usize>(A, B);
+/// impl<A: MyTrait, B: MyTrait, const N: usize> gub::Const for ConcatImpl<A, B, N> {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = panic!("...");
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_accept_upper_bound {
+    {
+        @parsed [[$eval:tt [$($impls:tt)*]] $($attrs:tt)*] [$($params:tt)*]
+        [$Self:ty $({ $($where_bounds:tt)* })? $(where $($where_clause:tt)*)?]
+
+        const DESIRED_GENERIC: $usize_d:ty = $DESIRED_GENERIC:expr;
+        $(const LADDER: $Ladder:ty = $LADDER:expr;)?
+        const EVAL<const $UPPER:ident: $usize_e:ty>: $Output:ty = $EVAL:expr;
+
+        $($rest:tt)*
+    } => {
+        $crate::impl_accept_upper_bound! {
+            @next $eval [
+                $($impls)*
+                {
+                    [$($attrs)*] [$($params)*] [$Self] [$($($where_bounds)*)? $(where $($where_clause)*)?]
+                    [$usize_d] [$DESIRED_GENERIC] [$(const LADDER: $Ladder = $LADDER;)?]
+                    [$UPPER $usize_e] [$Output] [$EVAL]
+                }
+            ]
+            $($rest)*
+        }
+    };
+    {
+        @next [[$($outer:tt)*] [$($inner:tt)*] $Eval:ident] [$(
+            {
+                [$($attrs:tt)*] [$($params:tt)*] [$Self:ty] [$($where:tt)*]
+                [$usize_d:ty] [$DESIRED_GENERIC:expr] [$($ladder:tt)*]
+                [$UPPER:ident $usize_e:ty] [$Output:ty] [$EVAL:expr]
+            }
+        )*]
+    } => {
+        $($outer)*
+        const _: () = {
+            $($inner)*
+            // `EVAL` is defined in an impl for the implementing type, so that `Self` refers to it
+            pub trait __EvalOf<const __UPPER: usize>: $crate::AcceptUpperBound {
+                const __VALUE: Self::Output;
+            }
+            impl<__A: __EvalOf<__UPPER>, const __UPPER: usize> $crate::Const for $Eval<__A, __UPPER> {
+                type Type = __A::Output;
+                const VALUE: Self::Type = __A::__VALUE;
+            }
+            $(
+                $($attrs)*
+                impl<$($params)* const $UPPER: $usize_e> __EvalOf<$UPPER> for $Self
+                    $($where)*
+                {
+                    const __VALUE: $Output = $EVAL;
+                }
+                $($attrs)*
+                impl<$($params)*> $crate::AcceptUpperBound for $Self
+                    $($where)*
+                {
+                    type Output = $Output;
+                    const DESIRED_GENERIC: $usize_d = $DESIRED_GENERIC;
+                    $($ladder)*
+                    type Eval<const $UPPER: $usize_e> = $Eval<Self, $UPPER>;
+                }
+            )*
+        };
+    };
+    {
+        @next $eval:tt [$($impls:tt)*]
+        $(#[$meta:meta])*
+        impl{$($params:tt)*} $($rest:tt)*
+    } => {
+        $crate::__impl_header! {
+            [$crate::impl_accept_upper_bound] [[$eval [$($impls)*]] $(#[$meta])*] [] {$($params)*} $($rest)*
+        }
+    };
+    {
+        $(#[$struct_meta:meta])*
+        $vis:vis struct $Eval:ident;
+        $($rest:tt)*
+    } => {
+        $crate::impl_accept_upper_bound! {
+            @next [
+                [
+                    $(#[$struct_meta])*
+                    $vis struct $Eval<__Eval: ?Sized, const __UPPER: usize>(
+                        ::core::marker::PhantomData<__Eval>,
+                    );
+                ]
+                []
+                $Eval
+            ]
+            [] $($rest)*
+        }
+    };
+    {
+        $(#[$meta:meta])*
+        impl{$($params:tt)*} $($rest:tt)*
+    } => {
+        $crate::impl_accept_upper_bound! {
+            @next [[] [
+                #[allow(dead_code)]
+                pub struct __Eval<__Eval, const __UPPER: usize>(__Eval);
+            ] __Eval]
+            [] $(#[$meta])* impl{$($params)*} $($rest)*
+        }
+    };
+}
+
+/// Parses the header of an invocation of an `impl_*` macro and passes it back to `$mac` as
+/// `@parsed [ATTRS] [PARAMS] [HEAD] REST`, where `PARAMS` is either empty or ends with a comma
+/// and `HEAD` consists of the tokens before the first `;`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_header {
+    ([$($mac:tt)*] [$($attrs:tt)*] [$($acc:tt)*] {} $($rest:tt)*) => {
+        $crate::__impl_header! { [$($mac)*] [$($attrs)*] [$($acc)*] [] $($rest)* }
+    };
+    ([$($mac:tt)*] [$($attrs:tt)*] [$($acc:tt)*] {,} $($rest:tt)*) => {
+        $crate::__impl_header! { [$($mac)*] [$($attrs)*] [$($acc)* ,] [] $($rest)* }
+    };
+    ([$($mac:tt)*] [$($attrs:tt)*] [$($acc:tt)*] {$last:tt} $($rest:tt)*) => {
+        $crate::__impl_header! { [$($mac)*] [$($attrs)*] [$($acc)* $last ,] [] $($rest)* }
+    };
+    ([$($mac:tt)*] [$($attrs:tt)*] [$($acc:tt)*] {$t:tt $($ts:tt)+} $($rest:tt)*) => {
+        $crate::__impl_header! { [$($mac)*] [$($attrs)*] [$($acc)* $t] {$($ts)+} $($rest)* }
+    };
+    ([$($mac:tt)*] [$($attrs:tt)*] [$($params:tt)*] [$($head:tt)*] ; $($rest:tt)*) => {
+        $($mac)*! { @parsed [$($attrs)*] [$($params)*] [$($head)*] $($rest)* }
+    };
+    ([$($mac:tt)*] [$($attrs:tt)*] [$($params:tt)*] [$($head:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::__impl_header! { [$($mac)*] [$($attrs)*] [$($params)*] [$($head)* $t] $($rest)* }
+    };
+}
+
+/// Declares a zero-sized acceptor struct and implements [`AcceptUpperBound`] for it.
+///
+/// The struct only contains [`PhantomData`](core::marker::PhantomData) for its lifetime and type
+/// parameters and cannot be constructed. Generic parameters cannot have inline bounds; bounds are
+/// instead passed in an optional `where` clause, which is used for both the struct and the
+/// implementation. The remaining items are passed like in [`impl_accept_upper_bound!`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// pub trait MyTrait {
+///     const SOME_STR: &'static str;
+/// }
+/// gub::define_acceptor! {
+///     /// Concatenates `A::SOME_STR` and `B::SOME_STR`.
+///     pub struct Concat<A, B> where A: MyTrait, B: MyTrait;
+///
+///     const DESIRED_GENERIC: usize = A::SOME_STR.len() + B::SOME_STR.len();
+///     const EVAL<const UPPER: usize>: &'static [u8] = &gub::build::BytesWriter::<UPPER>::new()
+///         .write_str(A::SOME_STR)
+///         .write_str(B::SOME_STR)
+///         .finish()
+///         .0;
+/// }
+/// impl MyTrait for () {
+///     const SOME_STR: &'static str = "ABC";
+/// }
+/// impl MyTrait for i32 {
+///     const SOME_STR: &'static str = "123";
+/// }
+/// assert_eq!(gub::eval_str::<Concat<(), i32>>(), "ABC123");
+/// assert_eq!(core::mem::size_of::<Concat<(), i32>>(), 0);
+/// ```
+///
+/// Lifetime and const parameters are supported as well:
+/// ```
+/// use generic_upper_bound as gub;
+/// gub::define_acceptor! {
+///     struct Prefix<'a, T, const N: usize> where T: ?Sized;
+///
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// assert!(gub::eval_with_upper_bound::<Prefix<'static, str, 100>>() >= 100);
+/// ```
+#[macro_export]
+macro_rules! define_acceptor {
+    {
+        @parsed [[$($attrs:tt)*] [$vis:vis] $Name:ident [$($phantom:tt)*]]
+        [$($params:tt)*] [$Self:ty $(where $($where_clause:tt)*)?]
+        $($body:tt)*
+    } => {
+        $($attrs)*
+        $vis struct $Name<$($params)*>(::core::marker::PhantomData<fn() -> ($($phantom)*)>)
+        $(where $($where_clause)*)?;
+        $crate::impl_accept_upper_bound! {
+            impl{$($params)*} $Self $(where $($where_clause)*)?;
+            $($body)*
+        }
+    };
+    (@generics $attrs:tt $vis:tt $Name:ident [$($params:tt)*] [$($args:tt)*] $phantom:tt > $($rest:tt)*) => {
+        $crate::__impl_header! {
+            [$crate::define_acceptor] [$attrs $vis $Name $phantom] [] {$($params)*} $Name<$($args)*> $($rest)*
+        }
+    };
+    (@generics $attrs:tt $vis:tt $Name:ident [$($params:tt)*] [$($args:tt)*] [$($phantom:tt)*] $lt:lifetime , $($rest:tt)*) => {
+        $crate::define_acceptor! {
+            @generics $attrs $vis $Name
+            [$($params)* $lt,] [$($args)* $lt,] [$($phantom)* &$lt (),] $($rest)*
+        }
+    };
+    (@generics $attrs:tt $vis:tt $Name:ident [$($params:tt)*] [$($args:tt)*] [$($phantom:tt)*] $lt:lifetime > $($rest:tt)*) => {
+        $crate::define_acceptor! {
+            @generics $attrs $vis $Name
+            [$($params)* $lt,] [$($args)* $lt,] [$($phantom)* &$lt (),] > $($rest)*
+        }
+    };
+    (@generics $attrs:tt $vis:tt $Name:ident [$($params:tt)*] [$($args:tt)*] $phantom:tt const $C:ident: $CT:ty, $($rest:tt)*) => {
+        $crate::define_acceptor! {
+            @generics $attrs $vis $Name
+            [$($params)* const $C: $CT,] [$($args)* $C,] $phantom $($rest)*
+        }
+    };
+    (@generics $attrs:tt $vis:tt $Name:ident [$($params:tt)*] [$($args:tt)*] $phantom:tt const $C:ident: $CT:ty > $($rest:tt)*) => {
+        $crate::define_acceptor! {
+            @generics $attrs $vis $Name
+            [$($params)* const $C: $CT,] [$($args)* $C,] $phantom > $($rest)*
+        }
+    };
+    (@generics $attrs:tt $vis:tt $Name:ident [$($params:tt)*] [$($args:tt)*] [$($phantom:tt)*] $T:ident , $($rest:tt)*) => {
+        $crate::define_acceptor! {
+            @generics $attrs $vis $Name
+            [$($params)* $T,] [$($args)* $T,] [$($phantom)* ::core::marker::PhantomData<$T>,] $($rest)*
+        }
+    };
+    (@generics $attrs:tt $vis:tt $Name:ident [$($params:tt)*] [$($args:tt)*] [$($phantom:tt)*] $T:ident > $($rest:tt)*) => {
+        $crate::define_acceptor! {
+            @generics $attrs $vis $Name
+            [$($params)* $T,] [$($args)* $T,] [$($phantom)* ::core::marker::PhantomData<$T>,] > $($rest)*
+        }
+    };
+    {
+        $(#[$meta:meta])*
+        $vis:vis struct $Name:ident < $($rest:tt)*
+    } => {
+        $crate::define_acceptor! { @generics [$(#[$meta])*] [$vis] $Name [] [] [] $($rest)* }
+    };
+    {
+        $(#[$meta:meta])*
+        $vis:vis struct $Name:ident $($rest:tt)*
+    } => {
+        $crate::define_acceptor! { @generics [$(#[$meta])*] [$vis] $Name [] [] [] > $($rest)* }
+    };
+}
+
+/// Evaluates a one-off acceptor that is defined inline.
+///
+/// `eval!(|const UPPER: usize| -> Output { ... }, desired = EXPR)` defines a hidden acceptor with
+/// the given desired value whose `EVAL` is the block, and evaluates it using
+/// [`eval_with_upper_bound`]. Optionally, `ladder = EXPR` can be passed after the desired value.
+///
+/// Items defined inside of an expression cannot use the generic parameters of the surrounding
+/// item. Generic parameters that are used by the acceptor therefore have to be redeclared before
+/// the closure, like in [`define_acceptor!`]: They cannot have inline bounds, but can be followed
+/// by a `where` clause.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// trait Named {
+///     const NAME: &'static str;
+/// }
+/// trait DistinctBytes {
+///     const DISTINCT_BYTES: usize;
+/// }
+/// impl<T: Named> DistinctBytes for T {
+///     const DISTINCT_BYTES: usize = gub::eval!(
+///         <T> where T: Named;
+///         |const UPPER: usize| -> usize {
+///             let bytes = T::NAME.as_bytes();
+///             let mut seen = [0; UPPER];
+///             let mut len = 0;
+///             let mut i = 0;
+///             while i < bytes.len() {
+///                 let mut j = 0;
+///                 while j < len && seen[j] != bytes[i] {
+///                     j += 1;
+///                 }
+///                 if j == len {
+///                     seen[len] = bytes[i];
+///                     len += 1;
+///                 }
+///                 i += 1;
+///             }
+///             len
+///         },
+///         desired = T::NAME.len(),
+///     );
+/// }
+/// struct Hello;
+/// impl Named for Hello {
+///     const NAME: &'static str = "hello";
+/// }
+/// assert_eq!(Hello::DISTINCT_BYTES, 4);
+///
+/// const BOUND: usize = gub::eval!(|const UPPER: usize| -> usize { UPPER }, desired = 1000);
+/// assert!(BOUND >= 1000);
+/// ```
+#[macro_export]
+macro_rules! eval {
+    {
+        @body [$($params:tt)*] [$($args:tt)*] [$($where_clause:tt)*]
+        |const $UPPER:ident: usize| -> $Output:ty $body:block,
+        desired = $desired:expr $(, ladder = $ladder:expr)? $(,)?
+    } => {{
+        $crate::define_acceptor! {
+            struct __InlineAcceptor<$($params)*> $($where_clause)*;
+
+            const DESIRED_GENERIC: usize = $desired;
+            $(const LADDER: $crate::Ladder = $ladder;)?
+            const EVAL<const $UPPER: usize>: $Output = $body;
+        }
+        $crate::eval_with_upper_bound::<__InlineAcceptor<$($args)*>>()
+    }};
+    (@where $params:tt $args:tt [$($where_clause:tt)*] ; $($rest:tt)*) => {
+        $crate::eval! { @body $params $args [$($where_clause)*] $($rest)* }
+    };
+    (@where $params:tt $args:tt [$($where_clause:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::eval! { @where $params $args [$($where_clause)* $t] $($rest)* }
+    };
+    (@generics $params:tt $args:tt > where $($rest:tt)*) => {
+        $crate::eval! { @where $params $args [where] $($rest)* }
+    };
+    (@generics $params:tt $args:tt > $($rest:tt)*) => {
+        $crate::eval! { @body $params $args [] $($rest)* }
+    };
+    (@generics [$($params:tt)*] [$($args:tt)*] $lt:lifetime , $($rest:tt)*) => {
+        $crate::eval! { @generics [$($params)* $lt,] [$($args)* $lt,] $($rest)* }
+    };
+    (@generics [$($params:tt)*] [$($args:tt)*] $lt:lifetime > $($rest:tt)*) => {
+        $crate::eval! { @generics [$($params)* $lt,] [$($args)* $lt,] > $($rest)* }
+    };
+    (@generics [$($params:tt)*] [$($args:tt)*] const $C:ident: $CT:ty, $($rest:tt)*) => {
+        $crate::eval! { @generics [$($params)* const $C: $CT,] [$($args)* $C,] $($rest)* }
+    };
+    (@generics [$($params:tt)*] [$($args:tt)*] const $C:ident: $CT:ty > $($rest:tt)*) => {
+        $crate::eval! { @generics [$($params)* const $C: $CT,] [$($args)* $C,] > $($rest)* }
+    };
+    (@generics [$($params:tt)*] [$($args:tt)*] $T:ident , $($rest:tt)*) => {
+        $crate::eval! { @generics [$($params)* $T,] [$($args)* $T,] $($rest)* }
+    };
+    (@generics [$($params:tt)*] [$($args:tt)*] $T:ident > $($rest:tt)*) => {
+        $crate::eval! { @generics [$($params)* $T,] [$($args)* $T,] > $($rest)* }
+    };
+    (< $($rest:tt)*) => {
+        $crate::eval! { @generics [] [] $($rest)* }
+    };
+    (| $($rest:tt)*) => {
+        $crate::eval! { @body [] [] [] | $($rest)* }
+    };
+}
+
+/// Declares `static` items that hold the trimmed outputs of acceptors.
+///
+/// Each item has the form `static NAME: TYPE = ACCEPTOR;` with optional attributes and visibility.
+/// The type determines how the output of the acceptor is trimmed:
+/// - `&[T]` uses [`eval_trimmed`].
+/// - `&str` uses [`eval_str`].
+/// - `&CStr` uses [`eval_cstr`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{ConcatStrs, Repeat};
+/// struct Parts;
+/// impl gub::Const for Parts {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["generic", "-", "upper", "-", "bound"];
+/// }
+/// struct Dash;
+/// impl gub::Const for Dash {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = b"-";
+/// }
+/// struct Twenty;
+/// impl gub::Const for Twenty {
+///     type Type = usize;
+///     const VALUE: Self::Type = 20;
+/// }
+/// gub::static_eval! {
+///     /// The name of the crate.
+///     pub static NAME: &str = ConcatStrs<Parts>;
+///     static RULE: &[u8] = Repeat<Dash, Twenty>;
+/// }
+/// assert_eq!(NAME, "generic-upper-bound");
+/// assert_eq!(RULE, [b'-'; 20]);
+/// ```
+#[macro_export]
+macro_rules! static_eval {
+    {} => {};
+    {
+        $(#[$meta:meta])*
+        $vis:vis static $NAME:ident: & $('static)? str = $A:ty;
+        $($rest:tt)*
+    } => {
+        $(#[$meta])*
+        $vis static $NAME: &str = $crate::eval_str::<$A>();
+        $crate::static_eval! { $($rest)* }
+    };
+    {
+        $(#[$meta:meta])*
+        $vis:vis static $NAME:ident: & $('static)? CStr = $A:ty;
+        $($rest:tt)*
+    } => {
+        $(#[$meta])*
+        $vis static $NAME: &::core::ffi::CStr = $crate::eval_cstr::<$A>();
+        $crate::static_eval! { $($rest)* }
+    };
+    {
+        $(#[$meta:meta])*
+        $vis:vis static $NAME:ident: & $('static)? [$T:ty] = $A:ty;
+        $($rest:tt)*
+    } => {
+        $(#[$meta])*
+        $vis static $NAME: &[$T] = $crate::eval_trimmed::<$A, $T>();
+        $crate::static_eval! { $($rest)* }
+    };
+}
+
+/// Evaluates several acceptors and returns a tuple of their trimmed outputs.
+///
+/// Each acceptor can be followed by the type that its output is trimmed to, like in
+/// [`static_eval!`]:
+/// - `as [T]` uses [`eval_trimmed`].
+/// - `as str` uses [`eval_str`].
+/// - `as CStr` uses [`eval_cstr`].
+///
+/// Without it, the output is returned as is, like by [`eval_with_upper_bound`]. The expansion is a
+/// constant expression. Each acceptor is evaluated with its own upper bound, which keeps the
+/// padding small. To instead evaluate them with one shared upper bound, group them in a tuple and
+/// pass it to [`eval_with_upper_bound`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{ConcatStrs, Repeat};
+/// struct Parts;
+/// impl gub::Const for Parts {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["generic", "-", "upper", "-", "bound"];
+/// }
+/// struct Dash;
+/// impl gub::Const for Dash {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = b"-";
+/// }
+/// struct Twenty;
+/// impl gub::Const for Twenty {
+///     type Type = usize;
+///     const VALUE: Self::Type = 20;
+/// }
+/// struct Bound<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Bound<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = UPPER;
+/// }
+/// const OUTPUTS: (&str, &[u8], usize) = gub::eval_many!(
+///     ConcatStrs<Parts> as str,
+///     Repeat<Dash, Twenty> as [u8],
+///     Bound<37>,
+/// );
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(OUTPUTS, ("generic-upper-bound", &[b'-'; 20][..], 37));
+///
+/// let (name,) = gub::eval_many!(ConcatStrs<Parts> as str);
+/// assert_eq!(name, "generic-upper-bound");
+/// ```
+#[macro_export]
+macro_rules! eval_many {
+    (@eval $A:ty as str) => {
+        $crate::eval_str::<$A>()
+    };
+    (@eval $A:ty as CStr) => {
+        $crate::eval_cstr::<$A>()
+    };
+    (@eval $A:ty as [$T:ty]) => {
+        $crate::eval_trimmed::<$A, $T>()
+    };
+    (@eval $A:ty) => {
+        $crate::eval_with_upper_bound::<$A>()
+    };
+    ($($A:ty $(as $kind:tt)?),+ $(,)?) => {
+        ($($crate::eval_many!(@eval $A $(as $kind)?),)+)
+    };
+}
+
+/// Chains adapters, passing the trimmed output of each stage as the input of the next one.
+///
+/// `pipeline!(Source => Stage1 => Stage2)` evaluates `Stage2<Trim<Stage1<Source>>>`, where
+/// `Trim` is [`NestedTrimmed<_, u8>`](combinators::NestedTrimmed). The input of each stage is
+/// inserted as its first generic argument, so further arguments can be passed as usual, e.g.
+/// `ToAsciiCase<true>` for `ToAsciiCase<Input, true>`.
+///
+/// A stage whose output is passed to an adapter that takes a string must be followed by `as str`,
+/// which trims it using [`NestedStr`](combinators::NestedStr) instead. The source is passed to the
+/// first stage unchanged, so it must be a [`Const`] of the right type. An acceptor can be used as
+/// the source by following it with `as str` or `as [u8]`.
+///
+/// The macro evaluates to the trimmed output of the last stage, as a string if it is followed by
+/// `as str` and as bytes otherwise. With a leading `type`, it expands to the type of the last stage
+/// instead.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::adapters::{ConcatStrs, HexEncode, PercentEncode, ToAsciiCase};
+/// struct Parts;
+/// impl gub::Const for Parts {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["Hello", ", ", "World"];
+/// }
+/// const UPPER: &str = gub::pipeline!(ConcatStrs<Parts> as str => ToAsciiCase<true> as str);
+/// assert_eq!(UPPER, "HELLO, WORLD");
+///
+/// let encoded = gub::pipeline!(
+///     ConcatStrs<Parts> as str
+///         => ToAsciiCase<false> as str
+///         => PercentEncode
+///         => HexEncode<true> as str
+/// );
+/// assert_eq!(encoded, "68656C6C6F253243253230776F726C64");
+///
+/// type Hex = gub::pipeline!(type ConcatStrs<Parts> as [u8] => HexEncode);
+/// assert_eq!(gub::eval_str::<Hex>(), "48656c6c6f2c20576f726c64");
+/// ```
+#[macro_export]
+macro_rules! pipeline {
+    // collect the source, up to the first `=>`
+    (@source $mode:tt [$($src:tt)*] as str => $($rest:tt)*) => {
+        $crate::pipeline! { @path $mode [$crate::combinators::NestedStr<$($src)*>] [] $($rest)* }
+    };
+    (@source $mode:tt [$($src:tt)*] as [u8] => $($rest:tt)*) => {
+        $crate::pipeline! {
+            @path $mode [$crate::combinators::NestedTrimmed<$($src)*, u8>] [] $($rest)*
+        }
+    };
+    (@source $mode:tt [$($src:tt)*] => $($rest:tt)*) => {
+        $crate::pipeline! { @path $mode [$($src)*] [] $($rest)* }
+    };
+    (@source $mode:tt [$($src:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::pipeline! { @source $mode [$($src)* $t] $($rest)* }
+    };
+    (@source $mode:tt [$($src:tt)*]) => {
+        ::core::compile_error!("a pipeline needs at least one stage after `=>`")
+    };
+
+    // collect the path of a stage, up to its generic arguments or the end of the stage
+    (@path $mode:tt $input:tt [$($path:tt)*] < $($rest:tt)*) => {
+        $crate::pipeline! { @args $mode $input [$($path)*] [] $($rest)* }
+    };
+    (@path $mode:tt [$($input:tt)*] [$($path:tt)*] $(as $kind:tt)? $(=> $($rest:tt)*)?) => {
+        $crate::pipeline! {
+            @stage $mode [$($path)*<$($input)*>] [$($kind)?] $($($rest)*)?
+        }
+    };
+    (@path $mode:tt $input:tt [$($path:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::pipeline! { @path $mode $input [$($path)* $t] $($rest)* }
+    };
+
+    // collect the remaining generic arguments of a stage, including the closing `>`
+    (@args $mode:tt [$($input:tt)*] [$($path:tt)*] [$($args:tt)*] $(as $kind:tt)? $(=> $($rest:tt)*)?) => {
+        $crate::pipeline! {
+            @stage $mode [$($path)*<$($input)*, $($args)*] [$($kind)?] $($($rest)*)?
+        }
+    };
+    (@args $mode:tt $input:tt $path:tt [$($args:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::pipeline! { @args $mode $input $path [$($args)* $t] $($rest)* }
+    };
+
+    // the last stage
+    (@stage [type] [$($stage:tt)*] $kind:tt) => { $($stage)* };
+    (@stage [eval] [$($stage:tt)*] [str]) => { $crate::eval_str::<$($stage)*>() };
+    (@stage [eval] [$($stage:tt)*] [$([u8])?]) => { $crate::eval_trimmed::<$($stage)*, u8>() };
+    // a stage whose output is passed to the next one
+    (@stage $mode:tt [$($stage:tt)*] [str] $($rest:tt)+) => {
+        $crate::pipeline! { @path $mode [$crate::combinators::NestedStr<$($stage)*>] [] $($rest)+ }
+    };
+    (@stage $mode:tt [$($stage:tt)*] [$([u8])?] $($rest:tt)+) => {
+        $crate::pipeline! {
+            @path $mode [$crate::combinators::NestedTrimmed<$($stage)*, u8>] [] $($rest)+
+        }
+    };
+
+    (type $($rest:tt)+) => {
+        $crate::pipeline! { @source [type] [] $($rest)+ }
+    };
+    ($($rest:tt)+) => {
+        $crate::pipeline! { @source [eval] [] $($rest)+ }
+    };
+}
+
+/// Declares unit structs that implement [`Const`] with type `&'static str`.
+///
+/// This allows passing string values to adapters without writing a [`Const`] implementation. See
+/// [`const_bytes!`] for byte strings and the [`consts`] module for other types.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::consts::{ConstChar, ConstUsize};
+/// gub::const_str! {
+///     /// A greeting.
+///     pub struct Hello = "Hello";
+///     struct World = concat!("Wor", "ld");
+/// }
+/// assert_eq!(gub::const_value::<World>(), "World");
+/// assert_eq!(gub::adapters::pad_right::<Hello, ConstUsize<7>, ConstChar<'!'>>(), "Hello!!");
+/// ```
+#[macro_export]
+macro_rules! const_str {
+    ($($(#[$meta:meta])* $vis:vis struct $Name:ident = $value:expr;)*) => {$(
+        $(#[$meta])*
+        $vis struct $Name;
+        impl $crate::Const for $Name {
+            type Type = &'static str;
+            const VALUE: Self::Type = $value;
+        }
+    )*};
+}
+
+/// Declares unit structs that implement [`Const`] with type `&'static [u8]`.
+///
+/// This is the byte string version of [`const_str!`].
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::consts::ConstUsize;
+/// gub::const_bytes! {
+///     pub struct Zero = &[0];
+///     struct Abc = b"abc";
+/// }
+/// assert_eq!(gub::adapters::hex_encode::<Abc>(), "616263");
+/// assert_eq!(gub::adapters::repeat_bytes::<Zero, ConstUsize<4>>(), [0; 4]);
+/// ```
+#[macro_export]
+macro_rules! const_bytes {
+    ($($(#[$meta:meta])* $vis:vis struct $Name:ident = $value:expr;)*) => {$(
+        $(#[$meta])*
+        $vis struct $Name;
+        impl $crate::Const for $Name {
+            type Type = &'static [u8];
+            const VALUE: Self::Type = $value;
+        }
+    )*};
+}
+
+/// Implements [`MapOutput`](combinators::MapOutput) by generating a hidden [`Const`] implementor.
+///
+/// Generic parameters and where bounds are passed like in [`impl_accept_upper_bound!`]. The
+/// expression can refer to the output of the wrapped acceptor and optionally to its desired value,
+/// using the given names.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::combinators::Map;
+/// struct Sum;
+/// gub::impl_map! {
+///     impl{} Sum;
+///     fn(values: &'static [u32]) -> u32 = {
+///         let mut sum = 0;
+///         let mut i = 0;
+///         while i < values.len() {
+///             sum += values[i];
+///             i += 1;
+///         }
+///         sum
+///     };
+/// }
+/// struct Ones<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Ones<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u32] = [1; UPPER].split_at(N).0;
+/// }
+/// assert_eq!(gub::eval_with_upper_bound::<Map<Ones<100>, Sum>>(), 100);
+///
+/// struct Scale<const K: u32>;
+/// gub::impl_map! {
+///     impl{const K: u32} Scale<K>;
+///     fn(n: u32) -> u32 = n * K;
+/// }
+/// assert_eq!(gub::eval_with_upper_bound::<Map<Map<Ones<4>, Sum>, Scale<5>>>(), 20);
+/// ```
+#[macro_export]
+macro_rules! impl_map {
+    {
+        @parsed [$($attrs:tt)*] [$($params:tt)*]
+        [$Self:ty $({ $($where_bounds:tt)* })? $(where $($where_clause:tt)*)?]
+
+        fn($value:ident: $Input:ty $(, $desired:ident: $usize_d:ty)? $(,)?) -> $Output:ty = $MAP:expr;
+
+    } => {
+        const _: () = {
+            pub struct __Apply<__Map, __A, const __UPPER: usize>(__Map, __A);
+            impl<$($params)* __A: $crate::AcceptUpperBound<Output = $Input>, const __UPPER: usize>
+                $crate::Const for __Apply<$Self, __A, __UPPER>
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                type Type = $Output;
+                const VALUE: Self::Type = {
+                    let $value: $Input = $crate::const_value::<__A::Eval<__UPPER>>();
+                    $(let $desired: $usize_d = $crate::desired_generic::<__A>();)?
+                    $MAP
+                };
+            }
+            $($attrs)*
+            impl<$($params)*> $crate::combinators::MapOutput<$Input> for $Self
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                type Output = $Output;
+                type Apply<__A: $crate::AcceptUpperBound<Output = $Input>, const __UPPER: usize> =
+                    __Apply<Self, __A, __UPPER>;
+            }
+        };
+    };
+    {
+        $(#[$meta:meta])*
+        impl{$($params:tt)*} $($rest:tt)*
+    } => {
+        $crate::__impl_header! { [$crate::impl_map] [$(#[$meta])*] [] {$($params)*} $($rest)* }
+    };
+}
+
+/// Implements [`IndexMapper`](adapters::IndexMapper) by generating hidden [`Const`] implementors.
+///
+/// Generic parameters and where bounds are passed like in [`impl_accept_upper_bound!`]. The
+/// expression computes the value for the index with the given name. Like other outputs, the
+/// values are stored in an array, so their type cannot depend on generic parameters if it may have
+/// interior mutability.
+///
+/// The values do not need to be [`Copy`]. See [`adapters::from_fn`] for an example with
+/// generic parameters.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// #[derive(Debug, PartialEq)]
+/// struct Pin {
+///     port: char,
+///     index: u8,
+/// }
+/// struct PortB;
+/// gub::impl_index_mapper! {
+///     impl{} PortB;
+///     fn(i: usize) -> Pin = Pin { port: 'B', index: i as u8 };
+/// }
+/// struct Three;
+/// impl gub::Const for Three {
+///     type Type = usize;
+///     const VALUE: Self::Type = 3;
+/// }
+/// const PINS: &[Pin] = gub::adapters::from_fn::<Three, PortB>();
+/// assert_eq!(PINS[2], Pin { port: 'B', index: 2 });
+/// assert_eq!(PINS.len(), 3);
+/// ```
+#[macro_export]
+macro_rules! impl_index_mapper {
+    {
+        @parsed [$($attrs:tt)*] [$($params:tt)*]
+        [$Self:ty $({ $($where_bounds:tt)* })? $(where $($where_clause:tt)*)?]
+
+        fn($index:ident: $usize_i:ty $(,)?) -> $Item:ty = $MAP:expr;
+
+    } => {
+        const _: () = {
+            pub struct __Builder<__Map: ?Sized, __N, const __UPPER: usize>(__N, ::core::marker::PhantomData<__Map>);
+            impl<$($params)* __N: $crate::Const<Type = usize>, const __UPPER: usize>
+                $crate::Const for __Builder<$Self, __N, __UPPER>
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                type Type = &'static $crate::build::UninitPrefix<$Item>;
+                const VALUE: Self::Type = &{
+                    let __len = $crate::const_value::<__N>();
+                    let mut __b = $crate::build::UninitArrayBuilder::<$Item, __UPPER>::new();
+                    let mut __i = 0;
+                    while __i < __len {
+                        let $index: $usize_i = __i;
+                        __b = __b.push($MAP);
+                        __i += 1;
+                    }
+                    __b
+                };
+            }
+            pub struct __Table<__Map: ?Sized, __N, const __UPPER: usize>(__N, ::core::marker::PhantomData<__Map>);
+            impl<$($params)* __N: $crate::Const<Type = usize>, const __UPPER: usize>
+                $crate::Const for __Table<$Self, __N, __UPPER>
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                type Type = &'static [$Item];
+                const VALUE: Self::Type =
+                    $crate::const_value::<__Builder<$Self, __N, __UPPER>>().finish_prefix();
+            }
+            $($attrs)*
+            impl<$($params)*> $crate::adapters::IndexMapper for $Self
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                type Item = $Item;
+                type Table<__N: $crate::Const<Type = usize>, const __UPPER: usize> =
+                    __Table<Self, __N, __UPPER>;
+            }
+        };
+    };
+    {
+        $(#[$meta:meta])*
+        impl{$($params:tt)*} $($rest:tt)*
+    } => {
+        $crate::__impl_header! { [$crate::impl_index_mapper] [$(#[$meta])*] [] {$($params)*} $($rest)* }
+    };
+}
+
+/// Implements [`Comparator`](adapters::Comparator) by generating hidden [`Const`] implementors.
+///
+/// Generic parameters and where bounds are passed like in [`impl_accept_upper_bound!`]. The
+/// expression compares the values with the given names and evaluates to a
+/// [`Ordering`](core::cmp::Ordering). Like other outputs, the sorted values are stored in an
+/// array, so their type cannot depend on generic parameters if it may have interior mutability.
+///
+/// See [`adapters::sort_by`] for an example with a struct.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use generic_upper_bound as gub;
+/// struct Descending;
+/// gub::impl_comparator! {
+///     impl{} Descending;
+///     fn(a: &u32, b: &u32) -> Ordering = if *a > *b {
+///         Ordering::Less
+///     } else if *a < *b {
+///         Ordering::Greater
+///     } else {
+///         Ordering::Equal
+///     };
+/// }
+/// struct Sizes;
+/// impl gub::Const for Sizes {
+///     type Type = &'static [u32];
+///     const VALUE: Self::Type = &[64, 4096, 512];
+/// }
+/// const SORTED: &[u32] = gub::adapters::sort_by::<Sizes, Descending>();
+/// assert_eq!(SORTED, [4096, 512, 64]);
+/// ```
+#[macro_export]
+macro_rules! impl_comparator {
+    {
+        @parsed [$($attrs:tt)*] [$($params:tt)*]
+        [$Self:ty $({ $($where_bounds:tt)* })? $(where $($where_clause:tt)*)?]
+
+        fn($a:ident: &$Item:ty, $b:ident: &$Item2:ty $(,)?) -> $Ordering:ty = $CMP:expr;
+
+    } => {
+        const _: () = {
+            pub struct __Builder<__Cmp: ?Sized, __L, const __UPPER: usize>(__L, ::core::marker::PhantomData<__Cmp>);
+            impl<$($params)* __L: $crate::Const<Type = &'static [$Item]>, const __UPPER: usize>
+                $crate::Const for __Builder<$Self, __L, __UPPER>
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                type Type = &'static $crate::build::UninitPrefix<$Item>;
+                const VALUE: Self::Type = &{
+                    let __items: &'static [$Item] = $crate::const_value::<__L>();
+                    // insertion sort, inserting `__i` into the sorted prefix `__order[..__i]`
+                    let mut __order = [0usize; __UPPER];
+                    let mut __i = 0;
+                    while __i < __items.len() {
+                        let mut __j = __i;
+                        while __j > 0 {
+                            let $a: &$Item = &__items[__order[__j - 1]];
+                            let $b: &$Item2 = &__items[__i];
+                            let __ord: $Ordering = $CMP;
+                            if !::core::matches!(__ord, ::core::cmp::Ordering::Greater) {
+                                break;
+                            }
+                            __order[__j] = __order[__j - 1];
+                            __j -= 1;
+                        }
+                        __order[__j] = __i;
+                        __i += 1;
+                    }
+                    let mut __b = $crate::build::UninitArrayBuilder::<$Item, __UPPER>::new();
+                    let mut __k = 0;
+                    while __k < __items.len() {
+                        __b = __b.push(__items[__order[__k]]);
+                        __k += 1;
+                    }
+                    __b
+                };
+            }
+            pub struct __Sorted<__Cmp: ?Sized, __L, const __UPPER: usize>(__L, ::core::marker::PhantomData<__Cmp>);
+            impl<$($params)* __L: $crate::Const<Type = &'static [$Item]>, const __UPPER: usize>
+                $crate::Const for __Sorted<$Self, __L, __UPPER>
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                type Type = &'static [$Item];
+                const VALUE: Self::Type =
+                    $crate::const_value::<__Builder<$Self, __L, __UPPER>>().finish_prefix();
+            }
+            $($attrs)*
+            impl<$($params)*> $crate::adapters::Comparator for $Self
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                type Item = $Item;
+                type Sorted<__L: $crate::Const<Type = &'static [$Item]>, const __UPPER: usize> =
+                    __Sorted<Self, __L, __UPPER>;
+            }
+        };
+    };
+    {
+        $(#[$meta:meta])*
+        impl{$($params:tt)*} $($rest:tt)*
+    } => {
+        $crate::__impl_header! { [$crate::impl_comparator] [$(#[$meta])*] [] {$($params)*} $($rest)* }
+    };
+}
+
+/// Implements [`AcceptConstParam`] by generating the dispatch to the candidates.
+///
+/// Generic parameters and where bounds are passed like in [`impl_accept_upper_bound!`]. The
+/// candidates are expressions of the parameter type, which must not depend on generic parameters.
+/// The predicate is evaluated for each candidate in order until it returns `true`, and `EVAL` is
+/// only evaluated for that candidate. Requires the `nightly` feature.
+///
+/// ```
+/// #![feature(adt_const_params)]
+/// #![allow(incomplete_features)]
+/// use core::marker::ConstParamTy;
+/// use generic_upper_bound as gub;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, ConstParamTy)]
+/// enum Strategy {
+///     Inline,
+///     Table,
+///     Compressed,
+/// }
+/// const fn describe<const S: Strategy>() -> &'static str {
+///     match S {
+///         Strategy::Inline => "inline",
+///         Strategy::Table => "table",
+///         Strategy::Compressed => "compressed",
+///     }
+/// }
+///
+/// /// Chooses the storage strategy for `N` entries.
+/// struct Storage<const N: usize>;
+/// gub::impl_accept_const_param! {
+///     impl{const N: usize} Storage<N>;
+///     const CANDIDATES: [Strategy] = [Strategy::Inline, Strategy::Table, Strategy::Compressed];
+///     fn accepts(strategy: Strategy) -> bool = match strategy {
+///         Strategy::Inline => N <= 8,
+///         Strategy::Table => N <= 1024,
+///         Strategy::Compressed => true,
+///     };
+///     const EVAL<const S: Strategy>: &'static str = describe::<S>();
+/// }
+/// assert_eq!(gub::eval_with_const_param::<Storage<4>>(), "inline");
+/// assert_eq!(gub::eval_with_const_param::<Storage<100>>(), "table");
+/// assert_eq!(*gub::get_const_param::<Storage<5000>>(), Strategy::Compressed);
+/// ```
+#[cfg(feature = "nightly")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
+#[macro_export]
+macro_rules! impl_accept_const_param {
+    {
+        @parsed [$($attrs:tt)*] [$($params:tt)*]
+        [$Self:ty $({ $($where_bounds:tt)* })? $(where $($where_clause:tt)*)?]
+
+        const CANDIDATES: [$Param:ty] = [$($candidate:expr),* $(,)?];
+        fn accepts($value:ident: $Param_a:ty $(,)?) -> bool = $ACCEPTS:expr;
+        const EVAL<const $P:ident: $Param_e:ty>: $Output:ty = $EVAL:expr;
+
+    } => {
+        const _: () = {
+            pub trait __EvalOf<const __P: $Param> {
+                const __VALUE: $Output;
+            }
+            $($attrs)*
+            impl<$($params)* const $P: $Param_e> __EvalOf<$P> for $Self
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                const __VALUE: $Output = $EVAL;
+            }
+            pub struct __Eval<__A: ?Sized>(::core::marker::PhantomData<__A>);
+            impl<$($params)*> $crate::Const for __Eval<$Self>
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                type Type = $Output;
+                // `const_value` is only called for the selected candidate, so the others are not
+                // evaluated
+                const VALUE: Self::Type = $(
+                    if { let $value: $Param_a = $candidate; $ACCEPTS } {
+                        $crate::const_value::<__EvalConst<$Self, { $candidate }>>()
+                    } else
+                )* {
+                    ::core::panic!("none of the candidates is accepted")
+                };
+            }
+            pub struct __EvalConst<__A: ?Sized, const __P: $Param>(::core::marker::PhantomData<__A>);
+            impl<__A: ?Sized + __EvalOf<__P>, const __P: $Param> $crate::Const for __EvalConst<__A, __P> {
+                type Type = $Output;
+                const VALUE: Self::Type = __A::__VALUE;
+            }
+            $($attrs)*
+            impl<$($params)*> $crate::AcceptConstParam for $Self
+                $($($where_bounds)*)? $(where $($where_clause)*)?
+            {
+                type Param = $Param;
+                type Output = $Output;
+                const CANDIDATES: &'static [$Param] = &[$($candidate),*];
+                const SELECTED: ::core::option::Option<usize> = {
+                    let mut __i = 0;
+                    '__select: {
+                        $(
+                            if { let $value: $Param_a = $candidate; $ACCEPTS } {
+                                break '__select ::core::option::Option::Some(__i);
+                            }
+                            __i += 1;
+                        )*
+                        let _ = __i;
+                        ::core::option::Option::None
+                    }
+                };
+                type Eval = __Eval<Self>;
+            }
+        };
+    };
+    {
+        $(#[$meta:meta])*
+        impl{$($params:tt)*} $($rest:tt)*
+    } => {
+        $crate::__impl_header! { [$crate::impl_accept_const_param] [$(#[$meta])*] [] {$($params)*} $($rest)* }
+    };
+}
+
+/// Implements [`AcceptUpperBound`] such that the output is a formatted string.
+///
+/// Generic parameters and where bounds are passed like in [`impl_accept_upper_bound!`]. They are
+/// followed by a template and the arguments that replace its `{}`, like in [`format!`]. See the
+/// [`fmt`] module for the supported arguments. The output is the bytes of the formatted string,
+/// and the desired value is its exact length, so [`eval_str`] returns the formatted string.
+///
+/// # Panics
+/// Evaluation panics if the number of `{}` in the template does not match the number of
+/// arguments, or if the template contains other braces that are not doubled.
+///
+/// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// trait Register {
+///     const NAME: &'static str;
+///     const ADDRESS: u32;
+///     const WIDTH: u8;
+/// }
+/// struct Describe<R>(R);
+/// gub::impl_format! {
+///     impl{R: Register} Describe<R>;
+///     "{}@{} ({}-bit, {{reset}})", R::NAME, R::ADDRESS, R::WIDTH
+/// }
+/// struct Status;
+/// impl Register for Status {
+///     const NAME: &'static str = "STATUS";
+///     const ADDRESS: u32 = 4096;
+///     const WIDTH: u8 = 16;
+/// }
+/// const MSG: &str = gub::eval_str::<Describe<Status>>();
+/// assert_eq!(MSG, "STATUS@4096 (16-bit, {reset})");
+/// ```
+/// ```compile_fail
+/// # use generic_upper_bound as gub;
+/// struct Missing;
+/// gub::impl_format! {
+///     impl{} Missing;
+///     "{} and {}", 1
+/// }
+/// let _ = gub::eval_str::<Missing>();
+/// ```
+#[cfg(feature = "fmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fmt")))]
+#[macro_export]
+macro_rules! impl_format {
+    {
+        @parsed [$($attrs:tt)*] [$($params:tt)*]
+        [$Self:ty $({ $($where_bounds:tt)* })? $(where $($where_clause:tt)*)?]
+
+        $template:expr $(, $arg:expr)* $(,)?
+    } => {
+        $crate::impl_accept_upper_bound! {
+            $($attrs)*
+            impl{$($params)*} $Self $({ $($where_bounds)* })? $(where $($where_clause)*)?;
+
+            const DESIRED_GENERIC: usize =
+                $crate::fmt::__formatted_len($template, &[$($crate::fmt::__Arg($arg).__len()),*]);
+            const EVAL<const UPPER: usize>: &'static [u8] = &{
+                let template: &str = $template;
+                let w = $crate::build::BytesWriter::<UPPER>::new();
+                let pos = 0;
+                $(
+                    let (w, pos) = $crate::fmt::__write_until_arg(template, pos, w);
+                    let w = $crate::fmt::__Arg($arg).__write(w);
+                )*
+                $crate::fmt::__write_rest(template, pos, w).finish().0
+            };
+        }
+    };
+    {
+        $(#[$meta:meta])*
+        impl{$($params:tt)*} $($rest:tt)*
+    } => {
+        $crate::__impl_header! { [$crate::impl_format] [$(#[$meta])*] [] {$($params)*} $($rest)* }
+    };
+}
+
+/// Fails compilation if the [`overhead`] of an acceptor exceeds the given fraction of its desired
+/// value.
+///
+/// The fraction is passed as `NUM / DEN`, where both are tokens that evaluate to `usize`.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// # #[cfg(not(gub_custom_candidates))]
+/// gub::assert_max_overhead!(Len<37>, 0 / 1);
+/// # #[cfg(not(gub_custom_candidates))]
+/// gub::assert_max_overhead!(Len<4097>, 1 / 2);
+/// ```
+///
+/// ```compile_fail
+/// # use generic_upper_bound as gub;
+/// # struct Len<const N: usize>;
+/// # gub::impl_accept_upper_bound! {
+/// #     impl{const N: usize} Len<N>;
+/// #     const DESIRED_GENERIC: usize = N;
+/// #     const EVAL<const UPPER: usize>: () = ();
+/// # }
+/// gub::assert_max_overhead!(Len<4097>, 1 / 100);
+/// ```
+#[macro_export]
+macro_rules! assert_max_overhead {
+    ($A:ty, $num:tt / $den:tt $(,)?) => {
+        const _: () = {
+            let overhead = $crate::overhead::<$A>() as u128;
+            let desired = $crate::desired_generic::<$A>() as u128;
+            if overhead * ($den as usize as u128) > desired * ($num as usize as u128) {
+                panic!("the overhead of the upper bound exceeds the maximum");
+            }
+        };
+    };
+}
+
+/// Fails compilation if the padded output of an acceptor is larger than the given number of bytes.
+///
+/// The padded size is [`get_upper_bound`] times the size of an element, which is `T` if the
+/// acceptor is followed by `as [T]` and one byte otherwise. See [`fits_output_budget`] for a
+/// version that can be used in other const assertions.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Zeros<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Zeros<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u32] = &[0; UPPER];
+/// }
+/// # #[cfg(not(gub_custom_candidates))]
+/// gub::assert_output_budget!(Zeros<1000> as [u32], 8192);
+/// gub::assert_output_budget!(Zeros<4096>, 4096);
+/// ```
+///
+/// ```compile_fail
+/// # use generic_upper_bound as gub;
+/// # struct Zeros<const N: usize>;
+/// # gub::impl_accept_upper_bound! {
+/// #     impl{const N: usize} Zeros<N>;
+/// #     const DESIRED_GENERIC: usize = N;
+/// #     const EVAL<const UPPER: usize>: &'static [u32] = &[0; UPPER];
+/// # }
+/// gub::assert_output_budget!(Zeros<1025> as [u32], 4096);
+/// ```
+#[macro_export]
+macro_rules! assert_output_budget {
+    ($A:ty $(as [$Elem:ty])?, $budget:expr $(,)?) => {
+        const _: () = {
+            let elem_size = $crate::assert_output_budget!(@size $($Elem)?);
+            if !$crate::fits_output_budget::<$A>(elem_size, $budget) {
+                panic!("the padded output exceeds the budget");
+            }
+        };
+    };
+    (@size) => { 1 };
+    (@size $Elem:ty) => { ::core::mem::size_of::<$Elem>() };
+}
+
+/// Generates a test that prints the upper bound and overhead of each of the given acceptors.
+///
+/// This is a one-line way to keep an eye on how much padded static data the upper bounds add,
+/// e.g. to a firmware image. Each acceptor can be followed by `as [T]` if its output consists of
+/// elements of type `T`, which are used to convert the overhead to bytes. Otherwise, every element
+/// is counted as one byte. The table is printed with [`testing::report_row`], so
+/// run the test with `--nocapture` to see it.
+///
+/// This macro requires the `testing` feature.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Zeros<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Zeros<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u32] = &[0; UPPER];
+/// }
+/// gub::bound_report!(bound_overhead: Zeros<37> as [u32], Zeros<4097> as [u32]);
+/// ```
+///
+/// which prints a table like this when running `cargo test -- --nocapture bound_overhead`:
+///
+/// ```text
+/// acceptor                                      desired        bound  overhead bytes     ratio
+/// my_crate::Zeros<37>                                37           37               0     1.000
+/// my_crate::Zeros<4097>                            4097         6144            8188     1.500
+/// ```
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+#[macro_export]
+macro_rules! bound_report {
+    ($name:ident: $($A:ty $(as [$Elem:ty])?),* $(,)?) => {
+        #[test]
+        fn $name() {
+            ::std::println!("{}", $crate::testing::ReportRow::HEADER);
+            $(
+                ::std::println!(
+                    "{}",
+                    $crate::testing::report_row::<$A>($crate::bound_report!(@size $($Elem)?)),
+                );
+            )*
+        }
+    };
+    (@size) => { 1 };
+    (@size $Elem:ty) => { ::core::mem::size_of::<$Elem>() };
+}
+
+/// Evaluates an expression with a const `N` that is the smallest candidate of
+/// [`Ladder::DEFAULT`] that is at least a runtime length.
+///
+/// This is the runtime counterpart of [`eval_with_upper_bound`]: The expression is expanded once
+/// for every candidate of [`Ladder::DEFAULT`], so it can use `N` as an array length. Candidates
+/// above `max` are replaced by `max`. The expression can refer to local variables, but `max` must
+/// be a constant expression that does not depend on generic parameters, since it is used to
+/// define a `const` item.
+///
+/// # Code size
+/// The expression is expanded and type-checked once per default candidate and once for each of
+/// the 31 slots for values listed in `GUB_CANDIDATES` (see the [`ladder`] module), regardless of
+/// `max` and of whether `GUB_CANDIDATES` is set. Only one of these groups is used. The copies for candidates above the smallest candidate that is at least
+/// `max` are behind a condition that is `false` at compile time, so they are removed by the
+/// optimizer. All other copies are part of the binary. Keep the expression small, e.g. a call to
+/// a function that is generic over `N`.
+///
+/// # Panics
+/// If the length is larger than `max`.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// fn checksum(data: &[u8]) -> u32 {
+///     gub::dispatch_upper_bound!(data.len(), max = 1024, |const N: usize| {
+///         let mut buf = [0u8; N];
+///         buf[..data.len()].copy_from_slice(data);
+///         buf.iter().map(|&b| u32::from(b)).sum()
+///     })
+/// }
+/// assert_eq!(checksum(&[1, 2, 3]), 6);
+/// assert_eq!(checksum(&[1; 1000]), 1000);
+/// ```
+#[macro_export]
+macro_rules! dispatch_upper_bound {
+    ($len:expr, max = $max:expr, |const $N:ident: usize| $body:expr $(,)?) => {
+        $crate::__dispatch_upper_bound! { [$] $len, $max, $N, $body }
+    };
+}
+
+/// Creates an array by evaluating an expression once for each index.
+///
+/// `array_from_const!(|i| EXPR; N)` is an array of type `[T; N]` whose element at index `i` is the
+/// result of `EXPR`, like `core::array::from_fn`, but usable in const contexts such as
+/// [`AcceptUpperBound::Eval`] implementations. `EXPR` is evaluated in ascending order of `i` and
+/// does not need to be [`Copy`]. `array_from_const!(F; N)` is a shorthand for
+/// [`build::from_fn_const::<F, N>()`](crate::build::from_fn_const).
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// /// Not `Copy`.
+/// struct Slot(u32);
+/// struct Slots<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Slots<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: u32 = {
+///         let slots: [Slot; UPPER] = gub::array_from_const!(|i| Slot(i as u32); UPPER);
+///         let mut sum = 0;
+///         let mut i = 0;
+///         while i < N {
+///             sum += slots[i].0;
+///             i += 1;
+///         }
+///         sum
+///     };
+/// }
+/// assert_eq!(gub::eval_with_upper_bound::<Slots<5>>(), 10);
+/// ```
+#[macro_export]
+macro_rules! array_from_const {
+    (|$i:ident| $value:expr; $N:expr $(,)?) => {{
+        let mut b = $crate::build::UninitArrayBuilder::<_, { $N }>::new();
+        let mut i: usize = 0;
+        while i < $N {
+            let $i = i;
+            b = b.push($value);
+            i += 1;
+        }
+        b.into_array()
+    }};
+    ($F:ty; $N:expr $(,)?) => {
+        $crate::build::from_fn_const::<$F, { $N }>()
+    };
+}
+
+/// Invokes a macro with every value that can be a candidate of a [`Ladder`].
+///
+/// `for_each_candidate!(path::to::mac)` expands to `path::to::mac! { 0 1 2 ... }`, where the
+/// values are unsuffixed integer literals of type `usize` in ascending order. This can be used to
+/// write custom dispatchers, e.g. to select a type for each size class.
+///
+/// # Stability
+/// The values are a superset of the candidates of every [`Ladder`], including `usize::MAX`, except
+/// for the refinements that are added by the `ladder-step-1_0625` feature. Which other values are
+/// included, and whether a literal is written in decimal or hexadecimal, is not part of the
+/// stable API: It depends on the target and may change in any release. Use [`Ladder::contains`]
+/// to select the candidates of a specific ladder.
+///
+/// Values listed in the `GUB_CANDIDATES` environment variable (see the [`ladder`] module) are
+/// only known while compiling this crate, so they are not yielded unless they are also default
+/// candidates. Use [`ladder::CANDIDATES`] to get the candidates that are actually used.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// macro_rules! count_default {
+///     ($($n:tt)*) => {
+///         0 $(+ gub::Ladder::DEFAULT.contains($n) as usize)*
+///     };
+/// }
+/// const COUNT: usize = gub::for_each_candidate!(count_default);
+/// # #[cfg(not(any(feature = "ladder-step-1_0625", gub_custom_candidates)))]
+/// assert_eq!(COUNT, gub::ladder::CANDIDATES.len());
+/// ```
+#[macro_export]
+macro_rules! for_each_candidate {
+    ($($mac:tt)*) => {
+        $crate::for_each_size! { $($mac)* }
+    };
+}
+
+// rustfmt does not indent the arms of the nested `macro_rules!` consistently
+#[rustfmt::skip]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dispatch_upper_bound {
+    ([$d:tt] $len:expr, $max:expr, $N:ident, $body:expr) => {{
+        let len: usize = $len;
+        const __MAX: usize = $max;
+        ::core::assert!(
+            len <= __MAX,
+            "the length is larger than the maximum given to `dispatch_upper_bound!`"
+        );
+        // The arms are in ascending order, so the first one that is used and large enough is the
+        // upper bound. They are expanded by several macros, so they cannot break out of a
+        // labeled block around them.
+        let mut __out = ::core::option::Option::None;
+        macro_rules! __arms {
+            ($d($d n:tt)*) => {$d({
+                const __USED: bool =
+                    $crate::__CUSTOM_LEN == 0 && $d n <= $crate::ladder::upper_bound_for(__MAX);
+                #[allow(unused)]
+                const $N: usize = if $d n < __MAX { $d n } else { __MAX };
+                if __USED && __out.is_none() && len <= $N {
+                    __out = ::core::option::Option::Some($body);
+                }
+            })*};
+        }
+        macro_rules! __slots {
+            ($d($d i:tt)*) => {$d({
+                const __USED: bool = $d i < $crate::__CUSTOM_LEN
+                    && $crate::__SLOTS[$d i] <= $crate::ladder::upper_bound_for(__MAX);
+                #[allow(unused)]
+                const $N: usize = if $crate::__SLOTS[$d i] < __MAX {
+                    $crate::__SLOTS[$d i]
+                } else {
+                    __MAX
+                };
+                if __USED && __out.is_none() && len <= $N {
+                    __out = ::core::option::Option::Some($body);
+                }
+            })*};
+        }
+        $crate::for_each_default_size! { __arms }
+        $crate::for_each_slot! { __slots }
+        match __out {
+            ::core::option::Option::Some(out) => out,
+            ::core::option::Option::None => ::core::unreachable!(),
+        }
+    }};
 }
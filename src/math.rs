@@ -0,0 +1,133 @@
+//! Const arithmetic for computing desired values.
+//!
+//! Formulas such as `A::LEN * B::LEN + PAD` are evaluated deep inside generic code, where an
+//! arithmetic overflow results in an error that is hard to trace back. The functions in this
+//! module panic with a message that names the operation (and its operands with the `const_panic`
+//! feature) instead, or saturate if that is preferred.
+//!
+//! ```
+//! use generic_upper_bound as gub;
+//! use gub::math::{checked_add, checked_mul, div_ceil};
+//! struct Grid<const W: usize, const H: usize>;
+//! gub::impl_accept_upper_bound! {
+//!     impl{const W: usize, const H: usize} Grid<W, H>;
+//!
+//!     // one byte per cell, one newline per row and a bitmap of the rows
+//!     const DESIRED_GENERIC: usize = checked_add(checked_mul(W + 1, H), div_ceil(H, 8));
+//!     const EVAL<const UPPER: usize>: usize = UPPER;
+//! }
+//! assert!(gub::eval_with_upper_bound::<Grid<3, 20>>() >= 83);
+//! ```
+//!
+//! ```compile_fail
+//! use generic_upper_bound as gub;
+//! struct Huge<const N: usize>;
+//! gub::impl_accept_upper_bound! {
+//!     impl{const N: usize} Huge<N>;
+//!
+//!     const DESIRED_GENERIC: usize = gub::math::checked_mul(N, N);
+//!     const EVAL<const UPPER: usize>: usize = UPPER;
+//! }
+//! let _ = gub::eval_with_upper_bound::<Huge<{ usize::MAX / 2 }>>();
+//! ```
+
+/// Returns `a + b`.
+///
+/// # Panics
+/// If the result overflows.
+#[track_caller]
+pub const fn checked_add(a: usize, b: usize) -> usize {
+    match a.checked_add(b) {
+        Some(n) => n,
+        None => panic_with!("overflow in addition", "a" = a, "b" = b),
+    }
+}
+
+/// Returns `a - b`.
+///
+/// # Panics
+/// If `b` is larger than `a`.
+#[track_caller]
+pub const fn checked_sub(a: usize, b: usize) -> usize {
+    match a.checked_sub(b) {
+        Some(n) => n,
+        None => panic_with!("overflow in subtraction", "a" = a, "b" = b),
+    }
+}
+
+/// Returns `a * b`.
+///
+/// # Panics
+/// If the result overflows.
+#[track_caller]
+pub const fn checked_mul(a: usize, b: usize) -> usize {
+    match a.checked_mul(b) {
+        Some(n) => n,
+        None => panic_with!("overflow in multiplication", "a" = a, "b" = b),
+    }
+}
+
+/// Returns `a + b`, or `usize::MAX` if the result overflows.
+pub const fn saturating_add(a: usize, b: usize) -> usize {
+    a.saturating_add(b)
+}
+
+/// Returns `a - b`, or `0` if `b` is larger than `a`.
+pub const fn saturating_sub(a: usize, b: usize) -> usize {
+    a.saturating_sub(b)
+}
+
+/// Returns `a * b`, or `usize::MAX` if the result overflows.
+pub const fn saturating_mul(a: usize, b: usize) -> usize {
+    a.saturating_mul(b)
+}
+
+/// Returns `a / b`, rounded up.
+///
+/// # Panics
+/// If `b` is zero.
+///
+/// ```
+/// assert_eq!(generic_upper_bound::math::div_ceil(17, 8), 3);
+/// ```
+#[track_caller]
+pub const fn div_ceil(a: usize, b: usize) -> usize {
+    if b == 0 {
+        panic_with!("division by zero in `div_ceil`", "a" = a);
+    }
+    a.div_ceil(b)
+}
+
+/// Returns the smallest power of two that is at least `n`.
+///
+/// # Panics
+/// If the result is larger than `usize::MAX`.
+///
+/// ```
+/// use generic_upper_bound::math::next_power_of_two;
+/// assert_eq!(next_power_of_two(0), 1);
+/// assert_eq!(next_power_of_two(37), 64);
+/// ```
+#[track_caller]
+pub const fn next_power_of_two(n: usize) -> usize {
+    match n.checked_next_power_of_two() {
+        Some(n) => n,
+        None => panic_with!("overflow in `next_power_of_two`", "n" = n),
+    }
+}
+
+/// Returns the base 2 logarithm of `n`, rounded down.
+///
+/// # Panics
+/// If `n` is zero.
+///
+/// ```
+/// assert_eq!(generic_upper_bound::math::ilog2(37), 5);
+/// ```
+#[track_caller]
+pub const fn ilog2(n: usize) -> u32 {
+    if n == 0 {
+        panic!("the logarithm of zero is undefined");
+    }
+    n.ilog2()
+}
@@ -0,0 +1,377 @@
+//! Const serialization of values into a compact byte format.
+//!
+//! The format is similar to [`postcard`](https://docs.rs/postcard), but integers have a fixed
+//! size:
+//! - `u8` to `u128` and `i8` to `i128` are encoded in little-endian byte order. `usize` and
+//!   `isize` are encoded like `u64` and `i64`, so that the encoding does not depend on the target.
+//! - `bool` is encoded as one byte that is `0` or `1` and `char` like a `u32`.
+//! - Arrays `[T; N]` are encoded as their elements in order.
+//! - `&'static str` and slices `&'static [T]` are encoded as their length as a varint (see
+//!   [`varint_len`]), followed by their bytes or elements.
+//! - Structs that implement [`ConstSerialize`] using [`impl_const_serialize!`](crate::impl_const_serialize)
+//!   are encoded as their fields in order.
+//!
+//! The encoding of a value is computed by the [`Serialize`] acceptor, which uses the exact length
+//! of the encoding as its desired value.
+//!
+//! ```
+//! use generic_upper_bound as gub;
+//! pub struct Entry {
+//!     id: u16,
+//!     name: &'static str,
+//!     flags: [bool; 2],
+//!     children: &'static [u32],
+//! }
+//! gub::impl_const_serialize! {
+//!     Entry { id: u16, name: &'static str, flags: [bool; 2], children: &'static [u32] }
+//! }
+//! pub trait HasId {
+//!     const ID: u16;
+//! }
+//! /// An entry whose contents depend on a generic parameter.
+//! struct EntryOf<T>(T);
+//! impl<T: HasId> gub::Const for EntryOf<T> {
+//!     type Type = Entry;
+//!     const VALUE: Self::Type = Entry {
+//!         id: T::ID,
+//!         name: "abc",
+//!         flags: [true, false],
+//!         children: &[7],
+//!     };
+//! }
+//! impl HasId for () {
+//!     const ID: u16 = 0x1234;
+//! }
+//! assert_eq!(
+//!     gub::serialize::serialize::<EntryOf<()>>(),
+//!     b"\x34\x12\x03abc\x01\x00\x01\x07\x00\x00\x00",
+//! );
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::{build::BytesWriter, eval_trimmed, AcceptUpperBound, Const, Ladder};
+
+/// A type whose values can be encoded by [`Serialize`].
+///
+/// This is implemented for the primitive types and `&'static str`. Implement it for structs
+/// using [`impl_const_serialize!`](crate::impl_const_serialize). See the
+/// [module level documentation](self) for the format.
+pub trait ConstSerialize {
+    #[doc(hidden)]
+    type __Serialize<V: Const<Type = Self>>: AcceptUpperBound<Output = &'static [u8]>;
+}
+
+/// Encodes `V::VALUE` into bytes.
+///
+/// The desired value is the length of the encoding. The output is padded with zeros. Use
+/// [`serialize`] to get the trimmed output.
+pub struct Serialize<V>(V);
+impl<T: ConstSerialize, V: Const<Type = T>> AcceptUpperBound for Serialize<V> {
+    type Output = &'static [u8];
+    const DESIRED_GENERIC: usize = crate::desired_generic::<T::__Serialize<V>>();
+    const LADDER: Ladder = <T::__Serialize<V> as AcceptUpperBound>::LADDER;
+    type Eval<const UPPER: usize> = <T::__Serialize<V> as AcceptUpperBound>::Eval<UPPER>;
+}
+
+/// Returns the encoding of `V::VALUE`.
+pub const fn serialize<V>() -> &'static [u8]
+where
+    V: Const,
+    V::Type: ConstSerialize,
+{
+    eval_trimmed::<Serialize<V>, u8>()
+}
+
+/// Returns the number of bytes of the varint encoding of `n`.
+///
+/// Each byte stores 7 bits of `n`, starting with the least significant ones. The most
+/// significant bit of each byte is set if another byte follows.
+///
+/// ```
+/// use generic_upper_bound::serialize::varint_len;
+/// assert_eq!(varint_len(0), 1);
+/// assert_eq!(varint_len(127), 1);
+/// assert_eq!(varint_len(128), 2);
+/// ```
+pub const fn varint_len(mut n: usize) -> usize {
+    let mut len = 1;
+    while n >= 0x80 {
+        n >>= 7;
+        len += 1;
+    }
+    len
+}
+
+/// Appends the varint encoding of `n` (see [`varint_len`]) to `w`.
+///
+/// # Panics
+/// If the encoding does not fit into the remaining capacity.
+#[track_caller]
+pub const fn write_varint<const CAP: usize>(
+    mut w: BytesWriter<CAP>,
+    mut n: usize,
+) -> BytesWriter<CAP> {
+    while n >= 0x80 {
+        w = w.push(n as u8 | 0x80);
+        n >>= 7;
+    }
+    w.push(n as u8)
+}
+
+/// The encoding of primitive types, used by [`impl_const_serialize!`](crate::impl_const_serialize).
+#[doc(hidden)]
+pub struct __Prim<T: ?Sized>(PhantomData<T>);
+
+macro_rules! impl_prim {
+    ($($T:ty => |$v:ident| $bytes:expr;)*) => {$(
+        impl __Prim<$T> {
+            pub const fn __gub_serialized_len(v: &$T) -> usize {
+                let $v = *v;
+                $bytes.len()
+            }
+            pub const fn __gub_serialize<const CAP: usize>(
+                v: &$T,
+                w: BytesWriter<CAP>,
+            ) -> BytesWriter<CAP> {
+                let $v = *v;
+                w.extend_from_slice(&$bytes)
+            }
+        }
+        impl ConstSerialize for $T {
+            type __Serialize<V: Const<Type = Self>> = SerializePrim<$T, V>;
+        }
+        crate::impl_accept_upper_bound! {
+            impl{V: Const<Type = $T>} SerializePrim<$T, V>;
+
+            const DESIRED_GENERIC: usize = __Prim::<$T>::__gub_serialized_len(&crate::const_value::<V>());
+            const EVAL<const UPPER: usize>: &'static [u8] = &__Prim::<$T>::__gub_serialize(
+                &crate::const_value::<V>(),
+                BytesWriter::<UPPER>::new(),
+            )
+            .finish()
+            .0;
+        }
+    )*};
+}
+impl_prim! {
+    u8 => |v| v.to_le_bytes();
+    u16 => |v| v.to_le_bytes();
+    u32 => |v| v.to_le_bytes();
+    u64 => |v| v.to_le_bytes();
+    u128 => |v| v.to_le_bytes();
+    usize => |v| (v as u64).to_le_bytes();
+    i8 => |v| v.to_le_bytes();
+    i16 => |v| v.to_le_bytes();
+    i32 => |v| v.to_le_bytes();
+    i64 => |v| v.to_le_bytes();
+    i128 => |v| v.to_le_bytes();
+    isize => |v| (v as i64).to_le_bytes();
+    bool => |v| [v as u8];
+    char => |v| (v as u32).to_le_bytes();
+}
+
+impl __Prim<&'static str> {
+    pub const fn __gub_serialized_len(v: &&'static str) -> usize {
+        varint_len(v.len()) + v.len()
+    }
+    pub const fn __gub_serialize<const CAP: usize>(
+        v: &&'static str,
+        w: BytesWriter<CAP>,
+    ) -> BytesWriter<CAP> {
+        write_varint(w, v.len()).write_str(v)
+    }
+}
+impl ConstSerialize for &'static str {
+    type __Serialize<V: Const<Type = Self>> = SerializePrim<&'static str, V>;
+}
+crate::impl_accept_upper_bound! {
+    impl{V: Const<Type = &'static str>} SerializePrim<&'static str, V>;
+
+    const DESIRED_GENERIC: usize =
+        __Prim::<&'static str>::__gub_serialized_len(&crate::const_value::<V>());
+    const EVAL<const UPPER: usize>: &'static [u8] = &__Prim::<&'static str>::__gub_serialize(
+        &crate::const_value::<V>(),
+        BytesWriter::<UPPER>::new(),
+    )
+    .finish()
+    .0;
+}
+
+/// The implementation of [`Serialize`] for the primitive types.
+#[doc(hidden)]
+pub struct SerializePrim<T, V>(PhantomData<T>, V);
+
+/// Implements [`ConstSerialize`](crate::serialize::ConstSerialize) for a struct.
+///
+/// The fields to encode are listed with their types, which must be primitive types,
+/// `&'static str`, arrays `[T; N]` and slices `&'static [T]` thereof, or other types that
+/// implement `ConstSerialize` using this macro. The element type of an array must be a single
+/// token tree, e.g. `u8` or `(Point)`. The struct cannot be generic.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// pub struct Point {
+///     x: i8,
+///     y: i8,
+/// }
+/// gub::impl_const_serialize! { Point { x: i8, y: i8 } }
+/// pub struct Shape {
+///     name: &'static str,
+///     corners: &'static [Point],
+///     tags: [&'static str; 2],
+///     origin: Point,
+/// }
+/// gub::impl_const_serialize! {
+///     Shape {
+///         name: &'static str,
+///         corners: &'static [Point],
+///         tags: [(&'static str); 2],
+///         origin: Point,
+///     }
+/// }
+/// struct Line;
+/// impl gub::Const for Line {
+///     type Type = Shape;
+///     const VALUE: Self::Type = Shape {
+///         name: "l",
+///         corners: &[Point { x: 1, y: 2 }, Point { x: 3, y: -1 }],
+///         tags: ["a", ""],
+///         origin: Point { x: 0, y: 5 },
+///     };
+/// }
+/// assert_eq!(
+///     gub::serialize::serialize::<Line>(),
+///     b"\x01l\x02\x01\x02\x03\xff\x01a\x00\x00\x05",
+/// );
+/// ```
+#[macro_export]
+macro_rules! impl_const_serialize {
+    ($Self:ident { $($fields:tt)* }) => {
+        $crate::impl_const_serialize! { @munch $Self [] [] $($fields)* }
+    };
+    (@munch $Self:ident [$($done:tt)*] [$($cur:tt)*] , $($rest:tt)*) => {
+        $crate::impl_const_serialize! { @munch $Self [$($done)* [$($cur)*]] [] $($rest)* }
+    };
+    (@munch $Self:ident [$($done:tt)*] [$($cur:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::impl_const_serialize! { @munch $Self [$($done)*] [$($cur)* $t] $($rest)* }
+    };
+    (@munch $Self:ident [$($done:tt)*] []) => {
+        $crate::impl_const_serialize! { @impl $Self $($done)* }
+    };
+    (@munch $Self:ident [$($done:tt)*] [$($cur:tt)+]) => {
+        $crate::impl_const_serialize! { @impl $Self $($done)* [$($cur)+] }
+    };
+    (@impl $Self:ident $([$field:ident : $($T:tt)+])*) => {
+        impl $Self {
+            #[doc(hidden)]
+            pub const fn __gub_serialized_len(&self) -> usize {
+                0 $(+ $crate::__serialize_field!(@len self.$field; $($T)+))*
+            }
+            #[doc(hidden)]
+            pub const fn __gub_serialize<const CAP: usize>(
+                &self,
+                w: $crate::build::BytesWriter<CAP>,
+            ) -> $crate::build::BytesWriter<CAP> {
+                $(let w = $crate::__serialize_field!(@write w, self.$field; $($T)+);)*
+                w
+            }
+        }
+        const _: () = {
+            pub struct __Serialize<V>(V);
+            $crate::impl_accept_upper_bound! {
+                impl{V: $crate::Const<Type = $Self>} __Serialize<V>;
+
+                const DESIRED_GENERIC: usize =
+                    $Self::__gub_serialized_len(&$crate::const_value::<V>());
+                const EVAL<const UPPER: usize>: &'static [u8] = &$Self::__gub_serialize(
+                    &$crate::const_value::<V>(),
+                    $crate::build::BytesWriter::<UPPER>::new(),
+                )
+                .finish()
+                .0;
+            }
+            impl $crate::serialize::ConstSerialize for $Self {
+                type __Serialize<V: $crate::Const<Type = Self>> = __Serialize<V>;
+            }
+        };
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __serialize_field {
+    (@len $v:expr; [$T:tt; $N:expr]) => {{
+        let items = &$v;
+        let mut len = 0;
+        let mut i = 0;
+        while i < items.len() {
+            len += $crate::__serialize_field!(@len items[i]; $T);
+            i += 1;
+        }
+        len
+    }};
+    (@len $v:expr; &'static [$($T:tt)+]) => {{
+        let items: &[_] = $v;
+        let mut len = $crate::serialize::varint_len(items.len());
+        let mut i = 0;
+        while i < items.len() {
+            len += $crate::__serialize_field!(@len items[i]; $($T)+);
+            i += 1;
+        }
+        len
+    }};
+    (@len $v:expr; ($($T:tt)+)) => {
+        $crate::__serialize_field!(@len $v; $($T)+)
+    };
+    (@len $v:expr; $($T:tt)+) => {
+        <$crate::__serialize_type!($($T)+)>::__gub_serialized_len(&$v)
+    };
+    (@write $w:ident, $v:expr; [$T:tt; $N:expr]) => {{
+        let items = &$v;
+        let mut w = $w;
+        let mut i = 0;
+        while i < items.len() {
+            w = $crate::__serialize_field!(@write w, items[i]; $T);
+            i += 1;
+        }
+        w
+    }};
+    (@write $w:ident, $v:expr; &'static [$($T:tt)+]) => {{
+        let items: &[_] = $v;
+        let mut w = $crate::serialize::write_varint($w, items.len());
+        let mut i = 0;
+        while i < items.len() {
+            w = $crate::__serialize_field!(@write w, items[i]; $($T)+);
+            i += 1;
+        }
+        w
+    }};
+    (@write $w:ident, $v:expr; ($($T:tt)+)) => {
+        $crate::__serialize_field!(@write $w, $v; $($T)+)
+    };
+    (@write $w:ident, $v:expr; $($T:tt)+) => {
+        <$crate::__serialize_type!($($T)+)>::__gub_serialize(&$v, $w)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __serialize_type {
+    (u8) => { $crate::serialize::__Prim<u8> };
+    (u16) => { $crate::serialize::__Prim<u16> };
+    (u32) => { $crate::serialize::__Prim<u32> };
+    (u64) => { $crate::serialize::__Prim<u64> };
+    (u128) => { $crate::serialize::__Prim<u128> };
+    (usize) => { $crate::serialize::__Prim<usize> };
+    (i8) => { $crate::serialize::__Prim<i8> };
+    (i16) => { $crate::serialize::__Prim<i16> };
+    (i32) => { $crate::serialize::__Prim<i32> };
+    (i64) => { $crate::serialize::__Prim<i64> };
+    (i128) => { $crate::serialize::__Prim<i128> };
+    (isize) => { $crate::serialize::__Prim<isize> };
+    (bool) => { $crate::serialize::__Prim<bool> };
+    (char) => { $crate::serialize::__Prim<char> };
+    (&'static str) => { $crate::serialize::__Prim<&'static str> };
+    ($T:ty) => { $T };
+}
@@ -0,0 +1,211 @@
+//! Const slice functions for consuming the outputs of acceptors.
+//!
+//! The methods of slices that search for elements are not `const fn` on the MSRV, since they rely
+//! on trait methods such as [`PartialEq::eq`]. These functions are implemented for bytes and for
+//! tables with `&str` or `usize` keys, which are the outputs of [`sort_bytes`],
+//! [`dedup_sorted`] and similar adapters.
+//!
+//! [`sort_bytes`]: crate::adapters::sort_bytes
+//! [`dedup_sorted`]: crate::adapters::dedup_sorted
+//!
+//! ```
+//! use generic_upper_bound as gub;
+//! gub::const_bytes! {
+//!     struct Chars = b"zyx wv";
+//! }
+//! const SORTED: &[u8] = gub::adapters::sort_bytes::<Chars>();
+//! // a lookup that is itself usable in const contexts
+//! const HAS_SPACE: bool = gub::slice::binary_search(SORTED, b' ').is_ok();
+//! const _: () = assert!(HAS_SPACE);
+//! assert_eq!(gub::slice::binary_search(SORTED, b'x'), Ok(3));
+//! ```
+
+use core::cmp::Ordering;
+
+/// Returns the index of the first occurrence of `byte` in `bytes`.
+///
+/// ```
+/// use generic_upper_bound::slice::position;
+/// assert_eq!(position(b"a.b.c", b'.'), Some(1));
+/// assert_eq!(position(b"abc", b'.'), None);
+/// ```
+pub const fn position(bytes: &[u8], byte: u8) -> Option<usize> {
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == byte {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Returns whether `bytes` contains `byte`.
+///
+/// ```
+/// use generic_upper_bound::slice::contains;
+/// assert!(contains(b"abc", b'b'));
+/// assert!(!contains(b"abc", b'd'));
+/// ```
+pub const fn contains(bytes: &[u8], byte: u8) -> bool {
+    position(bytes, byte).is_some()
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack`.
+///
+/// An empty `needle` is found at index `0`.
+///
+/// ```
+/// use generic_upper_bound::slice::find;
+/// assert_eq!(find(b"key=value", b"="), Some(3));
+/// assert_eq!(find(b"abcabd", b"abd"), Some(3));
+/// assert_eq!(find(b"abc", b"abcd"), None);
+/// ```
+pub const fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i + needle.len() <= haystack.len() {
+        let mut j = 0;
+        while j < needle.len() && haystack[i + j] == needle[j] {
+            j += 1;
+        }
+        if j == needle.len() {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Searches the sorted `bytes` for `byte` using binary search.
+///
+/// Like [`slice::binary_search`](prim@slice#method.binary_search), this returns `Ok` with the
+/// index of a matching element, or `Err` with the index where `byte` could be inserted while
+/// keeping the slice sorted. If `bytes` is not sorted, the result is unspecified.
+///
+/// ```
+/// use generic_upper_bound::slice::binary_search;
+/// assert_eq!(binary_search(b"aceg", b'e'), Ok(2));
+/// assert_eq!(binary_search(b"aceg", b'd'), Err(2));
+/// ```
+pub const fn binary_search(bytes: &[u8], byte: u8) -> Result<usize, usize> {
+    let (mut lo, mut hi) = (0, bytes.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if bytes[mid] < byte {
+            lo = mid + 1;
+        } else if bytes[mid] > byte {
+            hi = mid;
+        } else {
+            return Ok(mid);
+        }
+    }
+    Err(lo)
+}
+
+/// Searches `table`, which is sorted by its `&str` keys, for `key` using binary search.
+///
+/// See [`binary_search`] for the meaning of the result. The keys are compared using
+/// [`compare`].
+///
+/// ```
+/// use generic_upper_bound::slice::binary_search_by_str_key;
+/// const PORTS: &[(&str, u16)] = &[("http", 80), ("https", 443), ("ssh", 22)];
+/// const HTTPS: u16 = match binary_search_by_str_key(PORTS, "https") {
+///     Ok(i) => PORTS[i].1,
+///     Err(_) => panic!("unknown scheme"),
+/// };
+/// assert_eq!(HTTPS, 443);
+/// assert_eq!(binary_search_by_str_key(PORTS, "ftp"), Err(0));
+/// ```
+pub const fn binary_search_by_str_key<V>(table: &[(&str, V)], key: &str) -> Result<usize, usize> {
+    let (mut lo, mut hi) = (0, table.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match compare(table[mid].0.as_bytes(), key.as_bytes()) {
+            Ordering::Less => lo = mid + 1,
+            Ordering::Equal => return Ok(mid),
+            Ordering::Greater => hi = mid,
+        }
+    }
+    Err(lo)
+}
+
+/// Searches `table`, which is sorted by its `usize` keys, for `key` using binary search.
+///
+/// See [`binary_search`] for the meaning of the result.
+///
+/// ```
+/// use generic_upper_bound::slice::binary_search_by_usize_key;
+/// const SIZES: &[(usize, &str)] = &[(1, "byte"), (2, "half"), (4, "word")];
+/// assert_eq!(binary_search_by_usize_key(SIZES, 4), Ok(2));
+/// assert_eq!(binary_search_by_usize_key(SIZES, 3), Err(2));
+/// ```
+pub const fn binary_search_by_usize_key<V>(
+    table: &[(usize, V)],
+    key: usize,
+) -> Result<usize, usize> {
+    let (mut lo, mut hi) = (0, table.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if table[mid].0 < key {
+            lo = mid + 1;
+        } else if table[mid].0 > key {
+            hi = mid;
+        } else {
+            return Ok(mid);
+        }
+    }
+    Err(lo)
+}
+
+/// Returns whether `bytes` is sorted in ascending order.
+///
+/// This can be used to check the input of [`binary_search`] in a const assertion.
+///
+/// ```
+/// use generic_upper_bound::slice::is_sorted;
+/// assert!(is_sorted(b"aabc"));
+/// assert!(!is_sorted(b"acb"));
+/// ```
+pub const fn is_sorted(bytes: &[u8]) -> bool {
+    let mut i = 1;
+    while i < bytes.len() {
+        if bytes[i - 1] > bytes[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Compares `a` and `b` lexicographically, like [`Ord::cmp`].
+///
+/// Comparing the bytes of strings yields the same order as comparing the strings.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use generic_upper_bound::slice::compare;
+/// assert_eq!(compare(b"abc", b"abd"), Ordering::Less);
+/// assert_eq!(compare(b"ab", b"a"), Ordering::Greater);
+/// assert_eq!(compare(b"", b""), Ordering::Equal);
+/// ```
+pub const fn compare(a: &[u8], b: &[u8]) -> Ordering {
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return if a[i] < b[i] {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        i += 1;
+    }
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
@@ -0,0 +1,133 @@
+//! Const string functions for computing desired values.
+//!
+//! ```
+//! use generic_upper_bound as gub;
+//! struct Lines<L>(L);
+//! gub::impl_accept_upper_bound! {
+//!     impl{L: gub::Const<Type = &'static [&'static str]>} Lines<L>;
+//!
+//!     // every string followed by a newline
+//!     const DESIRED_GENERIC: usize =
+//!         gub::str::total_len(gub::const_value::<L>()) + gub::const_value::<L>().len();
+//!     const EVAL<const UPPER: usize>: &'static [u8] = &{
+//!         let strs = gub::const_value::<L>();
+//!         let mut w = gub::build::BytesWriter::<UPPER>::new();
+//!         let mut i = 0;
+//!         while i < strs.len() {
+//!             w = w.write_str(strs[i]).push(b'\n');
+//!             i += 1;
+//!         }
+//!         w.finish().0
+//!     };
+//! }
+//! struct Poem;
+//! impl gub::Const for Poem {
+//!     type Type = &'static [&'static str];
+//!     const VALUE: Self::Type = &["roses", "violets"];
+//! }
+//! assert_eq!(gub::eval_str::<Lines<Poem>>(), "roses\nviolets\n");
+//! ```
+
+/// Returns the sum of the lengths of `strs` in bytes.
+///
+/// ```
+/// assert_eq!(generic_upper_bound::str::total_len(&["ab", "", "cde"]), 5);
+/// ```
+pub const fn total_len(strs: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < strs.len() {
+        len += strs[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// Returns the maximum of the lengths of `strs` in bytes, or `0` if `strs` is empty.
+///
+/// ```
+/// assert_eq!(generic_upper_bound::str::max_len(&["ab", "", "cde"]), 3);
+/// ```
+pub const fn max_len(strs: &[&str]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < strs.len() {
+        if strs[i].len() > max {
+            max = strs[i].len();
+        }
+        i += 1;
+    }
+    max
+}
+
+/// Returns the number of occurrences of `c` in `s`.
+///
+/// ```
+/// use generic_upper_bound::str::count_char_occurrences;
+/// assert_eq!(count_char_occurrences("a.b.c", '.'), 2);
+/// assert_eq!(count_char_occurrences("Grüße, Jürgen", 'ü'), 2);
+/// ```
+pub const fn count_char_occurrences(s: &str, c: char) -> usize {
+    let (encoded, len) = encode_utf8(c);
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    // since UTF-8 is self-synchronizing, every match is at a char boundary
+    while i + len <= bytes.len() {
+        let mut j = 0;
+        while j < len && bytes[i + j] == encoded[j] {
+            j += 1;
+        }
+        if j == len {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Returns the number of chars in `s`, i.e. `s.chars().count()`.
+///
+/// ```
+/// assert_eq!(generic_upper_bound::str::utf8_char_count("Grüße"), 5);
+/// ```
+pub const fn utf8_char_count(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // count all bytes except for continuation bytes
+        if bytes[i] & 0xc0 != 0x80 {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Returns the UTF-8 encoding of `c` in the first `len` bytes of the array.
+pub(crate) const fn encode_utf8(c: char) -> ([u8; 4], usize) {
+    let c = c as u32;
+    match c {
+        0..=0x7f => ([c as u8, 0, 0, 0], 1),
+        0x80..=0x7ff => ([0xc0 | (c >> 6) as u8, 0x80 | (c & 0x3f) as u8, 0, 0], 2),
+        0x800..=0xffff => (
+            [
+                0xe0 | (c >> 12) as u8,
+                0x80 | (c >> 6 & 0x3f) as u8,
+                0x80 | (c & 0x3f) as u8,
+                0,
+            ],
+            3,
+        ),
+        _ => (
+            [
+                0xf0 | (c >> 18) as u8,
+                0x80 | (c >> 12 & 0x3f) as u8,
+                0x80 | (c >> 6 & 0x3f) as u8,
+                0x80 | (c & 0x3f) as u8,
+            ],
+            4,
+        ),
+    }
+}
@@ -0,0 +1,185 @@
+//! Checks for the contract of [`AcceptUpperBound::Eval`].
+//!
+//! The output of an acceptor should not depend on which upper bound is passed to it. The functions
+//! in this module check this by evaluating the acceptor at [`get_upper_bound`] and at the next
+//! larger candidate of its [ladder](AcceptUpperBound::LADDER), and comparing the results.
+//!
+//! These are meant to be called from tests. Note that passing them does not prove that the
+//! acceptor is correct, since only two of the possible bounds are compared.
+//!
+//! [`bound_report!`](crate::bound_report) generates a test that prints how much padding the upper
+//! bounds of a list of acceptors add, using [`report_row`].
+
+use core::fmt::{self, Debug, Display};
+
+use crate::{
+    bound_info, desired_generic, eval_trimmed, eval_with_upper_bound, get_upper_bound,
+    AcceptUpperBound, BoundInfo, Ladder,
+};
+
+/// Evaluates `A` at the smallest candidate of its ladder that is larger than its actual bound.
+struct NextCandidate<A>(A);
+impl<A: AcceptUpperBound> AcceptUpperBound for NextCandidate<A> {
+    type Output = A::Output;
+    const DESIRED_GENERIC: usize = get_upper_bound::<A>().saturating_add(1);
+    const LADDER: Ladder = A::LADDER;
+    type Eval<const UPPER: usize> = A::Eval<UPPER>;
+}
+
+/// Asserts that `A` has the same output for its actual upper bound and the next larger candidate.
+///
+/// # Panics
+/// If the outputs are not equal.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct SumOfSquares<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} SumOfSquares<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: u64 = {
+///         let mut squares = [0; UPPER];
+///         let (mut i, mut sum) = (0, 0);
+///         while i < N {
+///             squares[i] = (i * i) as u64;
+///             sum += squares[i];
+///             i += 1;
+///         }
+///         sum
+///     };
+/// }
+/// gub::testing::assert_consistent::<SumOfSquares<100>>();
+/// ```
+///
+/// An acceptor that accidentally returns `UPPER` is caught:
+/// ```should_panic
+/// use generic_upper_bound as gub;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: usize = [1; UPPER].len();
+/// }
+/// gub::testing::assert_consistent::<Len<100>>();
+/// ```
+#[track_caller]
+pub fn assert_consistent<A>()
+where
+    A: AcceptUpperBound,
+    A::Output: PartialEq + Debug,
+{
+    assert_eq!(
+        eval_with_upper_bound::<A>(),
+        eval_with_upper_bound::<NextCandidate<A>>(),
+        "output of `{}` depends on the upper bound ({} vs {})",
+        core::any::type_name::<A>(),
+        get_upper_bound::<A>(),
+        get_upper_bound::<NextCandidate<A>>(),
+    );
+}
+
+/// Like [`assert_consistent`], but compares the outputs after trimming them to
+/// [`desired_generic`] elements like [`eval_trimmed`].
+///
+/// This is useful for acceptors whose output is padded to the length of the upper bound.
+///
+/// # Panics
+/// If the trimmed outputs are not equal or if the output at the next candidate is too short.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Zeros<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Zeros<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u8] = &[0; UPPER];
+/// }
+/// gub::testing::assert_consistent_trimmed::<Zeros<100>, u8>();
+/// ```
+#[track_caller]
+pub fn assert_consistent_trimmed<A, T>()
+where
+    A: AcceptUpperBound<Output = &'static [T]>,
+    T: PartialEq + Debug + 'static,
+{
+    let next = eval_with_upper_bound::<NextCandidate<A>>();
+    assert_eq!(
+        eval_trimmed::<A, T>(),
+        &next[..desired_generic::<A>()],
+        "trimmed output of `{}` depends on the upper bound ({} vs {})",
+        core::any::type_name::<A>(),
+        get_upper_bound::<A>(),
+        get_upper_bound::<NextCandidate<A>>(),
+    );
+}
+
+/// A line of the table printed by [`bound_report!`](crate::bound_report), as returned by
+/// [`report_row`].
+///
+/// The [`Display`] implementation prints the name of the acceptor, its desired value, its upper
+/// bound, the overhead in bytes and the ratio of the upper bound to the desired value, aligned
+/// with [`ReportRow::HEADER`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReportRow {
+    /// The name of the acceptor.
+    pub name: &'static str,
+    /// The upper bound of the acceptor, see [`bound_info`].
+    pub info: BoundInfo,
+    /// The [`overhead`](crate::overhead) multiplied by the size of an element.
+    pub overhead_bytes: usize,
+    /// `info.actual / info.desired`, or `1.0` if both are zero.
+    pub ratio: f64,
+}
+
+impl ReportRow {
+    /// The column headers of the table.
+    pub const HEADER: &'static str =
+        "acceptor                                      desired        bound  overhead bytes     ratio";
+}
+
+impl Display for ReportRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<40} {:>12} {:>12} {:>15} {:>9.3}",
+            self.name, self.info.desired, self.info.actual, self.overhead_bytes, self.ratio,
+        )
+    }
+}
+
+/// Returns the line of [`bound_report!`](crate::bound_report) for `A`, whose output consists of
+/// up to `UPPER` elements of `elem_size` bytes each.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// struct Zeros<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Zeros<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: &'static [u32] = &[0; UPPER];
+/// }
+/// let row = gub::testing::report_row::<Zeros<4097>>(4);
+/// assert_eq!(row.overhead_bytes, 4 * gub::overhead::<Zeros<4097>>());
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert!(row.ratio > 1.0 && row.ratio < 1.5);
+/// assert!(row.to_string().contains("4097"));
+/// # #[cfg(not(gub_custom_candidates))]
+/// assert_eq!(gub::testing::report_row::<Zeros<37>>(4).ratio, 1.0);
+/// ```
+pub fn report_row<A: AcceptUpperBound>(elem_size: usize) -> ReportRow {
+    let info = bound_info::<A>();
+    ReportRow {
+        name: core::any::type_name::<A>(),
+        info,
+        overhead_bytes: info.overhead.saturating_mul(elem_size),
+        ratio: if info.desired == 0 {
+            if info.actual == 0 {
+                1.0
+            } else {
+                f64::INFINITY
+            }
+        } else {
+            info.actual as f64 / info.desired as f64
+        },
+    }
+}
@@ -0,0 +1,65 @@
+//! Interop with the [`typenum`](::typenum) crate.
+//!
+//! Type-level integers can be used as desired values through [`UnsignedConst`] or directly
+//! through [`Unsigned::USIZE`]. In the other direction, every candidate of the
+//! [ladder](crate::ladder) can be converted to a type-level integer using [`UIntOf`].
+//!
+//! Note that a generic bound such as `UIntOf<{ get_upper_bound::<A>() }>` requires the unstable
+//! `generic_const_exprs` feature if `A` is generic, since it is a const expression.
+
+use ::typenum::Unsigned;
+
+use crate::Const;
+
+/// A [`Const`] with the value of the type-level integer `U`.
+///
+/// This allows passing type-level integers to the [adapters](crate::adapters).
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::typenum::UnsignedConst;
+/// use typenum::U3;
+/// struct Dash;
+/// impl gub::Const for Dash {
+///     type Type = &'static str;
+///     const VALUE: Self::Type = "-";
+/// }
+/// assert_eq!(gub::adapters::repeat_str::<Dash, UnsignedConst<U3>>(), "---");
+/// ```
+pub struct UnsignedConst<U>(U);
+impl<U: Unsigned> Const for UnsignedConst<U> {
+    type Type = usize;
+    const VALUE: Self::Type = U::USIZE;
+}
+
+/// Marker type for a candidate `N`.
+///
+/// [`ToUInt`] is implemented for every value that can be returned by
+/// [`get_upper_bound`](crate::get_upper_bound) for some ladder, i.e. the candidates described
+/// by [`Ladder`](crate::Ladder), except for the refinements of `usize::MAX` with the
+/// `ladder-step-1_0625` feature.
+pub struct Candidate<const N: usize>;
+
+/// Converts a [`Candidate`] to a type-level integer.
+pub trait ToUInt {
+    /// The type-level integer with the value of the candidate.
+    type Output: Unsigned;
+}
+
+/// The type-level integer with the value `N`, which must be a candidate.
+///
+/// ```
+/// use generic_upper_bound as gub;
+/// use gub::typenum::UIntOf;
+/// use typenum::Unsigned;
+/// struct Len<const N: usize>;
+/// gub::impl_accept_upper_bound! {
+///     impl{const N: usize} Len<N>;
+///     const DESIRED_GENERIC: usize = N;
+///     const EVAL<const UPPER: usize>: () = ();
+/// }
+/// type Bound = UIntOf<{ gub::get_upper_bound::<Len<1000>>() }>;
+/// assert_eq!(Bound::USIZE, gub::get_upper_bound::<Len<1000>>());
+/// assert_eq!(<UIntOf<{ usize::MAX }>>::USIZE, usize::MAX);
+/// ```
+pub type UIntOf<const N: usize> = <Candidate<N> as ToUInt>::Output;